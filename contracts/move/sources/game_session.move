@@ -11,6 +11,7 @@
 /// - Optimistic gameplay with challenge period for verification
 /// - Multiple action logs stored via Dynamic Object Fields during challenge period
 /// - Events emitted for all state transitions
+/// - RNG seed committed (salted) at creation, revealed and checked at finalization
 ///
 /// # Challenge-Based Verification
 /// - Players submit ZK proofs with action logs published to Walrus
@@ -20,9 +21,12 @@
 /// - Old action logs can be cleaned up after challenge period expires
 /// - Enables trustless verification without authority signatures
 module dungeon::game_session {
+    use std::bcs;
+    use std::hash;
     use sui::event;
     use sui::dynamic_object_field as dof;
     use walrus::blob::Blob;
+    use dungeon::proof_verifier::{Self, VerifierRegistry, VerifyingKey};
 
     // ===== Error Codes =====
 
@@ -36,6 +40,12 @@ module dungeon::game_session {
     const EChallengeNotExpired: u64 = 4;
     /// Action logs must be cleaned up before finalization
     const EActionLogsRemaining: u64 = 5;
+    /// Revealed seed and salt do not match the seed commitment
+    const ESeedMismatch: u64 = 6;
+    /// Aggregate proof does not continue the session or cover the submitted batches
+    const EAggregateMismatch: u64 = 7;
+    /// Verifying key is not the one pinned in the verifier registry
+    const EUnknownVerifyingKey: u64 = 8;
 
     // ===== Constants =====
 
@@ -43,6 +53,10 @@ module dungeon::game_session {
     /// After this period, action logs can be cleaned up
     const CHALLENGE_PERIOD_EPOCHS: u64 = 7 * 24 * 60; // ~7 days (assuming 1 epoch = 1 minute)
 
+    /// Domain tag of the seed commitment preimage
+    /// Must match game_core::SEED_COMMITMENT_DOMAIN
+    const SEED_COMMITMENT_DOMAIN: vector<u8> = b"dungeon.seed_commitment.v1";
+
     // ===== Structs =====
 
     /// Represents an active game session
@@ -56,7 +70,9 @@ module dungeon::game_session {
         oracle_root: vector<u8>,
         /// Initial state root at game start
         initial_state_root: vector<u8>,
-        /// Seed commitment for RNG fairness
+        /// Salted seed commitment for RNG fairness
+        /// SHA-256(SEED_COMMITMENT_DOMAIN || reveal_salt || game_seed as u64 LE),
+        /// where reveal_salt is derived from the private session salt
         seed_commitment: vector<u8>,
 
         // Mutable state (updated by proofs)
@@ -105,10 +121,15 @@ module dungeon::game_session {
     }
 
     /// Emitted when a game session is finalized
+    ///
+    /// Carries the revealed seed and reveal salt so anyone can audit the RNG
+    /// by replaying the session's action logs.
     public struct SessionFinalizedEvent has copy, drop {
         session_id: address,
         final_state_root: vector<u8>,
         final_nonce: u64,
+        game_seed: u64,
+        seed_salt: vector<u8>,
         finalized_at: u64,
     }
 
@@ -130,7 +151,7 @@ module dungeon::game_session {
     /// # Arguments
     /// * `oracle_root` - Content hash of oracle data (maps, items, NPCs, etc.)
     /// * `initial_state_root` - Merkle root of initial game state
-    /// * `seed_commitment` - Commitment to RNG seed (hash of seed)
+    /// * `seed_commitment` - Salted commitment to RNG seed (see `compute_seed_commitment`)
     /// * `ctx` - Transaction context
     ///
     /// # Events
@@ -262,6 +283,133 @@ module dungeon::game_session {
         });
     }
 
+    /// Update session state with one aggregate proof covering several batches
+    ///
+    /// Finalizes a run of consecutive batches in a single transaction. The
+    /// aggregate proof's journal spans from the session's current state root to
+    /// the state after the last batch; each batch's action log is stored
+    /// separately, keyed by the batch's end nonce and with the batch's own start
+    /// state root, exactly as `update` would have stored it.
+    ///
+    /// The batch journals must chain from the session's state root to the
+    /// aggregate's new state root. They are only checked for that linkage.
+    ///
+    /// TODO: Bind the batch journals and blobs to the aggregate's actions_root,
+    /// which commits to the per-batch actions roots (see
+    /// zk::compute_aggregate_actions_root), once `update` binds its single blob.
+    ///
+    /// If the session was previously finalized, this will unfinalize it
+    /// (since new action logs require a new challenge period).
+    ///
+    /// # Arguments
+    /// * `session` - Mutable reference to GameSession
+    /// * `registry` - Verifier registry pinning the aggregation key
+    /// * `vk` - Verifying key of the aggregation program
+    /// * `proof` - Groth16 proof bytes of the aggregate
+    /// * `journal_bytes` - Aggregate journal (168 bytes)
+    /// * `batch_journals` - Journal of every batch (168 bytes each), in nonce order
+    /// * `actions_blobs` - Walrus blob of every batch, in the same order
+    /// * `ctx` - Transaction context (for sender and epoch)
+    ///
+    /// # Aborts
+    /// * `ENotOwner` - If caller is not the session owner
+    /// * `EUnknownVerifyingKey` - If `vk` is not the pinned aggregation key
+    /// * `EAggregateMismatch` - If the journal does not continue the session,
+    ///   or the batch journals do not chain from the session to the journal
+    /// * `proof_verifier` errors - If the proof or a journal is invalid
+    ///
+    /// # Events
+    /// Emits one `ActionLogPublishedEvent` per batch and one `SessionUpdatedEvent`
+    public fun update_aggregate(
+        session: &mut GameSession,
+        registry: &VerifierRegistry,
+        vk: &VerifyingKey,
+        proof: vector<u8>,
+        journal_bytes: vector<u8>,
+        batch_journals: vector<vector<u8>>,
+        mut actions_blobs: vector<Blob>,
+        ctx: &mut TxContext,
+    ) {
+        // Validate ownership
+        assert!(tx_context::sender(ctx) == session.player, ENotOwner);
+
+        let count = vector::length(&batch_journals);
+        assert!(count > 0 && count == vector::length(&actions_blobs), EAggregateMismatch);
+
+        // Verify the aggregate proof against its journal, with the pinned key only
+        assert!(proof_verifier::is_aggregate_verifying_key(registry, vk), EUnknownVerifyingKey);
+        let journal = proof_verifier::parse_journal_bytes(journal_bytes);
+        let journal = proof_verifier::verify_game_proof(
+            vk,
+            hash::sha2_256(journal_bytes),
+            &journal,
+            proof,
+        );
+
+        // The aggregate must continue this session
+        assert!(*proof_verifier::oracle_root(&journal) == session.oracle_root, EAggregateMismatch);
+        assert!(*proof_verifier::seed_commitment(&journal) == session.seed_commitment, EAggregateMismatch);
+        assert!(*proof_verifier::prev_state_root(&journal) == session.state_root, EAggregateMismatch);
+
+        let new_state_root = *proof_verifier::new_state_root(&journal);
+        let new_nonce = proof_verifier::new_nonce(&journal);
+
+        // Store every batch's action log under its own start state root,
+        // checking that the batches chain from the session to the aggregate
+        let mut start_state_root = session.state_root;
+        let mut previous = session.nonce;
+        vector::reverse(&mut actions_blobs);
+        let mut i = 0;
+        while (i < count) {
+            let batch = proof_verifier::parse_journal_bytes(*vector::borrow(&batch_journals, i));
+            assert!(*proof_verifier::oracle_root(&batch) == session.oracle_root, EAggregateMismatch);
+            assert!(*proof_verifier::seed_commitment(&batch) == session.seed_commitment, EAggregateMismatch);
+            assert!(*proof_verifier::prev_state_root(&batch) == start_state_root, EAggregateMismatch);
+            let nonce = proof_verifier::new_nonce(&batch);
+            assert!(nonce > previous, EAggregateMismatch);
+
+            let actions_blob = vector::pop_back(&mut actions_blobs);
+            let blob_id = walrus::blob::blob_id(&actions_blob);
+            let action_log = ActionLogBlob {
+                id: object::new(ctx),
+                blob: actions_blob,
+                submitted_at: tx_context::epoch(ctx),
+                start_state_root,
+            };
+            dof::add(&mut session.id, nonce, action_log);
+
+            event::emit(ActionLogPublishedEvent {
+                session_id: session_id(session),
+                actions_blob_id: blob_id,
+                nonce,
+                published_at: tx_context::epoch(ctx),
+            });
+
+            start_state_root = *proof_verifier::new_state_root(&batch);
+            previous = nonce;
+            i = i + 1;
+        };
+        vector::destroy_empty(actions_blobs);
+        assert!(start_state_root == new_state_root && previous == new_nonce, EAggregateMismatch);
+
+        // Update session state
+        session.state_root = new_state_root;
+        session.nonce = new_nonce;
+        session.pending_action_logs = session.pending_action_logs + count;
+
+        // Unfinalize if previously finalized (new action logs require challenge period)
+        if (session.finalized) {
+            session.finalized = false;
+        };
+
+        event::emit(SessionUpdatedEvent {
+            session_id: session_id(session),
+            new_state_root,
+            nonce: new_nonce,
+            updated_at: tx_context::epoch(ctx),
+        });
+    }
+
     /// Remove expired action logs after challenge period
     ///
     /// Removes action log blobs that have passed the challenge period, freeing up
@@ -330,18 +478,28 @@ module dungeon::game_session {
     /// All action logs must be cleaned up before finalization to ensure
     /// all challenge periods have expired and verification is complete.
     ///
+    /// The player reveals the RNG seed and the reveal salt of its commitment
+    /// (`game_core::seed_reveal_salt`), which must hash to the
+    /// `seed_commitment` given at creation. Never pass the session salt itself:
+    /// it also blinds hidden actions and state commitments.
+    ///
     /// # Arguments
     /// * `session` - Mutable reference to GameSession
+    /// * `game_seed` - Revealed RNG seed
+    /// * `seed_salt` - Reveal salt derived from the session salt (32 bytes)
     /// * `ctx` - Transaction context (for sender verification)
     ///
     /// # Aborts
     /// * `ENotOwner` - If caller is not the session owner
     /// * `EActionLogsRemaining` - If there are pending action logs that haven't been cleaned up
+    /// * `ESeedMismatch` - If the revealed seed and salt don't match the commitment
     ///
     /// # Events
-    /// Emits `SessionFinalizedEvent` with final state and turn count
+    /// Emits `SessionFinalizedEvent` with final state, turn count and revealed seed
     public fun finalize(
         session: &mut GameSession,
+        game_seed: u64,
+        seed_salt: vector<u8>,
         ctx: &TxContext,
     ) {
         // Check ownership
@@ -350,6 +508,13 @@ module dungeon::game_session {
         // Ensure all action logs have been cleaned up
         assert!(session.pending_action_logs == 0, EActionLogsRemaining);
 
+        // Check the reveal against the commitment made at creation
+        assert!(vector::length(&seed_salt) == 32, ESeedMismatch);
+        assert!(
+            compute_seed_commitment(game_seed, seed_salt) == session.seed_commitment,
+            ESeedMismatch
+        );
+
         // Mark as finalized
         session.finalized = true;
 
@@ -357,6 +522,8 @@ module dungeon::game_session {
             session_id: session_id(session),
             final_state_root: session.state_root,
             final_nonce: session.nonce,
+            game_seed,
+            seed_salt,
             finalized_at: tx_context::epoch(ctx),
         });
     }
@@ -513,6 +680,19 @@ module dungeon::game_session {
         &session.seed_commitment
     }
 
+    /// Compute the salted seed commitment
+    ///
+    /// SHA-256(SEED_COMMITMENT_DOMAIN || seed_salt || game_seed as u64 LE),
+    /// matching `game_core::compute_revealed_seed_commitment`: `seed_salt` is
+    /// the reveal salt, not the session salt.
+    public fun compute_seed_commitment(game_seed: u64, seed_salt: vector<u8>): vector<u8> {
+        let mut preimage = SEED_COMMITMENT_DOMAIN;
+        vector::append(&mut preimage, seed_salt);
+        // BCS encodes u64 as 8 little-endian bytes
+        vector::append(&mut preimage, bcs::to_bytes(&game_seed));
+        hash::sha2_256(preimage)
+    }
+
     /// Get the challenge period duration in epochs
     public fun challenge_period_epochs(): u64 {
         CHALLENGE_PERIOD_EPOCHS
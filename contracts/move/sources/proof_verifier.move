@@ -82,6 +82,24 @@ module dungeon::proof_verifier {
         version: u64,
     }
 
+    /// Authority to pin the verifying keys sessions accept
+    ///
+    /// Created once at publish time and sent to the publisher.
+    public struct VerifierAdminCap has key, store {
+        id: UID,
+    }
+
+    /// Shared record of the verifying keys sessions accept
+    ///
+    /// `create_verifying_key` is open to anyone, so a key built from arbitrary
+    /// bytes proves nothing by itself. A key is only trusted once the admin has
+    /// pinned its object ID here.
+    public struct VerifierRegistry has key {
+        id: UID,
+        /// Verifying key of the aggregation program, if pinned
+        aggregate_vk: Option<ID>,
+    }
+
     /// Journal data structure - matches guest program output (168 bytes)
     ///
     /// This struct represents the parsed journal committed by the RISC0 guest program.
@@ -105,6 +123,31 @@ module dungeon::proof_verifier {
 
     // ===== Admin Functions =====
 
+    /// Send the admin capability to the publisher and share an empty registry
+    fun init(ctx: &mut TxContext) {
+        transfer::transfer(VerifierAdminCap { id: object::new(ctx) }, tx_context::sender(ctx));
+        transfer::share_object(VerifierRegistry {
+            id: object::new(ctx),
+            aggregate_vk: option::none(),
+        });
+    }
+
+    /// Pin the verifying key that aggregate proofs must verify against
+    ///
+    /// Replaces any previously pinned key.
+    ///
+    /// # Arguments
+    /// * `cap` - Admin capability
+    /// * `registry` - The shared verifier registry
+    /// * `vk` - Verifying key of the aggregation program
+    entry fun set_aggregate_verifying_key(
+        _cap: &VerifierAdminCap,
+        registry: &mut VerifierRegistry,
+        vk: &VerifyingKey,
+    ) {
+        registry.aggregate_vk = option::some(object::id(vk));
+    }
+
     /// Initialize a new verifying key as a shared object
     ///
     /// This should be called once to prepare the verifying key for the game circuit.
@@ -358,6 +401,11 @@ module dungeon::proof_verifier {
 
     // ===== View Functions =====
 
+    /// Whether `vk` is the pinned verifying key of the aggregation program
+    public fun is_aggregate_verifying_key(registry: &VerifierRegistry, vk: &VerifyingKey): bool {
+        registry.aggregate_vk == option::some(object::id(vk))
+    }
+
     /// Get the verifying key version
     public fun verifying_key_version(vk: &VerifyingKey): u64 {
         vk.version
@@ -121,7 +121,12 @@ impl SuiBlockchainClient {
             crate::config::SuiNetwork::Local => "local",
         };
 
-        let vk_object_id = DeploymentInfo::from_env().ok().and_then(|d| d.vk_object_id);
+        let deployment = DeploymentInfo::from_env().ok();
+        let vk_object_id = deployment.as_ref().and_then(|d| d.vk_object_id.clone());
+        let aggregate_vk_object_id = deployment
+            .as_ref()
+            .and_then(|d| d.aggregate_vk_object_id.clone());
+        let verifier_registry_id = deployment.and_then(|d| d.verifier_registry_id);
 
         if let Some(ref vk_id) = vk_object_id {
             tracing::info!("Loaded VK object ID from deployment: {}", vk_id);
@@ -134,7 +139,13 @@ impl SuiBlockchainClient {
         }
 
         // Create game session contract client
-        let game_session = GameSessionContract::new(package_id, vk_object_id);
+        let mut game_session = GameSessionContract::new(package_id, vk_object_id);
+        if let Some(vk_id) = aggregate_vk_object_id {
+            game_session.set_aggregate_vk(vk_id);
+        }
+        if let Some(registry_id) = verifier_registry_id {
+            game_session.set_verifier_registry(registry_id);
+        }
 
         Ok(Self {
             config,
@@ -251,6 +262,38 @@ impl SuiBlockchainClient {
             .await
     }
 
+    /// Update session with one aggregate proof covering several batches.
+    ///
+    /// Convenience wrapper that injects SDK dependencies into GameSessionContract.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - Session object ID to update
+    /// * `proof` - Aggregate proof submission (its journal spans all batches)
+    /// * `batches` - Journal and Walrus blob object ID of every batch, in order
+    ///
+    /// # Returns
+    ///
+    /// Transaction digest of the update transaction.
+    pub async fn update_session_aggregate(
+        &self,
+        session_id: &crate::core::SessionId,
+        proof: crate::core::ProofSubmission,
+        batches: &[(Vec<u8>, String)],
+    ) -> Result<crate::core::TxDigest> {
+        self.game_session
+            .update_aggregate(
+                &self.sui_client,
+                &self.keystore,
+                self.active_address,
+                self.config.gas_budget,
+                session_id,
+                proof,
+                batches,
+            )
+            .await
+    }
+
     /// Update session state with ZK proof without Walrus blob (testing only).
     ///
     /// Convenience wrapper that injects SDK dependencies into GameSessionContract.
@@ -288,6 +331,9 @@ impl SuiBlockchainClient {
     /// # Arguments
     ///
     /// * `session_id` - Session object ID to finalize
+    /// * `game_seed` - RNG seed revealed for auditing
+    /// * `seed_salt` - Revealed salt of the seed commitment (`SessionInit::reveal_salt`,
+    ///   never the session salt itself)
     ///
     /// # Returns
     ///
@@ -295,6 +341,8 @@ impl SuiBlockchainClient {
     pub async fn finalize_session(
         &self,
         session_id: &crate::core::SessionId,
+        game_seed: u64,
+        seed_salt: [u8; 32],
     ) -> Result<crate::core::TxDigest> {
         self.game_session
             .finalize(
@@ -303,6 +351,8 @@ impl SuiBlockchainClient {
                 self.active_address,
                 self.config.gas_budget,
                 session_id,
+                game_seed,
+                seed_salt,
             )
             .await
     }
@@ -4,6 +4,8 @@
 //! - SUI_NETWORK - Network name (testnet, mainnet, local)
 //! - SUI_PACKAGE_ID - Deployed package ID
 //! - SUI_VK_OBJECT_ID - Verifying key object ID
+//! - SUI_AGGREGATE_VK_OBJECT_ID - Verifying key object ID of the aggregation program
+//! - SUI_VERIFIER_REGISTRY_ID - Verifier registry object ID (pins the aggregation key)
 //! - SUI_SESSION_OBJECT_ID - Game session object ID
 
 use std::env;
@@ -28,6 +30,12 @@ pub struct DeploymentInfo {
     /// Verifying key object ID (on-chain VK for proof verification)
     pub vk_object_id: Option<String>,
 
+    /// Verifying key object ID of the aggregation program (aggregate proofs)
+    pub aggregate_vk_object_id: Option<String>,
+
+    /// Verifier registry object ID (shared, pins the aggregation VK)
+    pub verifier_registry_id: Option<String>,
+
     /// Game session object ID (for current active session)
     pub session_object_id: Option<String>,
 }
@@ -39,6 +47,8 @@ impl DeploymentInfo {
             network,
             package_id,
             vk_object_id: None,
+            aggregate_vk_object_id: None,
+            verifier_registry_id: None,
             session_object_id: None,
         }
     }
@@ -49,6 +59,8 @@ impl DeploymentInfo {
     /// - SUI_NETWORK - Network name (required)
     /// - SUI_PACKAGE_ID - Package ID (required)
     /// - SUI_VK_OBJECT_ID - VK object ID (optional)
+    /// - SUI_AGGREGATE_VK_OBJECT_ID - Aggregation VK object ID (optional)
+    /// - SUI_VERIFIER_REGISTRY_ID - Verifier registry object ID (optional)
     /// - SUI_SESSION_OBJECT_ID - Session object ID (optional)
     pub fn from_env() -> Result<Self> {
         let network =
@@ -58,12 +70,16 @@ impl DeploymentInfo {
             env::var("SUI_PACKAGE_ID").context("SUI_PACKAGE_ID environment variable not set")?;
 
         let vk_object_id = env::var("SUI_VK_OBJECT_ID").ok();
+        let aggregate_vk_object_id = env::var("SUI_AGGREGATE_VK_OBJECT_ID").ok();
+        let verifier_registry_id = env::var("SUI_VERIFIER_REGISTRY_ID").ok();
         let session_object_id = env::var("SUI_SESSION_OBJECT_ID").ok();
 
         Ok(Self {
             network,
             package_id,
             vk_object_id,
+            aggregate_vk_object_id,
+            verifier_registry_id,
             session_object_id,
         })
     }
@@ -73,6 +89,16 @@ impl DeploymentInfo {
         self.vk_object_id = Some(vk_object_id);
     }
 
+    /// Update aggregation VK object ID.
+    pub fn set_aggregate_vk_object_id(&mut self, vk_object_id: String) {
+        self.aggregate_vk_object_id = Some(vk_object_id);
+    }
+
+    /// Update verifier registry object ID.
+    pub fn set_verifier_registry_id(&mut self, registry_id: String) {
+        self.verifier_registry_id = Some(registry_id);
+    }
+
     /// Update session object ID.
     pub fn set_session_object_id(&mut self, session_object_id: String) {
         self.session_object_id = Some(session_object_id);
@@ -113,6 +139,14 @@ impl DeploymentInfo {
             Self::upsert_var(&mut env_vars, "SUI_VK_OBJECT_ID", vk_id);
         }
 
+        if let Some(ref vk_id) = self.aggregate_vk_object_id {
+            Self::upsert_var(&mut env_vars, "SUI_AGGREGATE_VK_OBJECT_ID", vk_id);
+        }
+
+        if let Some(ref registry_id) = self.verifier_registry_id {
+            Self::upsert_var(&mut env_vars, "SUI_VERIFIER_REGISTRY_ID", registry_id);
+        }
+
         if let Some(ref session_id) = self.session_object_id {
             Self::upsert_var(&mut env_vars, "SUI_SESSION_OBJECT_ID", session_id);
         }
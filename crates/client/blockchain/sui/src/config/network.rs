@@ -60,6 +60,8 @@ impl SuiConfig {
     /// - `SUI_RPC_URL` - Custom RPC endpoint URL (optional)
     /// - `SUI_PACKAGE_ID` - Deployed game package ID (optional)
     /// - `SUI_VK_OBJECT_ID` - Verifying key object ID (optional)
+    /// - `SUI_AGGREGATE_VK_OBJECT_ID` - Aggregation verifying key object ID (optional)
+    /// - `SUI_VERIFIER_REGISTRY_ID` - Verifier registry object ID (optional)
     /// - `SUI_SESSION_OBJECT_ID` - Game session object ID (optional)
     /// - `SUI_GAS_BUDGET` - Gas budget in MIST (default: 100000000)
     pub fn from_env() -> Result<Self, String> {
@@ -20,6 +20,7 @@
 //!
 //!     public fun create(...): GameSession;
 //!     public fun update(session: &mut GameSession, ...);
+//!     public fun update_aggregate(session: &mut GameSession, ...);
 //!     public fun finalize(session: &mut GameSession, ...);
 //! }
 //! ```
@@ -122,6 +123,12 @@ pub struct GameSessionContract {
 
     /// Verifying key object ID (for proof verification)
     pub vk_object_id: Option<String>,
+
+    /// Verifying key object ID of the aggregation program (for aggregate proofs)
+    pub aggregate_vk_object_id: Option<String>,
+
+    /// Verifier registry object ID (pins the aggregation verifying key)
+    pub verifier_registry_id: Option<String>,
 }
 
 impl GameSessionContract {
@@ -130,6 +137,8 @@ impl GameSessionContract {
         Self {
             package_id,
             vk_object_id,
+            aggregate_vk_object_id: None,
+            verifier_registry_id: None,
         }
     }
 
@@ -138,6 +147,16 @@ impl GameSessionContract {
         self.vk_object_id = Some(vk_id);
     }
 
+    /// Set verifying key object ID of the aggregation program.
+    pub fn set_aggregate_vk(&mut self, vk_id: String) {
+        self.aggregate_vk_object_id = Some(vk_id);
+    }
+
+    /// Set verifier registry object ID.
+    pub fn set_verifier_registry(&mut self, registry_id: String) {
+        self.verifier_registry_id = Some(registry_id);
+    }
+
     /// Get package ID as ObjectID.
     fn package_object_id(&self) -> Result<ObjectID> {
         self.package_id.parse().context("Invalid package ID format")
@@ -170,6 +189,57 @@ impl GameSessionContract {
         Ok(gas_coin.object_ref())
     }
 
+    /// Fetch an owned or immutable object and wrap it as a PTB object argument.
+    async fn object_arg(sui_client: &SuiClient, object_id: &str, what: &str) -> Result<ObjectArg> {
+        let object_id: ObjectID = object_id
+            .parse()
+            .with_context(|| format!("Invalid {} object ID format", what))?;
+
+        let object = sui_client
+            .read_api()
+            .get_object_with_options(object_id, SuiObjectDataOptions::default())
+            .await
+            .with_context(|| format!("Failed to fetch {} object", what))?
+            .into_object()
+            .with_context(|| format!("{} object not found", what))?;
+
+        Ok(ObjectArg::ImmOrOwnedObject(object.object_ref()))
+    }
+
+    /// Fetch a shared object and wrap it as a read-only PTB object argument.
+    async fn shared_object_arg(
+        sui_client: &SuiClient,
+        object_id: &str,
+        what: &str,
+    ) -> Result<ObjectArg> {
+        let object_id: ObjectID = object_id
+            .parse()
+            .with_context(|| format!("Invalid {} object ID format", what))?;
+
+        let object = sui_client
+            .read_api()
+            .get_object_with_options(object_id, SuiObjectDataOptions::new().with_owner())
+            .await
+            .with_context(|| format!("Failed to fetch {} object", what))?
+            .into_object()
+            .with_context(|| format!("{} object not found", what))?;
+
+        match object.owner {
+            Some(Owner::Shared {
+                initial_shared_version,
+            }) => Ok(ObjectArg::SharedObject {
+                id: object.object_id,
+                initial_shared_version,
+                mutability: sui_types::transaction::SharedObjectMutability::Immutable,
+            }),
+            other => Err(anyhow!(
+                "{} object is not shared (owner: {:?})",
+                what,
+                other
+            )),
+        }
+    }
+
     /// Create a new game session on-chain.
     ///
     /// Builds and executes a PTB calling `dungeon::game_session::create()`.
@@ -495,6 +565,164 @@ impl GameSessionContract {
         Ok(digest)
     }
 
+    /// Update session with one aggregate proof covering several batches.
+    ///
+    /// Builds and executes a PTB calling `dungeon::game_session::update_aggregate()`,
+    /// which verifies the aggregate once and stores every batch's action log, so
+    /// a run of proven batches is submitted in a single transaction.
+    ///
+    /// **Prerequisites:**
+    /// - Batch proofs must be folded with `zk::Prover::aggregate`
+    /// - Every batch's action log must be uploaded to Walrus
+    /// - The aggregation VK must be registered on-chain and pinned in the
+    ///   verifier registry (`proof_verifier::set_aggregate_verifying_key`)
+    ///
+    /// # Arguments
+    ///
+    /// * `sui_client` - Sui RPC client (injected dependency)
+    /// * `keystore` - Keystore for transaction signing (injected dependency)
+    /// * `active_address` - Signer address (injected dependency)
+    /// * `gas_budget` - Gas budget in MIST
+    /// * `session_id` - Session object ID to update
+    /// * `proof` - Aggregate proof submission (its journal spans all batches)
+    /// * `batches` - Journal and Walrus blob object ID of every batch, in order
+    ///
+    /// # Returns
+    ///
+    /// Transaction digest of the update transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Aggregation VK or verifier registry object ID is not configured
+    /// - No batches are given
+    /// - Session/VK/registry/Blob objects cannot be fetched
+    /// - PTB construction fails
+    /// - Transaction execution fails
+    #[allow(clippy::too_many_arguments)] // SDK dependency injection pattern
+    pub async fn update_aggregate(
+        &self,
+        sui_client: &SuiClient,
+        keystore: &FileBasedKeystore,
+        active_address: SuiAddress,
+        gas_budget: u64,
+        session_id: &SessionId,
+        proof: ProofSubmission,
+        batches: &[(Vec<u8>, String)],
+    ) -> Result<TxDigest> {
+        tracing::info!(
+            "Updating session {} with aggregate proof over {} batches...",
+            session_id.as_str(),
+            batches.len()
+        );
+
+        // Verify the aggregation VK is configured
+        let vk_id = self.aggregate_vk_object_id.as_ref().ok_or_else(|| {
+            anyhow!("Aggregation verifying key not configured. Set SUI_AGGREGATE_VK_OBJECT_ID.")
+        })?;
+        let registry_id = self.verifier_registry_id.as_ref().ok_or_else(|| {
+            anyhow!("Verifier registry not configured. Set SUI_VERIFIER_REGISTRY_ID.")
+        })?;
+
+        if batches.is_empty() {
+            return Err(anyhow!("Aggregate update needs at least one batch"));
+        }
+
+        // ========================================================================
+        // Build Programmable Transaction Block
+        // ========================================================================
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let session_arg =
+            ptb.obj(Self::object_arg(sui_client, session_id.as_str(), "Session").await?)?;
+        let registry_arg =
+            ptb.obj(Self::shared_object_arg(sui_client, registry_id, "Verifier registry").await?)?;
+        let vk_arg = ptb.obj(Self::shared_object_arg(sui_client, vk_id, "VK").await?)?;
+
+        let mut blob_objs = Vec::with_capacity(batches.len());
+        for (_, blob_object_id) in batches {
+            blob_objs.push(Self::object_arg(sui_client, blob_object_id, "Walrus blob").await?);
+        }
+        let blobs_arg = ptb.make_obj_vec(blob_objs)?;
+
+        let journals: Vec<Vec<u8>> = batches.iter().map(|(journal, _)| journal.clone()).collect();
+        let proof_arg = ptb.pure(proof.proof_points.clone())?;
+        let journal_arg = ptb.pure(proof.journal.clone())?;
+        let journals_arg = ptb.pure(journals)?;
+
+        // ========================================================================
+        // Call game_session::update_aggregate
+        // ========================================================================
+
+        let package_id = self.package_object_id()?;
+        ptb.programmable_move_call(
+            package_id,
+            Identifier::new("game_session")?,
+            Identifier::new("update_aggregate")?,
+            vec![], // No type arguments
+            vec![
+                session_arg,
+                registry_arg,
+                vk_arg,
+                proof_arg,
+                journal_arg,
+                journals_arg,
+                blobs_arg, // Walrus Blob objects
+            ],
+        );
+
+        let pt = ptb.finish();
+
+        // ========================================================================
+        // Execute transaction
+        // ========================================================================
+
+        let gas_price = sui_client
+            .read_api()
+            .get_reference_gas_price()
+            .await
+            .context("Failed to get reference gas price")?;
+
+        let gas_coin = Self::get_gas_coin(sui_client, active_address).await?;
+
+        let tx_data = TransactionData::new_programmable(
+            active_address,
+            vec![gas_coin],
+            pt,
+            gas_budget,
+            gas_price,
+        );
+
+        let keypair = keystore
+            .export(&active_address)
+            .context("Failed to export keypair from keystore")?;
+
+        let signature = sui_types::crypto::Signature::new_secure(
+            &IntentMessage::new(Intent::sui_transaction(), &tx_data),
+            keypair,
+        );
+
+        tracing::debug!("Executing aggregate update transaction...");
+        let response = sui_client
+            .quorum_driver_api()
+            .execute_transaction_block(
+                sui_types::transaction::Transaction::from_data(tx_data, vec![signature]),
+                sui_sdk::rpc_types::SuiTransactionBlockResponseOptions::new().with_effects(),
+                None, // No execution options
+            )
+            .await
+            .context("Failed to execute aggregate update transaction")?;
+
+        let digest = TxDigest::new(response.digest.to_string());
+        tracing::info!(
+            "✓ Session updated with aggregate. Transaction: {}",
+            digest.as_str()
+        );
+
+        Ok(digest)
+    }
+
     /// Update session state without Walrus blob (testing only).
     ///
     /// Similar to `update()` but calls `update_without_blob()` which bypasses Walrus blob
@@ -808,6 +1036,9 @@ impl GameSessionContract {
     /// * `active_address` - Signer address (injected dependency)
     /// * `gas_budget` - Gas budget in MIST
     /// * `session_id` - Session object ID to finalize
+    /// * `game_seed` - RNG seed revealed for auditing
+    /// * `seed_salt` - Revealed salt of the seed commitment (`SessionInit::reveal_salt`,
+    ///   never the session salt itself)
     ///
     /// # Returns
     ///
@@ -818,8 +1049,10 @@ impl GameSessionContract {
     /// Returns error if:
     /// - Session object cannot be fetched
     /// - Session has pending action logs (must be cleaned up first)
+    /// - Revealed seed and salt don't match the seed commitment
     /// - PTB construction fails
     /// - Transaction execution fails
+    #[allow(clippy::too_many_arguments)] // SDK dependency injection pattern
     pub async fn finalize(
         &self,
         sui_client: &SuiClient,
@@ -827,6 +1060,8 @@ impl GameSessionContract {
         active_address: SuiAddress,
         gas_budget: u64,
         session_id: &SessionId,
+        game_seed: u64,
+        seed_salt: [u8; 32],
     ) -> Result<TxDigest> {
         tracing::info!("Finalizing session {}...", session_id.as_str());
 
@@ -849,6 +1084,10 @@ impl GameSessionContract {
 
         let session_arg = ptb.obj(ObjectArg::ImmOrOwnedObject(session_obj.object_ref()))?;
 
+        // Seed reveal (checked on-chain against the seed commitment)
+        let game_seed_arg = ptb.pure(game_seed)?;
+        let seed_salt_arg = ptb.pure(seed_salt.to_vec())?;
+
         // Add Move call: package::game_session::finalize
         let package_id = self.package_object_id()?;
         ptb.programmable_move_call(
@@ -856,7 +1095,7 @@ impl GameSessionContract {
             Identifier::new("game_session")?,
             Identifier::new("finalize")?,
             vec![], // No type arguments
-            vec![session_arg],
+            vec![session_arg, game_seed_arg, seed_salt_arg],
         );
 
         // Finalize PTB
@@ -2,8 +2,12 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use anyhow::Result;
-use runtime::{AiKind, ProviderKind, Runtime, Scenario};
+use anyhow::{Context, Result};
+use game_core::EntityId;
+use runtime::{
+    AiKind, InteractiveKind, NetworkAccess, NetworkServer, ProviderKind, Runtime, Scenario,
+    StateStorage,
+};
 
 use crate::config::RuntimeConfig;
 use crate::oracles::{ContentOracleFactory, OracleBundle, OracleFactory};
@@ -130,6 +134,20 @@ impl RuntimeBuilder {
         // Enable proving if requested
         builder = builder.enable_proving(self.config.enable_proving);
 
+        // Offload proving to a remote daemon if configured
+        if let Some(ref url) = self.config.remote_prover_url {
+            #[cfg(feature = "remote-prover")]
+            {
+                let secret = self.config.remote_prover_secret.clone().unwrap_or_default();
+                builder = builder.remote_prover(runtime::RemoteConfig::new(url.clone(), secret));
+            }
+            #[cfg(not(feature = "remote-prover"))]
+            tracing::warn!(
+                "REMOTE_PROVER_URL={} ignored: built without the remote-prover feature",
+                url
+            );
+        }
+
         // Enable persistence if requested
         builder = builder.enable_persistence(self.config.enable_persistence);
 
@@ -148,6 +166,26 @@ impl RuntimeBuilder {
             builder = builder.checkpoint_interval(interval);
         }
 
+        // Set checkpoint strategy if provided (takes precedence over the interval)
+        if let Some(ref strategy) = self.config.checkpoint_strategy {
+            builder = builder.checkpoint_strategy(strategy.clone());
+        }
+
+        // Set state storage format if provided
+        if let Some(interval) = self.config.keyframe_interval {
+            builder = builder.state_storage(match interval {
+                0 | 1 => StateStorage::Full,
+                keyframe_interval => StateStorage::Delta { keyframe_interval },
+            });
+        }
+
+        // Set SQLite save database if provided
+        #[cfg(feature = "sqlite")]
+        if let Some(ref path) = self.config.save_database {
+            builder =
+                builder.storage_backend(runtime::StorageBackend::Sqlite { path: path.clone() });
+        }
+
         // Set blockchain clients if provided (Sui feature only)
         #[cfg(feature = "sui")]
         if let Some(blockchain_clients) = self.blockchain_clients {
@@ -172,6 +210,23 @@ impl RuntimeBuilder {
         // Set Utility AI as default for all NPCs
         handle.set_default_provider(utility_ai_kind)?;
 
+        // Let an external client play instead of the local frontend
+        if let Some(addr) = self.config.network_input_addr {
+            let secret = self
+                .config
+                .network_input_secret
+                .clone()
+                .context("NETWORK_INPUT_SECRET is required with NETWORK_INPUT_ADDR")?;
+            let access =
+                NetworkAccess::new(secret).trusted_network(self.config.network_input_trusted);
+            let (server, provider) = NetworkServer::bind(addr, access, handle.clone()).await?;
+            let network_kind = ProviderKind::Interactive(InteractiveKind::NetworkInput);
+            handle.register_provider(network_kind, provider)?;
+            handle.bind_entity_provider(EntityId::PLAYER, network_kind)?;
+            tracing::info!("Player bound to network input on {}", server.local_addr()?);
+            server.spawn();
+        }
+
         Ok(RuntimeSetup {
             config: self.config,
             oracles,
@@ -4,8 +4,12 @@
 //! that is shared across all client types.
 
 use std::env;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 
+use anyhow::{Result, anyhow};
+use runtime::CheckpointStrategy;
+
 /// Configuration for runtime initialization.
 ///
 /// This contains only runtime-related settings (ZK proving, persistence, session management).
@@ -17,6 +21,23 @@ pub struct RuntimeConfig {
     pub session_id: Option<String>,
     pub save_data_dir: Option<PathBuf>,
     pub checkpoint_interval: Option<u64>,
+    /// When action batches end; overrides `checkpoint_interval`.
+    pub checkpoint_strategy: Option<CheckpointStrategy>,
+    /// Checkpoints per full state snapshot (1 disables delta storage).
+    pub keyframe_interval: Option<u64>,
+    /// Store sessions in this SQLite database instead of one file per record.
+    #[cfg(feature = "sqlite")]
+    pub save_database: Option<PathBuf>,
+    /// Serve the player over the network input protocol on this address.
+    pub network_input_addr: Option<SocketAddr>,
+    /// Shared secret network input clients must present.
+    pub network_input_secret: Option<String>,
+    /// Allow a non-loopback `network_input_addr`; traffic is not encrypted.
+    pub network_input_trusted: bool,
+    /// Base URL of a `prover-server` daemon to offload proving to.
+    pub remote_prover_url: Option<String>,
+    /// Shared secret of the remote prover daemon.
+    pub remote_prover_secret: Option<String>,
 }
 
 impl RuntimeConfig {
@@ -27,6 +48,15 @@ impl RuntimeConfig {
             session_id: None,
             save_data_dir: None,
             checkpoint_interval: None,
+            checkpoint_strategy: None,
+            keyframe_interval: None,
+            #[cfg(feature = "sqlite")]
+            save_database: None,
+            network_input_addr: None,
+            network_input_secret: None,
+            network_input_trusted: false,
+            remote_prover_url: None,
+            remote_prover_secret: None,
         }
     }
 
@@ -38,22 +68,27 @@ impl RuntimeConfig {
     /// - `GAME_SESSION_ID` - Session identifier for save files (default: auto-generated)
     /// - `SAVE_DATA_DIR` - Directory for save data (default: platform-specific)
     /// - `CHECKPOINT_INTERVAL` - Actions between checkpoints (default: 10)
-    pub fn from_env() -> Self {
+    /// - `CHECKPOINT_STRATEGY` - Batch boundaries, e.g. `actions:50|boss_defeated|hp_below:25`
+    /// - `KEYFRAME_INTERVAL` - Checkpoints per full state snapshot, 1 = no deltas (default: 1)
+    /// - `SAVE_DATABASE` - Store sessions in this SQLite database (`sqlite` feature, default: files)
+    /// - `NETWORK_INPUT_ADDR` - Drive the player over TCP on this address (default: off)
+    /// - `NETWORK_INPUT_SECRET` - Shared secret network input clients must present
+    /// - `NETWORK_INPUT_TRUSTED` - Allow a non-loopback network input address (default: false)
+    /// - `REMOTE_PROVER_URL` - Prove on this `prover-server` daemon (default: in-process)
+    /// - `REMOTE_PROVER_SECRET` - Shared secret of the remote prover daemon
+    ///
+    /// Fails if a variable is set to a value that does not parse.
+    pub fn from_env() -> Result<Self> {
         let mut config = Self::default();
 
         // Enable ZK proving if environment variable is set
-        if let Some(enable) = read_env::<bool>("ENABLE_ZK_PROVING") {
+        if let Some(enable) = read_flag("ENABLE_ZK_PROVING") {
             config.enable_proving = enable;
-        } else if env::var("ENABLE_ZK_PROVING").is_ok() {
-            // Also accept just setting the variable without value as "true"
-            config.enable_proving = true;
         }
 
         // Enable persistence if environment variable is set
-        if let Some(enable) = read_env::<bool>("ENABLE_PERSISTENCE") {
+        if let Some(enable) = read_flag("ENABLE_PERSISTENCE") {
             config.enable_persistence = enable;
-        } else if env::var("ENABLE_PERSISTENCE").is_ok() {
-            config.enable_persistence = true;
         }
 
         // Session ID (optional)
@@ -63,15 +98,53 @@ impl RuntimeConfig {
         config.save_data_dir = env::var("SAVE_DATA_DIR").ok().map(PathBuf::from);
 
         // Checkpoint interval (optional)
-        config.checkpoint_interval = read_env::<u64>("CHECKPOINT_INTERVAL");
+        config.checkpoint_interval = read_env::<u64>("CHECKPOINT_INTERVAL")?;
+
+        // Checkpoint strategy (optional)
+        config.checkpoint_strategy = read_env::<CheckpointStrategy>("CHECKPOINT_STRATEGY")?;
+
+        // Keyframe interval (optional)
+        config.keyframe_interval = read_env::<u64>("KEYFRAME_INTERVAL")?;
+
+        // SQLite save database (optional)
+        #[cfg(feature = "sqlite")]
+        {
+            config.save_database = env::var("SAVE_DATABASE").ok().map(PathBuf::from);
+        }
 
-        config
+        // Network input address (optional)
+        config.network_input_addr = read_env::<SocketAddr>("NETWORK_INPUT_ADDR")?;
+        config.network_input_secret = env::var("NETWORK_INPUT_SECRET").ok();
+        if let Some(trusted) = read_flag("NETWORK_INPUT_TRUSTED") {
+            config.network_input_trusted = trusted;
+        }
+
+        // Remote prover daemon (optional)
+        config.remote_prover_url = env::var("REMOTE_PROVER_URL").ok();
+        config.remote_prover_secret = env::var("REMOTE_PROVER_SECRET").ok();
+
+        Ok(config)
     }
 }
 
-fn read_env<T>(key: &str) -> Option<T>
+/// Read a flag; setting the variable without a boolean value counts as `true`.
+fn read_flag(key: &str) -> Option<bool> {
+    env::var(key)
+        .ok()
+        .map(|value| value.parse().unwrap_or(true))
+}
+
+/// Read and parse a variable, rejecting values that do not parse.
+fn read_env<T>(key: &str) -> Result<Option<T>>
 where
     T: std::str::FromStr,
+    T::Err: std::fmt::Display,
 {
-    env::var(key).ok()?.parse().ok()
+    let Ok(value) = env::var(key) else {
+        return Ok(None);
+    };
+    value
+        .parse()
+        .map(Some)
+        .map_err(|e| anyhow!("invalid {}={:?}: {}", key, value, e))
 }
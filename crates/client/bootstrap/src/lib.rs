@@ -10,4 +10,8 @@ pub mod session;
 pub use builder::{RuntimeBuilder, RuntimeSetup};
 pub use config::RuntimeConfig;
 pub use oracles::{ContentOracleFactory, OracleBundle, OracleFactory};
-pub use session::{SessionInfo, find_latest_session, list_sessions, load_latest_state};
+#[cfg(feature = "sqlite")]
+pub use session::list_sqlite_sessions;
+pub use session::{
+    SessionInfo, find_latest_session, list_sessions, load_genesis_state, load_latest_state,
+};
@@ -2,7 +2,10 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use runtime::{ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl};
+use runtime::{
+    ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, DialogueOracleImpl, ItemOracleImpl,
+    MapOracleImpl,
+};
 
 // Re-export OracleBundle from runtime
 pub use runtime::OracleBundle;
@@ -24,7 +27,8 @@ pub trait OracleFactory: Send + Sync {
 /// ├── config.toml
 /// ├── tables.toml (placeholder)
 /// ├── items.ron
-/// ├── npcs.ron
+/// ├── actors.ron
+/// ├── dialogues.ron
 /// └── maps/
 ///     └── {map_name}.ron
 /// ```
@@ -135,6 +139,15 @@ impl OracleFactory for ContentOracleFactory {
             )
         });
 
+        // Load dialogue trees
+        let dialogues = factory.load_dialogues().unwrap_or_else(|e| {
+            panic!(
+                "Failed to load dialogues.ron from {}: {}",
+                self.data_dir.display(),
+                e
+            )
+        });
+
         // Load map (terrain only, no entities)
         let (dimensions, tiles) = factory.load_map(&self.map_name).unwrap_or_else(|e| {
             panic!(
@@ -158,6 +171,12 @@ impl OracleFactory for ContentOracleFactory {
             item_oracle.add_definition(item_def);
         }
 
+        // Build dialogue oracle
+        let mut dialogue_oracle = DialogueOracleImpl::new();
+        for tree in dialogues {
+            dialogue_oracle.add(tree);
+        }
+
         // Build map oracle (terrain only)
         let map_oracle = MapOracleImpl::new(dimensions, tiles);
 
@@ -171,6 +190,7 @@ impl OracleFactory for ContentOracleFactory {
             Arc::new(actions_oracle),
             Arc::new(actor_oracle),
             Arc::new(config_oracle),
+            Arc::new(dialogue_oracle),
         )
     }
 }
@@ -1,7 +1,7 @@
 //! Session management utilities for resuming games.
 
 use anyhow::{Context, Result, anyhow};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use game_core::GameState;
 use runtime::StateRepository;
@@ -40,18 +40,10 @@ pub fn list_sessions(base_dir: &Path) -> Result<Vec<SessionInfo>> {
             .to_str()
             .context("Invalid UTF-8 in directory name")?;
 
-        // Extract timestamp from session_<timestamp> format
-        let timestamp = if let Some(stripped) = name.strip_prefix("session_") {
-            stripped.parse::<u64>().ok()
-        } else {
-            // Also support plain numeric session IDs for backwards compatibility
-            name.parse::<u64>().ok()
-        };
-
-        if let Some(timestamp) = timestamp {
+        if let Some(timestamp) = session_timestamp(name) {
             // Find latest nonce in this session
             let session_dir = entry.path();
-            let states_dir = session_dir.join("states");
+            let states_dir = active_branch_dir(&session_dir)?.join("states");
 
             let latest_nonce = find_highest_state_nonce(&states_dir).unwrap_or(0);
 
@@ -69,6 +61,39 @@ pub fn list_sessions(base_dir: &Path) -> Result<Vec<SessionInfo>> {
     Ok(sessions)
 }
 
+/// List the sessions of a SQLite save database.
+///
+/// Uses the database's session index instead of scanning directories.
+#[cfg(feature = "sqlite")]
+pub fn list_sqlite_sessions(store: &runtime::SqliteStore) -> Result<Vec<SessionInfo>> {
+    let mut sessions: Vec<_> = store
+        .sessions()?
+        .into_iter()
+        .filter_map(|session| {
+            Some(SessionInfo {
+                timestamp: session_timestamp(&session.session_id)?,
+                latest_nonce: session.latest_state.unwrap_or(0),
+                session_id: session.session_id,
+            })
+        })
+        .collect();
+
+    // Sort by timestamp (descending) - most recent first
+    sessions.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    Ok(sessions)
+}
+
+/// Extract the timestamp from a session ID in session_<timestamp> format.
+fn session_timestamp(session_id: &str) -> Option<u64> {
+    if let Some(stripped) = session_id.strip_prefix("session_") {
+        stripped.parse::<u64>().ok()
+    } else {
+        // Also support plain numeric session IDs for backwards compatibility
+        session_id.parse::<u64>().ok()
+    }
+}
+
 /// Find the most recent session by timestamp.
 pub fn find_latest_session(base_dir: &Path) -> Result<Option<SessionInfo>> {
     let sessions = list_sessions(base_dir)?;
@@ -101,11 +126,18 @@ fn find_highest_state_nonce(states_dir: &Path) -> Option<u64> {
     max_nonce
 }
 
+/// Directory of the branch a session records new actions on.
+fn active_branch_dir(session_dir: &Path) -> Result<PathBuf> {
+    let index = runtime::FileBranchRepository::new(session_dir).load()?;
+    Ok(index.active.dir(session_dir))
+}
+
 /// Load the latest state from a session directory.
 ///
-/// Returns the highest nonce state available, or None if no states exist.
+/// Returns the highest nonce state of the session's active branch, or None if
+/// no states exist.
 pub fn load_latest_state(base_dir: &Path, session_id: &str) -> Result<Option<(u64, GameState)>> {
-    let states_dir = base_dir.join(session_id).join("states");
+    let states_dir = active_branch_dir(&base_dir.join(session_id))?.join("states");
 
     let nonce = match find_highest_state_nonce(&states_dir) {
         Some(n) => n,
@@ -120,3 +152,11 @@ pub fn load_latest_state(base_dir: &Path, session_id: &str) -> Result<Option<(u6
 
     Ok(Some((nonce, state)))
 }
+
+/// Load the genesis state (nonce 0) of a session, the starting point of a replay.
+pub fn load_genesis_state(base_dir: &Path, session_id: &str) -> Result<GameState> {
+    let state_repo = runtime::FileStateRepository::new(base_dir.join(session_id).join("states"))?;
+    state_repo
+        .load(0)?
+        .ok_or_else(|| anyhow!("Session {} has no genesis state (state_0.bin)", session_id))
+}
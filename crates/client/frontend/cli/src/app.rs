@@ -8,7 +8,7 @@ use async_trait::async_trait;
 use tokio::sync::mpsc;
 
 use game_core::{Action, EntityId};
-use runtime::{InteractiveKind, ProviderKind, RuntimeHandle, Topic};
+use runtime::{InteractiveKind, ProviderKind, ReplayControl, RuntimeHandle, Topic};
 
 use crate::event::{CliEventConsumer, EventLoop};
 use crate::input::CliActionProvider;
@@ -34,6 +34,7 @@ pub struct CliFrontend {
     config: FrontendConfig,
     cli_config: crate::config::CliConfig,
     oracles: OracleBundle,
+    replay: Option<ReplayControl>,
 }
 
 impl CliFrontend {
@@ -53,8 +54,18 @@ impl CliFrontend {
             config,
             cli_config,
             oracles,
+            replay: None,
         }
     }
+
+    /// Watch a recorded session instead of playing.
+    ///
+    /// The player is not bound to keyboard input; keys control playback
+    /// through the given replay control instead.
+    pub fn with_replay(mut self, control: ReplayControl) -> Self {
+        self.replay = Some(control);
+        self
+    }
 }
 
 #[async_trait]
@@ -65,31 +76,52 @@ impl client_frontend_core::Frontend for CliFrontend {
         // Setup CLI-specific action provider (interactive input)
         let (tx_action, rx_action) = mpsc::channel::<Action>(self.config.channels.action_buffer);
 
-        let cli_kind = ProviderKind::Interactive(InteractiveKind::CliInput);
+        // Replays drive every entity from the action log, and a player bound
+        // to network input is played by an external client
+        let network_kind = ProviderKind::Interactive(InteractiveKind::NetworkInput);
+        let remote = self.replay.is_none()
+            && handle.get_entity_provider_kind(EntityId::PLAYER)? == network_kind;
+        if self.replay.is_none() && !remote {
+            let cli_kind = ProviderKind::Interactive(InteractiveKind::CliInput);
 
-        // Register CLI input provider for player
-        handle.register_provider(cli_kind, CliActionProvider::new(rx_action))?;
+            // Register CLI input provider for player
+            handle.register_provider(cli_kind, CliActionProvider::new(rx_action))?;
 
-        // Bind player to CLI input
-        handle.bind_entity_provider(EntityId::PLAYER, cli_kind)?;
+            // Bind player to CLI input
+            handle.bind_entity_provider(EntityId::PLAYER, cli_kind)?;
+        }
 
         // Subscribe to events
-        let subscriptions = handle.subscribe_multiple(&[Topic::GameState, Topic::Proof]);
+        let subscriptions =
+            handle.subscribe_multiple(&[Topic::GameState, Topic::Proof, Topic::Game]);
         let initial_state = handle.query_state().await?;
 
         // Initialize message log
         let mut messages = MessageLog::new(self.config.messages.capacity);
-        messages.push_text(format!(
-            "[{}] Welcome to the dungeon.",
-            initial_state.turn.clock
-        ));
+        if self.replay.is_some() {
+            messages.push_text(format!(
+                "[{}] Replaying session {}.",
+                initial_state.turn.clock,
+                handle.session_id()
+            ));
+        } else if remote {
+            messages.push_text(format!(
+                "[{}] Watching a networked player.",
+                initial_state.turn.clock
+            ));
+        } else {
+            messages.push_text(format!(
+                "[{}] Welcome to the dungeon.",
+                initial_state.turn.clock
+            ));
+        }
 
         // Create event consumer
         let consumer =
             CliEventConsumer::new(messages, self.config.messages.effect_visibility.clone());
 
         // Create event loop
-        let event_loop = EventLoop::new(
+        let mut event_loop = EventLoop::new(
             subscriptions,
             tx_action,
             initial_state.entities.player().id,
@@ -99,7 +131,11 @@ impl client_frontend_core::Frontend for CliFrontend {
             None, // Use default targeting strategy (ThreatBased)
             self.cli_config.clone(),
             handle.clone(),
-        );
+        )
+        .with_replay(self.replay.clone());
+        if remote {
+            event_loop = event_loop.spectate();
+        }
 
         // Initialize terminal
         let mut terminal = terminal::init()?;
@@ -1,16 +1,18 @@
 //! Maintains the CLI message log in response to runtime events.
-use runtime::{Event, GameStateEvent};
+use runtime::{Event, GameEvent, GameStateEvent};
 
 use client_frontend_core::{
     EffectVisibility,
     event::{EventConsumer, EventImpact},
-    format::format_action_and_effects,
+    format::{format_action_and_effects, format_game_event, is_reported_as_game_event},
     message::{MessageEntry, MessageLevel, MessageLog},
 };
 
 pub struct CliEventConsumer {
     log: MessageLog,
     effect_visibility: EffectVisibility,
+    /// Clock of the last executed action, stamped on the game events that follow it.
+    clock: u64,
 }
 
 impl CliEventConsumer {
@@ -18,6 +20,7 @@ impl CliEventConsumer {
         Self {
             log,
             effect_visibility,
+            clock: 0,
         }
     }
 
@@ -27,10 +30,12 @@ impl CliEventConsumer {
         action_result: &game_core::ActionResult,
         timestamp: u64,
     ) {
-        // Use two-tier message formatting: action message + effect messages
+        // Use two-tier message formatting: action message + effect messages.
+        // Effects published as game events are logged when those arrive.
         let (action_msg, effect_msgs) =
             format_action_and_effects(action, action_result, |applied_value| {
                 self.effect_visibility.should_show(applied_value)
+                    && !is_reported_as_game_event(applied_value)
             });
 
         // Push the main action message
@@ -50,6 +55,22 @@ impl CliEventConsumer {
         }
     }
 
+    /// Returns whether a message was logged.
+    fn push_game_event(&mut self, event: &GameEvent) -> bool {
+        if !self.effect_visibility.should_show_event(event) {
+            return false;
+        }
+        let Some(text) = format_game_event(event) else {
+            return false;
+        };
+        self.log.push(MessageEntry::new(
+            text,
+            Some(self.clock),
+            MessageLevel::Info,
+        ));
+        true
+    }
+
     fn push_failure(
         &mut self,
         action: &game_core::Action,
@@ -75,6 +96,7 @@ impl EventConsumer for CliEventConsumer {
                 clock,
                 ..
             }) => {
+                self.clock = *clock;
                 // Filter out system actions from message log
                 if !action.actor().is_system() {
                     self.push_action(action, action_result, *clock);
@@ -113,6 +135,13 @@ impl EventConsumer for CliEventConsumer {
                 // ActionRef is for internal persistence only, not displayed to user
                 EventImpact::none()
             }
+            Event::Game(event) => {
+                if self.push_game_event(event) {
+                    EventImpact::redraw()
+                } else {
+                    EventImpact::none()
+                }
+            }
         }
     }
 
@@ -179,6 +179,24 @@ where
     ) -> Result<()> {
         use game_core::{ActionInput, CharacterAction, env::ActionOracle};
 
+        // Orders carry their own input; reuse the contextual command key logic
+        if action_kind == game_core::ActionKind::Command {
+            return self.handle_command_companions().await;
+        }
+
+        // Conversations start with an adjacent NPC rather than the caster
+        if action_kind == game_core::ActionKind::Talk {
+            return self.handle_talk().await;
+        }
+
+        // Trades are picked on the shop screen
+        if matches!(
+            action_kind,
+            game_core::ActionKind::Buy | game_core::ActionKind::Sell
+        ) {
+            return self.handle_open_shop().await;
+        }
+
         // Get targeting mode from action profile via ActionOracle
         let action_profile = self.oracles.actions.action_profile(action_kind);
         let targeting = action_profile.targeting;
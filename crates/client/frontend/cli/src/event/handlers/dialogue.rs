@@ -0,0 +1,108 @@
+//! Dialogue handling (start, reply, leave).
+//!
+//! Every step of a conversation is submitted as a `Talk` action so that
+//! outcomes (items, faction changes, story flags) go through the state
+//! transition. The dialogue overlay only mirrors `WorldState::conversation`.
+
+use anyhow::Result;
+use client_frontend_core::EventConsumer;
+use game_core::{
+    Action, ActionInput, ActionKind, CharacterAction, EntityId, Faction, env::DialogueOracle,
+};
+
+use super::super::EventLoop;
+use crate::state::AppMode;
+
+impl<C> EventLoop<C>
+where
+    C: EventConsumer,
+{
+    /// Start a conversation.
+    ///
+    /// Prefers the highlighted NPC if it is adjacent and has something to say,
+    /// otherwise the first adjacent NPC with a dialogue.
+    pub(in crate::event) async fn handle_talk(&mut self) -> Result<()> {
+        let Some(player_pos) = self.view_model.player.position else {
+            return Ok(());
+        };
+
+        let can_talk = |npc: &&client_frontend_core::view_model::ActorView| {
+            npc.dialogue.is_some()
+                && npc.stats.resource_current.hp > 0
+                && !npc.faction.is_hostile_to(&Faction::Player)
+                && npc
+                    .position
+                    .is_some_and(|pos| pos.chebyshev_distance(player_pos) <= 1)
+        };
+
+        let partner = self
+            .app_state
+            .highlighted_entity
+            .and_then(|id| self.view_model.npcs().find(|npc| npc.id == id))
+            .filter(can_talk)
+            .or_else(|| self.view_model.npcs().find(can_talk))
+            .map(|npc| npc.id);
+
+        let Some(npc) = partner else {
+            self.consumer
+                .message_log_mut()
+                .push_text("Nobody here to talk to.".to_string());
+            return Ok(());
+        };
+
+        self.submit_talk(ActionInput::Target(npc)).await
+    }
+
+    /// Reply with the `index`-th displayed choice.
+    pub(in crate::event) async fn handle_dialogue_choice(&mut self, index: usize) -> Result<()> {
+        let Some(dialogue) = &self.view_model.dialogue else {
+            return Ok(());
+        };
+        let Some(tree) = self
+            .oracles
+            .dialogues
+            .dialogue(&dialogue.conversation.dialogue)
+        else {
+            return Ok(());
+        };
+
+        // Displayed replies skip unavailable choices, so map back to the authored index
+        let Some(choice) = dialogue
+            .available_choices(&tree)
+            .get(index)
+            .map(|(choice, _)| *choice)
+        else {
+            return Ok(());
+        };
+
+        self.submit_talk(ActionInput::Choice(choice)).await
+    }
+
+    /// Leave the current conversation.
+    pub(in crate::event) async fn handle_leave_conversation(&mut self) -> Result<()> {
+        if self.view_model.dialogue.is_none() {
+            self.sync_dialogue_mode();
+            return Ok(());
+        }
+
+        self.submit_talk(ActionInput::None).await
+    }
+
+    /// Keep dialogue mode in step with the conversation in the game state.
+    pub(in crate::event) fn sync_dialogue_mode(&mut self) {
+        match (&self.app_state.mode, self.view_model.dialogue.is_some()) {
+            (AppMode::Normal, true) => self.app_state.enter_dialogue(),
+            (AppMode::Dialogue, false) => {
+                self.app_state.exit_to_normal();
+                self.compute_auto_target();
+            }
+            _ => {}
+        }
+    }
+
+    async fn submit_talk(&mut self, input: ActionInput) -> Result<()> {
+        let action = CharacterAction::new(EntityId::PLAYER, ActionKind::Talk, input);
+        self.tx_action.send(Action::Character(action)).await?;
+        Ok(())
+    }
+}
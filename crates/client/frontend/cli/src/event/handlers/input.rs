@@ -162,6 +162,36 @@ where
                 self.render(terminal)?;
                 Ok(false)
             }
+            KeyAction::CommandCompanions => {
+                self.handle_command_companions().await?;
+                self.render(terminal)?;
+                Ok(false)
+            }
+            KeyAction::Talk => {
+                self.handle_talk().await?;
+                self.render(terminal)?;
+                Ok(false)
+            }
+            KeyAction::SelectDialogueChoice(index) => {
+                self.handle_dialogue_choice(index).await?;
+                self.render(terminal)?;
+                Ok(false)
+            }
+            KeyAction::LeaveConversation => {
+                self.handle_leave_conversation().await?;
+                self.render(terminal)?;
+                Ok(false)
+            }
+            KeyAction::OpenShop => {
+                self.handle_open_shop().await?;
+                self.render(terminal)?;
+                Ok(false)
+            }
+            KeyAction::SwitchShopPane => {
+                self.handle_switch_shop_pane();
+                self.render(terminal)?;
+                Ok(false)
+            }
             KeyAction::SaveGame => {
                 self.handle_save_game().await?;
                 self.render(terminal)?;
@@ -177,6 +207,11 @@ where
                 self.render(terminal)?;
                 Ok(false)
             }
+            KeyAction::UndoTurn => {
+                self.handle_undo_turn().await?;
+                self.render(terminal)?;
+                Ok(false)
+            }
             KeyAction::MenuUp => {
                 self.handle_menu_up();
                 self.render(terminal)?;
@@ -207,6 +242,13 @@ where
                 self.render(terminal)?;
                 Ok(false)
             }
+            KeyAction::Replay(command) => {
+                // Status changes arrive through the replay watch and trigger a redraw
+                if let Some(replay) = &self.replay {
+                    replay.send(command).await?;
+                }
+                Ok(false)
+            }
             KeyAction::None => Ok(false),
         }
     }
@@ -224,11 +266,14 @@ where
         let (dx, dy) = direction.offset();
         let target_pos = game_core::Position::new(player_pos.x + dx, player_pos.y + dy);
 
-        // Check if there's an enemy at target position
+        // Check if there's an enemy at target position (never bump-attack companions)
         let enemy_at_target = self.view_model.actors.iter().find(|actor| {
             actor.id != EntityId::PLAYER
                 && actor.position == Some(target_pos)
                 && actor.stats.resource_current.hp > 0
+                && actor
+                    .companion
+                    .is_none_or(|bond| bond.leader != EntityId::PLAYER)
         });
 
         let action = if let Some(enemy) = enemy_at_target {
@@ -285,6 +330,72 @@ where
         Ok(())
     }
 
+    /// Handle commanding companions.
+    ///
+    /// Picks the most useful order for the situation:
+    /// 1. Highlighted hostile NPC → all companions attack it
+    /// 2. Adjacent unbound friendly NPC → recruit it
+    /// 3. Otherwise → toggle all companions between follow and stay
+    pub(in crate::event) async fn handle_command_companions(&mut self) -> Result<()> {
+        use game_core::{
+            ActionInput, ActionKind, CharacterAction, CompanionCommand, CompanionOrder, Faction,
+        };
+
+        let Some(player_pos) = self.view_model.player.position else {
+            return Ok(());
+        };
+        let companions: Vec<_> = self.view_model.companions().collect();
+
+        let hostile_target = self
+            .app_state
+            .highlighted_entity
+            .and_then(|id| self.view_model.npcs().find(|npc| npc.id == id))
+            .filter(|npc| Faction::Player.is_hostile_to(&npc.faction) && npc.position.is_some());
+
+        let recruit = self.view_model.npcs().find(|npc| {
+            npc.faction == Faction::Friendly
+                && npc.companion.is_none()
+                && npc.stats.resource_current.hp > 0
+                && npc
+                    .position
+                    .is_some_and(|pos| pos.chebyshev_distance(player_pos) <= 1)
+        });
+
+        let command = match (hostile_target, recruit) {
+            (Some(target), _) if !companions.is_empty() => {
+                CompanionCommand::all(CompanionOrder::Attack(target.id))
+            }
+            (_, Some(recruit)) => CompanionCommand::single(recruit.id, CompanionOrder::Follow),
+            _ if !companions.is_empty() => {
+                let any_following = companions.iter().any(|companion| {
+                    companion
+                        .companion
+                        .is_some_and(|bond| bond.order != CompanionOrder::Stay)
+                });
+                if any_following {
+                    CompanionCommand::all(CompanionOrder::Stay)
+                } else {
+                    CompanionCommand::all(CompanionOrder::Follow)
+                }
+            }
+            _ => {
+                self.consumer
+                    .message_log_mut()
+                    .push_text("No companions to command.".to_string());
+                return Ok(());
+            }
+        };
+
+        let action = CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Command,
+            ActionInput::Order(command),
+        );
+        self.tx_action.send(Action::Character(action)).await?;
+
+        Ok(())
+    }
+
     /// Handle save game (Ctrl+S) - create manual checkpoint.
     pub(in crate::event) async fn handle_save_game(&mut self) -> Result<()> {
         self.consumer
@@ -358,9 +469,20 @@ where
                     }
                 };
 
+                let branches = match self.runtime_handle.list_branches().await {
+                    Ok(branches) => branches,
+                    Err(e) => {
+                        self.consumer
+                            .message_log_mut()
+                            .push_text(format!("Failed to list branches: {}", e));
+                        return Ok(());
+                    }
+                };
+
                 // Enter save menu mode with finalized checkpoints
                 self.app_state.enter_save_menu(
                     batches,
+                    branches,
                     #[cfg(feature = "sui")]
                     session_info,
                 );
@@ -375,7 +497,7 @@ where
         Ok(())
     }
 
-    /// Handle menu navigation up (StartScreen, SaveMenu).
+    /// Handle menu navigation up (StartScreen, SaveMenu, Shop).
     pub(in crate::event) fn handle_menu_up(&mut self) {
         use crate::state::AppMode;
 
@@ -390,14 +512,22 @@ where
                     menu_state.selected_index -= 1;
                 }
             }
+            AppMode::Shop(shop_state) => {
+                shop_state.selected = shop_state.selected.saturating_sub(1);
+            }
             _ => {}
         }
     }
 
-    /// Handle menu navigation down (StartScreen, SaveMenu).
+    /// Handle menu navigation down (StartScreen, SaveMenu, Shop).
     pub(in crate::event) fn handle_menu_down(&mut self) {
         use crate::state::AppMode;
 
+        let shop_rows = match &self.app_state.mode {
+            AppMode::Shop(shop_state) => self.shop_row_count(shop_state.merchant, shop_state.pane),
+            _ => 0,
+        };
+
         match &mut self.app_state.mode {
             AppMode::StartScreen(start_state) => {
                 let max_index = start_state.sessions.len(); // 0 = New Game, 1+ = sessions
@@ -410,11 +540,16 @@ where
                     menu_state.selected_index += 1;
                 }
             }
+            AppMode::Shop(shop_state) => {
+                if shop_state.selected + 1 < shop_rows {
+                    shop_state.selected += 1;
+                }
+            }
             _ => {}
         }
     }
 
-    /// Handle menu confirm (StartScreen session selection, SaveMenu load state).
+    /// Handle menu confirm (StartScreen session selection, SaveMenu load state, Shop trade).
     pub(in crate::event) async fn handle_menu_confirm(&mut self) -> Result<()> {
         use crate::state::AppMode;
 
@@ -440,6 +575,10 @@ where
             return Ok(());
         }
 
+        if let AppMode::Shop(_) = &self.app_state.mode {
+            return self.handle_shop_confirm().await;
+        }
+
         if let AppMode::SaveMenu(menu_state) = &self.app_state.mode {
             if menu_state.saved_states.is_empty() {
                 // No saved states - just exit
@@ -449,47 +588,25 @@ where
 
             // Get selected state
             let selected_state = &menu_state.saved_states[menu_state.selected_index];
-            let target_nonce = selected_state.nonce;
+            let (branch, target_nonce) = (selected_state.branch, selected_state.nonce);
 
-            self.consumer
-                .message_log_mut()
-                .push_text(format!("Restoring state at nonce {}...", target_nonce));
+            self.consumer.message_log_mut().push_text(format!(
+                "Restoring state at nonce {} on {}...",
+                target_nonce, branch
+            ));
 
-            // Use restore_state to actually replace the simulation worker's state
-            match self.runtime_handle.restore_state(target_nonce).await {
-                Ok(()) => {
+            // Rebuild the state and continue recording on the branch it leads to
+            match self.runtime_handle.rewind(branch, target_nonce).await {
+                Ok(info) => {
                     self.consumer.message_log_mut().push_text(format!(
-                        "Successfully restored state at nonce {}",
-                        target_nonce
+                        "Successfully restored state at nonce {} (now on {})",
+                        target_nonce, info.id
                     ));
-
-                    // Prepare next turn to refresh the ViewModel
-                    match self.runtime_handle.prepare_next_turn().await {
-                        Ok((_, restored_state)) => {
-                            // Update ViewModel with restored state
-                            self.view_model =
-                                client_frontend_core::view_model::ViewModel::from_initial_state(
-                                    &restored_state,
-                                    self.oracles.map.as_ref(),
-                                );
-
-                            self.consumer.message_log_mut().push_text(format!(
-                                "State restored (turn {})",
-                                restored_state.turn.clock
-                            ));
-
-                            // Recompute auto-target for new state
-                            self.compute_auto_target();
-                        }
-                        Err(e) => {
-                            self.consumer
-                                .message_log_mut()
-                                .push_text(format!("Failed to refresh view: {}", e));
-                        }
-                    }
+                    self.refresh_after_rewind().await;
 
                     // Exit back to normal mode
                     self.app_state.exit_to_normal();
+                    self.sync_dialogue_mode();
                 }
                 Err(e) => {
                     self.consumer
@@ -504,6 +621,57 @@ where
         Ok(())
     }
 
+    /// Handle undo (Ctrl+Z) - rewind to the start of the player's previous turn.
+    pub(in crate::event) async fn handle_undo_turn(&mut self) -> Result<()> {
+        match self.runtime_handle.undo_turn(EntityId::PLAYER).await {
+            Ok(Some(info)) => {
+                self.consumer
+                    .message_log_mut()
+                    .push_text(format!("Undid last turn (now on {})", info.id));
+                self.refresh_after_rewind().await;
+                self.sync_dialogue_mode();
+            }
+            Ok(None) => {
+                self.consumer
+                    .message_log_mut()
+                    .push_text("Nothing to undo".to_string());
+            }
+            Err(e) => {
+                self.consumer
+                    .message_log_mut()
+                    .push_text(format!("Failed to undo: {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Prepare the next turn after a rewind and rebuild the ViewModel from it.
+    async fn refresh_after_rewind(&mut self) {
+        match self.runtime_handle.prepare_next_turn().await {
+            Ok((_, restored_state)) => {
+                // Update ViewModel with restored state
+                self.view_model = client_frontend_core::view_model::ViewModel::from_initial_state(
+                    &restored_state,
+                    self.oracles.map.as_ref(),
+                );
+
+                self.consumer.message_log_mut().push_text(format!(
+                    "State restored (turn {})",
+                    restored_state.turn.clock
+                ));
+
+                // Recompute auto-target for new state
+                self.compute_auto_target();
+            }
+            Err(e) => {
+                self.consumer
+                    .message_log_mut()
+                    .push_text(format!("Failed to refresh view: {}", e));
+            }
+        }
+    }
+
     /// Handle upload to Walrus (W key in SaveMenu).
     pub(in crate::event) async fn handle_upload_to_walrus(&mut self) -> Result<()> {
         #[cfg(not(feature = "sui"))]
@@ -747,9 +915,20 @@ where
                         }
                     };
 
+                    let branches = match self.runtime_handle.list_branches().await {
+                        Ok(branches) => branches,
+                        Err(e) => {
+                            self.app_state
+                                .save_menu_log
+                                .push_text(format!("Failed to refresh branches: {}", e));
+                            return Ok(());
+                        }
+                    };
+
                     // Re-enter save menu with updated batches, preserving selection
                     self.app_state.enter_save_menu(
                         batches,
+                        branches,
                         #[cfg(feature = "sui")]
                         session_info,
                     );
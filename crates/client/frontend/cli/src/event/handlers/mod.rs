@@ -3,6 +3,8 @@
 //! This module contains handler methods organized by responsibility:
 //! - `input`: Keyboard input and directional handling
 //! - `action`: Action execution (slots, abilities, targeting)
+//! - `dialogue`: Conversations (talk, reply, leave)
+//! - `shop`: Trading with merchants (buy, sell)
 //! - `targeting`: Auto-targeting and entity cycling
 //! - `rendering`: Terminal rendering
 //!
@@ -10,6 +12,8 @@
 //! and are automatically available to the EventLoop through Rust's module system.
 
 mod action;
+mod dialogue;
 mod input;
 mod rendering;
+mod shop;
 mod targeting;
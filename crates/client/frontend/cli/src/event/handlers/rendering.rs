@@ -31,6 +31,8 @@ where
             available_actions: &available_actions,
             message_panel_height: self.cli_config.ui.message_panel_height,
             map: self.oracles.map.as_ref(),
+            dialogues: self.oracles.dialogues.as_ref(),
+            items: self.oracles.items.as_ref(),
         };
 
         ui::render_with_view_model(terminal, &ctx)
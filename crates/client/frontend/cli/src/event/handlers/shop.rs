@@ -0,0 +1,155 @@
+//! Shop handling (open, browse, buy and sell).
+//!
+//! Purchases and sales are submitted as `Buy`/`Sell` actions so stock, prices
+//! and gold are validated by the state transition. The shop screen only
+//! mirrors the merchant's `Shop` and the player's inventory.
+
+use anyhow::Result;
+use client_frontend_core::EventConsumer;
+use game_core::{
+    Action, ActionInput, ActionKind, CharacterAction, EntityId, Faction, ItemHandle, TradeOrder,
+};
+
+use super::super::EventLoop;
+use crate::state::{AppMode, ShopPane};
+
+impl<C> EventLoop<C>
+where
+    C: EventConsumer,
+{
+    /// Open the shop of an adjacent merchant.
+    ///
+    /// Prefers the highlighted NPC if it is an adjacent merchant, otherwise the
+    /// first adjacent merchant.
+    pub(in crate::event) async fn handle_open_shop(&mut self) -> Result<()> {
+        let Some(player_pos) = self.view_model.player.position else {
+            return Ok(());
+        };
+
+        let can_trade = |npc: &&client_frontend_core::view_model::ActorView| {
+            npc.shop.is_some()
+                && npc.stats.resource_current.hp > 0
+                && !npc.faction.is_hostile_to(&Faction::Player)
+                && npc
+                    .position
+                    .is_some_and(|pos| pos.chebyshev_distance(player_pos) <= 1)
+        };
+
+        let merchant = self
+            .app_state
+            .highlighted_entity
+            .and_then(|id| self.view_model.npcs().find(|npc| npc.id == id))
+            .filter(can_trade)
+            .or_else(|| self.view_model.npcs().find(can_trade))
+            .map(|npc| npc.id);
+
+        match merchant {
+            Some(merchant) => self.app_state.enter_shop(merchant),
+            None => self
+                .consumer
+                .message_log_mut()
+                .push_text("Nobody here to trade with.".to_string()),
+        }
+        Ok(())
+    }
+
+    /// Switch between the buy and sell lists.
+    pub(in crate::event) fn handle_switch_shop_pane(&mut self) {
+        if let AppMode::Shop(shop_state) = &mut self.app_state.mode {
+            shop_state.pane = match shop_state.pane {
+                ShopPane::Buy => ShopPane::Sell,
+                ShopPane::Sell => ShopPane::Buy,
+            };
+            shop_state.selected = 0;
+        }
+    }
+
+    /// Buy or sell one unit of the selected row.
+    pub(in crate::event) async fn handle_shop_confirm(&mut self) -> Result<()> {
+        let AppMode::Shop(shop_state) = &self.app_state.mode else {
+            return Ok(());
+        };
+        let merchant = shop_state.merchant;
+        let Some(item) = self.shop_row_item(shop_state.pane, shop_state.selected) else {
+            return Ok(());
+        };
+
+        let kind = match shop_state.pane {
+            ShopPane::Buy => ActionKind::Buy,
+            ShopPane::Sell => ActionKind::Sell,
+        };
+        let order = TradeOrder::new(merchant, item, 1);
+        let action = CharacterAction::new(EntityId::PLAYER, kind, ActionInput::Trade(order));
+        self.tx_action.send(Action::Character(action)).await?;
+        Ok(())
+    }
+
+    /// Number of rows in a shop pane.
+    pub(in crate::event) fn shop_row_count(&self, merchant: EntityId, pane: ShopPane) -> usize {
+        match pane {
+            ShopPane::Buy => self
+                .view_model
+                .npcs()
+                .find(|npc| npc.id == merchant)
+                .and_then(|npc| npc.shop.as_ref())
+                .map_or(0, |shop| shop.stock.len()),
+            ShopPane::Sell => self.view_model.player.inventory.items.len(),
+        }
+    }
+
+    /// Keep the shop screen valid after the game state changes.
+    ///
+    /// Leaves the shop when the merchant can no longer trade (dead, moved away)
+    /// and clamps the selection when a row disappears.
+    pub(in crate::event) fn sync_shop_mode(&mut self) {
+        let AppMode::Shop(shop_state) = &self.app_state.mode else {
+            return;
+        };
+        let (merchant, pane) = (shop_state.merchant, shop_state.pane);
+
+        let player_pos = self.view_model.player.position;
+        let still_trading = self.view_model.npcs().any(|npc| {
+            npc.id == merchant
+                && npc.shop.is_some()
+                && npc.stats.resource_current.hp > 0
+                && npc
+                    .position
+                    .zip(player_pos)
+                    .is_some_and(|(a, b)| a.chebyshev_distance(b) <= 1)
+        });
+        if !still_trading {
+            self.app_state.exit_to_normal();
+            self.compute_auto_target();
+            return;
+        }
+
+        let rows = self.shop_row_count(merchant, pane);
+        if let AppMode::Shop(shop_state) = &mut self.app_state.mode {
+            shop_state.selected = shop_state.selected.min(rows.saturating_sub(1));
+        }
+    }
+
+    fn shop_row_item(&self, pane: ShopPane, row: usize) -> Option<ItemHandle> {
+        let AppMode::Shop(shop_state) = &self.app_state.mode else {
+            return None;
+        };
+        match pane {
+            ShopPane::Buy => self
+                .view_model
+                .npcs()
+                .find(|npc| npc.id == shop_state.merchant)?
+                .shop
+                .as_ref()?
+                .stock
+                .get(row)
+                .map(|entry| entry.item),
+            ShopPane::Sell => self
+                .view_model
+                .player
+                .inventory
+                .items
+                .get(row)
+                .map(|slot| slot.handle),
+        }
+    }
+}
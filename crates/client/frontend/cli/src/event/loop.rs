@@ -9,7 +9,7 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use game_core::{Action, EntityId, GameState};
-use runtime::{Event as RuntimeEvent, Topic};
+use runtime::{Event as RuntimeEvent, GameStateEvent, Topic};
 use tokio::{
     sync::{broadcast, broadcast::error::RecvError, mpsc},
     time::{self, Duration},
@@ -22,7 +22,7 @@ use client_frontend_core::{
     services::{ViewModelUpdater, targeting::TargetSelector},
     view_model::ViewModel,
 };
-use runtime::RuntimeHandle;
+use runtime::{ReplayControl, RuntimeHandle};
 
 const FRAME_INTERVAL_MS: u64 = 16;
 const SAVE_MENU_REFRESH_INTERVAL_MS: u64 = 2000; // Refresh Save Menu every 2 seconds
@@ -53,6 +53,8 @@ where
     pub(crate) cli_config: crate::config::CliConfig,
     /// Runtime handle for save/load operations
     pub(crate) runtime_handle: RuntimeHandle,
+    /// Playback control when watching a recorded session
+    pub(crate) replay: Option<ReplayControl>,
 }
 
 impl<C> EventLoop<C>
@@ -84,9 +86,27 @@ where
             oracles,
             cli_config,
             runtime_handle,
+            replay: None,
         }
     }
 
+    /// Watch the game without driving the player.
+    pub fn spectate(mut self) -> Self {
+        self.input.set_spectating(true);
+        self.app_state.spectating = true;
+        self
+    }
+
+    /// Watch a recorded session: keys steer playback instead of the player.
+    pub fn with_replay(mut self, replay: Option<ReplayControl>) -> Self {
+        if replay.is_some() {
+            self = self.spectate();
+        }
+        self.app_state.replay = replay.as_ref().map(ReplayControl::status);
+        self.replay = replay;
+        self
+    }
+
     pub async fn run(mut self, terminal: &mut Tui) -> Result<C> {
         // Initial render
         self.compute_auto_target();
@@ -95,6 +115,8 @@ where
         // Extract receivers from subscriptions
         let mut game_rx = self.subscriptions.remove(&Topic::GameState);
         let mut proof_rx = self.subscriptions.remove(&Topic::Proof);
+        let mut domain_rx = self.subscriptions.remove(&Topic::Game);
+        let mut replay_rx = self.replay.as_ref().map(ReplayControl::subscribe);
 
         // Save Menu refresh interval
         let mut save_menu_refresh_interval =
@@ -103,16 +125,34 @@ where
 
         loop {
             tokio::select! {
+                // Game state events first, so an action's message precedes
+                // the domain events published after it
+                biased;
+
                 result = async { game_rx.as_mut().unwrap().recv().await }, if game_rx.is_some() => {
                     if self.handle_runtime_event(result, terminal).await? {
                         break;
                     }
                 }
+                result = async { domain_rx.as_mut().unwrap().recv().await }, if domain_rx.is_some() => {
+                    if self.handle_runtime_event(result, terminal).await? {
+                        break;
+                    }
+                }
                 result = async { proof_rx.as_mut().unwrap().recv().await }, if proof_rx.is_some() => {
                     if self.handle_runtime_event(result, terminal).await? {
                         break;
                     }
                 }
+                result = async { replay_rx.as_mut().unwrap().changed().await }, if replay_rx.is_some() => {
+                    if result.is_err() {
+                        // Replay driver stopped; keep showing the last state
+                        replay_rx = None;
+                        continue;
+                    }
+                    self.app_state.replay = replay_rx.as_ref().map(|rx| rx.borrow().clone());
+                    self.render(terminal)?;
+                }
                 _ = time::sleep(Duration::from_millis(FRAME_INTERVAL_MS)) => {
                     if self.handle_input_tick(terminal).await? {
                         break;
@@ -144,6 +184,31 @@ where
                 // Let consumer process event (message logging, etc.)
                 let impact = self.consumer.on_event(&event);
 
+                // Domain events only add messages; the state change itself
+                // arrives with ActionExecuted
+                if matches!(event, RuntimeEvent::Game(_)) {
+                    if impact.requires_redraw {
+                        self.render(terminal)?;
+                    }
+                    return Ok(false);
+                }
+
+                // Rewinding a replay restores state without a delta to apply
+                if self.replay.is_some()
+                    && matches!(
+                        event,
+                        RuntimeEvent::GameState(GameStateEvent::StateRestored { .. })
+                    )
+                {
+                    let state = self.runtime_handle.query_state().await?;
+                    self.view_model
+                        .rebuild_from_state(&state, self.oracles.map.as_ref());
+                    self.sync_dialogue_mode();
+                    self.compute_auto_target();
+                    self.render(terminal)?;
+                    return Ok(false);
+                }
+
                 // If Save Menu is open and we got a Proof event, refresh it
                 if should_refresh_save_menu {
                     if let Err(e) = self.refresh_save_menu().await {
@@ -163,6 +228,8 @@ where
 
                     // Only render if something actually changed
                     if !scope.is_empty() {
+                        self.sync_dialogue_mode();
+                        self.sync_shop_mode();
                         // Recompute auto-target after state change
                         self.compute_auto_target();
                         self.render(terminal)?;
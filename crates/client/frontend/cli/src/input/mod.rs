@@ -6,6 +6,7 @@
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use game_core::{Action, ActionInput, ActionKind, CardinalDirection, CharacterAction, EntityId};
+use runtime::ReplayCommand;
 
 pub mod provider;
 pub use provider::CliActionProvider;
@@ -41,10 +42,24 @@ pub enum KeyAction {
     ConfirmTarget,
     /// Pick up item at player's position.
     PickupItem,
+    /// Command companions (attack target, recruit, or toggle stay/follow).
+    CommandCompanions,
+    /// Talk to an adjacent NPC.
+    Talk,
+    /// Pick a reply in dialogue mode (index into the displayed replies).
+    SelectDialogueChoice(usize),
+    /// Leave the current conversation.
+    LeaveConversation,
+    /// Open the shop of an adjacent merchant.
+    OpenShop,
+    /// Switch between the buy and sell lists in the shop.
+    SwitchShopPane,
     /// Create a manual checkpoint (save game).
     SaveGame,
     /// Open save/load menu to view checkpoints.
     OpenSaveMenu,
+    /// Rewind to the start of the player's previous turn.
+    UndoTurn,
     /// Navigate up in menu (SaveMenu, Inventory, etc.).
     MenuUp,
     /// Navigate down in menu (SaveMenu, Inventory, etc.).
@@ -57,6 +72,8 @@ pub enum KeyAction {
     SubmitProof,
     /// Create session on blockchain (SaveMenu).
     CreateSession,
+    /// Control playback of a replayed session.
+    Replay(ReplayCommand),
     /// No meaningful command was produced.
     None,
 }
@@ -69,11 +86,17 @@ pub enum KeyAction {
 /// - Bump-to-attack logic is handled in event loop, not here
 pub struct InputHandler {
     player_entity: EntityId,
+    /// The player is driven elsewhere (replay or network): game keys only
+    /// inspect the map and steer playback.
+    spectating: bool,
 }
 
 impl InputHandler {
     pub fn new(player_entity: EntityId) -> Self {
-        Self { player_entity }
+        Self {
+            player_entity,
+            spectating: false,
+        }
     }
 
     /// Updates the entity the handler should bind actions to.
@@ -81,6 +104,11 @@ impl InputHandler {
         self.player_entity = player_entity;
     }
 
+    /// Switches game keys to spectator (and replay playback) controls.
+    pub fn set_spectating(&mut self, spectating: bool) {
+        self.spectating = spectating;
+    }
+
     /// Converts a raw key event into a higher-level command.
     ///
     /// Mode is provided externally (from AppState) for cleaner separation.
@@ -88,12 +116,18 @@ impl InputHandler {
         use crate::state::AppMode;
 
         match mode {
+            // Conversations and trades are driven elsewhere, not by keys
+            AppMode::Normal | AppMode::Dialogue | AppMode::Shop(_) if self.spectating => {
+                self.handle_spectator_mode(key)
+            }
             AppMode::StartScreen(_) => self.handle_start_screen_mode(key),
             AppMode::Normal => self.handle_normal_mode(key),
             AppMode::ExamineManual => self.handle_examine_mode(key),
             AppMode::AbilityMenu => self.handle_ability_menu(key),
             AppMode::Targeting(targeting_state) => self.handle_targeting_mode(key, targeting_state),
             AppMode::SaveMenu(_) => self.handle_save_menu_mode(key),
+            AppMode::Dialogue => self.handle_dialogue_mode(key),
+            AppMode::Shop(_) => self.handle_shop_mode(key),
             AppMode::Inventory => KeyAction::None, // TODO: Future
         }
     }
@@ -132,6 +166,9 @@ impl InputHandler {
             KeyCode::Char('a') => KeyAction::OpenAbilityMenu,
             KeyCode::Char('x') => KeyAction::ToggleExamine,
             KeyCode::Char('g') => KeyAction::PickupItem,
+            KeyCode::Char('c') => KeyAction::CommandCompanions,
+            KeyCode::Char('t') => KeyAction::Talk,
+            KeyCode::Char('m') => KeyAction::OpenShop,
             KeyCode::Char('s') => {
                 if key.modifiers.contains(KeyModifiers::CONTROL) {
                     KeyAction::SaveGame // Ctrl+S to save
@@ -146,6 +183,13 @@ impl InputHandler {
                     KeyAction::None
                 }
             }
+            KeyCode::Char('z') => {
+                if key.modifiers.contains(KeyModifiers::CONTROL) {
+                    KeyAction::UndoTurn // Ctrl+Z to undo the last turn
+                } else {
+                    KeyAction::None
+                }
+            }
             KeyCode::Char(' ') | KeyCode::Char('.') => self.wait(),
             KeyCode::Char('q') => KeyAction::OpenStartScreen,
 
@@ -163,6 +207,36 @@ impl InputHandler {
         }
     }
 
+    /// Handle input while spectating (playback, examine, cycling).
+    ///
+    /// Playback keys are ignored by the event loop unless a replay is running.
+    fn handle_spectator_mode(&self, key: KeyEvent) -> KeyAction {
+        match key.code {
+            KeyCode::Char(' ') | KeyCode::Char('p') => {
+                KeyAction::Replay(ReplayCommand::TogglePause)
+            }
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('.') => {
+                KeyAction::Replay(ReplayCommand::StepForward)
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Char(',') => {
+                KeyAction::Replay(ReplayCommand::StepBack)
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => KeyAction::Replay(ReplayCommand::Faster),
+            KeyCode::Char('-') => KeyAction::Replay(ReplayCommand::Slower),
+            KeyCode::Char('x') => KeyAction::ToggleExamine,
+            KeyCode::Tab => {
+                if key.modifiers.contains(KeyModifiers::SHIFT) {
+                    KeyAction::PrevEntity
+                } else {
+                    KeyAction::NextEntity
+                }
+            }
+            KeyCode::BackTab => KeyAction::PrevEntity,
+            KeyCode::Char('q') | KeyCode::Esc => KeyAction::Quit,
+            _ => KeyAction::None,
+        }
+    }
+
     /// Handle input in Examine mode (cursor movement, entity cycling).
     fn handle_examine_mode(&self, key: KeyEvent) -> KeyAction {
         match key.code {
@@ -200,6 +274,31 @@ impl InputHandler {
         }
     }
 
+    /// Handle input in Dialogue mode (pick a reply or leave).
+    fn handle_dialogue_mode(&self, key: KeyEvent) -> KeyAction {
+        match key.code {
+            KeyCode::Char(c @ '1'..='9') => {
+                KeyAction::SelectDialogueChoice(c as usize - '1' as usize)
+            }
+            KeyCode::Esc => KeyAction::LeaveConversation,
+            _ => KeyAction::None,
+        }
+    }
+
+    /// Handle input in Shop mode (browse, trade, switch lists).
+    fn handle_shop_mode(&self, key: KeyEvent) -> KeyAction {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => KeyAction::MenuUp,
+            KeyCode::Down | KeyCode::Char('j') => KeyAction::MenuDown,
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Left | KeyCode::Right => {
+                KeyAction::SwitchShopPane
+            }
+            KeyCode::Enter => KeyAction::MenuConfirm,
+            KeyCode::Esc | KeyCode::Char('m') => KeyAction::ExitModal,
+            _ => KeyAction::None,
+        }
+    }
+
     /// Handle input in Targeting mode (mode-specific input).
     fn handle_targeting_mode(
         &self,
@@ -3,7 +3,7 @@
 //! This module provides the main render entry point that composes all widgets
 //! to create the complete terminal UI.
 use anyhow::Result;
-use game_core::env::MapOracle;
+use game_core::env::{DialogueOracle, ItemOracle, MapOracle};
 use ratatui::layout::{Alignment, Constraint, Direction, Layout};
 
 use crate::{
@@ -21,13 +21,15 @@ pub struct RenderContext<'a> {
     pub available_actions: &'a [game_core::ActionKind],
     pub message_panel_height: u16,
     pub map: &'a dyn MapOracle,
+    pub dialogues: &'a dyn DialogueOracle,
+    pub items: &'a dyn ItemOracle,
 }
 
 /// Render the terminal UI using ViewModel and widget system.
 ///
 /// This function routes rendering based on the current app mode:
 /// - **Full-screen modes**: Completely replace the game UI (SaveMenu, Inventory, etc.)
-/// - **Overlay modes**: Render game UI with a modal on top (AbilityMenu)
+/// - **Overlay modes**: Render game UI with a modal on top (AbilityMenu, Dialogue, Shop)
 /// - **Standard modes**: Render normal game UI (Normal, Examine, Targeting)
 ///
 /// All widgets consume ViewModel directly with no adapter layers.
@@ -88,12 +90,24 @@ fn render_fullscreen_mode(frame: &mut ratatui::Frame, ctx: &RenderContext) {
 
 /// Render overlay mode UI (on top of game view).
 fn render_overlay_mode(frame: &mut ratatui::Frame, ctx: &RenderContext) {
-    match ctx.app_state.mode {
+    match &ctx.app_state.mode {
         AppMode::AbilityMenu => {
             // Center the ability menu overlay
             let area = centered_rect(60, 80, frame.area());
             widgets::ability_menu::render(frame, area, ctx.available_actions, ctx.action_slots);
         }
+        AppMode::Dialogue => {
+            // Conversation may already be over; the loop switches back to Normal
+            if let Some(dialogue) = &ctx.view_model.dialogue {
+                let area = centered_rect(70, 50, frame.area());
+                let tree = ctx.dialogues.dialogue(&dialogue.conversation.dialogue);
+                widgets::dialogue::render(frame, area, dialogue, tree.as_ref());
+            }
+        }
+        AppMode::Shop(shop_state) => {
+            let area = centered_rect(80, 60, frame.area());
+            widgets::shop::render(frame, area, ctx.view_model, shop_state, ctx.items);
+        }
         _ => {
             // Should never reach here due to is_overlay() guard
             unreachable!("render_overlay_mode called with non-overlay mode")
@@ -0,0 +1,69 @@
+//! Dialogue widget showing the current conversation and available replies.
+
+use client_frontend_core::view_model::DialogueView;
+use game_core::DialogueTree;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Render the dialogue overlay.
+///
+/// Shows what the NPC says followed by the replies whose conditions currently
+/// hold, numbered 1-9 in display order.
+pub fn render(frame: &mut Frame, area: Rect, dialogue: &DialogueView, tree: Option<&DialogueTree>) {
+    let mut lines = Vec::new();
+
+    match tree.and_then(|tree| dialogue.node(tree).map(|node| (tree, node))) {
+        Some((tree, node)) => {
+            lines.push(Line::from(Span::styled(
+                node.text.clone(),
+                Style::default().fg(Color::White),
+            )));
+            lines.push(Line::from(""));
+
+            let choices = dialogue.available_choices(tree);
+            if choices.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    "(Nothing more to say)",
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            for (i, (_, choice)) in choices.iter().take(9).enumerate() {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {}. ", i + 1), Style::default().fg(Color::Yellow)),
+                    Span::styled(choice.text.clone(), Style::default().fg(Color::Cyan)),
+                ]));
+            }
+        }
+        None => {
+            lines.push(Line::from(Span::styled(
+                "(This conversation cannot be shown)",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Press 1-9 to reply | ESC to leave",
+        Style::default()
+            .fg(Color::Gray)
+            .add_modifier(Modifier::ITALIC),
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title(format!(" NPC#{} ", dialogue.npc().0))
+                .title_alignment(Alignment::Center),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(paragraph, area);
+}
@@ -14,10 +14,31 @@ use crate::state::{AppMode, AppState, TargetingInputMode};
 /// Displays context-sensitive controls based on current app mode.
 pub fn render(frame: &mut Frame, area: Rect, app_state: &AppState) {
     let text = match &app_state.mode {
+        AppMode::Normal | AppMode::Dialogue | AppMode::Shop(_) if app_state.replay.is_some() => {
+            vec![Line::from(vec![
+                Span::raw("[Space] Play/Pause | "),
+                Span::raw("[l/→] Step | "),
+                Span::raw("[h/←] Back | "),
+                Span::raw("[+/-] Speed | "),
+                Span::raw("[x] Examine | "),
+                Span::raw("[q] Quit"),
+            ])]
+        }
+        AppMode::Normal | AppMode::Dialogue | AppMode::Shop(_) if app_state.spectating => {
+            vec![Line::from(vec![
+                Span::raw("Player controlled remotely | "),
+                Span::raw("[x] Examine | "),
+                Span::raw("[Tab] Cycle | "),
+                Span::raw("[q] Quit"),
+            ])]
+        }
         AppMode::Normal => vec![Line::from(vec![
             Span::raw("[hjkl/WASD/Arrows] Move | "),
             Span::raw("[Space/Enter/.] Wait | "),
             Span::raw("[x] Manual examine | "),
+            Span::raw("[c] Command | "),
+            Span::raw("[t] Talk | "),
+            Span::raw("[m] Trade | "),
             Span::raw("[Tab] Cycle | "),
             Span::raw("[q] Quit"),
         ])],
@@ -61,6 +82,16 @@ pub fn render(frame: &mut Frame, area: Rect, app_state: &AppState) {
                 ])]
             }
         },
+        AppMode::Dialogue => vec![Line::from(vec![
+            Span::raw("[1-9] Reply | "),
+            Span::raw("[ESC] Leave conversation"),
+        ])],
+        AppMode::Shop(_) => vec![Line::from(vec![
+            Span::raw("[Up/Down] Select | "),
+            Span::raw("[Tab] Buy/Sell | "),
+            Span::raw("[Enter] Trade one | "),
+            Span::raw("[ESC] Leave shop"),
+        ])],
         _ => vec![Line::from(vec![Span::raw("[ESC] Exit mode")])],
     };
 
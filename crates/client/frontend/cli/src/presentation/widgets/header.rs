@@ -8,6 +8,7 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
+use runtime::ReplayStatus;
 
 use crate::state::{AppMode, AppState};
 
@@ -22,10 +23,12 @@ pub fn render(frame: &mut Frame, area: Rect, view_model: &ViewModel, app_state:
         AppMode::AbilityMenu => " [ABILITY MENU]",
         AppMode::Targeting(state) => &format!(" [TARGETING: {:?}]", state.action_kind),
         AppMode::SaveMenu(_) => " [SAVE/LOAD]",
+        AppMode::Dialogue => " [DIALOGUE]",
+        AppMode::Shop(_) => " [SHOP]",
         AppMode::Inventory => " [INVENTORY]",
     };
 
-    let text = vec![Line::from(vec![
+    let mut spans = vec![
         Span::raw("Time: "),
         Span::styled(
             view_model.turn.clock.to_string(),
@@ -49,10 +52,38 @@ pub fn render(frame: &mut Frame, area: Rect, view_model: &ViewModel, app_state:
                 .fg(Color::Magenta)
                 .add_modifier(Modifier::BOLD),
         ),
-    ])];
+    ];
+    if let Some(replay) = &app_state.replay {
+        spans.push(replay_span(replay));
+    }
+    let text = vec![Line::from(spans)];
 
     let paragraph =
         Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Game"));
 
     frame.render_widget(paragraph, area);
 }
+
+/// Replay progress: turn counter, playback state and verified saves.
+fn replay_span(replay: &ReplayStatus) -> Span<'static> {
+    let (state, color) = if let Some(error) = &replay.error {
+        (format!("DIVERGED: {}", error), Color::Red)
+    } else if replay.is_finished() {
+        ("FINISHED".to_string(), Color::LightGreen)
+    } else if replay.paused {
+        ("PAUSED".to_string(), Color::Yellow)
+    } else {
+        (
+            format!("PLAYING {}ms/turn", replay.delay.as_millis()),
+            Color::Cyan,
+        )
+    };
+
+    Span::styled(
+        format!(
+            " [REPLAY {}/{} | {} | verified {}/{}]",
+            replay.turn, replay.total_turns, state, replay.verified_states, replay.saved_states
+        ),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    )
+}
@@ -8,6 +8,7 @@
 
 pub mod ability_menu;
 pub mod action_slots;
+pub mod dialogue;
 pub mod examine;
 pub mod footer;
 pub mod game_area;
@@ -16,4 +17,5 @@ pub mod map;
 pub mod messages;
 pub mod player_stats;
 pub mod save_menu;
+pub mod shop;
 pub mod start_screen;
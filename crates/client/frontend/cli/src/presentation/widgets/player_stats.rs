@@ -1,6 +1,7 @@
 //! Player stats widget displaying health, energy, and other player information.
 
 use client_frontend_core::view_model::{PresentationMapper, ViewModel};
+use game_core::CompanionOrder;
 use ratatui::{
     Frame,
     layout::Rect,
@@ -41,6 +42,14 @@ pub fn render<T: PresentationMapper<Style = Style>>(
         Span::styled(format!("{}/{}", mp_cur, mp_max), mp_style),
     ]));
 
+    lines.push(Line::from(vec![
+        Span::styled("Gold: ", Style::default().fg(Color::White)),
+        Span::styled(
+            player.inventory.gold.to_string(),
+            Style::default().fg(Color::Yellow),
+        ),
+    ]));
+
     lines.push(Line::from(vec![
         Span::styled("Speed (Phys): ", Style::default().fg(Color::White)),
         Span::raw(player.stats.speed.physical.to_string()),
@@ -70,6 +79,24 @@ pub fn render<T: PresentationMapper<Style = Style>>(
         Span::raw(world.loose_item_count.to_string()),
     ]));
 
+    // Companions (allies, pets, summons)
+    for companion in view_model.companions() {
+        let (cur, max) = companion.stats.hp();
+        let order = match companion.companion.map(|bond| bond.order) {
+            Some(CompanionOrder::Stay) => "stay".to_string(),
+            Some(CompanionOrder::Attack(target)) => format!("attack #{}", target.0),
+            _ => "follow".to_string(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("Ally #{}: ", companion.id.0),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(format!("{}/{}", cur, max), theme.style_health(cur, max)),
+            Span::raw(format!(" ({})", order)),
+        ]));
+    }
+
     let paragraph =
         Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Player"));
 
@@ -1,7 +1,7 @@
 //! Save/Load menu widget (full-screen).
 //!
 //! Two-pane layout:
-//! - Left: Saved states (loadable checkpoints) - Enter to load
+//! - Left: Saved states (loadable checkpoints) grouped by branch - Enter to load
 //! - Right: Proof status and blockchain submission
 
 use ratatui::{
@@ -123,10 +123,29 @@ fn render_saved_states_pane(frame: &mut Frame, area: Rect, menu_state: &SaveMenu
             let is_selected = idx == menu_state.selected_index;
             let prefix = if is_selected { "► " } else { "  " };
 
-            let line = Line::from(vec![
+            let mut lines = Vec::new();
+
+            // Branch header above the first state of each branch
+            let starts_branch =
+                idx == 0 || menu_state.saved_states[idx - 1].branch != state_info.branch;
+            if starts_branch
+                && let Some(branch) = menu_state
+                    .branches
+                    .iter()
+                    .find(|branch| branch.info.id == state_info.branch)
+            {
+                lines.push(branch_header(branch));
+            }
+
+            let label = if state_info.is_tip {
+                format!("  Nonce {} (latest)", state_info.nonce)
+            } else {
+                format!("  Nonce {}", state_info.nonce)
+            };
+            lines.push(Line::from(vec![
                 Span::styled(prefix, Style::default().fg(Color::Yellow)),
                 Span::styled(
-                    format!("Nonce {}", state_info.nonce),
+                    label,
                     if is_selected {
                         Style::default()
                             .fg(Color::White)
@@ -135,9 +154,9 @@ fn render_saved_states_pane(frame: &mut Frame, area: Rect, menu_state: &SaveMenu
                         Style::default().fg(Color::White)
                     },
                 ),
-            ]);
+            ]));
 
-            ListItem::new(line)
+            ListItem::new(lines)
         })
         .collect();
 
@@ -152,6 +171,26 @@ fn render_saved_states_pane(frame: &mut Frame, area: Rect, menu_state: &SaveMenu
     frame.render_widget(list, area);
 }
 
+/// Header line naming a branch and where it was forked.
+fn branch_header(branch: &runtime::BranchSummary) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        branch.info.id.to_string(),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let Some(parent) = branch.info.parent {
+        spans.push(Span::styled(
+            format!(" (from {} at nonce {})", parent, branch.info.fork_nonce),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    if branch.active {
+        spans.push(Span::styled(" [active]", Style::default().fg(Color::Green)));
+    }
+    Line::from(spans)
+}
+
 /// Render the right pane showing details and proof operations.
 fn render_details_pane(frame: &mut Frame, area: Rect, menu_state: &SaveMenuState) {
     if menu_state.saved_states.is_empty() {
@@ -177,6 +216,10 @@ fn render_details_pane(frame: &mut Frame, area: Rect, menu_state: &SaveMenuState
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
             ),
+            Span::styled(
+                format!(" on {}", selected_state.branch),
+                Style::default().fg(Color::Gray),
+            ),
         ]),
         Line::from(""),
         Line::from(""),
@@ -196,6 +239,19 @@ fn render_details_pane(frame: &mut Frame, area: Rect, menu_state: &SaveMenuState
             ),
             Span::styled("Load This State", Style::default().fg(Color::White)),
         ]),
+        Line::from(vec![
+            Span::styled("      ", Style::default()),
+            Span::styled(
+                if selected_state.is_tip {
+                    format!("(resumes {})", selected_state.branch)
+                } else {
+                    "(continues on a new branch)".to_string()
+                },
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+        ]),
         Line::from(""),
         Line::from(""),
     ];
@@ -204,7 +260,7 @@ fn render_details_pane(frame: &mut Frame, area: Rect, menu_state: &SaveMenuState
     #[cfg(feature = "sui")]
     {
         // Only show session management for Genesis state (nonce 0)
-        if selected_state.nonce == 0 {
+        if selected_state.branch.is_main() && selected_state.nonce == 0 {
             if let Some(session) = &menu_state.session_info {
                 // Session already exists - show info
                 lines.push(Line::from(vec![Span::styled(
@@ -490,7 +546,7 @@ fn render_details_pane(frame: &mut Frame, area: Rect, menu_state: &SaveMenuState
         }
     } else {
         // Genesis state (nonce 0) - no associated batch
-        if selected_state.nonce == 0 {
+        if selected_state.branch.is_main() && selected_state.nonce == 0 {
             lines.push(Line::from(vec![Span::styled(
                 "Genesis State",
                 Style::default()
@@ -0,0 +1,159 @@
+//! Shop widget showing merchant stock (buy) and the player's inventory (sell).
+
+use client_frontend_core::view_model::{ActorView, ViewModel};
+use game_core::{ItemHandle, ItemKind, Shop, env::ItemOracle};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::state::{ShopPane, ShopState};
+
+/// Render the shop overlay.
+///
+/// Rows follow the merchant's stock order and the player's inventory slot
+/// order, which is what the event loop uses to resolve the selection.
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    view_model: &ViewModel,
+    shop_state: &ShopState,
+    items: &dyn ItemOracle,
+) {
+    let merchant = view_model.npcs().find(|npc| npc.id == shop_state.merchant);
+    let Some((merchant, shop)) = merchant.and_then(|m| m.shop.as_ref().map(|shop| (m, shop)))
+    else {
+        let paragraph = Paragraph::new("(This merchant is no longer trading)")
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title(" Shop "));
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(format!(
+            " NPC#{} | Merchant gold: {} | Your gold: {} ",
+            merchant.id.0, merchant.inventory.gold, view_model.player.inventory.gold
+        ))
+        .title_alignment(Alignment::Center);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(inner);
+
+    let buy_rows = shop
+        .stock
+        .iter()
+        .map(|entry| {
+            let price = items
+                .definition(entry.item)
+                .map(|def| format!("{}g", shop.buy_price(def.value)))
+                .unwrap_or_else(|| "-".to_string());
+            let quantity = if entry.quantity == 0 {
+                "sold out".to_string()
+            } else {
+                format!("x{}", entry.quantity)
+            };
+            format!("{} {} {}", item_label(items, entry.item), quantity, price)
+        })
+        .collect();
+    render_pane(
+        frame,
+        panes[0],
+        " Buy ",
+        buy_rows,
+        shop_state.pane == ShopPane::Buy,
+        shop_state.selected,
+    );
+
+    render_pane(
+        frame,
+        panes[1],
+        " Sell ",
+        sell_rows(&view_model.player, shop, items),
+        shop_state.pane == ShopPane::Sell,
+        shop_state.selected,
+    );
+}
+
+fn sell_rows(player: &ActorView, shop: &Shop, items: &dyn ItemOracle) -> Vec<String> {
+    player
+        .inventory
+        .items
+        .iter()
+        .map(|slot| {
+            let price = items
+                .definition(slot.handle)
+                .filter(|def| def.value > 0)
+                .map(|def| format!("{}g", shop.sell_price(def.value)))
+                .unwrap_or_else(|| "not wanted".to_string());
+            format!(
+                "{} x{} {}",
+                item_label(items, slot.handle),
+                slot.quantity,
+                price
+            )
+        })
+        .collect()
+}
+
+fn render_pane(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    rows: Vec<String>,
+    active: bool,
+    selected: usize,
+) {
+    let mut lines: Vec<Line> = rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            if active && i == selected {
+                Line::from(Span::styled(
+                    format!("> {}", row),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::raw(format!("  {}", row)))
+            }
+        })
+        .collect();
+
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  (nothing)",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let border = if active { Color::Yellow } else { Color::Gray };
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border))
+            .title(title),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Short label for an item (definitions carry no display names).
+fn item_label(items: &dyn ItemOracle, handle: ItemHandle) -> String {
+    match items.definition(handle).map(|def| def.kind) {
+        Some(ItemKind::Weapon(weapon)) => format!("{:?} #{}", weapon.kind, handle.0),
+        Some(ItemKind::Armor(_)) => format!("Armor #{}", handle.0),
+        Some(ItemKind::Consumable(_)) => format!("Consumable #{}", handle.0),
+        Some(ItemKind::Key { .. }) => format!("Key #{}", handle.0),
+        _ => format!("Item #{}", handle.0),
+    }
+}
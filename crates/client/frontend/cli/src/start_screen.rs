@@ -1,4 +1,4 @@
-//! Start screen for choosing New Game or Continue.
+//! Start screen for choosing New Game, Continue or Replay.
 
 use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEvent};
@@ -20,6 +20,8 @@ pub enum StartChoice {
     NewGame,
     /// Continue from existing session (with session index)
     Continue(usize),
+    /// Watch a recorded session from the start (with session index)
+    Replay(usize),
 }
 
 /// Show the start screen and get user's choice.
@@ -61,6 +63,9 @@ pub fn show_start_screen(
                         return Ok(StartChoice::Continue(selected - 1));
                     }
                 }
+                KeyCode::Char('r') if selected > 0 => {
+                    return Ok(StartChoice::Replay(selected - 1));
+                }
                 KeyCode::Char('q') | KeyCode::Esc => {
                     return Err(anyhow::anyhow!("User quit from start screen"));
                 }
@@ -201,6 +206,8 @@ fn render_footer(frame: &mut Frame, area: ratatui::layout::Rect) {
             Span::styled(" Navigate  ", Style::default().fg(Color::Gray)),
             Span::styled("Enter", Style::default().fg(Color::Yellow)),
             Span::styled(" Select  ", Style::default().fg(Color::Gray)),
+            Span::styled("r", Style::default().fg(Color::Yellow)),
+            Span::styled(" Replay  ", Style::default().fg(Color::Gray)),
             Span::styled("q/Esc", Style::default().fg(Color::Yellow)),
             Span::styled(" Quit", Style::default().fg(Color::Gray)),
         ]),
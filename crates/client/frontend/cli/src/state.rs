@@ -3,6 +3,7 @@
 use crate::cursor::CursorState;
 use client_frontend_core::MessageLog;
 use game_core::{ActionKind, EntityId, Position};
+use runtime::ReplayStatus;
 
 /// Top-level application mode determining input handling and UI layout.
 #[derive(Clone, Debug, PartialEq)]
@@ -19,6 +20,10 @@ pub enum AppMode {
     Targeting(TargetingState),
     /// Save/Load menu (full-screen).
     SaveMenu(SaveMenuState),
+    /// Conversation with an NPC (overlay, follows the game state's conversation).
+    Dialogue,
+    /// Trading with a merchant (overlay).
+    Shop(ShopState),
     /// Inventory management mode (future).
     #[allow(dead_code)]
     Inventory,
@@ -38,7 +43,10 @@ impl AppMode {
 
     /// Returns true if this mode should render as an overlay (on top of game view).
     pub fn is_overlay(&self) -> bool {
-        matches!(self, AppMode::AbilityMenu)
+        matches!(
+            self,
+            AppMode::AbilityMenu | AppMode::Dialogue | AppMode::Shop(_)
+        )
     }
 }
 
@@ -51,6 +59,26 @@ pub struct StartScreenState {
     pub sessions: Vec<client_bootstrap::SessionInfo>,
 }
 
+/// State for the shop screen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShopState {
+    /// The merchant being traded with.
+    pub merchant: EntityId,
+    /// Which list the selection applies to.
+    pub pane: ShopPane,
+    /// Selected row in the active pane.
+    pub selected: usize,
+}
+
+/// Shop screen panes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShopPane {
+    /// Merchant stock (buy).
+    Buy,
+    /// Player inventory (sell).
+    Sell,
+}
+
 /// State for targeting mode.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TargetingState {
@@ -64,14 +92,16 @@ pub struct TargetingState {
 ///
 /// **Design:**
 /// Two-pane layout:
-/// - Left pane: List of saved states (nonces) - for loading game
+/// - Left pane: List of saved states (nonces) grouped by branch - for loading game
 /// - Right pane: ActionBatch details for selected state - for proof management
 #[derive(Clone, Debug)]
 pub struct SaveMenuState {
     /// Currently selected saved state index (left pane).
     pub selected_index: usize,
-    /// List of saved states (derived from ActionBatch start_nonces).
+    /// List of saved states of every branch, grouped by branch.
     pub saved_states: Vec<SavedStateInfo>,
+    /// Session branches (main first).
+    pub branches: Vec<runtime::BranchSummary>,
     /// Full list of action batches for proof operations.
     pub action_batches: Vec<runtime::ActionBatch>,
     /// Blockchain session info (if available).
@@ -82,9 +112,13 @@ pub struct SaveMenuState {
 /// Information about a saved state (loadable checkpoint).
 #[derive(Clone, Debug)]
 pub struct SavedStateInfo {
-    /// The nonce at which this state was saved.
+    /// Branch the state belongs to.
+    pub branch: runtime::BranchId,
+    /// State nonce (number of actions applied).
     pub nonce: u64,
-    /// Associated action batch (if any) for proof info.
+    /// Whether this is the latest state of an inactive branch (loading resumes it).
+    pub is_tip: bool,
+    /// Associated main branch action batch (if any) for proof info.
     pub batch_index: Option<usize>,
 }
 
@@ -179,6 +213,10 @@ pub struct AppState {
     ///
     /// Persists across Save Menu open/close to maintain operation history.
     pub save_menu_log: MessageLog,
+    /// Playback status when watching a recorded session.
+    pub replay: Option<ReplayStatus>,
+    /// The player is driven elsewhere (replay or network input).
+    pub spectating: bool,
 }
 
 impl AppState {
@@ -195,6 +233,8 @@ impl AppState {
             | AppMode::Normal
             | AppMode::AbilityMenu
             | AppMode::SaveMenu(_)
+            | AppMode::Dialogue
+            | AppMode::Shop(_)
             | AppMode::Inventory => None,
         }
     }
@@ -213,6 +253,8 @@ impl Default for AppState {
             manual_cursor: None,
             action_slots: ActionSlots::new(),
             save_menu_log: MessageLog::new(50), // Keep last 50 blockchain operation messages
+            replay: None,
+            spectating: false,
         }
     }
 }
@@ -274,33 +316,51 @@ impl AppState {
         self.manual_cursor = None;
     }
 
-    /// Enters save menu mode with the provided checkpoint list.
+    /// Enters save menu mode with the provided checkpoint and branch lists.
     pub fn enter_save_menu(
         &mut self,
         action_batches: Vec<runtime::ActionBatch>,
+        mut branches: Vec<runtime::BranchSummary>,
         #[cfg(feature = "sui")] session_info: Option<client_blockchain_sui::contracts::GameSession>,
     ) {
-        // Build saved state list from action batch end_nonces
-        // (States are saved at the END of each batch, not the start)
-        let mut saved_states = Vec::new();
+        branches.sort_by_key(|branch| branch.info.id);
 
-        // Always add Genesis state (nonce 0) as the first entry
-        saved_states.push(SavedStateInfo {
-            nonce: 0,
-            batch_index: None, // Genesis has no associated batch
-        });
+        let mut saved_states = Vec::new();
+        for branch in &branches {
+            let id = branch.info.id;
+            for &nonce in &branch.saved_nonces {
+                // States are saved at the END of each batch: state nonce = end_nonce + 1
+                let batch_index = id
+                    .is_main()
+                    .then(|| {
+                        action_batches
+                            .iter()
+                            .position(|batch| batch.end_nonce + 1 == nonce)
+                    })
+                    .flatten();
+                saved_states.push(SavedStateInfo {
+                    branch: id,
+                    nonce,
+                    is_tip: false,
+                    batch_index,
+                });
+            }
 
-        // Add completed batch checkpoints
-        for (idx, batch) in action_batches.iter().enumerate() {
-            saved_states.push(SavedStateInfo {
-                nonce: batch.end_nonce,
-                batch_index: Some(idx),
-            });
+            // Inactive branches can also be resumed where they were left
+            if !branch.active && !branch.saved_nonces.contains(&branch.tip) {
+                saved_states.push(SavedStateInfo {
+                    branch: id,
+                    nonce: branch.tip,
+                    is_tip: true,
+                    batch_index: None,
+                });
+            }
         }
 
         self.mode = AppMode::SaveMenu(SaveMenuState {
             selected_index: 0,
             saved_states,
+            branches,
             action_batches,
             #[cfg(feature = "sui")]
             session_info,
@@ -308,6 +368,22 @@ impl AppState {
         self.manual_cursor = None;
     }
 
+    /// Enters dialogue mode (overlay showing the current conversation).
+    pub fn enter_dialogue(&mut self) {
+        self.mode = AppMode::Dialogue;
+        self.manual_cursor = None;
+    }
+
+    /// Enters shop mode with the merchant's stock selected.
+    pub fn enter_shop(&mut self, merchant: EntityId) {
+        self.mode = AppMode::Shop(ShopState {
+            merchant,
+            pane: ShopPane::Buy,
+            selected: 0,
+        });
+        self.manual_cursor = None;
+    }
+
     /// Exits to Normal mode (auto-target).
     pub fn exit_to_normal(&mut self) {
         self.mode = AppMode::Normal;
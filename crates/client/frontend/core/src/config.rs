@@ -142,9 +142,27 @@ impl EffectVisibility {
             AppliedValue::Summon { .. } => self.show_summon,
             AppliedValue::ItemAcquired { .. } => true, // Show item acquisitions
             AppliedValue::ItemUsed { .. } => true,     // Show item usage
+            AppliedValue::OrderIssued { .. } => true,  // Show companion orders
+            AppliedValue::Conversation { .. } => true, // Show conversation endings
+            AppliedValue::Bought { .. } | AppliedValue::Sold { .. } => true, // Show trades
             AppliedValue::None => false,               // Never show empty effects
         }
     }
+
+    /// Returns true if messages should be generated for this game event.
+    pub fn should_show_event(&self, event: &runtime::GameEvent) -> bool {
+        use runtime::GameEvent;
+
+        match event {
+            GameEvent::DamageTaken { .. }
+            | GameEvent::CriticalHit { .. }
+            | GameEvent::AttackMissed { .. } => self.show_damage,
+            GameEvent::Healed { .. } => self.show_healing,
+            GameEvent::EntityMoved { .. } => self.show_movement,
+            GameEvent::StatusApplied { .. } | GameEvent::StatusExpired { .. } => self.show_status,
+            _ => true,
+        }
+    }
 }
 
 fn read_env<T>(key: &str) -> Option<T>
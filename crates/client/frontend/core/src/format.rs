@@ -3,11 +3,16 @@
 //! Two-tier message system:
 //! 1. Action message: "Actor performs action on target"
 //! 2. Effect messages: Individual results for each affected entity
+//!
+//! Effects that the runtime also publishes as [`GameEvent`]s (damage, healing,
+//! status effects, items) are formatted from those events instead, see
+//! [`format_game_event`].
 
 use game_core::{
-    Action, ActionKind, ActionResult, EntityId,
+    Action, ActionKind, ActionResult, CompanionOrder, EntityId,
     action::{ActionInput, AppliedValue, EffectResult},
 };
+use runtime::GameEvent;
 
 /// Formats the primary action message.
 ///
@@ -25,6 +30,11 @@ pub fn format_action_message(action: &Action, actor_name: &str) -> String {
                 ActionKind::Wait => "waits",
                 ActionKind::PickupItem => "picks up",
                 ActionKind::UseItem => "uses",
+                ActionKind::Command => "commands",
+                ActionKind::Talk => "talks to",
+                ActionKind::Buy => "buys",
+                ActionKind::Sell => "sells",
+                ActionKind::RaiseDead => "raises the dead",
             };
 
             match &char_action.input {
@@ -39,6 +49,9 @@ pub fn format_action_message(action: &Action, actor_name: &str) -> String {
                 ActionInput::Position(pos) => {
                     format!("{} {} at ({}, {})", actor_name, kind_verb, pos.x, pos.y)
                 }
+                ActionInput::None if char_action.kind == ActionKind::Talk => {
+                    format!("{} ends the conversation", actor_name)
+                }
                 ActionInput::None => {
                     format!("{} {}", actor_name, kind_verb)
                 }
@@ -55,6 +68,37 @@ pub fn format_action_message(action: &Action, actor_name: &str) -> String {
                 ActionInput::InventorySlot(slot) => {
                     format!("{} {} item from slot {}", actor_name, kind_verb, slot)
                 }
+                ActionInput::Order(command) => {
+                    let companion = command
+                        .companion
+                        .map(entity_name)
+                        .unwrap_or_else(|| "companions".to_string());
+                    format!(
+                        "{} {} {}: {}",
+                        actor_name,
+                        kind_verb,
+                        companion,
+                        order_name(&command.order)
+                    )
+                }
+                ActionInput::Choice(index) => {
+                    format!("{} replies (choice {})", actor_name, index + 1)
+                }
+                ActionInput::Trade(order) => {
+                    let preposition = match char_action.kind {
+                        ActionKind::Sell => "to",
+                        _ => "from",
+                    };
+                    format!(
+                        "{} {} {:?} x{} {} {}",
+                        actor_name,
+                        kind_verb,
+                        order.item,
+                        order.quantity,
+                        preposition,
+                        entity_name(order.merchant)
+                    )
+                }
             }
         }
         Action::System { kind } => {
@@ -161,6 +205,47 @@ where
                     target_name, slot, handle
                 )),
 
+                AppliedValue::OrderIssued { companion, order } => {
+                    let companion = companion
+                        .map(entity_name)
+                        .unwrap_or_else(|| "Companions".to_string());
+                    Some(format!("{} will {}", companion, order_name(order)))
+                }
+
+                AppliedValue::Conversation { npc, ended } => {
+                    if *ended {
+                        Some(format!("Conversation with {} ends", entity_name(*npc)))
+                    } else {
+                        None
+                    }
+                }
+
+                AppliedValue::Bought {
+                    merchant,
+                    quantity,
+                    price,
+                    ..
+                } => Some(format!(
+                    "{} pays {} gold to {} for {} item(s)",
+                    target_name,
+                    price,
+                    entity_name(*merchant),
+                    quantity
+                )),
+
+                AppliedValue::Sold {
+                    merchant,
+                    quantity,
+                    price,
+                    ..
+                } => Some(format!(
+                    "{} receives {} gold from {} for {} item(s)",
+                    target_name,
+                    price,
+                    entity_name(*merchant),
+                    quantity
+                )),
+
                 AppliedValue::None => None, // No message for empty effects
             }
         })
@@ -186,6 +271,105 @@ where
     (action_msg, effect_msgs)
 }
 
+/// Formats a domain event from the runtime's `Topic::Game`.
+///
+/// Returns `None` for bookkeeping events that are not worth a message.
+/// Examples:
+/// - "NPC#5 takes 12 damage"
+/// - "Player lands a critical hit on NPC#5"
+/// - "NPC#5 dies"
+/// - "Player reaches level 3"
+pub fn format_game_event(event: &GameEvent) -> Option<String> {
+    let message = match event {
+        GameEvent::DamageTaken { entity, amount, .. } => {
+            format!("{} takes {} damage", entity_name(*entity), amount)
+        }
+        GameEvent::Healed { entity, amount, .. } => {
+            format!("{} heals {} HP", entity_name(*entity), amount)
+        }
+        GameEvent::CriticalHit {
+            attacker, target, ..
+        } => format!(
+            "{} lands a critical hit on {}",
+            entity_name(*attacker),
+            entity_name(*target)
+        ),
+        GameEvent::AttackMissed { attacker, target } => {
+            format!("{} misses {}", entity_name(*attacker), entity_name(*target))
+        }
+        GameEvent::EntityDied { entity, .. } => format!("{} dies", entity_name(*entity)),
+        GameEvent::EntityMoved {
+            entity,
+            from: Some(from),
+            to: Some(to),
+        } => format!(
+            "{} moves from ({}, {}) to ({}, {})",
+            entity_name(*entity),
+            from.x,
+            from.y,
+            to.x,
+            to.y
+        ),
+        GameEvent::StatusApplied { entity, status, .. } => {
+            format!("{} is affected by {:?}", entity_name(*entity), status)
+        }
+        GameEvent::StatusExpired { entity, status } => {
+            format!(
+                "{} is no longer affected by {:?}",
+                entity_name(*entity),
+                status
+            )
+        }
+        GameEvent::ItemPickedUp {
+            entity,
+            item,
+            quantity,
+        } => format!("{} picks up {:?} x{}", entity_name(*entity), item, quantity),
+        GameEvent::ItemUsed { entity, item } => {
+            format!("{} uses {:?}", entity_name(*entity), item)
+        }
+        GameEvent::ItemEquipped { entity, item } => {
+            format!("{} equips {:?}", entity_name(*entity), item)
+        }
+        GameEvent::LeveledUp { entity, level } => {
+            format!("{} reaches level {}", entity_name(*entity), level)
+        }
+        GameEvent::PropStateChanged {
+            prop,
+            kind,
+            is_active,
+        } => format!(
+            "{:?} {} is now {}",
+            kind,
+            entity_name(*prop),
+            if *is_active { "active" } else { "inactive" }
+        ),
+        GameEvent::ActionCompleted { .. }
+        | GameEvent::EntityMoved { .. }
+        | GameEvent::EntityRemovedFromActive { .. }
+        | GameEvent::EntityRemovedFromWorld { .. }
+        | GameEvent::HealthThresholdCrossed { .. }
+        | GameEvent::ReadyAtUpdated { .. } => return None,
+    };
+    Some(message)
+}
+
+/// Whether an effect is also reported as a [`GameEvent`].
+///
+/// Message logs fed by `Topic::Game` skip these effects to avoid duplicates.
+pub fn is_reported_as_game_event(applied_value: &AppliedValue) -> bool {
+    matches!(
+        applied_value,
+        AppliedValue::Damage { .. }
+            | AppliedValue::Healing { .. }
+            | AppliedValue::Movement { .. }
+            | AppliedValue::StatusApplied { .. }
+            | AppliedValue::StatusRemoved { .. }
+            | AppliedValue::ItemAcquired { .. }
+            | AppliedValue::ItemUsed { .. }
+    )
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -201,3 +385,12 @@ fn entity_name(id: EntityId) -> String {
         format!("NPC#{}", id.0)
     }
 }
+
+/// Describes a companion order as a verb phrase.
+fn order_name(order: &CompanionOrder) -> String {
+    match order {
+        CompanionOrder::Follow => "follow".to_string(),
+        CompanionOrder::Stay => "stay".to_string(),
+        CompanionOrder::Attack(target) => format!("attack {}", entity_name(*target)),
+    }
+}
@@ -13,7 +13,7 @@ use game_core::{GameState, StateDelta, env::MapOracle};
 use runtime::{Event, GameStateEvent};
 
 use crate::view_model::{
-    ViewModel,
+    DialogueView, ViewModel,
     entities::{collect_actors, collect_items, collect_props},
 };
 
@@ -145,6 +145,10 @@ impl ViewModelUpdater {
                 // ActionRef is just a log reference, doesn't carry state changes
                 UpdateScope::empty()
             }
+            Event::Game(_) => {
+                // Domain events describe changes already carried by ActionExecuted
+                UpdateScope::empty()
+            }
         }
     }
 
@@ -247,7 +251,8 @@ impl ViewModelUpdater {
 
         // Update world state if changed
         if !delta.world.is_empty() {
-            // NOTE: Currently WorldChanges only tracks occupancy (entity movements).
+            // NOTE: WorldChanges tracks occupancy (entity movements), story flags and
+            // the conversation cursor (handled below).
             // Terrain is static and never changes during gameplay.
             // Map widget computes entity positions dynamically from ViewModel.actors/props,
             // so we don't need to rebuild MapView for occupancy changes.
@@ -271,6 +276,13 @@ impl ViewModelUpdater {
             scope |= UpdateScope::WORLD;
         }
 
+        // Refresh the conversation when it changed or while one is open
+        // (choice conditions depend on inventory, faction and flags)
+        if delta.world.conversation || view_model.dialogue.is_some() {
+            view_model.dialogue = DialogueView::from_state(state);
+            scope |= UpdateScope::WORLD;
+        }
+
         // Update sync nonce
         view_model.last_sync_nonce = state.turn.nonce;
 
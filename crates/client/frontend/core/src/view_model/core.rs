@@ -1,11 +1,11 @@
 //! Core ViewModel structure.
 
-use game_core::{GameState, env::MapOracle};
+use game_core::{EntityId, GameState, env::MapOracle};
 
 use super::entities::{
     ActorView, ItemView, PropView, collect_actors, collect_items, collect_props,
 };
-use super::{MapView, TurnView, WorldSummary};
+use super::{DialogueView, MapView, TurnView, WorldSummary};
 
 /// Stateful ViewModel owned by the event loop.
 ///
@@ -42,6 +42,9 @@ pub struct ViewModel {
     /// Aggregate world statistics.
     pub world: WorldSummary,
 
+    /// The player's ongoing conversation, if any.
+    pub dialogue: Option<DialogueView>,
+
     /// Last synchronized GameState nonce for sync verification.
     pub last_sync_nonce: u64,
 }
@@ -71,6 +74,7 @@ impl ViewModel {
             props: collect_props(state),
             items: collect_items(state),
             world: WorldSummary::from_state(state),
+            dialogue: DialogueView::from_state(state),
             last_sync_nonce: state.turn.nonce,
         };
 
@@ -95,6 +99,7 @@ impl ViewModel {
         self.props = collect_props(state);
         self.items = collect_items(state);
         self.world = WorldSummary::from_state(state);
+        self.dialogue = DialogueView::from_state(state);
         self.last_sync_nonce = state.turn.nonce;
 
         #[cfg(debug_assertions)]
@@ -109,6 +114,16 @@ impl ViewModel {
         self.actors.iter().skip(1)
     }
 
+    /// Get iterator over the player's companions still on the map.
+    pub fn companions(&self) -> impl Iterator<Item = &ActorView> {
+        self.npcs().filter(|actor| {
+            actor.position.is_some()
+                && actor
+                    .companion
+                    .is_some_and(|bond| bond.leader == EntityId::PLAYER)
+        })
+    }
+
     /// Check if ViewModel is synchronized with given GameState.
     pub fn is_synced(&self, state: &GameState) -> bool {
         self.last_sync_nonce == state.turn.nonce
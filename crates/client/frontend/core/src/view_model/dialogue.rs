@@ -0,0 +1,59 @@
+//! Conversation view for dialogue rendering.
+
+use game_core::{
+    ActorState, Conversation, DialogueChoice, DialogueNode, DialogueTree, EntityId, GameState,
+    WorldState,
+};
+
+/// The player's ongoing conversation.
+///
+/// Dialogue trees live in the dialogue oracle, so this view keeps the
+/// conversation cursor plus the state needed to evaluate choice conditions.
+/// Frontends resolve the tree and ask for the currently available replies.
+#[derive(Clone, Debug)]
+pub struct DialogueView {
+    pub conversation: Conversation,
+    player: ActorState,
+    npc: ActorState,
+    world: WorldState,
+}
+
+impl DialogueView {
+    /// Returns the view for the player's conversation, if one is active.
+    pub fn from_state(state: &GameState) -> Option<Self> {
+        let conversation = state.world.conversation.clone()?;
+        let player = state.entities.actor(EntityId::PLAYER)?.clone();
+        let npc = state.entities.actor(conversation.npc)?.clone();
+
+        Some(Self {
+            conversation,
+            player,
+            npc,
+            world: state.world.clone(),
+        })
+    }
+
+    /// NPC being talked to.
+    pub fn npc(&self) -> EntityId {
+        self.conversation.npc
+    }
+
+    /// Current node of `tree`.
+    pub fn node<'t>(&self, tree: &'t DialogueTree) -> Option<&'t DialogueNode> {
+        tree.node(&self.conversation.node)
+    }
+
+    /// Available replies with their choice index (as sent in `ActionInput::Choice`).
+    pub fn available_choices<'t>(&self, tree: &'t DialogueTree) -> Vec<(u8, &'t DialogueChoice)> {
+        self.node(tree)
+            .map(|node| {
+                node.choices
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, choice)| choice.is_available(&self.player, &self.npc, &self.world))
+                    .map(|(index, choice)| (index as u8, choice))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -5,8 +5,8 @@
 
 use arrayvec::ArrayVec;
 use game_core::{
-    ActionAbility, EntityId, GameConfig, ItemHandle, PassiveAbility, Position, PropKind, Tick,
-    stats::StatsSnapshot,
+    ActionAbility, Companion, EntityId, Faction, GameConfig, InventoryState, ItemHandle,
+    PassiveAbility, Position, PropKind, Shop, Tick, stats::StatsSnapshot,
 };
 
 /// Actor view (Player + NPCs) for rendering and targeting.
@@ -29,6 +29,16 @@ pub struct ActorView {
     pub actions: ArrayVec<ActionAbility, { GameConfig::MAX_ACTIONS }>,
     /// Passive abilities that provide automatic benefits.
     pub passives: ArrayVec<PassiveAbility, { GameConfig::MAX_PASSIVES }>,
+    /// Allegiance, used to tell enemies from potential allies.
+    pub faction: Faction,
+    /// Leader bond if this actor is a companion, pet, or summon.
+    pub companion: Option<Companion>,
+    /// Dialogue tree identifier if this actor can be talked to.
+    pub dialogue: Option<String>,
+    /// Carried items and gold.
+    pub inventory: InventoryState,
+    /// Merchant stock and pricing if this actor trades.
+    pub shop: Option<Shop>,
 }
 
 impl ActorView {
@@ -41,6 +51,11 @@ impl ActorView {
             ready_at: actor.ready_at,
             actions: actor.actions.clone(),
             passives: actor.passives.clone(),
+            faction: actor.faction,
+            companion: actor.companion,
+            dialogue: actor.dialogue.clone(),
+            inventory: actor.inventory.clone(),
+            shop: actor.shop.clone(),
         }
     }
 }
@@ -7,6 +7,7 @@
 //! to ensure consistency between ZK proofs and UI rendering.
 
 pub mod core;
+pub mod dialogue;
 pub mod entities;
 pub mod map;
 pub mod presentation;
@@ -15,6 +16,7 @@ pub mod world;
 
 // Re-export core types
 pub use self::core::ViewModel;
+pub use dialogue::DialogueView;
 pub use entities::{ActorView, ItemView, PropView};
 pub use map::{MapView, TileView};
 pub use presentation::PresentationMapper;
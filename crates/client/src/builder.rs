@@ -1,19 +1,19 @@
 //! Client builder with dependency injection pattern.
 
-use crate::{BlockchainClient, Client, Frontend};
+use crate::{BlockchainClient, Client, Driver, Frontend};
 use anyhow::{Context, Result};
 
 /// Builder for constructing a Client with proper validation.
 ///
 /// # Design Principles
 ///
-/// - **Required fields**: Runtime and Frontend must be provided
+/// - **Required fields**: Runtime (or Replayer) and Frontend must be provided
 /// - **Optional fields**: Blockchain client is optional
 /// - **Fail-fast validation**: Missing required fields cause build() to fail
 /// - **Fluent API**: Chainable methods for ergonomic construction
 #[derive(Default)]
 pub struct ClientBuilder {
-    runtime: Option<runtime::Runtime>,
+    driver: Option<Driver>,
     frontend: Option<Box<dyn Frontend>>,
     blockchain: Option<Box<dyn BlockchainClient>>,
 }
@@ -29,7 +29,16 @@ impl ClientBuilder {
     /// The runtime handles game logic, state management, and event emission.
     /// It should be constructed via `RuntimeBuilder` from the `client-bootstrap` crate.
     pub fn runtime(mut self, runtime: runtime::Runtime) -> Self {
-        self.runtime = Some(runtime);
+        self.driver = Some(Driver::Live(runtime));
+        self
+    }
+
+    /// Replay a recorded session instead of running a live game.
+    ///
+    /// Replaces any runtime set with `.runtime()`. The frontend should be given
+    /// the matching `ReplayControl` so the player can steer playback.
+    pub fn replayer(mut self, replayer: runtime::Replayer) -> Self {
+        self.driver = Some(Driver::Replay(Box::new(replayer)));
         self
     }
 
@@ -60,16 +69,16 @@ impl ClientBuilder {
     /// - Runtime is not set (required)
     /// - Frontend is not set (required)
     pub fn build(self) -> Result<Client> {
-        let runtime = self
-            .runtime
-            .context("Runtime is required. Use .runtime() to set it.")?;
+        let driver = self
+            .driver
+            .context("Runtime is required. Use .runtime() or .replayer() to set it.")?;
 
         let frontend = self
             .frontend
             .context("Frontend is required. Use .frontend() to set it.")?;
 
         Ok(Client {
-            runtime,
+            driver,
             frontend,
             blockchain: self.blockchain,
         })
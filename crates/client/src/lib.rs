@@ -48,11 +48,35 @@ use runtime::RuntimeHandle;
 /// 4. Client::run() transfers control to frontend (blocking)
 /// 5. On frontend exit, runtime and blockchain workers are cleaned up
 pub struct Client {
-    runtime: runtime::Runtime,
+    driver: Driver,
     frontend: Box<dyn Frontend>,
     blockchain: Option<Box<dyn BlockchainClient>>,
 }
 
+/// What advances the game in the background.
+enum Driver {
+    /// Live play: every entity acts through its provider.
+    Live(runtime::Runtime),
+    /// Playback of a recorded session, controlled by the frontend.
+    Replay(Box<runtime::Replayer>),
+}
+
+impl Driver {
+    fn handle(&self) -> RuntimeHandle {
+        match self {
+            Driver::Live(runtime) => runtime.handle(),
+            Driver::Replay(replayer) => replayer.handle(),
+        }
+    }
+
+    async fn run(self) -> runtime::Result<()> {
+        match self {
+            Driver::Live(mut runtime) => runtime.run().await,
+            Driver::Replay(replayer) => (*replayer).run().await,
+        }
+    }
+}
+
 impl Client {
     /// Create a new ClientBuilder.
     pub fn builder() -> ClientBuilder {
@@ -74,7 +98,7 @@ impl Client {
     /// - Frontend execution fails
     /// - Blockchain worker fails critically
     pub async fn run(self) -> Result<()> {
-        let handle = self.runtime.handle();
+        let handle = self.driver.handle();
 
         // Optional: Start blockchain proof submission worker
         let blockchain_task = if let Some(blockchain) = self.blockchain {
@@ -88,11 +112,11 @@ impl Client {
             None
         };
 
-        // Start runtime in background
-        let mut runtime = self.runtime;
+        // Start runtime (or replay) in background
+        let driver = self.driver;
         let frontend_handle = handle.clone();
         let runtime_task = tokio::spawn(async move {
-            if let Err(e) = runtime.run().await {
+            if let Err(e) = driver.run().await {
                 tracing::error!("Runtime error: {}", e);
             }
         });
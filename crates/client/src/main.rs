@@ -49,19 +49,21 @@ async fn main() -> Result<()> {
 /// Run the CLI frontend.
 #[cfg(feature = "cli")]
 async fn run_cli() -> Result<()> {
-    use client_bootstrap::{RuntimeBuilder, RuntimeConfig, list_sessions, load_latest_state};
+    use client_bootstrap::{
+        RuntimeBuilder, RuntimeConfig, list_sessions, load_genesis_state, load_latest_state,
+    };
     use client_frontend_cli::{
         CliConfig, CliFrontend, FrontendConfig, StartChoice, logging, show_start_screen,
     };
     use dungeon_client::Client;
 
     // 1. Load configuration from environment
-    let mut runtime_config = RuntimeConfig::from_env();
+    let mut runtime_config = RuntimeConfig::from_env()?;
     let frontend_config = FrontendConfig::from_env();
     let cli_config = CliConfig::from_env();
 
     // 2. Show start screen and determine session
-    let (session_id, initial_state) = {
+    let (session_id, initial_state, replay) = {
         use client_frontend_cli::presentation::terminal;
 
         // Initialize terminal for start screen
@@ -91,7 +93,7 @@ async fn run_cli() -> Result<()> {
                         .as_secs();
                     format!("session_{}", timestamp)
                 });
-                (session_id, None)
+                (session_id, None, false)
             }
             StartChoice::Continue(session_idx) => {
                 // Get selected session
@@ -108,13 +110,30 @@ async fn run_cli() -> Result<()> {
                         )
                     })?;
 
-                (selected_session.session_id.clone(), Some(state))
+                (selected_session.session_id.clone(), Some(state), false)
+            }
+            StartChoice::Replay(session_idx) => {
+                let selected_session = sessions
+                    .get(session_idx)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid session index: {}", session_idx))?;
+
+                // Replays start from genesis and re-execute the action log
+                let state = load_genesis_state(save_dir, &selected_session.session_id)?;
+
+                (selected_session.session_id.clone(), Some(state), true)
             }
         }
     };
 
+    // A replay only reads the recorded session; it must not write to it
+    if replay {
+        runtime_config.enable_persistence = false;
+        runtime_config.enable_proving = false;
+        runtime_config.network_input_addr = None;
+    }
+
     // Update runtime config with chosen session ID
-    runtime_config.session_id = Some(session_id);
+    runtime_config.session_id = Some(session_id.clone());
 
     // 3. Setup logging (now with final session ID)
     logging::setup_logging(&runtime_config.session_id)?;
@@ -123,6 +142,7 @@ async fn run_cli() -> Result<()> {
     tracing::info!("Session ID: {:?}", runtime_config.session_id);
     tracing::info!("ZK proving: {}", runtime_config.enable_proving);
     tracing::info!("Persistence: {}", runtime_config.enable_persistence);
+    tracing::info!("Replay: {}", replay);
 
     // 3. Optional: Initialize blockchain clients for manual workflow (Sui feature only)
     #[cfg(feature = "sui")]
@@ -198,7 +218,20 @@ async fn run_cli() -> Result<()> {
     let frontend = CliFrontend::new(frontend_config, cli_config, setup.oracles.clone());
 
     // 6. Build Client (composition layer)
-    let builder = Client::builder().runtime(setup.runtime).frontend(frontend);
+    let builder = if replay {
+        let save_dir = setup
+            .config
+            .save_data_dir
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Save data directory not configured"))?;
+        let (replayer, control) =
+            runtime::Replayer::new(setup.runtime, save_dir.join(&session_id), &session_id).await?;
+        Client::builder()
+            .replayer(replayer)
+            .frontend(frontend.with_replay(control))
+    } else {
+        Client::builder().runtime(setup.runtime).frontend(frontend)
+    };
 
     // 7. Build and run
     let client = builder.build()?;
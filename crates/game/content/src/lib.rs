@@ -5,6 +5,7 @@
 //! - Map layouts (data-driven via RON)
 //! - NPC templates (data-driven via RON)
 //! - Item catalogs (data-driven via RON)
+//! - Dialogue trees (data-driven via RON)
 //! - Game rules tables (data-driven via TOML)
 //! - Game configuration (data-driven via TOML)
 //!
@@ -24,6 +25,6 @@ pub use traits::{
 
 #[cfg(feature = "loaders")]
 pub use loaders::{
-    ActionProfileRegistry, ActorLoader, ConfigLoader, ContentFactory, ItemLoader, MapLoader,
-    load_trait_registry,
+    ActionProfileRegistry, ActorLoader, ConfigLoader, ContentFactory, DialogueLoader, ItemLoader,
+    MapLoader, load_trait_registry,
 };
@@ -51,6 +51,14 @@ impl ActionProfileRegistry {
             profiles.insert(profile.kind, profile);
         }
 
+        // Load summoning actions (RaiseDead, etc.)
+        let summon_ron = include_str!("../../data/actions/summon.ron");
+        let summon_profiles: Vec<ActionProfile> =
+            ron::from_str(summon_ron).map_err(|e| format!("Failed to parse summon.ron: {}", e))?;
+        for profile in summon_profiles {
+            profiles.insert(profile.kind, profile);
+        }
+
         Ok(Self { profiles })
     }
 
@@ -107,5 +115,14 @@ mod tests {
         // Verify Wait
         let wait_profile = registry.get(ActionKind::Wait);
         assert_eq!(wait_profile.kind, ActionKind::Wait);
+
+        // Verify RaiseDead summons bound skeletons
+        let raise_profile = registry.get(ActionKind::RaiseDead);
+        assert!(raise_profile.tags.contains(&game_core::ActionTag::Summon));
+        assert!(matches!(
+            &raise_profile.effects[0].kind,
+            game_core::EffectKind::Summon(summon)
+                if summon.template_id == "skeleton_warrior" && summon.duration.is_some()
+        ));
     }
 }
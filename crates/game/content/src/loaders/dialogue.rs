@@ -0,0 +1,56 @@
+//! Dialogue catalog loader.
+
+use std::path::Path;
+
+use game_core::DialogueTree;
+use serde::{Deserialize, Serialize};
+
+use crate::loaders::{LoadResult, read_file};
+
+/// Dialogue catalog structure for RON files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogueCatalog {
+    pub dialogues: Vec<DialogueTree>,
+}
+
+/// Loader for dialogue catalog from RON files.
+pub struct DialogueLoader;
+
+impl DialogueLoader {
+    /// Load dialogue catalog from a RON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the RON file containing DialogueCatalog
+    ///
+    /// # Returns
+    ///
+    /// Returns a Vec of DialogueTrees.
+    pub fn load(path: &Path) -> LoadResult<Vec<DialogueTree>> {
+        let content = read_file(path)?;
+        let catalog: DialogueCatalog = ron::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("Failed to parse dialogue catalog RON: {}", e))?;
+
+        for tree in &catalog.dialogues {
+            if tree.root_node().is_none() {
+                anyhow::bail!("Dialogue '{}' has no root node '{}'", tree.id, tree.root);
+            }
+            for node in &tree.nodes {
+                for choice in &node.choices {
+                    if let Some(next) = &choice.next
+                        && tree.node(next).is_none()
+                    {
+                        anyhow::bail!(
+                            "Dialogue '{}' node '{}' links to unknown node '{}'",
+                            tree.id,
+                            node.id,
+                            next
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(catalog.dialogues)
+    }
+}
@@ -3,7 +3,8 @@
 use std::path::{Path, PathBuf};
 
 use crate::loaders::{
-    ActorLoader, ConfigLoader, ItemLoader, LoadResult, MapLoader, load_trait_registry,
+    ActorLoader, ConfigLoader, DialogueLoader, ItemLoader, LoadResult, MapLoader,
+    load_trait_registry,
 };
 use crate::traits::TraitRegistry;
 
@@ -16,7 +17,8 @@ use crate::traits::TraitRegistry;
 /// ├── config.toml
 /// ├── tables.toml
 /// ├── items.ron
-/// ├── npcs.ron
+/// ├── actors.ron
+/// ├── dialogues.ron
 /// └── maps/
 ///     ├── test_dungeon.ron
 ///     └── boss_arena.ron
@@ -49,6 +51,12 @@ impl ContentFactory {
         ItemLoader::load(&path)
     }
 
+    /// Load dialogue catalog from `dialogues.ron`.
+    pub fn load_dialogues(&self) -> LoadResult<Vec<game_core::DialogueTree>> {
+        let path = self.data_dir.join("dialogues.ron");
+        DialogueLoader::load(&path)
+    }
+
     /// Load trait registry from `traits/` directory.
     pub fn load_trait_registry(&self) -> LoadResult<TraitRegistry> {
         let traits_dir = self.data_dir.join("traits");
@@ -6,6 +6,7 @@
 pub mod actions;
 pub mod actors;
 pub mod config;
+pub mod dialogue;
 pub mod factory;
 pub mod item;
 pub mod map;
@@ -14,6 +15,7 @@ pub mod traits;
 pub use actions::ActionProfileRegistry;
 pub use actors::ActorLoader;
 pub use config::ConfigLoader;
+pub use dialogue::DialogueLoader;
 pub use factory::ContentFactory;
 pub use item::ItemLoader;
 pub use map::MapLoader;
@@ -0,0 +1,299 @@
+//! Companion effect implementations (orders, recruitment, summoning).
+
+use crate::action::CardinalDirection;
+use crate::action::effect::ExecutionPhase;
+use crate::action::error::ActionError;
+use crate::action::execute::EffectContext;
+use crate::action::types::{ActionInput, AppliedValue, CompanionCommand};
+use crate::state::{Companion, CompanionOrder, EntityId, Position, Tick};
+use crate::traits::Faction;
+
+/// Give an order to one or all of the caster's companions.
+///
+/// This effect:
+/// 1. Gets the command from ActionInput::Order
+/// 2. Updates the order stored in each addressed companion bond
+/// 3. Recruits the addressed actor if it is an adjacent, unbound `Friendly`
+///    actor and the order is `Follow` or `Stay`
+///
+/// Orders are stored in state, so companion AI remains a pure function of state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IssueOrderEffect;
+
+impl IssueOrderEffect {
+    /// Create a new IssueOrder effect.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Pre-validate: Check the addressed companion and the order target.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        let command = Self::command(ctx)?;
+
+        if let CompanionOrder::Attack(target) = command.order {
+            let target = ctx
+                .state
+                .entities
+                .actor(target)
+                .ok_or(ActionError::TargetNotFound)?;
+            if !target.is_alive() || target.id == ctx.caster {
+                return Err(ActionError::InvalidTarget);
+            }
+        }
+
+        match command.companion {
+            Some(companion) => {
+                let actor = ctx
+                    .state
+                    .entities
+                    .actor(companion)
+                    .ok_or(ActionError::TargetNotFound)?;
+                if actor.is_companion_of(ctx.caster) {
+                    return Ok(());
+                }
+                Self::validate_recruit(ctx, companion, command.order)
+            }
+            None => {
+                if ctx
+                    .state
+                    .entities
+                    .companions_of(ctx.caster)
+                    .next()
+                    .is_none()
+                {
+                    return Err(ActionError::EffectFailed(
+                        "No companions to command".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Apply the order (recruiting the companion if needed).
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        let command = Self::command(ctx)?;
+        let caster = ctx.caster;
+
+        match command.companion {
+            Some(companion) => {
+                let actor = ctx
+                    .state
+                    .entities
+                    .actor_mut(companion)
+                    .ok_or(ActionError::TargetNotFound)?;
+                let bond = actor
+                    .companion
+                    .get_or_insert_with(|| Companion::follower(caster));
+                bond.order = command.order;
+            }
+            None => {
+                for actor in ctx.state.entities.all_actors_mut() {
+                    if let Some(bond) = actor.companion.as_mut()
+                        && bond.leader == caster
+                    {
+                        bond.order = command.order;
+                    }
+                }
+            }
+        }
+
+        Ok(AppliedValue::OrderIssued {
+            companion: command.companion,
+            order: command.order,
+        })
+    }
+
+    /// Post-validate: No additional validation needed.
+    pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    /// Get default execution phase for IssueOrder effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+
+    fn command(ctx: &EffectContext) -> Result<CompanionCommand, ActionError> {
+        match ctx.action_input {
+            ActionInput::Order(command) => Ok(*command),
+            _ => Err(ActionError::EffectFailed(
+                "IssueOrderEffect requires Order input".to_string(),
+            )),
+        }
+    }
+
+    /// Recruitment requires an adjacent, living, unbound `Friendly` actor.
+    fn validate_recruit(
+        ctx: &EffectContext,
+        recruit: EntityId,
+        order: CompanionOrder,
+    ) -> Result<(), ActionError> {
+        let actor = ctx
+            .state
+            .entities
+            .actor(recruit)
+            .ok_or(ActionError::TargetNotFound)?;
+
+        if recruit == ctx.caster
+            || actor.companion.is_some()
+            || actor.faction != Faction::Friendly
+            || !actor.is_alive()
+            || matches!(order, CompanionOrder::Attack(_))
+        {
+            return Err(ActionError::InvalidTarget);
+        }
+
+        let caster_pos = ctx
+            .state
+            .actor_position(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?;
+        let recruit_pos = actor.position.ok_or(ActionError::InvalidTarget)?;
+        if caster_pos.chebyshev_distance(recruit_pos) > 1 {
+            return Err(ActionError::OutOfRange);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for IssueOrderEffect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Summon creatures bound to the caster.
+///
+/// This effect:
+/// 1. Looks up `template_id` in the actors oracle
+/// 2. Spawns up to `count` actors on free tiles adjacent to the caster
+///    (in [`CardinalDirection::all`] order)
+/// 3. Gives each summon the caster's faction and a [`Companion`] bond that
+///    expires after `duration` ticks (`None` = permanent)
+/// 4. Schedules summons to act immediately
+///
+/// Returns the last summoned entity as `AppliedValue::Summon`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SummonEffect {
+    pub template_id: String,
+    pub count: u32,
+    pub duration: Option<Tick>,
+}
+
+impl SummonEffect {
+    /// Create a new Summon effect.
+    pub fn new(template_id: impl Into<String>, count: u32, duration: Option<Tick>) -> Self {
+        Self {
+            template_id: template_id.into(),
+            count,
+            duration,
+        }
+    }
+
+    /// Pre-validate: Check the template exists and there is room to summon.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        self.template(ctx)?;
+
+        if self.count == 0 {
+            return Err(ActionError::EffectFailed(
+                "Summon count must be positive".to_string(),
+            ));
+        }
+
+        let caster_pos = ctx
+            .state
+            .actor_position(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?;
+        if Self::free_tiles(ctx, caster_pos)?.is_empty() {
+            return Err(ActionError::Occupied);
+        }
+
+        Ok(())
+    }
+
+    /// Apply summoning: spawn bound actors around the caster.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        let template = self.template(ctx)?;
+        let caster = ctx
+            .state
+            .entities
+            .actor(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?;
+        let caster_pos = caster.position.ok_or(ActionError::ActorNotFound)?;
+        let faction = caster.faction;
+
+        let now = ctx.state.turn.clock;
+        let expires_at = self.duration.map(|duration| now.saturating_add(duration));
+
+        let mut last = None;
+        for position in Self::free_tiles(ctx, caster_pos)?
+            .into_iter()
+            .take(self.count as usize)
+        {
+            let id = ctx
+                .state
+                .add_npc(&template, position)
+                .map_err(|e| ActionError::EffectFailed(format!("Failed to summon: {}", e)))?;
+
+            let summon = ctx
+                .state
+                .entities
+                .actor_mut(id)
+                .ok_or(ActionError::ActorNotFound)?;
+            summon.faction = faction;
+            summon.companion = Some(Companion::summoned(ctx.caster, expires_at));
+            summon.ready_at = Some(now);
+            ctx.state.turn.active_actors.insert(id);
+
+            last = Some(id);
+        }
+
+        let entity_id = last.ok_or(ActionError::Occupied)?;
+        Ok(AppliedValue::Summon { entity_id })
+    }
+
+    /// Post-validate: No additional validation needed.
+    pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    /// Get default execution phase for Summon effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+
+    fn template(&self, ctx: &EffectContext) -> Result<crate::env::ActorTemplate, ActionError> {
+        let actors = ctx
+            .env
+            .actors()
+            .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+        actors.template(&self.template_id).ok_or_else(|| {
+            ActionError::EffectFailed(format!("Actor template '{}' not found", self.template_id))
+        })
+    }
+
+    /// Passable, unoccupied tiles adjacent to `center`, in direction order.
+    fn free_tiles(ctx: &EffectContext, center: Position) -> Result<Vec<Position>, ActionError> {
+        let map = ctx.env.map().map_err(|_| ActionError::MapNotAvailable)?;
+
+        Ok(CardinalDirection::all()
+            .into_iter()
+            .map(|direction| {
+                let (dx, dy) = direction.offset();
+                Position::new(center.x + dx, center.y + dy)
+            })
+            .filter(|position| {
+                map.dimensions().contains(*position)
+                    && map.tile(*position).is_some_and(|tile| tile.is_passable())
+                    && !ctx
+                        .state
+                        .entities
+                        .all_actors()
+                        .any(|actor| actor.position == Some(*position))
+            })
+            .collect())
+    }
+}
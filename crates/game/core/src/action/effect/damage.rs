@@ -3,25 +3,49 @@
 use crate::action::effect::ExecutionPhase;
 use crate::action::error::ActionError;
 use crate::action::execute::EffectContext;
+use crate::action::formula::evaluate::scale;
 use crate::action::formula::{Formula, evaluate};
 use crate::action::types::{AppliedValue, DamageType};
+use crate::env::{RngOracle, compute_seed};
 
 /// Deal damage to target.
+///
+/// Effects that `can_miss` roll to hit with a chance of
+/// `BASE_HIT_CHANCE + caster DEX - target DEX` percent, clamped to
+/// `MIN_HIT_CHANCE..=MAX_HIT_CHANCE`; a miss deals no damage. Hits of effects
+/// that `can_crit` are critical with a chance of the caster's EGO / 4 percent
+/// and deal `CRIT_DAMAGE_PERCENT` of the planned damage.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DamageEffect {
     pub formula: Formula,
     pub damage_type: DamageType,
     pub can_crit: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub can_miss: bool,
 }
 
 impl DamageEffect {
+    /// Hit chance (percent) when caster and target have the same DEX.
+    pub const BASE_HIT_CHANCE: u32 = 75;
+    /// Lowest hit chance (percent), however evasive the target.
+    pub const MIN_HIT_CHANCE: u32 = 5;
+    /// Highest hit chance (percent), however accurate the caster.
+    pub const MAX_HIT_CHANCE: u32 = 95;
+    /// Damage of a critical hit, in percent of the planned damage.
+    pub const CRIT_DAMAGE_PERCENT: u32 = 150;
+    /// [`compute_seed`] context of the hit roll.
+    pub const HIT_ROLL: u32 = 0;
+    /// [`compute_seed`] context of the critical roll.
+    pub const CRIT_ROLL: u32 = 2;
+
     /// Create a new damage effect.
     pub fn new(formula: Formula, damage_type: DamageType) -> Self {
         Self {
             formula,
             damage_type,
             can_crit: false,
+            can_miss: false,
         }
     }
 
@@ -31,6 +55,12 @@ impl DamageEffect {
         self
     }
 
+    /// Make this damage effect roll to hit.
+    pub fn with_miss(mut self) -> Self {
+        self.can_miss = true;
+        self
+    }
+
     /// Pre-validate: No additional validation needed.
     /// Target existence is checked at action-level pre_validate.
     pub fn pre_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
@@ -39,8 +69,14 @@ impl DamageEffect {
 
     /// Apply damage to target.
     pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
-        // 1. Evaluate formula
-        let planned = evaluate(&self.formula, ctx)?;
+        // 1. Evaluate formula, then roll to hit and to crit
+        let mut planned = evaluate(&self.formula, ctx)?;
+        let (hit, critical) = self.roll(ctx)?;
+        if critical {
+            planned = scale(planned, Self::CRIT_DAMAGE_PERCENT);
+        }
+        ctx.missed = !hit;
+        ctx.critical = critical;
 
         // 2. Get target actor
         let actor = ctx
@@ -49,10 +85,13 @@ impl DamageEffect {
             .actor_mut(ctx.target)
             .ok_or(ActionError::TargetNotFound)?;
 
-        // 3. Calculate actual damage (capped at current HP)
+        // 3. Calculate actual damage (capped at current HP, none on a miss)
         // TODO: Apply resistance/armor based on damage_type
-        // TODO: Check for critical hit based on can_crit flag
-        let actual_damage = planned.min(actor.resources.hp);
+        let actual_damage = if hit {
+            planned.min(actor.resources.hp)
+        } else {
+            0
+        };
 
         // 4. Apply damage
         actor.resources.hp = actor.resources.hp.saturating_sub(actual_damage);
@@ -66,6 +105,52 @@ impl DamageEffect {
         })
     }
 
+    /// Rolls to hit and to crit, returning `(hit, critical)`.
+    ///
+    /// Seeds are derived per target, so area attacks roll each target
+    /// separately. Effects that can neither miss nor crit never touch the RNG.
+    fn roll(&self, ctx: &EffectContext) -> Result<(bool, bool), ActionError> {
+        if !self.can_miss && !self.can_crit {
+            return Ok((true, false));
+        }
+
+        let caster = ctx
+            .state
+            .entities
+            .actor(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?
+            .snapshot()
+            .core;
+        let target = ctx
+            .state
+            .entities
+            .actor(ctx.target)
+            .ok_or(ActionError::TargetNotFound)?
+            .snapshot()
+            .core;
+        let rng = ctx
+            .env
+            .rng()
+            .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+        let roll = |context| {
+            rng.roll_d100(compute_seed(
+                ctx.state.game_seed,
+                ctx.state.turn.nonce,
+                ctx.target.0,
+                context,
+            ))
+        };
+
+        let hit = !self.can_miss || {
+            let chance = (Self::BASE_HIT_CHANCE as i32 + caster.dex - target.dex)
+                .clamp(Self::MIN_HIT_CHANCE as i32, Self::MAX_HIT_CHANCE as i32);
+            roll(Self::HIT_ROLL) <= chance as u32
+        };
+        let critical =
+            self.can_crit && hit && roll(Self::CRIT_ROLL) <= caster.ego.max(0) as u32 / 4;
+        Ok((hit, critical))
+    }
+
     /// Post-validate: No additional validation needed.
     pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
         Ok(())
@@ -76,3 +161,120 @@ impl DamageEffect {
         ExecutionPhase::Primary
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::types::ActionInput;
+    use crate::env::{GameEnv, PcgRng};
+    use crate::state::{EntityId, GameState};
+
+    const TARGET: EntityId = EntityId(1);
+
+    /// The player and a copy of it with 100 HP, with the player's EGO maxed
+    /// so crits are common.
+    fn state(game_seed: u64) -> GameState {
+        let mut state = GameState::with_player();
+        state.game_seed = game_seed;
+        let mut target = state.entities.actor(EntityId::PLAYER).unwrap().clone();
+        target.id = TARGET;
+        target.resources.hp = 100;
+        state.entities.actors.push(target).unwrap();
+        state
+            .entities
+            .actor_mut(EntityId::PLAYER)
+            .unwrap()
+            .core_stats
+            .ego = 99;
+        state
+    }
+
+    /// Applies `effect` once, returning the result with the crit and miss flags.
+    fn hit(effect: &DamageEffect, state: &mut GameState) -> (AppliedValue, bool, bool) {
+        let rng = PcgRng;
+        let env = GameEnv::new(
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(&rng as &dyn RngOracle),
+            None,
+        );
+        let input = ActionInput::Target(TARGET);
+        let mut ctx = EffectContext::new(EntityId::PLAYER, TARGET, state, &env, &input);
+        let applied = effect.apply(&mut ctx).unwrap();
+        (applied, ctx.critical, ctx.missed)
+    }
+
+    #[test]
+    fn attacks_roll_to_hit_and_to_crit() {
+        let effect = DamageEffect::new(Formula::Constant(10), DamageType::Physical)
+            .with_crit()
+            .with_miss();
+        let (mut misses, mut crits, mut hits) = (0, 0, 0);
+        for seed in 0..200 {
+            let mut state = state(seed);
+            let hp = state.entities.actor(TARGET).unwrap().resources.hp;
+            let (applied, critical, missed) = hit(&effect, &mut state);
+            let expected = match (missed, critical) {
+                (true, _) => {
+                    misses += 1;
+                    AppliedValue::Damage {
+                        planned: 10,
+                        actual: 0,
+                    }
+                }
+                (false, true) => {
+                    crits += 1;
+                    AppliedValue::Damage {
+                        planned: 15,
+                        actual: 15,
+                    }
+                }
+                (false, false) => {
+                    hits += 1;
+                    AppliedValue::Damage {
+                        planned: 10,
+                        actual: 10,
+                    }
+                }
+            };
+            assert_eq!(applied, expected);
+            let AppliedValue::Damage { actual, .. } = applied else {
+                unreachable!()
+            };
+            assert_eq!(
+                state.entities.actor(TARGET).unwrap().resources.hp,
+                hp - actual
+            );
+
+            // Rolls depend only on the seed, nonce and target
+            assert_eq!(
+                hit(&effect, &mut self::state(seed)),
+                (applied, critical, missed)
+            );
+        }
+        assert!(
+            misses > 0 && crits > 0 && hits > 0,
+            "{misses} {crits} {hits}"
+        );
+    }
+
+    #[test]
+    fn plain_damage_never_rolls() {
+        let effect = DamageEffect::new(Formula::Constant(10), DamageType::Physical);
+        let env = GameEnv::empty();
+        let input = ActionInput::Target(TARGET);
+        let mut state = state(0);
+        let mut ctx = EffectContext::new(EntityId::PLAYER, TARGET, &mut state, &env, &input);
+        assert_eq!(
+            effect.apply(&mut ctx),
+            Ok(AppliedValue::Damage {
+                planned: 10,
+                actual: 10
+            })
+        );
+        assert!(!ctx.critical && !ctx.missed);
+    }
+}
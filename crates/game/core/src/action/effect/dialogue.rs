@@ -0,0 +1,434 @@
+//! Dialogue effect implementation.
+
+use crate::action::effect::ExecutionPhase;
+use crate::action::error::ActionError;
+use crate::action::execute::EffectContext;
+use crate::action::types::{ActionInput, AppliedValue};
+use crate::env::{DialogueChoice, DialogueOutcome, DialogueTree};
+use crate::state::{ActorState, Conversation, EntityId};
+
+/// Start, advance or leave the player's conversation.
+///
+/// The action input selects the step:
+/// - `ActionInput::Target(npc)` starts a conversation at the NPC's root node
+/// - `ActionInput::Choice(i)` takes reply `i` of the current node, applies its
+///   outcomes and moves to the next node (or ends the conversation)
+/// - `ActionInput::None` leaves the current conversation
+///
+/// The NPC must be alive, adjacent, not hostile to the player and have a
+/// dialogue tree. Choice conditions are re-checked against the current state,
+/// so outcomes can only be applied when the authored requirements hold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConverseEffect;
+
+impl ConverseEffect {
+    /// Create a new Converse effect.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Pre-validate: Check the NPC, the tree and the selected reply.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        if ctx.caster != EntityId::PLAYER {
+            return Err(ActionError::EffectFailed(
+                "Only the player can hold conversations".to_string(),
+            ));
+        }
+
+        match ctx.action_input {
+            ActionInput::Target(npc) => {
+                let tree = Self::validate_partner(ctx, *npc)?;
+                tree.root_node().ok_or_else(|| {
+                    ActionError::EffectFailed(format!("Dialogue '{}' has no root node", tree.id))
+                })?;
+                Ok(())
+            }
+            ActionInput::Choice(index) => {
+                let conversation = Self::conversation(ctx)?;
+                let tree = Self::validate_partner(ctx, conversation.npc)?;
+                let choice = Self::choice(&tree, conversation, *index)?;
+
+                let player = Self::actor(ctx, ctx.caster)?;
+                let npc = Self::actor(ctx, conversation.npc)?;
+                if !choice.is_available(player, npc, &ctx.state.world) {
+                    return Err(ActionError::EffectFailed(
+                        "Dialogue choice is not available".to_string(),
+                    ));
+                }
+
+                Self::validate_outcomes(player, &choice.outcomes)
+            }
+            ActionInput::None => {
+                Self::conversation(ctx)?;
+                Ok(())
+            }
+            _ => Err(ActionError::EffectFailed(
+                "ConverseEffect requires Target, Choice or None input".to_string(),
+            )),
+        }
+    }
+
+    /// Apply the conversation step.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        match ctx.action_input {
+            ActionInput::Target(npc) => {
+                let npc = *npc;
+                let tree = Self::tree(ctx, npc)?;
+                ctx.state.world.conversation =
+                    Some(Conversation::new(npc, tree.id.clone(), tree.root.clone()));
+
+                Ok(AppliedValue::Conversation { npc, ended: false })
+            }
+            ActionInput::Choice(index) => {
+                let conversation = Self::conversation(ctx)?.clone();
+                let tree = Self::tree(ctx, conversation.npc)?;
+                let choice = Self::choice(&tree, &conversation, *index)?.clone();
+
+                for outcome in &choice.outcomes {
+                    Self::apply_outcome(ctx, conversation.npc, outcome)?;
+                }
+
+                let ended = match choice.next {
+                    Some(next) => {
+                        ctx.state.world.conversation =
+                            Some(Conversation::new(conversation.npc, tree.id, next));
+                        false
+                    }
+                    None => {
+                        ctx.state.world.conversation = None;
+                        true
+                    }
+                };
+
+                Ok(AppliedValue::Conversation {
+                    npc: conversation.npc,
+                    ended,
+                })
+            }
+            _ => {
+                let conversation = ctx
+                    .state
+                    .world
+                    .conversation
+                    .take()
+                    .ok_or_else(Self::not_talking)?;
+
+                Ok(AppliedValue::Conversation {
+                    npc: conversation.npc,
+                    ended: true,
+                })
+            }
+        }
+    }
+
+    /// Post-validate: The conversation must point at an existing node.
+    pub fn post_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        if let Some(conversation) = &ctx.state.world.conversation {
+            let tree = Self::tree(ctx, conversation.npc)?;
+            if tree.node(&conversation.node).is_none() {
+                return Err(ActionError::EffectFailed(format!(
+                    "Dialogue '{}' has no node '{}'",
+                    tree.id, conversation.node
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get default execution phase for Converse effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+
+    fn not_talking() -> ActionError {
+        ActionError::EffectFailed("Not in a conversation".to_string())
+    }
+
+    fn conversation<'c>(ctx: &'c EffectContext) -> Result<&'c Conversation, ActionError> {
+        ctx.state
+            .world
+            .conversation
+            .as_ref()
+            .ok_or_else(Self::not_talking)
+    }
+
+    fn actor<'c>(ctx: &'c EffectContext, id: EntityId) -> Result<&'c ActorState, ActionError> {
+        ctx.state
+            .entities
+            .actor(id)
+            .ok_or(ActionError::TargetNotFound)
+    }
+
+    /// Looks up the dialogue tree assigned to `npc`.
+    fn tree(ctx: &EffectContext, npc: EntityId) -> Result<DialogueTree, ActionError> {
+        let id = Self::actor(ctx, npc)?
+            .dialogue
+            .as_deref()
+            .ok_or(ActionError::InvalidTarget)?;
+        let dialogues = ctx
+            .env
+            .dialogues()
+            .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+        dialogues
+            .dialogue(id)
+            .ok_or_else(|| ActionError::EffectFailed(format!("Dialogue '{}' not found", id)))
+    }
+
+    fn choice<'t>(
+        tree: &'t DialogueTree,
+        conversation: &Conversation,
+        index: u8,
+    ) -> Result<&'t DialogueChoice, ActionError> {
+        let node = tree.node(&conversation.node).ok_or_else(|| {
+            ActionError::EffectFailed(format!(
+                "Dialogue '{}' has no node '{}'",
+                tree.id, conversation.node
+            ))
+        })?;
+        node.choices
+            .get(index as usize)
+            .ok_or_else(|| ActionError::EffectFailed("Invalid dialogue choice".to_string()))
+    }
+
+    /// A conversation partner must be alive, adjacent, non-hostile and have a tree.
+    fn validate_partner(ctx: &EffectContext, npc: EntityId) -> Result<DialogueTree, ActionError> {
+        if npc == ctx.caster {
+            return Err(ActionError::InvalidTarget);
+        }
+
+        let player = Self::actor(ctx, ctx.caster)?;
+        let partner = Self::actor(ctx, npc)?;
+        if !partner.is_alive() || partner.faction.is_hostile_to(&player.faction) {
+            return Err(ActionError::InvalidTarget);
+        }
+
+        let player_pos = player.position.ok_or(ActionError::ActorNotFound)?;
+        let partner_pos = partner.position.ok_or(ActionError::InvalidTarget)?;
+        if player_pos.chebyshev_distance(partner_pos) > 1 {
+            return Err(ActionError::OutOfRange);
+        }
+
+        Self::tree(ctx, npc)
+    }
+
+    /// Item outcomes must be satisfiable before any of them is applied.
+    ///
+    /// The outcomes are replayed in order against a copy of the inventory, so
+    /// several `TakeItem`s of the same item are checked against their sum.
+    fn validate_outcomes(
+        player: &ActorState,
+        outcomes: &[DialogueOutcome],
+    ) -> Result<(), ActionError> {
+        let mut inventory = player.inventory.clone();
+        for outcome in outcomes {
+            match outcome {
+                DialogueOutcome::TakeItem { item, quantity } => {
+                    inventory.remove_item(*item, *quantity).map_err(|_| {
+                        ActionError::EffectFailed("Not enough items to hand over".to_string())
+                    })?;
+                }
+                DialogueOutcome::GiveItem { item, quantity } => {
+                    inventory
+                        .add_item(*item, *quantity)
+                        .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+                }
+                DialogueOutcome::SetFaction(_)
+                | DialogueOutcome::SetFlag(_)
+                | DialogueOutcome::ClearFlag(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_outcome(
+        ctx: &mut EffectContext,
+        npc: EntityId,
+        outcome: &DialogueOutcome,
+    ) -> Result<(), ActionError> {
+        match outcome {
+            DialogueOutcome::GiveItem { item, quantity } => {
+                let player = ctx
+                    .state
+                    .entities
+                    .actor_mut(ctx.caster)
+                    .ok_or(ActionError::ActorNotFound)?;
+                player
+                    .inventory
+                    .add_item(*item, *quantity)
+                    .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+            }
+            DialogueOutcome::TakeItem { item, quantity } => {
+                let player = ctx
+                    .state
+                    .entities
+                    .actor_mut(ctx.caster)
+                    .ok_or(ActionError::ActorNotFound)?;
+                player
+                    .inventory
+                    .remove_item(*item, *quantity)
+                    .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+            }
+            DialogueOutcome::SetFaction(faction) => {
+                let partner = ctx
+                    .state
+                    .entities
+                    .actor_mut(npc)
+                    .ok_or(ActionError::TargetNotFound)?;
+                partner.faction = *faction;
+            }
+            DialogueOutcome::SetFlag(flag) => {
+                ctx.state.world.flags.insert(flag.clone());
+            }
+            DialogueOutcome::ClearFlag(flag) => {
+                ctx.state.world.flags.remove(flag);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for ConverseEffect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::GameConfig;
+    use crate::env::{
+        ActionSnapshot, ActorTemplate, ActorsSnapshot, ConfigSnapshot, DialogueCondition,
+        DialogueNode, DialoguesSnapshot, ItemsSnapshot, MapDimensions, MapSnapshot, OracleSnapshot,
+        SnapshotOracleBundle, StaticTile, TerrainKind,
+    };
+    use crate::state::{GameState, InventoryState, ItemHandle, Position};
+    use crate::traits::{Faction, TraitProfile};
+
+    const TOKEN: ItemHandle = ItemHandle(7);
+
+    /// A fence who wants two tokens, handed over one at a time.
+    fn fence() -> DialogueTree {
+        DialogueTree {
+            id: "fence".to_string(),
+            root: "start".to_string(),
+            nodes: vec![DialogueNode {
+                id: "start".to_string(),
+                text: "Two tokens and we're square.".to_string(),
+                choices: vec![
+                    DialogueChoice {
+                        text: "Here you go.".to_string(),
+                        conditions: vec![],
+                        outcomes: vec![
+                            DialogueOutcome::TakeItem {
+                                item: TOKEN,
+                                quantity: 1,
+                            },
+                            DialogueOutcome::TakeItem {
+                                item: TOKEN,
+                                quantity: 1,
+                            },
+                            DialogueOutcome::SetFlag("fence.paid".to_string()),
+                        ],
+                        next: None,
+                    },
+                    DialogueChoice {
+                        text: "We're square, remember?".to_string(),
+                        conditions: vec![DialogueCondition::Flag("fence.paid".to_string())],
+                        outcomes: vec![],
+                        next: None,
+                    },
+                ],
+            }],
+        }
+    }
+
+    fn snapshot() -> OracleSnapshot {
+        OracleSnapshot::new(
+            MapSnapshot::new(
+                MapDimensions::new(10, 10),
+                vec![Some(StaticTile::new(TerrainKind::Floor)); 100],
+            ),
+            ItemsSnapshot::empty(),
+            ActorsSnapshot::empty(),
+            ActionSnapshot::new(BTreeMap::new()),
+            ConfigSnapshot::new(GameConfig::default()),
+            DialoguesSnapshot::new([("fence".to_string(), fence())].into()),
+        )
+    }
+
+    /// The player at the origin, talking to the fence next to them.
+    fn talking_to_fence(tokens: u16) -> (GameState, EntityId) {
+        let template = ActorTemplate::builder()
+            .faction(Faction::Neutral)
+            .trait_profile(TraitProfile::default())
+            .dialogue("fence")
+            .build();
+
+        let mut state = GameState::with_player();
+        state
+            .world
+            .tile_map
+            .add_occupant(Position::ORIGIN, EntityId::PLAYER);
+        let mut inventory = InventoryState::empty();
+        inventory.add_item(TOKEN, tokens).unwrap();
+        state.entities.actors[0].inventory = inventory;
+        let npc = state.add_npc(&template, Position::new(1, 0)).unwrap();
+        state.world.conversation = Some(Conversation::new(npc, "fence", "start"));
+        (state, npc)
+    }
+
+    fn step(
+        state: &mut GameState,
+        snapshot: &OracleSnapshot,
+        input: ActionInput,
+    ) -> Result<AppliedValue, ActionError> {
+        let bundle = SnapshotOracleBundle::new(snapshot);
+        let env = bundle.as_env().into_game_env();
+        let mut ctx = EffectContext::new(EntityId::PLAYER, EntityId::PLAYER, state, &env, &input);
+        ConverseEffect.pre_validate(&ctx)?;
+        ConverseEffect.apply(&mut ctx)
+    }
+
+    #[test]
+    fn repeated_item_outcomes_are_validated_together() {
+        let snapshot = snapshot();
+
+        let (mut short, _) = talking_to_fence(1);
+        let before = short.clone();
+        assert!(step(&mut short, &snapshot, ActionInput::Choice(0)).is_err());
+        assert_eq!(short, before);
+
+        let (mut paid, npc) = talking_to_fence(2);
+        assert_eq!(
+            step(&mut paid, &snapshot, ActionInput::Choice(0)),
+            Ok(AppliedValue::Conversation { npc, ended: true })
+        );
+        assert_eq!(paid.entities.actors[0].inventory.quantity_of(TOKEN), 0);
+        assert!(paid.world.has_flag("fence.paid"));
+    }
+
+    #[test]
+    fn choices_are_gated_by_their_conditions() {
+        let snapshot = snapshot();
+        let (mut state, npc) = talking_to_fence(0);
+        assert!(step(&mut state, &snapshot, ActionInput::Choice(1)).is_err());
+
+        state.world.flags.insert("fence.paid".to_string());
+        assert_eq!(
+            step(&mut state, &snapshot, ActionInput::Choice(1)),
+            Ok(AppliedValue::Conversation { npc, ended: true })
+        );
+
+        // Hostile partners do not talk
+        step(&mut state, &snapshot, ActionInput::Target(npc)).unwrap();
+        state.entities.actor_mut(npc).unwrap().faction = Faction::Hostile;
+        assert_eq!(
+            step(&mut state, &snapshot, ActionInput::Choice(1)),
+            Err(ActionError::InvalidTarget)
+        );
+    }
+}
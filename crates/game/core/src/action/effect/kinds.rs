@@ -9,14 +9,17 @@ use crate::state::Tick;
 use crate::state::types::StatusEffectKind;
 use crate::stats::ResourceKind;
 
+use super::companion::{IssueOrderEffect, SummonEffect};
 use super::condition::Condition;
 use super::damage::DamageEffect;
+use super::dialogue::ConverseEffect;
 use super::displacement::Displacement;
 use super::interaction::InteractionType;
 use super::item::{AcquireItemEffect, UseConsumableEffect};
 use super::movement::{MoveSelfEffect, MoveTargetEffect, SwapEffect};
 use super::resource::{RestoreResourceEffect, SetResourceEffect};
 use super::status::{ApplyStatusEffect, ClearBuffsEffect, ClearDebuffsEffect, RemoveStatusEffect};
+use super::trade::{BuyEffect, SellEffect};
 
 /// The actual effect to apply.
 ///
@@ -58,15 +61,25 @@ pub enum EffectKind {
     UseConsumable(UseConsumableEffect),
 
     // ========================================================================
-    // Complex/Unimplemented (keeping as enum variants for now)
+    // Companions
     // ========================================================================
-    /// Summon entity.
-    Summon {
-        template_id: String,
-        count: u32,
-        duration: Option<Tick>,
-    },
+    IssueOrder(IssueOrderEffect),
+    Summon(SummonEffect),
+
+    // ========================================================================
+    // Dialogue
+    // ========================================================================
+    Converse(ConverseEffect),
 
+    // ========================================================================
+    // Trade
+    // ========================================================================
+    Buy(BuyEffect),
+    Sell(SellEffect),
+
+    // ========================================================================
+    // Complex/Unimplemented (keeping as enum variants for now)
+    // ========================================================================
     /// Transform caster into different form.
     Transform {
         into_template: String,
@@ -100,6 +113,7 @@ impl EffectKind {
             formula,
             damage_type,
             can_crit,
+            can_miss: false,
         })
     }
 
@@ -157,10 +171,14 @@ impl EffectKind {
             Self::Swap(e) => e.pre_validate(ctx),
             Self::AcquireItem(e) => e.pre_validate(ctx),
             Self::UseConsumable(e) => e.pre_validate(ctx),
+            Self::IssueOrder(e) => e.pre_validate(ctx),
+            Self::Summon(e) => e.pre_validate(ctx),
+            Self::Converse(e) => e.pre_validate(ctx),
+            Self::Buy(e) => e.pre_validate(ctx),
+            Self::Sell(e) => e.pre_validate(ctx),
 
             // Unimplemented effects - no validation yet
-            Self::Summon { .. }
-            | Self::Transform { .. }
+            Self::Transform { .. }
             | Self::Interact { .. }
             | Self::Conditional { .. }
             | Self::Repeat { .. } => Ok(()),
@@ -187,11 +205,13 @@ impl EffectKind {
             Self::Swap(e) => e.apply(ctx),
             Self::AcquireItem(e) => e.apply(ctx),
             Self::UseConsumable(e) => e.apply(ctx),
+            Self::IssueOrder(e) => e.apply(ctx),
+            Self::Summon(e) => e.apply(ctx),
+            Self::Converse(e) => e.apply(ctx),
+            Self::Buy(e) => e.apply(ctx),
+            Self::Sell(e) => e.apply(ctx),
 
             // Unimplemented effects
-            Self::Summon { .. } => Err(crate::action::error::ActionError::NotImplemented(
-                "Summon effect not yet implemented".to_string(),
-            )),
             Self::Transform { .. } => Err(crate::action::error::ActionError::NotImplemented(
                 "Transform effect not yet implemented".to_string(),
             )),
@@ -227,10 +247,14 @@ impl EffectKind {
             Self::Swap(e) => e.post_validate(ctx),
             Self::AcquireItem(e) => e.post_validate(ctx),
             Self::UseConsumable(e) => e.post_validate(ctx),
+            Self::IssueOrder(e) => e.post_validate(ctx),
+            Self::Summon(e) => e.post_validate(ctx),
+            Self::Converse(e) => e.post_validate(ctx),
+            Self::Buy(e) => e.post_validate(ctx),
+            Self::Sell(e) => e.post_validate(ctx),
 
             // Unimplemented effects - no validation yet
-            Self::Summon { .. }
-            | Self::Transform { .. }
+            Self::Transform { .. }
             | Self::Interact { .. }
             | Self::Conditional { .. }
             | Self::Repeat { .. } => Ok(()),
@@ -35,8 +35,10 @@
 //! - ✅ Code organization (each effect in separate file)
 //! - ✅ Explicit validation phases
 
+mod companion;
 mod condition;
 mod damage;
+mod dialogue;
 mod displacement;
 mod interaction;
 mod item;
@@ -45,10 +47,13 @@ mod movement;
 mod phase;
 mod resource;
 mod status;
+mod trade;
 
 // Re-export core types
+pub use companion::{IssueOrderEffect, SummonEffect};
 pub use condition::Condition;
 pub use damage::DamageEffect;
+pub use dialogue::ConverseEffect;
 pub use displacement::Displacement;
 pub use interaction::InteractionType;
 pub use item::{AcquireItemEffect, UseConsumableEffect};
@@ -57,6 +62,7 @@ pub use movement::{MoveSelfEffect, MoveTargetEffect, SwapEffect};
 pub use phase::ExecutionPhase;
 pub use resource::{RestoreResourceEffect, SetResourceEffect};
 pub use status::{ApplyStatusEffect, ClearBuffsEffect, ClearDebuffsEffect, RemoveStatusEffect};
+pub use trade::{BuyEffect, SellEffect};
 
 // ============================================================================
 // Action Effect (with phase and priority)
@@ -0,0 +1,464 @@
+//! Trade effect implementations.
+
+use crate::action::effect::ExecutionPhase;
+use crate::action::error::ActionError;
+use crate::action::execute::EffectContext;
+use crate::action::types::{ActionInput, AppliedValue, TradeOrder};
+use crate::env::ItemDefinition;
+use crate::state::{ActorState, EntityId, Shop};
+
+/// Buy items from an adjacent merchant.
+///
+/// Reads the merchant, item and quantity from `ActionInput::Trade`. The merchant
+/// must be alive, adjacent, not hostile to the caster and carry the item in
+/// stock; the caster must afford `Shop::buy_price` per unit and have room in
+/// their inventory. Gold moves from the caster to the merchant.
+///
+/// A due restock is applied before the purchase, so the stock checked here is
+/// the stock the merchant has at the current tick.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BuyEffect;
+
+impl BuyEffect {
+    /// Create a new Buy effect.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Pre-validate: Check the merchant, stock, price and inventory space.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        let order = trade_order(ctx)?;
+        let shop = validate_merchant(ctx, order.merchant)?;
+
+        if shop.stock_of(order.item) < order.quantity {
+            return Err(ActionError::EffectFailed(
+                "Merchant does not have enough in stock".to_string(),
+            ));
+        }
+
+        let price = total_price(
+            shop.buy_price(definition(ctx, order)?.value),
+            order.quantity,
+        )?;
+        let buyer = actor(ctx, ctx.caster)?;
+        if buyer.inventory.gold < price {
+            return Err(ActionError::EffectFailed("Not enough gold".to_string()));
+        }
+        if buyer.inventory.is_full() && buyer.inventory.quantity_of(order.item) == 0 {
+            return Err(ActionError::EffectFailed("Inventory is full".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Apply the purchase: stock and gold move to the caster and merchant.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        let order = *trade_order(ctx)?;
+        let value = definition(ctx, &order)?.value;
+        restock(ctx, order.merchant)?;
+
+        let merchant = ctx
+            .state
+            .entities
+            .actor_mut(order.merchant)
+            .ok_or(ActionError::TargetNotFound)?;
+        let shop = merchant.shop.as_mut().ok_or(ActionError::InvalidTarget)?;
+        let price = total_price(shop.buy_price(value), order.quantity)?;
+        shop.take(order.item, order.quantity)
+            .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+        merchant.inventory.gold = merchant.inventory.gold.saturating_add(price);
+
+        let buyer = ctx
+            .state
+            .entities
+            .actor_mut(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?;
+        buyer.inventory.gold = buyer
+            .inventory
+            .gold
+            .checked_sub(price)
+            .ok_or_else(|| ActionError::EffectFailed("Not enough gold".to_string()))?;
+        buyer
+            .inventory
+            .add_item(order.item, order.quantity)
+            .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+
+        Ok(AppliedValue::Bought {
+            merchant: order.merchant,
+            item: order.item,
+            quantity: order.quantity,
+            price,
+        })
+    }
+
+    /// Post-validate: No additional validation needed.
+    pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    /// Get default execution phase for Buy effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+}
+
+impl Default for BuyEffect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sell items to an adjacent merchant.
+///
+/// Reads the merchant, item and quantity from `ActionInput::Trade`. The caster
+/// must hold the items and the merchant must be able to pay `Shop::sell_price`
+/// per unit from their own gold. Items worth nothing cannot be sold. Sold items
+/// join the merchant's stock and can be bought back, but are never restocked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SellEffect;
+
+impl SellEffect {
+    /// Create a new Sell effect.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Pre-validate: Check the merchant, held items and merchant gold.
+    pub fn pre_validate(&self, ctx: &EffectContext) -> Result<(), ActionError> {
+        let order = trade_order(ctx)?;
+        let shop = validate_merchant(ctx, order.merchant)?;
+
+        let seller = actor(ctx, ctx.caster)?;
+        if seller.inventory.quantity_of(order.item) < order.quantity as u32 {
+            return Err(ActionError::EffectFailed(
+                "Not enough items to sell".to_string(),
+            ));
+        }
+
+        let value = definition(ctx, order)?.value;
+        if value == 0 {
+            return Err(ActionError::EffectFailed(
+                "Merchant will not buy this item".to_string(),
+            ));
+        }
+
+        let price = total_price(shop.sell_price(value), order.quantity)?;
+        let merchant = actor(ctx, order.merchant)?;
+        if merchant.inventory.gold < price {
+            return Err(ActionError::EffectFailed(
+                "Merchant cannot afford this".to_string(),
+            ));
+        }
+        if shop.stock.is_full() && shop.stock.iter().all(|entry| entry.item != order.item) {
+            return Err(ActionError::EffectFailed("Shop stock is full".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Apply the sale: items move to the merchant's stock, gold to the caster.
+    pub fn apply(&self, ctx: &mut EffectContext) -> Result<AppliedValue, ActionError> {
+        let order = *trade_order(ctx)?;
+        let value = definition(ctx, &order)?.value;
+        restock(ctx, order.merchant)?;
+
+        let merchant = ctx
+            .state
+            .entities
+            .actor_mut(order.merchant)
+            .ok_or(ActionError::TargetNotFound)?;
+        let shop = merchant.shop.as_mut().ok_or(ActionError::InvalidTarget)?;
+        let price = total_price(shop.sell_price(value), order.quantity)?;
+        shop.put(order.item, order.quantity)
+            .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+        merchant.inventory.gold =
+            merchant.inventory.gold.checked_sub(price).ok_or_else(|| {
+                ActionError::EffectFailed("Merchant cannot afford this".to_string())
+            })?;
+
+        let seller = ctx
+            .state
+            .entities
+            .actor_mut(ctx.caster)
+            .ok_or(ActionError::ActorNotFound)?;
+        seller
+            .inventory
+            .remove_item(order.item, order.quantity)
+            .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+        seller.inventory.gold = seller.inventory.gold.saturating_add(price);
+
+        Ok(AppliedValue::Sold {
+            merchant: order.merchant,
+            item: order.item,
+            quantity: order.quantity,
+            price,
+        })
+    }
+
+    /// Post-validate: No additional validation needed.
+    pub fn post_validate(&self, _ctx: &EffectContext) -> Result<(), ActionError> {
+        Ok(())
+    }
+
+    /// Get default execution phase for Sell effects.
+    pub fn default_phase() -> ExecutionPhase {
+        ExecutionPhase::Primary
+    }
+}
+
+impl Default for SellEffect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// Shared helpers
+// ============================================================================
+
+fn trade_order<'c>(ctx: &'c EffectContext) -> Result<&'c TradeOrder, ActionError> {
+    match ctx.action_input {
+        ActionInput::Trade(order) if order.quantity > 0 => Ok(order),
+        ActionInput::Trade(_) => Err(ActionError::EffectFailed(
+            "Trade quantity must be positive".to_string(),
+        )),
+        _ => Err(ActionError::EffectFailed(
+            "Trade effects require Trade input".to_string(),
+        )),
+    }
+}
+
+fn actor<'c>(ctx: &'c EffectContext, id: EntityId) -> Result<&'c ActorState, ActionError> {
+    ctx.state
+        .entities
+        .actor(id)
+        .ok_or(ActionError::TargetNotFound)
+}
+
+fn definition(ctx: &EffectContext, order: &TradeOrder) -> Result<ItemDefinition, ActionError> {
+    ctx.env
+        .items()
+        .map_err(|e| ActionError::EffectFailed(e.to_string()))?
+        .definition(order.item)
+        .ok_or_else(|| ActionError::EffectFailed(format!("Item {:?} not found", order.item)))
+}
+
+fn total_price(unit_price: u32, quantity: u16) -> Result<u32, ActionError> {
+    unit_price
+        .checked_mul(quantity as u32)
+        .ok_or_else(|| ActionError::EffectFailed("Trade price overflow".to_string()))
+}
+
+/// A trading partner must be alive, adjacent, non-hostile and have a shop.
+///
+/// Returns the merchant's shop as it will be once any due restock is applied.
+fn validate_merchant(ctx: &EffectContext, merchant: EntityId) -> Result<Shop, ActionError> {
+    if merchant == ctx.caster {
+        return Err(ActionError::InvalidTarget);
+    }
+
+    let caster = actor(ctx, ctx.caster)?;
+    let partner = actor(ctx, merchant)?;
+    if !partner.is_alive() || partner.faction.is_hostile_to(&caster.faction) {
+        return Err(ActionError::InvalidTarget);
+    }
+
+    let caster_pos = caster.position.ok_or(ActionError::ActorNotFound)?;
+    let partner_pos = partner.position.ok_or(ActionError::InvalidTarget)?;
+    if caster_pos.chebyshev_distance(partner_pos) > 1 {
+        return Err(ActionError::OutOfRange);
+    }
+
+    let mut shop = partner.shop.clone().ok_or(ActionError::InvalidTarget)?;
+    let rng = ctx
+        .env
+        .rng()
+        .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+    shop.restock(ctx.state.turn.clock, merchant, ctx.state.game_seed, rng);
+    Ok(shop)
+}
+
+/// Applies a due restock to the merchant's shop.
+fn restock(ctx: &mut EffectContext, merchant: EntityId) -> Result<(), ActionError> {
+    let rng = ctx
+        .env
+        .rng()
+        .map_err(|e| ActionError::EffectFailed(e.to_string()))?;
+    let now = ctx.state.turn.clock;
+    let game_seed = ctx.state.game_seed;
+    let shop = ctx
+        .state
+        .entities
+        .actor_mut(merchant)
+        .and_then(|actor| actor.shop.as_mut())
+        .ok_or(ActionError::InvalidTarget)?;
+    shop.restock(now, merchant, game_seed, rng);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use arrayvec::ArrayVec;
+
+    use super::*;
+    use crate::GameConfig;
+    use crate::env::{
+        ActionSnapshot, ActorTemplate, ActorsSnapshot, ConfigSnapshot, DialoguesSnapshot, ItemKind,
+        ItemsSnapshot, MapDimensions, MapSnapshot, OracleSnapshot, SnapshotOracleBundle,
+        StaticTile, TerrainKind,
+    };
+    use crate::state::{GameState, ItemHandle, Position, ShopStock};
+    use crate::traits::{Faction, TraitProfile};
+
+    const POTION: ItemHandle = ItemHandle(1);
+    const RING: ItemHandle = ItemHandle(2);
+
+    fn snapshot() -> OracleSnapshot {
+        OracleSnapshot::new(
+            MapSnapshot::new(
+                MapDimensions::new(10, 10),
+                vec![Some(StaticTile::new(TerrainKind::Floor)); 100],
+            ),
+            ItemsSnapshot::new(vec![
+                (
+                    POTION,
+                    ItemDefinition::new(POTION, ItemKind::Utility, 99).with_value(10),
+                ),
+                (
+                    RING,
+                    ItemDefinition::new(RING, ItemKind::Utility, 1).with_value(40),
+                ),
+            ]),
+            ActorsSnapshot::empty(),
+            ActionSnapshot::new(BTreeMap::new()),
+            ConfigSnapshot::new(GameConfig::default()),
+            DialoguesSnapshot::empty(),
+        )
+    }
+
+    /// The player at the origin with `gold`, next to a potion seller.
+    fn at_the_shop(gold: u32) -> (GameState, EntityId) {
+        let mut stock = ArrayVec::new();
+        stock.push(ShopStock::new(POTION, 3, 3));
+        let template = ActorTemplate::builder()
+            .faction(Faction::Neutral)
+            .trait_profile(TraitProfile::default())
+            .shop(Shop {
+                stock,
+                buy_price_pct: 120,
+                sell_price_pct: 50,
+                restock_interval: 0,
+                restock_at: 0,
+            })
+            .build();
+
+        let mut state = GameState::with_player();
+        state
+            .world
+            .tile_map
+            .add_occupant(Position::ORIGIN, EntityId::PLAYER);
+        state.entities.actors[0].inventory.gold = gold;
+        let merchant = state.add_npc(&template, Position::new(1, 0)).unwrap();
+        state.entities.actor_mut(merchant).unwrap().inventory.gold = 100;
+        (state, merchant)
+    }
+
+    fn buy(state: &mut GameState, order: TradeOrder) -> Result<AppliedValue, ActionError> {
+        let snapshot = snapshot();
+        let bundle = SnapshotOracleBundle::new(&snapshot);
+        let env = bundle.as_env().into_game_env();
+        let input = ActionInput::Trade(order);
+        let mut ctx = EffectContext::new(EntityId::PLAYER, EntityId::PLAYER, state, &env, &input);
+        BuyEffect.pre_validate(&ctx)?;
+        BuyEffect.apply(&mut ctx)
+    }
+
+    fn sell(state: &mut GameState, order: TradeOrder) -> Result<AppliedValue, ActionError> {
+        let snapshot = snapshot();
+        let bundle = SnapshotOracleBundle::new(&snapshot);
+        let env = bundle.as_env().into_game_env();
+        let input = ActionInput::Trade(order);
+        let mut ctx = EffectContext::new(EntityId::PLAYER, EntityId::PLAYER, state, &env, &input);
+        SellEffect.pre_validate(&ctx)?;
+        SellEffect.apply(&mut ctx)
+    }
+
+    #[test]
+    fn buying_needs_gold_and_stock() {
+        let (mut broke, merchant) = at_the_shop(11);
+        let before = broke.clone();
+        assert_eq!(
+            buy(&mut broke, TradeOrder::new(merchant, POTION, 1)),
+            Err(ActionError::EffectFailed("Not enough gold".to_string()))
+        );
+        assert_eq!(broke, before);
+
+        let (mut state, merchant) = at_the_shop(100);
+        for item in [(POTION, 4), (RING, 1)] {
+            assert_eq!(
+                buy(&mut state, TradeOrder::new(merchant, item.0, item.1)),
+                Err(ActionError::EffectFailed(
+                    "Merchant does not have enough in stock".to_string()
+                ))
+            );
+        }
+
+        assert_eq!(
+            buy(&mut state, TradeOrder::new(merchant, POTION, 2)),
+            Ok(AppliedValue::Bought {
+                merchant,
+                item: POTION,
+                quantity: 2,
+                price: 24,
+            })
+        );
+        let player = &state.entities.actors[0];
+        assert_eq!(player.inventory.gold, 76);
+        assert_eq!(player.inventory.quantity_of(POTION), 2);
+        let seller = state.entities.actor(merchant).unwrap();
+        assert_eq!(seller.inventory.gold, 124);
+        assert_eq!(seller.shop.as_ref().unwrap().stock_of(POTION), 1);
+    }
+
+    #[test]
+    fn selling_needs_the_items_in_hand() {
+        let (mut state, merchant) = at_the_shop(0);
+        let before = state.clone();
+        assert_eq!(
+            sell(&mut state, TradeOrder::new(merchant, RING, 1)),
+            Err(ActionError::EffectFailed(
+                "Not enough items to sell".to_string()
+            ))
+        );
+        assert_eq!(state, before);
+
+        state.entities.actors[0]
+            .inventory
+            .add_item(RING, 1)
+            .unwrap();
+        assert_eq!(
+            sell(&mut state, TradeOrder::new(merchant, RING, 1)),
+            Ok(AppliedValue::Sold {
+                merchant,
+                item: RING,
+                quantity: 1,
+                price: 20,
+            })
+        );
+        let player = &state.entities.actors[0];
+        assert_eq!(player.inventory.gold, 20);
+        assert_eq!(player.inventory.quantity_of(RING), 0);
+        let shop = state
+            .entities
+            .actor(merchant)
+            .unwrap()
+            .shop
+            .clone()
+            .unwrap();
+        assert_eq!(shop.stock_of(RING), 1);
+    }
+}
@@ -403,3 +403,169 @@ impl GameError for RemoveFromWorldError {
         }
     }
 }
+
+/// Errors that can occur during perception updates.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PerceptionError {
+    /// System actor validation failed.
+    #[error("perception action must be executed by SYSTEM actor")]
+    NotSystemActor {
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// Player not found in game state (or not on the map for sight checks).
+    #[error("player not found in game state")]
+    PlayerNotFound {
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+}
+
+impl PerceptionError {
+    /// Creates a NotSystemActor error with context.
+    pub fn not_system_actor(nonce: u64) -> Self {
+        Self::NotSystemActor {
+            context: ErrorContext::new(nonce)
+                .with_message("system action executed by non-system actor"),
+        }
+    }
+
+    /// Creates a PlayerNotFound error with context.
+    pub fn player_not_found(nonce: u64) -> Self {
+        Self::PlayerNotFound {
+            context: ErrorContext::new(nonce).with_message("player entity not found"),
+        }
+    }
+}
+
+impl GameError for PerceptionError {
+    fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::NotSystemActor { .. } => ErrorSeverity::Validation,
+            Self::PlayerNotFound { .. } => ErrorSeverity::Validation,
+        }
+    }
+
+    fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::NotSystemActor { context } => Some(context),
+            Self::PlayerNotFound { context } => Some(context),
+        }
+    }
+
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::NotSystemActor { .. } => "PERCEPTION_NOT_SYSTEM_ACTOR",
+            Self::PlayerNotFound { .. } => "PERCEPTION_PLAYER_NOT_FOUND",
+        }
+    }
+}
+
+/// Errors that can occur during an in-engine NPC turn.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AiTurnError {
+    /// System actor validation failed.
+    #[error("ai turn action must be executed by SYSTEM actor")]
+    NotSystemActor {
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// Current actor not found in game state.
+    #[error("actor {entity} not found in game state")]
+    ActorNotFound {
+        entity: EntityId,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// Current actor is not driven by the in-engine AI.
+    #[error("actor {entity} is not driven by the provable AI")]
+    NotProvableAi {
+        entity: EntityId,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+
+    /// The derived character action failed.
+    #[error("derived action for actor {entity} failed: {error}")]
+    ActionFailed {
+        entity: EntityId,
+        error: ActionError,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
+}
+
+impl AiTurnError {
+    /// Creates a NotSystemActor error with context.
+    pub fn not_system_actor(nonce: u64) -> Self {
+        Self::NotSystemActor {
+            context: ErrorContext::new(nonce)
+                .with_message("system action executed by non-system actor"),
+        }
+    }
+
+    /// Creates an ActorNotFound error with context.
+    pub fn actor_not_found(entity: EntityId, nonce: u64) -> Self {
+        Self::ActorNotFound {
+            entity,
+            context: ErrorContext::new(nonce)
+                .with_actor(entity)
+                .with_message("current actor not found"),
+        }
+    }
+
+    /// Creates a NotProvableAi error with context.
+    pub fn not_provable_ai(entity: EntityId, nonce: u64) -> Self {
+        Self::NotProvableAi {
+            entity,
+            context: ErrorContext::new(nonce)
+                .with_actor(entity)
+                .with_message("actor provider kind is not ai/provable"),
+        }
+    }
+
+    /// Creates an ActionFailed error with context.
+    pub fn action_failed(entity: EntityId, error: ActionError, nonce: u64) -> Self {
+        Self::ActionFailed {
+            entity,
+            error,
+            context: ErrorContext::new(nonce)
+                .with_actor(entity)
+                .with_message("derived character action failed"),
+        }
+    }
+}
+
+impl GameError for AiTurnError {
+    fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::NotSystemActor { .. } => ErrorSeverity::Validation,
+            Self::ActorNotFound { .. } => ErrorSeverity::Validation,
+            Self::NotProvableAi { .. } => ErrorSeverity::Validation,
+            Self::ActionFailed { error, .. } => error.severity(),
+        }
+    }
+
+    fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::NotSystemActor { context } => Some(context),
+            Self::ActorNotFound { context, .. } => Some(context),
+            Self::NotProvableAi { context, .. } => Some(context),
+            Self::ActionFailed { context, .. } => Some(context),
+        }
+    }
+
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::NotSystemActor { .. } => "AI_TURN_NOT_SYSTEM_ACTOR",
+            Self::ActorNotFound { .. } => "AI_TURN_ACTOR_NOT_FOUND",
+            Self::NotProvableAi { .. } => "AI_TURN_NOT_PROVABLE_AI",
+            Self::ActionFailed { .. } => "AI_TURN_ACTION_FAILED",
+        }
+    }
+}
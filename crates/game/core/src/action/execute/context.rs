@@ -43,6 +43,12 @@ pub struct EffectContext<'a> {
 
     /// Whether any effect was a critical hit.
     pub was_critical: bool,
+
+    /// Whether the effect being applied was a critical hit (reset per effect).
+    pub critical: bool,
+
+    /// Whether the effect being applied missed its target (reset per effect).
+    pub missed: bool,
 }
 
 impl<'a> EffectContext<'a> {
@@ -63,6 +69,8 @@ impl<'a> EffectContext<'a> {
             accumulated_damage: 0,
             accumulated_healing: 0,
             was_critical: false,
+            critical: false,
+            missed: false,
         }
     }
 }
@@ -78,7 +86,18 @@ pub(super) fn apply_effect(
     effect: &ActionEffect,
     ctx: &mut EffectContext,
 ) -> Result<EffectResult, ActionError> {
+    ctx.critical = false;
+    ctx.missed = false;
+
     // Delegate to EffectKind's apply method (defined in effect/kinds.rs)
     let applied_value = effect.kind.apply(ctx)?;
-    Ok(EffectResult::new(ctx.target, applied_value))
+    let mut result = EffectResult::new(ctx.target, applied_value);
+    if ctx.critical {
+        ctx.was_critical = true;
+        result = result.with_critical();
+    }
+    if ctx.missed {
+        result = result.with_failure();
+    }
+    Ok(result)
 }
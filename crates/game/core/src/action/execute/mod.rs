@@ -31,7 +31,6 @@
 //! ## Future Extensions
 //!
 //! - Line of sight checking
-//! - Critical hit calculation
 //! - Resistance/armor calculations
 //! - Status effect application
 //! - Cooldown management
@@ -30,6 +30,10 @@ use crate::action::execute::EffectContext;
 /// - `Min`: Minimum of sub-formulas
 /// - `Max`: Maximum of sub-formulas
 ///
+/// ## Arithmetic
+/// Negative stats count as `0`. Percentages and products are computed in
+/// `u64` and clamped at `u32::MAX`, so no formula overflows.
+///
 /// ## Error Handling
 /// - `ActorNotFound` if caster doesn't exist
 /// - `TargetNotFound` if target doesn't exist
@@ -45,7 +49,7 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
                 .actor(ctx.caster)
                 .ok_or(ActionError::ActorNotFound)?;
             let stat_value = get_stat_value(&actor.snapshot().core, stat);
-            Ok((stat_value as u32 * percent) / 100)
+            Ok(scale(stat_value.max(0) as u32, *percent))
         }
 
         Formula::TargetStat { stat, percent } => {
@@ -55,7 +59,7 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
                 .actor(ctx.target)
                 .ok_or(ActionError::TargetNotFound)?;
             let stat_value = get_stat_value(&actor.snapshot().core, stat);
-            Ok((stat_value as u32 * percent) / 100)
+            Ok(scale(stat_value.max(0) as u32, *percent))
         }
 
         Formula::WeaponDamage { percent } => {
@@ -91,12 +95,12 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
                 5 // Unarmed damage
             };
 
-            Ok((weapon_damage as u32) * percent / 100)
+            Ok(scale(weapon_damage as u32, *percent))
         }
 
-        Formula::FromPreviousDamage { percent } => Ok(ctx.accumulated_damage * percent / 100),
+        Formula::FromPreviousDamage { percent } => Ok(scale(ctx.accumulated_damage, *percent)),
 
-        Formula::FromPreviousHealing { percent } => Ok(ctx.accumulated_healing * percent / 100),
+        Formula::FromPreviousHealing { percent } => Ok(scale(ctx.accumulated_healing, *percent)),
 
         Formula::CasterResource { resource, percent } => {
             let actor = ctx
@@ -105,7 +109,7 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
                 .actor(ctx.caster)
                 .ok_or(ActionError::ActorNotFound)?;
             let current = get_resource_current(&actor.resources, resource);
-            Ok(current * percent / 100)
+            Ok(scale(current, *percent))
         }
 
         Formula::TargetResource { resource, percent } => {
@@ -115,7 +119,7 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
                 .actor(ctx.target)
                 .ok_or(ActionError::TargetNotFound)?;
             let current = get_resource_current(&actor.resources, resource);
-            Ok(current * percent / 100)
+            Ok(scale(current, *percent))
         }
 
         Formula::TargetMissingResource { resource, percent } => {
@@ -127,7 +131,7 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
             let max = actor.snapshot().resource_max.get(*resource);
             let current = get_resource_current(&actor.resources, resource);
             let missing = max.saturating_sub(current);
-            Ok(missing * percent / 100)
+            Ok(scale(missing, *percent))
         }
 
         Formula::TargetMaxResource { resource, percent } => {
@@ -137,7 +141,7 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
                 .actor(ctx.target)
                 .ok_or(ActionError::TargetNotFound)?;
             let max = actor.snapshot().resource_max.get(*resource);
-            Ok(max * percent / 100)
+            Ok(scale(max, *percent))
         }
 
         Formula::Sum(formulas) => {
@@ -155,7 +159,7 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
             let mut result = evaluate(&formulas[0], ctx)?;
             for f in &formulas[1..] {
                 let value = evaluate(f, ctx)?;
-                result = (result * value) / 100; // Percent multiplication
+                result = scale(result, value); // Percent multiplication
             }
             Ok(result)
         }
@@ -176,6 +180,11 @@ pub fn evaluate(formula: &Formula, ctx: &EffectContext) -> Result<u32, ActionErr
 // Helper Functions
 // ============================================================================
 
+/// `value * percent / 100`, computed in `u64` and clamped at `u32::MAX`.
+pub(crate) fn scale(value: u32, percent: u32) -> u32 {
+    (value as u64 * percent as u64 / 100).min(u32::MAX as u64) as u32
+}
+
 /// Get stat value from CoreEffective (final computed stats).
 fn get_stat_value(stats: &CoreEffective, stat: &CoreStatKind) -> i32 {
     match stat {
@@ -196,3 +205,50 @@ fn get_resource_current(resources: &ResourceCurrent, resource: &ResourceKind) ->
         ResourceKind::Lucidity => resources.lucidity,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::types::ActionInput;
+    use crate::env::GameEnv;
+    use crate::state::{EntityId, GameState};
+
+    #[test]
+    fn negative_base_stats_do_not_wrap() {
+        let mut state = GameState::with_player();
+        state
+            .entities
+            .actor_mut(EntityId::PLAYER)
+            .unwrap()
+            .core_stats
+            .str = -20;
+        let env = GameEnv::empty();
+        let input = ActionInput::None;
+        let ctx = EffectContext::new(EntityId::PLAYER, EntityId::PLAYER, &mut state, &env, &input);
+
+        let formula = Formula::CasterStat {
+            stat: CoreStatKind::Str,
+            percent: 1000,
+        };
+        assert_eq!(evaluate(&formula, &ctx).unwrap(), 10);
+    }
+
+    #[test]
+    fn percentages_and_products_saturate() {
+        let mut state = GameState::with_player();
+        let env = GameEnv::empty();
+        let input = ActionInput::None;
+        let mut ctx =
+            EffectContext::new(EntityId::PLAYER, EntityId::PLAYER, &mut state, &env, &input);
+        ctx.accumulated_damage = u32::MAX;
+
+        let product = Formula::Product(vec![Formula::Constant(u32::MAX), Formula::Constant(200)]);
+        assert_eq!(evaluate(&product, &ctx).unwrap(), u32::MAX);
+
+        let previous = Formula::FromPreviousDamage { percent: 150 };
+        assert_eq!(evaluate(&previous, &ctx).unwrap(), u32::MAX);
+
+        let product = Formula::Product(vec![Formula::Constant(300), Formula::Constant(50)]);
+        assert_eq!(evaluate(&product, &ctx).unwrap(), 150);
+    }
+}
@@ -13,7 +13,7 @@
 //!
 //! # Module Structure
 //!
-//! - `error`: Action error types (ActionError, TurnError, ActionCostError, ActivationError)
+//! - `error`: Action error types (ActionError, TurnError, ActivationError, PerceptionError)
 //! - `formula`: Formula system for dynamic value calculation
 //! - `effect`: Effect definitions (Damage, Heal, Status, Movement, etc.)
 //! - `targeting`: Targeting modes and filters
@@ -38,17 +38,24 @@ pub use effect::{
     ActionEffect, Condition, Displacement, EffectKind, ExecutionPhase, InteractionType,
     RestoreResourceEffect,
 };
-pub use error::{ActionError, ActivationError, DeactivateError, RemoveFromWorldError, TurnError};
+pub use error::{
+    ActionError, ActivationError, AiTurnError, DeactivateError, PerceptionError,
+    RemoveFromWorldError, TurnError,
+};
 pub use execute::{EffectContext, apply, post_validate, pre_validate};
 pub use formula::Formula;
 pub use profile::{ActionKind, ActionProfile, ActionTag, Requirement, ResourceCost};
+pub use root::PublishedAction;
 #[cfg(feature = "serde")]
-pub use root::compute_actions_root;
-pub use system::{ActivationAction, DeactivateAction, PrepareTurnAction, RemoveFromWorldAction};
+pub use root::{compute_actions_root, compute_published_actions_root, publish_actions};
+pub use system::{
+    ActivationAction, AiTurnAction, DeactivateAction, PerceptionAction, PrepareTurnAction,
+    RemoveFromWorldAction, Stimulus,
+};
 pub use targeting::TargetingMode;
 pub use types::{
     ActionInput, ActionResult, ActionSummary, AppliedValue, CardinalDirection, CharacterAction,
-    DamageType, EffectFlags, EffectResult,
+    CompanionCommand, DamageType, EffectFlags, EffectResult, TradeOrder,
 };
 
 use crate::env::GameEnv;
@@ -93,6 +100,8 @@ pub enum SystemActionKind {
     Activation(ActivationAction),
     Deactivate(DeactivateAction),
     RemoveFromWorld(RemoveFromWorldAction),
+    Perception(PerceptionAction),
+    AiTurn(AiTurnAction),
 }
 
 /// Top-level action enum that can be either a character action or system action.
@@ -125,6 +134,25 @@ impl Action {
         }
     }
 
+    /// Whether this action is safe to publish in the action log.
+    ///
+    /// The player's own actions are known to the player. Other actors'
+    /// actions would reveal their positions and intentions, and so would
+    /// system actions naming an entity or a noise origin; only system actions
+    /// without such data are public.
+    pub fn is_public(&self) -> bool {
+        match self {
+            Action::Character(action) => action.actor == EntityId::PLAYER,
+            Action::System { kind } => match kind {
+                SystemActionKind::PrepareTurn(_)
+                | SystemActionKind::Activation(_)
+                | SystemActionKind::AiTurn(_) => true,
+                SystemActionKind::Perception(action) => action.stimulus == Stimulus::Sight,
+                SystemActionKind::Deactivate(_) | SystemActionKind::RemoveFromWorld(_) => false,
+            },
+        }
+    }
+
     /// Returns the time cost (in ticks) for this action.
     ///
     /// Cost is scaled by the actor's speed stat (from snapshot).
@@ -150,6 +178,8 @@ impl Action {
                 SystemActionKind::Activation(action) => action.cost(env),
                 SystemActionKind::Deactivate(action) => action.cost(env),
                 SystemActionKind::RemoveFromWorld(action) => action.cost(env),
+                SystemActionKind::Perception(action) => action.cost(env),
+                SystemActionKind::AiTurn(action) => action.cost(env),
             },
         };
 
@@ -167,6 +197,8 @@ impl Action {
                 SystemActionKind::Activation(_) => "activation",
                 SystemActionKind::Deactivate(_) => "deactivate",
                 SystemActionKind::RemoveFromWorld(_) => "remove_from_world",
+                SystemActionKind::Perception(_) => "perception",
+                SystemActionKind::AiTurn(_) => "ai_turn",
             },
         }
     }
@@ -196,6 +228,18 @@ impl From<RemoveFromWorldAction> for SystemActionKind {
     }
 }
 
+impl From<PerceptionAction> for SystemActionKind {
+    fn from(action: PerceptionAction) -> Self {
+        Self::Perception(action)
+    }
+}
+
+impl From<AiTurnAction> for SystemActionKind {
+    fn from(action: AiTurnAction) -> Self {
+        Self::AiTurn(action)
+    }
+}
+
 // ============================================================================
 // Available Actions Query
 // ============================================================================
@@ -33,6 +33,18 @@ pub enum ActionKind {
     /// Use an item from inventory.
     UseItem,
 
+    /// Give an order to companions (stay, follow, attack target) or recruit an ally.
+    Command,
+
+    /// Talk to an adjacent NPC, or reply within the current conversation.
+    Talk,
+
+    /// Buy items from an adjacent merchant.
+    Buy,
+
+    /// Sell items to an adjacent merchant.
+    Sell,
+
     // /// Interact with props (doors, levers, etc.).
     // Interact,
 
@@ -86,6 +98,11 @@ pub enum ActionKind {
     // /// Dash 2+ tiles in one action.
     // Dash,
 
+    // ========================================================================
+    // Summoning
+    // ========================================================================
+    /// Raise skeletons that fight for the caster for a limited time.
+    RaiseDead,
     // ========================================================================
     // Stealth
     // ========================================================================
@@ -119,9 +136,16 @@ impl ActionKind {
             ActionKind::Wait => "wait",
             ActionKind::PickupItem => "pickup_item",
             ActionKind::UseItem => "use_item",
+            ActionKind::Command => "command",
+            ActionKind::Talk => "talk",
+            ActionKind::Buy => "buy",
+            ActionKind::Sell => "sell",
 
             // Combat - Melee
             ActionKind::MeleeAttack => "melee_attack",
+
+            // Summoning
+            ActionKind::RaiseDead => "raise_dead",
         }
     }
 
@@ -135,8 +159,14 @@ impl ActionKind {
             ActionKind::Wait,
             ActionKind::PickupItem,
             ActionKind::UseItem,
+            ActionKind::Command,
+            ActionKind::Talk,
+            ActionKind::Buy,
+            ActionKind::Sell,
             // Combat - Melee
             ActionKind::MeleeAttack,
+            // Summoning
+            ActionKind::RaiseDead,
         ]
     }
 }
@@ -157,6 +187,7 @@ pub enum ActionTag {
     Attack,
     Spell,
     Movement,
+    Summon,
 
     // Delivery Methods
     Melee,
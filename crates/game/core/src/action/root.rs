@@ -2,10 +2,80 @@
 //!
 //! This module provides functionality to compute a cryptographic commitment
 //! to a sequence of actions, used for ZK proof generation and verification.
+//!
+//! Proofs commit to the *published* form of a batch (see [`PublishedAction`]),
+//! which is what gets uploaded to Walrus: actions that would leak hidden
+//! information are replaced by blinded commitments.
 
-#[cfg(feature = "serde")]
 use super::Action;
 
+/// Domain tag of hidden action commitments.
+pub const HIDDEN_ACTION_DOMAIN: &[u8] = b"dungeon.hidden_action.v1";
+
+/// An action log entry in the form that is safe to publish.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PublishedAction {
+    /// A public action (see [`Action::is_public`]), published as is.
+    Revealed(Action),
+    /// A private action, replaced by
+    /// `SHA-256(HIDDEN_ACTION_DOMAIN || blinding || nonce (u64 LE) || action)`.
+    Hidden([u8; 32]),
+}
+
+impl PublishedAction {
+    /// Publish the action executed at `nonce`, hiding it behind `blinding`
+    /// unless it is public.
+    #[cfg(feature = "serde")]
+    pub fn publish(nonce: u64, action: &Action, blinding: &[u8; 32]) -> Self {
+        use sha2::{Digest, Sha256};
+
+        if action.is_public() {
+            return Self::Revealed(action.clone());
+        }
+
+        let serialized =
+            bincode::serialize(action).expect("Action serialization should never fail");
+        let mut hasher = Sha256::new();
+        hasher.update(HIDDEN_ACTION_DOMAIN);
+        hasher.update(blinding);
+        hasher.update(nonce.to_le_bytes());
+        hasher.update(&serialized);
+        Self::Hidden(hasher.finalize().into())
+    }
+}
+
+/// Publish a batch of actions starting at `start_nonce`.
+#[cfg(feature = "serde")]
+pub fn publish_actions(
+    start_nonce: u64,
+    actions: &[Action],
+    blinding: &[u8; 32],
+) -> Vec<PublishedAction> {
+    actions
+        .iter()
+        .zip(start_nonce..)
+        .map(|(action, nonce)| PublishedAction::publish(nonce, action, blinding))
+        .collect()
+}
+
+/// Computes the actions root of a published batch.
+///
+/// This is the `actions_root` the guest commits: anyone holding the published
+/// log can recompute it without learning the hidden actions.
+#[cfg(feature = "serde")]
+pub fn compute_published_actions_root(published: &[PublishedAction]) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    for entry in published {
+        let entry_bytes =
+            bincode::serialize(entry).expect("PublishedAction serialization should never fail");
+        hasher.update(&entry_bytes);
+    }
+    hasher.finalize().into()
+}
+
 /// Computes actions root for ZK proofs
 ///
 /// # Arguments
@@ -44,3 +114,66 @@ pub fn compute_actions_root(actions: &[Action]) -> [u8; 32] {
 
     hasher.finalize().into()
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::{
+        ActionInput, ActionKind, CardinalDirection, CharacterAction, DeactivateAction, EntityId,
+        PerceptionAction, Position, PrepareTurnAction, SystemActionKind,
+        compute_revealed_seed_commitment, compute_seed_commitment, seed_reveal_salt,
+    };
+
+    #[test]
+    fn revealing_the_seed_does_not_open_hidden_actions() {
+        let salt = [7; 32];
+        let npc_step = Action::character(CharacterAction::new(
+            EntityId(3),
+            ActionKind::Move,
+            ActionInput::Direction(CardinalDirection::West),
+        ));
+        let published = publish_actions(5, std::slice::from_ref(&npc_step), &salt);
+        let PublishedAction::Hidden(commitment) = published[0] else {
+            panic!("NPC actions are hidden");
+        };
+
+        // The revealed salt checks out against the seed commitment...
+        let revealed = seed_reveal_salt(&salt);
+        assert_eq!(
+            compute_revealed_seed_commitment(42, &revealed),
+            compute_seed_commitment(42, &salt)
+        );
+
+        // ...but does not reproduce the action's blinded commitment
+        assert_ne!(revealed, salt);
+        assert_ne!(
+            PublishedAction::publish(5, &npc_step, &revealed),
+            PublishedAction::Hidden(commitment)
+        );
+        assert_eq!(
+            PublishedAction::publish(5, &npc_step, &salt),
+            PublishedAction::Hidden(commitment)
+        );
+    }
+
+    #[test]
+    fn system_actions_naming_npcs_are_hidden() {
+        let actions = [
+            Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction)),
+            Action::system(SystemActionKind::Perception(PerceptionAction::sight())),
+            Action::system(SystemActionKind::Deactivate(DeactivateAction::new(
+                EntityId(3),
+            ))),
+            Action::system(SystemActionKind::Perception(PerceptionAction::noise(
+                Position::new(4, 2),
+                3,
+            ))),
+        ];
+        let published = publish_actions(5, &actions, &[7; 32]);
+
+        assert!(matches!(published[0], PublishedAction::Revealed(_)));
+        assert!(matches!(published[1], PublishedAction::Revealed(_)));
+        assert!(matches!(published[2], PublishedAction::Hidden(_)));
+        assert!(matches!(published[3], PublishedAction::Hidden(_)));
+    }
+}
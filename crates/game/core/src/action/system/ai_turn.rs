@@ -0,0 +1,100 @@
+//! In-engine NPC turn system action.
+//!
+//! Executes the current actor's turn using the deterministic policy in
+//! [`crate::ai`], so the chosen action is derived inside the state transition
+//! rather than submitted from outside.
+
+use crate::action::error::AiTurnError;
+use crate::action::{
+    ActionInput, ActionKind, ActionResult, ActionTransition, CharacterAction, execute,
+};
+use crate::ai;
+use crate::env::GameEnv;
+use crate::provider::{AiKind, ProviderKind};
+use crate::state::{EntityId, GameState, Tick};
+
+/// System action that lets the current actor take its turn via the in-engine AI.
+///
+/// This action:
+/// 1. Verifies the current actor is driven by [`AiKind::Provable`]
+/// 2. Derives the character action with [`ai::decide_action`]
+/// 3. Falls back to `Wait` if the derived action fails pre-validation
+/// 4. Runs the character action through the normal execution pipeline
+///    (including action cost and cooldowns)
+///
+/// The action carries no input, so logs and proofs only contain the fact that
+/// the NPC acted. Any verifier re-derives the same choice from state.
+///
+/// # Invariants
+///
+/// - `turn.current_actor` must be an actor with provider kind `Ai(Provable)`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AiTurnAction;
+
+impl AiTurnAction {
+    /// Derives the character action the current actor will take.
+    ///
+    /// Exposed so hosts can display or log the decision without executing it.
+    pub fn resolve(state: &GameState, env: &GameEnv<'_>) -> CharacterAction {
+        let entity = state.turn.current_actor;
+        let decided = ai::decide_action(state, env, entity);
+
+        if execute::pre_validate(&decided, state, env).is_ok() {
+            decided
+        } else {
+            CharacterAction::new(entity, ActionKind::Wait, ActionInput::None)
+        }
+    }
+}
+
+impl ActionTransition for AiTurnAction {
+    type Error = AiTurnError;
+    type Result = ActionResult;
+
+    fn actor(&self) -> EntityId {
+        EntityId::SYSTEM
+    }
+
+    fn pre_validate(&self, state: &GameState, _env: &GameEnv<'_>) -> Result<(), Self::Error> {
+        let nonce = state.turn.nonce;
+
+        // Verify this action is executed by the SYSTEM actor
+        if self.actor() != EntityId::SYSTEM {
+            return Err(AiTurnError::not_system_actor(nonce));
+        }
+
+        // Verify the current actor is driven by the in-engine AI
+        let entity = state.turn.current_actor;
+        let actor = state
+            .entities
+            .actor(entity)
+            .ok_or_else(|| AiTurnError::actor_not_found(entity, nonce))?;
+
+        if actor.provider_kind != ProviderKind::Ai(AiKind::Provable) {
+            return Err(AiTurnError::not_provable_ai(entity, nonce));
+        }
+
+        Ok(())
+    }
+
+    fn apply(&self, state: &mut GameState, env: &GameEnv<'_>) -> Result<ActionResult, Self::Error> {
+        let nonce = state.turn.nonce;
+        let action = Self::resolve(state, env);
+        let entity = action.actor;
+
+        execute::pre_validate(&action, state, env)
+            .map_err(|error| AiTurnError::action_failed(entity, error, nonce))?;
+        let result = execute::apply(&action, state, env)
+            .map_err(|error| AiTurnError::action_failed(entity, error, nonce))?;
+        execute::post_validate(&action, state, env)
+            .map_err(|error| AiTurnError::action_failed(entity, error, nonce))?;
+
+        Ok(result)
+    }
+
+    fn cost(&self, _env: &GameEnv<'_>) -> Tick {
+        // The derived character action applies its own cost
+        0
+    }
+}
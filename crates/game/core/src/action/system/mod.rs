@@ -6,6 +6,8 @@
 //! - Turn scheduling and actor selection
 //! - Entity activation/deactivation based on game rules
 //! - Entity removal from world and turn scheduling
+//! - NPC perception (sight and noise) updating alert state
+//! - In-engine NPC turns driven by the deterministic AI policy
 //!
 //! Note: Action cost application is now handled directly within character action
 //! execution (see `action::execute::pipeline`) to avoid double validation overhead.
@@ -15,11 +17,15 @@
 //! provable in zero-knowledge proof systems.
 
 mod activation;
+mod ai_turn;
 mod deactivate;
+mod perception;
 mod prepare_turn;
 mod remove_from_world;
 
 pub use activation::ActivationAction;
+pub use ai_turn::AiTurnAction;
 pub use deactivate::DeactivateAction;
+pub use perception::{PerceptionAction, Stimulus};
 pub use prepare_turn::PrepareTurnAction;
 pub use remove_from_world::RemoveFromWorldAction;
@@ -0,0 +1,231 @@
+//! Perception system action.
+//!
+//! Updates NPC awareness from sight and noise stimuli. Perception ranges and
+//! alert duration are derived from each actor's [`TraitProfile`](crate::TraitProfile),
+//! so the resulting alert state is part of canonical state and covered by proofs.
+
+use crate::action::ActionTransition;
+use crate::action::error::PerceptionError;
+use crate::env::GameEnv;
+use crate::state::{AlertLevel, EntityId, GameState, Position, Tick};
+
+/// Stimulus that NPCs may perceive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Stimulus {
+    /// Line-of-sight check against the player's current position.
+    Sight,
+
+    /// Noise emitted at `origin` (combat, breaking objects, etc.).
+    ///
+    /// `loudness` extends each listener's hearing range in tiles.
+    Noise { origin: Position, loudness: u32 },
+}
+
+/// System action that updates NPC awareness in response to a stimulus.
+///
+/// This action:
+/// 1. Forgets expired alerts for all active NPCs
+/// 2. For `Sight`: alerts every active NPC whose sight range covers the player
+/// 3. For `Noise`: makes every active NPC within hearing range suspicious,
+///    remembering the noise origin as the position to investigate
+///
+/// Only NPCs in `turn.active_actors` are considered, matching the activation
+/// radius mechanic.
+///
+/// # Invariants
+///
+/// - Player must exist in the game state
+/// - Distances use Manhattan metric, consistent with AI sight checks
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerceptionAction {
+    pub stimulus: Stimulus,
+}
+
+impl PerceptionAction {
+    /// Creates a sight perception action.
+    pub fn sight() -> Self {
+        Self {
+            stimulus: Stimulus::Sight,
+        }
+    }
+
+    /// Creates a noise perception action.
+    pub fn noise(origin: Position, loudness: u32) -> Self {
+        Self {
+            stimulus: Stimulus::Noise { origin, loudness },
+        }
+    }
+}
+
+impl ActionTransition for PerceptionAction {
+    type Error = PerceptionError;
+    type Result = ();
+
+    fn actor(&self) -> EntityId {
+        EntityId::SYSTEM
+    }
+
+    fn pre_validate(&self, state: &GameState, _env: &GameEnv<'_>) -> Result<(), Self::Error> {
+        let nonce = state.turn.nonce;
+
+        // Verify this action is executed by the SYSTEM actor
+        if self.actor() != EntityId::SYSTEM {
+            return Err(PerceptionError::not_system_actor(nonce));
+        }
+
+        // Verify player exists
+        state
+            .entities
+            .actor(EntityId::PLAYER)
+            .ok_or_else(|| PerceptionError::player_not_found(nonce))?;
+
+        Ok(())
+    }
+
+    fn apply(&self, state: &mut GameState, _env: &GameEnv<'_>) -> Result<(), Self::Error> {
+        let nonce = state.turn.nonce;
+        let now = state.turn.clock;
+
+        let player_pos = state.actor_position(EntityId::PLAYER);
+        if matches!(self.stimulus, Stimulus::Sight) && player_pos.is_none() {
+            return Err(PerceptionError::player_not_found(nonce));
+        }
+
+        // Iterate in BTreeSet order for determinism
+        let listeners: Vec<EntityId> = state
+            .turn
+            .active_actors
+            .iter()
+            .copied()
+            .filter(|id| *id != EntityId::PLAYER)
+            .collect();
+
+        for entity_id in listeners {
+            let Some(actor) = state.entities.actor_mut(entity_id) else {
+                continue;
+            };
+            let Some(actor_pos) = actor.position else {
+                continue;
+            };
+
+            actor.awareness.forget_if_expired(now);
+
+            let profile = actor.trait_profile;
+            let expires_at = now.saturating_add(profile.alert_duration());
+
+            match self.stimulus {
+                Stimulus::Sight => {
+                    // Checked above
+                    let Some(player_pos) = player_pos else {
+                        continue;
+                    };
+                    if actor_pos.manhattan_distance(player_pos) <= profile.sight_range() {
+                        actor
+                            .awareness
+                            .observe(AlertLevel::Alerted, player_pos, expires_at, now);
+                    }
+                }
+                Stimulus::Noise { origin, loudness } => {
+                    let range = profile.hearing_range().saturating_add(loudness);
+                    if actor_pos.manhattan_distance(origin) <= range {
+                        actor
+                            .awareness
+                            .observe(AlertLevel::Suspicious, origin, expires_at, now);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn cost(&self, _env: &GameEnv<'_>) -> Tick {
+        0 // System actions have no time cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::ActorTemplate;
+    use crate::traits::TraitProfile;
+
+    /// Player at the origin, one NPC 3 tiles away and one 20 tiles away.
+    fn listeners() -> (GameState, EntityId, EntityId) {
+        let template = ActorTemplate::builder()
+            .trait_profile(TraitProfile::default())
+            .build();
+        let mut state = GameState::with_player();
+        move_player(&mut state, Position::new(0, 0));
+        let near = state.add_npc(&template, Position::new(3, 0)).unwrap();
+        let far = state.add_npc(&template, Position::new(20, 0)).unwrap();
+        state.turn.active_actors.extend([near, far]);
+        (state, near, far)
+    }
+
+    fn move_player(state: &mut GameState, position: Position) {
+        if let Some(old) = state.entities.actors[0].position.replace(position) {
+            state.world.tile_map.remove_occupant(&old, EntityId::PLAYER);
+        }
+        state
+            .world
+            .tile_map
+            .add_occupant(position, EntityId::PLAYER);
+    }
+
+    fn perceive(state: &mut GameState, action: PerceptionAction) {
+        let env = GameEnv::empty();
+        action.pre_validate(state, &env).unwrap();
+        action.apply(state, &env).unwrap();
+    }
+
+    fn awareness(state: &GameState, entity: EntityId) -> (AlertLevel, Option<Position>) {
+        let awareness = state.entities.actor(entity).unwrap().awareness;
+        let now = state.turn.clock;
+        (
+            awareness.level_at(now),
+            awareness.last_known_position_at(now),
+        )
+    }
+
+    #[test]
+    fn sight_and_noise_follow_trait_ranges() {
+        let (mut state, near, far) = listeners();
+
+        perceive(&mut state, PerceptionAction::sight());
+        let player = Some(Position::new(0, 0));
+        assert_eq!(awareness(&state, near), (AlertLevel::Alerted, player));
+        assert_eq!(awareness(&state, far), (AlertLevel::Unaware, None));
+
+        // Heard only by the far NPC; the near one keeps its stronger alert
+        let origin = Position::new(20, 3);
+        perceive(&mut state, PerceptionAction::noise(origin, 0));
+        assert_eq!(awareness(&state, near), (AlertLevel::Alerted, player));
+        assert_eq!(
+            awareness(&state, far),
+            (AlertLevel::Suspicious, Some(origin))
+        );
+    }
+
+    #[test]
+    fn alerts_expire_after_the_trait_duration() {
+        let (mut state, near, _) = listeners();
+        perceive(&mut state, PerceptionAction::sight());
+
+        let duration = TraitProfile::default().alert_duration();
+        state.turn.clock = duration - 1;
+        assert_eq!(awareness(&state, near).0, AlertLevel::Alerted);
+
+        state.turn.clock = duration;
+        move_player(&mut state, Position::new(0, 40));
+        perceive(&mut state, PerceptionAction::sight());
+        assert_eq!(awareness(&state, near), (AlertLevel::Unaware, None));
+        assert_eq!(
+            state.entities.actor(near).unwrap().awareness,
+            Default::default(),
+            "expired memory is cleared from state"
+        );
+    }
+}
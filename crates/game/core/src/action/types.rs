@@ -6,7 +6,7 @@
 //! - `ActionResult`: The result of action execution
 
 use crate::action::ActionKind;
-use crate::state::{EntityId, Position};
+use crate::state::{CompanionOrder, EntityId, Position};
 
 // ============================================================================
 // Damage Type
@@ -135,6 +135,77 @@ pub enum ActionInput {
     /// Used by item-related effects (UseConsumableEffect, EquipItemEffect)
     /// to specify which inventory slot contains the item to use/equip.
     InventorySlot(u8),
+
+    /// Issue an order to companions.
+    ///
+    /// Used by IssueOrderEffect (the `Command` action).
+    Order(CompanionCommand),
+
+    /// Pick a reply in the current conversation.
+    ///
+    /// Used by ConverseEffect (the `Talk` action). The index refers to the
+    /// current node's choices in authored order.
+    Choice(u8),
+
+    /// Trade with a merchant.
+    ///
+    /// Used by BuyEffect and SellEffect (the `Buy` and `Sell` actions).
+    Trade(TradeOrder),
+}
+
+/// Items to exchange with a merchant for gold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TradeOrder {
+    /// The merchant to trade with.
+    pub merchant: EntityId,
+
+    /// The item being bought or sold.
+    pub item: crate::state::ItemHandle,
+
+    /// How many units change hands.
+    pub quantity: u16,
+}
+
+impl TradeOrder {
+    pub fn new(merchant: EntityId, item: crate::state::ItemHandle, quantity: u16) -> Self {
+        Self {
+            merchant,
+            item,
+            quantity,
+        }
+    }
+}
+
+/// Order issued by a leader to one or all of its companions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompanionCommand {
+    /// Companion to command. `None` commands every companion of the caster.
+    ///
+    /// Naming an adjacent, unbound `Friendly` actor recruits it.
+    pub companion: Option<EntityId>,
+
+    /// The order to give.
+    pub order: CompanionOrder,
+}
+
+impl CompanionCommand {
+    /// Creates a command addressed to all companions of the caster.
+    pub fn all(order: CompanionOrder) -> Self {
+        Self {
+            companion: None,
+            order,
+        }
+    }
+
+    /// Creates a command addressed to a single companion (or recruit).
+    pub fn single(companion: EntityId, order: CompanionOrder) -> Self {
+        Self {
+            companion: Some(companion),
+            order,
+        }
+    }
 }
 
 // ============================================================================
@@ -272,6 +343,46 @@ pub enum AppliedValue {
         handle: crate::state::ItemHandle,
     },
 
+    /// Companion received an order (or was recruited).
+    OrderIssued {
+        /// The companion that received the order. `None` means all companions.
+        companion: Option<EntityId>,
+        /// The order given.
+        order: CompanionOrder,
+    },
+
+    /// Conversation started, advanced or ended.
+    Conversation {
+        /// The NPC being talked to.
+        npc: EntityId,
+        /// Whether the conversation is over.
+        ended: bool,
+    },
+
+    /// Items bought from a merchant.
+    Bought {
+        /// The merchant that sold the items.
+        merchant: EntityId,
+        /// The item handle that was bought.
+        item: crate::state::ItemHandle,
+        /// How many were bought.
+        quantity: u16,
+        /// Total gold paid.
+        price: u32,
+    },
+
+    /// Items sold to a merchant.
+    Sold {
+        /// The merchant that bought the items.
+        merchant: EntityId,
+        /// The item handle that was sold.
+        item: crate::state::ItemHandle,
+        /// How many were sold.
+        quantity: u16,
+        /// Total gold received.
+        price: u32,
+    },
+
     /// No value (for effects like Wait, or failed effects).
     None,
 }
@@ -0,0 +1,469 @@
+//! Deterministic NPC decision policy.
+//!
+//! This module computes NPC actions *inside* the state transition so that the
+//! prover covers not only the legality of an NPC action but also that the NPC
+//! chose it according to its rules. Actors whose provider kind is
+//! [`AiKind::Provable`](crate::provider::AiKind::Provable) are driven by
+//! [`AiTurnAction`](crate::action::AiTurnAction), which calls [`decide_action`]
+//! in both the runtime and the zkVM guest. The action log therefore records only
+//! that an NPC took its turn, never which move it picked.
+//!
+//! # Determinism
+//!
+//! The policy is a pure function of `GameState` and the oracles:
+//! - Integer arithmetic only (no floats)
+//! - Candidates are evaluated in fixed order (ability order, then
+//!   [`CardinalDirection::all`]); ties keep the first candidate
+//! - No RNG, no host-side state, no allocation beyond the ability list
+//!
+//! # Decision Process
+//!
+//! Mirrors the runtime Utility AI goal selection:
+//!
+//! 1. **Survival**: Low HP and player close → step away from the player
+//! 2. **Combat**: Player in sight → attack if courageous, otherwise keep distance
+//! 3. **Search**: Player remembered (see [`Awareness`](crate::state::Awareness)) →
+//!    step toward the last known position
+//! 4. **Default**: Wait
+//!
+//! Companions (see [`Companion`](crate::state::Companion)) follow their own
+//! policy instead: engage [`companion_target`], otherwise stay near the leader.
+
+use crate::action::{
+    ActionInput, ActionKind, ActionTag, CardinalDirection, CharacterAction, TargetingMode,
+    get_available_actions,
+};
+use crate::env::GameEnv;
+use crate::state::{AlertLevel, CompanionOrder, EntityId, GameState, Position};
+use crate::traits::TraitKind;
+
+/// HP percentage below which an NPC considers fleeing.
+const LOW_HP_PERCENT: u32 = 30;
+
+/// Distance (Manhattan) at which a wounded NPC flees.
+const FLEE_DISTANCE: u32 = 5;
+
+/// Distance (Manhattan) at which a cowardly NPC backs away.
+const KEEP_AWAY_DISTANCE: u32 = 3;
+
+/// Minimum Curiosity for a suspicious NPC to investigate a noise.
+///
+/// Shared with the runtime utility AI so both policies search alike.
+pub const INVESTIGATE_CURIOSITY: u8 = 60;
+
+/// Distance (Chebyshev) a following companion tries to keep from its leader.
+pub const FOLLOW_DISTANCE: u32 = 2;
+
+/// Selects the next action for `entity`.
+///
+/// Always returns an action; falls back to `Wait` when nothing better applies.
+/// The returned action is not guaranteed to pass validation (e.g. the
+/// target may have moved out of a non-Chebyshev range); callers should fall
+/// back to `Wait` if it does not.
+pub fn decide_action(state: &GameState, env: &GameEnv<'_>, entity: EntityId) -> CharacterAction {
+    let wait = CharacterAction::new(entity, ActionKind::Wait, ActionInput::None);
+
+    let Some(actor) = state.entities.actor(entity) else {
+        return wait;
+    };
+    let Some(my_pos) = actor.position else {
+        return wait;
+    };
+
+    let available = get_available_actions(entity, state, env);
+
+    if actor.companion.is_some() {
+        return decide_companion_action(state, env, entity, my_pos, &available).unwrap_or(wait);
+    }
+
+    let profile = actor.trait_profile;
+    let now = state.turn.clock;
+
+    let player_pos = state.entities.player().position;
+    let player_distance = player_pos.map(|p| my_pos.manhattan_distance(p));
+    let sees_player = player_distance.is_some_and(|d| d <= profile.sight_range());
+
+    // ========================================================================
+    // Priority 1 & 2: Survival / Combat (Player visible)
+    // ========================================================================
+
+    if let (true, Some(player_pos), Some(distance)) = (sees_player, player_pos, player_distance) {
+        let hp_max = actor.snapshot().resource_max.hp_max;
+        let hp_percent = actor
+            .resources
+            .hp
+            .saturating_mul(100)
+            .checked_div(hp_max)
+            .map_or(100, |p| p.min(100));
+
+        if hp_percent < LOW_HP_PERCENT && distance <= FLEE_DISTANCE {
+            return step_away(state, env, entity, my_pos, player_pos, &available).unwrap_or(wait);
+        }
+
+        let bravery = profile.get(TraitKind::Bravery) as u32 * 100 / 240;
+        let courage = (hp_percent + bravery) / 2;
+
+        if courage > 50 {
+            if let Some(attack) = attack_target(
+                env,
+                entity,
+                my_pos,
+                EntityId::PLAYER,
+                player_pos,
+                &available,
+            ) {
+                return attack;
+            }
+            return step_toward(state, env, entity, my_pos, player_pos, &available).unwrap_or(wait);
+        }
+
+        if distance <= KEEP_AWAY_DISTANCE {
+            return step_away(state, env, entity, my_pos, player_pos, &available).unwrap_or(wait);
+        }
+
+        return wait;
+    }
+
+    // ========================================================================
+    // Priority 3: Search (Player remembered but not visible)
+    // ========================================================================
+
+    if let Some(last_known) = actor.awareness.last_known_position_at(now)
+        && last_known != my_pos
+    {
+        let investigates = match actor.awareness.level_at(now) {
+            AlertLevel::Alerted => true,
+            AlertLevel::Suspicious => profile.get(TraitKind::Curiosity) >= INVESTIGATE_CURIOSITY,
+            AlertLevel::Unaware => false,
+        };
+
+        if investigates {
+            return step_toward(state, env, entity, my_pos, last_known, &available).unwrap_or(wait);
+        }
+    }
+
+    wait
+}
+
+/// Selects the enemy a companion should engage, if any.
+///
+/// - `Attack(target)`: the ordered target while it is alive
+/// - `Follow`: the nearest visible actor hostile to the leader
+/// - `Stay`: an adjacent actor hostile to the leader
+///
+/// Ties are broken by entity ID so the choice is deterministic. Shared by the
+/// provable policy and the runtime utility AI.
+pub fn companion_target(state: &GameState, entity: EntityId) -> Option<EntityId> {
+    let actor = state.entities.actor(entity)?;
+    let bond = actor.companion?;
+    let my_pos = actor.position?;
+    let leader = state.entities.actor(bond.leader)?;
+
+    let reach = match bond.order {
+        CompanionOrder::Attack(target) => {
+            if let Some(target) = state.entities.actor(target)
+                && target.is_alive()
+                && target.position.is_some()
+            {
+                return Some(target.id);
+            }
+            actor.trait_profile.sight_range()
+        }
+        CompanionOrder::Follow => actor.trait_profile.sight_range(),
+        CompanionOrder::Stay => 1,
+    };
+
+    state
+        .entities
+        .all_actors()
+        .filter(|other| other.id != entity && other.is_alive())
+        .filter(|other| other.companion.is_none_or(|b| b.leader != bond.leader))
+        .filter(|other| leader.faction.is_hostile_to(&other.faction))
+        .filter_map(|other| {
+            let distance = match bond.order {
+                CompanionOrder::Stay => my_pos.chebyshev_distance(other.position?),
+                _ => my_pos.manhattan_distance(other.position?),
+            };
+            (distance <= reach).then_some((distance, other.id))
+        })
+        .min()
+        .map(|(_, id)| id)
+}
+
+/// Companion policy: fight the companion target, otherwise follow the leader.
+fn decide_companion_action(
+    state: &GameState,
+    env: &GameEnv<'_>,
+    entity: EntityId,
+    my_pos: Position,
+    available: &[ActionKind],
+) -> Option<CharacterAction> {
+    let bond = state.entities.actor(entity)?.companion?;
+
+    if let Some(target) = companion_target(state, entity) {
+        let target_pos = state.actor_position(target)?;
+        if let Some(attack) = attack_target(env, entity, my_pos, target, target_pos, available) {
+            return Some(attack);
+        }
+        if bond.order != CompanionOrder::Stay {
+            return step_toward(state, env, entity, my_pos, target_pos, available);
+        }
+        return None;
+    }
+
+    if bond.order == CompanionOrder::Stay {
+        return None;
+    }
+
+    let leader_pos = state.actor_position(bond.leader)?;
+    if my_pos.chebyshev_distance(leader_pos) > FOLLOW_DISTANCE {
+        return step_toward(state, env, entity, my_pos, leader_pos, available);
+    }
+
+    None
+}
+
+/// Returns the first available single-target attack that reaches `target`.
+fn attack_target(
+    env: &GameEnv<'_>,
+    entity: EntityId,
+    my_pos: Position,
+    target: EntityId,
+    target_pos: Position,
+    available: &[ActionKind],
+) -> Option<CharacterAction> {
+    let actions = env.actions().ok()?;
+
+    available.iter().copied().find_map(|kind| {
+        let profile = actions.action_profile(kind);
+        if !profile.tags.contains(&ActionTag::Attack) {
+            return None;
+        }
+        match profile.targeting {
+            TargetingMode::SingleTarget { range, .. }
+                if my_pos.chebyshev_distance(target_pos) <= range =>
+            {
+                Some(CharacterAction::new(
+                    entity,
+                    kind,
+                    ActionInput::Target(target),
+                ))
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Returns the move that most reduces Manhattan distance to `goal`.
+fn step_toward(
+    state: &GameState,
+    env: &GameEnv<'_>,
+    entity: EntityId,
+    my_pos: Position,
+    goal: Position,
+    available: &[ActionKind],
+) -> Option<CharacterAction> {
+    let current = my_pos.manhattan_distance(goal);
+    best_step(state, env, entity, my_pos, available, |next| {
+        let distance = next.manhattan_distance(goal);
+        (distance < current).then_some(u32::MAX - distance)
+    })
+}
+
+/// Returns the move that most increases Manhattan distance from `threat`.
+fn step_away(
+    state: &GameState,
+    env: &GameEnv<'_>,
+    entity: EntityId,
+    my_pos: Position,
+    threat: Position,
+    available: &[ActionKind],
+) -> Option<CharacterAction> {
+    let current = my_pos.manhattan_distance(threat);
+    best_step(state, env, entity, my_pos, available, |next| {
+        let distance = next.manhattan_distance(threat);
+        (distance > current).then_some(distance)
+    })
+}
+
+/// Evaluates every walkable direction and returns the highest scoring move.
+///
+/// `score` returns `None` for directions that should not be considered.
+/// Ties keep the earliest direction in [`CardinalDirection::all`] order.
+fn best_step(
+    state: &GameState,
+    env: &GameEnv<'_>,
+    entity: EntityId,
+    my_pos: Position,
+    available: &[ActionKind],
+    score: impl Fn(Position) -> Option<u32>,
+) -> Option<CharacterAction> {
+    if !available.contains(&ActionKind::Move) {
+        return None;
+    }
+
+    let mut best: Option<(u32, CardinalDirection)> = None;
+    for direction in CardinalDirection::all() {
+        let (dx, dy) = direction.offset();
+        let next = Position::new(my_pos.x + dx, my_pos.y + dy);
+
+        if !is_walkable(state, env, entity, next) {
+            continue;
+        }
+        let Some(value) = score(next) else {
+            continue;
+        };
+        if best.is_none_or(|(best_value, _)| value > best_value) {
+            best = Some((value, direction));
+        }
+    }
+
+    best.map(|(_, direction)| {
+        CharacterAction::new(entity, ActionKind::Move, ActionInput::Direction(direction))
+    })
+}
+
+/// Checks the same destination rules as the movement effect.
+fn is_walkable(state: &GameState, env: &GameEnv<'_>, mover: EntityId, position: Position) -> bool {
+    let Ok(map) = env.map() else {
+        return false;
+    };
+
+    if !map.dimensions().contains(position) {
+        return false;
+    }
+    if !map.tile(position).is_some_and(|tile| tile.is_passable()) {
+        return false;
+    }
+
+    !state
+        .entities
+        .all_actors()
+        .any(|actor| actor.id != mover && actor.position == Some(position))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrayvec::ArrayVec;
+
+    use super::*;
+    use crate::action::effect::MoveSelfEffect;
+    use crate::action::{
+        Action, ActionEffect, ActionProfile, Displacement, EffectKind, SystemActionKind,
+    };
+    use crate::engine::GameEngine;
+    use crate::env::{
+        ActionSnapshot, ActorTemplate, ActorsSnapshot, ConfigSnapshot, DialoguesSnapshot,
+        ItemsSnapshot, MapDimensions, MapSnapshot, OracleSnapshot, SnapshotOracleBundle,
+        StaticTile, TerrainKind,
+    };
+    use crate::provider::{AiKind, ProviderKind};
+    use crate::state::ActionAbility;
+    use crate::traits::TraitProfile;
+    use crate::{AiTurnAction, GameConfig};
+
+    fn snapshot() -> OracleSnapshot {
+        OracleSnapshot::new(
+            MapSnapshot::new(
+                MapDimensions::new(10, 10),
+                vec![Some(StaticTile::new(TerrainKind::Floor)); 100],
+            ),
+            ItemsSnapshot::empty(),
+            ActorsSnapshot::empty(),
+            ActionSnapshot::new([(ActionKind::Move, move_profile())].into()),
+            ConfigSnapshot::new(GameConfig::default()),
+            DialoguesSnapshot::empty(),
+        )
+    }
+
+    /// Mirrors `actions/movement.ron`.
+    fn move_profile() -> ActionProfile {
+        ActionProfile {
+            kind: ActionKind::Move,
+            tags: vec![ActionTag::Movement],
+            targeting: TargetingMode::Directional {
+                range: 1,
+                width: None,
+            },
+            base_cost: 100,
+            resource_costs: vec![],
+            effects: vec![ActionEffect::new(EffectKind::MoveSelf(
+                MoveSelfEffect::new(Displacement::FromInput { distance: 1 }),
+            ))],
+            requirements: vec![],
+            cooldown: None,
+        }
+    }
+
+    /// A provable NPC at (5, 5) that remembers the player at (2, 5).
+    fn alerted_npc() -> (GameState, EntityId) {
+        let mut actions = ArrayVec::new();
+        actions.push(ActionAbility::new(ActionKind::Wait));
+        actions.push(ActionAbility::new(ActionKind::Move));
+        let template = ActorTemplate::builder()
+            .provider_kind(ProviderKind::Ai(AiKind::Provable))
+            .trait_profile(TraitProfile::default())
+            .actions(actions)
+            .build();
+
+        let mut state = GameState::with_player();
+        // Out of sight: the NPC can only act on its memory
+        state.entities.actors[0].position = None;
+        let npc = state.add_npc(&template, Position::new(5, 5)).unwrap();
+        let actor = state.entities.actor_mut(npc).unwrap();
+        actor.ready_at = Some(0);
+        actor
+            .awareness
+            .observe(AlertLevel::Alerted, Position::new(2, 5), 100, 0);
+        state.turn.active_actors.insert(npc);
+        state.turn.current_actor = npc;
+        (state, npc)
+    }
+
+    #[test]
+    fn decisions_are_a_pure_function_of_state() {
+        let snapshot = snapshot();
+        let bundle = SnapshotOracleBundle::new(&snapshot);
+        let env = bundle.as_env().into_game_env();
+        let (state, npc) = alerted_npc();
+
+        let decided = decide_action(&state, &env, npc);
+        assert_eq!(decided, decide_action(&state.clone(), &env, npc));
+        assert_eq!(
+            decided,
+            CharacterAction::new(
+                npc,
+                ActionKind::Move,
+                ActionInput::Direction(CardinalDirection::West)
+            )
+        );
+
+        // Once the memory fades there is nothing left to act on
+        let mut forgotten = state.clone();
+        forgotten.turn.clock = 100;
+        assert_eq!(
+            decide_action(&forgotten, &env, npc),
+            CharacterAction::new(npc, ActionKind::Wait, ActionInput::None)
+        );
+    }
+
+    #[test]
+    fn ai_turn_executes_the_derived_action() {
+        let snapshot = snapshot();
+        let bundle = SnapshotOracleBundle::new(&snapshot);
+        let env = bundle.as_env();
+        let (mut state, npc) = alerted_npc();
+        let mut replayed = state.clone();
+
+        let ai_turn = Action::system(SystemActionKind::AiTurn(AiTurnAction));
+        GameEngine::new(&mut state)
+            .execute(env.as_game_env(), &ai_turn)
+            .unwrap();
+        GameEngine::new(&mut replayed)
+            .execute(env.as_game_env(), &ai_turn)
+            .unwrap();
+
+        assert_eq!(state.actor_position(npc), Some(Position::new(4, 5)));
+        assert_eq!(state, replayed);
+    }
+}
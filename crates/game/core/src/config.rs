@@ -23,6 +23,7 @@ impl GameConfig {
     pub const MAX_ACTIONS: usize = 12;
     pub const MAX_PASSIVES: usize = 8;
     pub const MAX_STATUS_EFFECTS: usize = 8;
+    pub const MAX_SHOP_STOCK: usize = 8;
 
     // ===== runtime-tunable defaults =====
     pub const DEFAULT_ACTIVATION_RADIUS: u32 = 5;
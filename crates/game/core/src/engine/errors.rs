@@ -1,8 +1,8 @@
 //! Error types for action execution pipeline.
 
 use crate::action::{
-    ActionError, ActionTransition, ActivationAction, DeactivateAction, PrepareTurnAction,
-    RemoveFromWorldAction,
+    ActionError, ActionTransition, ActivationAction, AiTurnAction, DeactivateAction,
+    PerceptionAction, PrepareTurnAction, RemoveFromWorldAction,
 };
 use crate::error::{ErrorContext, ErrorSeverity, GameError};
 
@@ -114,6 +114,14 @@ pub enum ExecuteError {
     #[cfg_attr(feature = "serde", serde(skip))]
     RemoveFromWorld(TransitionPhaseError<<RemoveFromWorldAction as ActionTransition>::Error>),
 
+    #[error("perception action failed: {0}")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Perception(TransitionPhaseError<<PerceptionAction as ActionTransition>::Error>),
+
+    #[error("ai turn action failed: {0}")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    AiTurn(TransitionPhaseError<<AiTurnAction as ActionTransition>::Error>),
+
     #[error("hook chain too deep: hook '{hook_name}' reached depth {depth}")]
     HookChainTooDeep {
         hook_name: String,
@@ -138,6 +146,13 @@ pub enum ExecuteError {
         #[cfg_attr(feature = "serde", serde(skip))]
         context: ErrorContext,
     },
+
+    #[error("invalid actor: {actor} is driven by the provable AI and acts only through AiTurn")]
+    ProvableAiActor {
+        actor: crate::state::EntityId,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        context: ErrorContext,
+    },
 }
 
 impl ExecuteError {
@@ -174,6 +189,16 @@ impl ExecuteError {
                 .with_message("action actor mismatch"),
         }
     }
+
+    /// Creates a ProvableAiActor error with context.
+    pub fn provable_ai_actor(actor: crate::state::EntityId, nonce: u64) -> Self {
+        Self::ProvableAiActor {
+            actor,
+            context: ErrorContext::new(nonce)
+                .with_actor(actor)
+                .with_message("character action submitted for provable AI actor"),
+        }
+    }
 }
 
 impl ExecuteError {
@@ -187,9 +212,12 @@ impl ExecuteError {
             Self::Activation(e) => Some(e.phase),
             Self::Deactivate(e) => Some(e.phase),
             Self::RemoveFromWorld(e) => Some(e.phase),
+            Self::Perception(e) => Some(e.phase),
+            Self::AiTurn(e) => Some(e.phase),
             Self::HookChainTooDeep { .. }
             | Self::SystemActionNotFromSystem { .. }
-            | Self::ActorNotCurrent { .. } => None,
+            | Self::ActorNotCurrent { .. }
+            | Self::ProvableAiActor { .. } => None,
         }
     }
 
@@ -227,6 +255,7 @@ impl ExecuteError {
             Self::HookChainTooDeep { .. }
                 | Self::SystemActionNotFromSystem { .. }
                 | Self::ActorNotCurrent { .. }
+                | Self::ProvableAiActor { .. }
         )
     }
 
@@ -246,9 +275,12 @@ impl GameError for ExecuteError {
             Self::Activation(e) => e.severity(),
             Self::Deactivate(e) => e.severity(),
             Self::RemoveFromWorld(e) => e.severity(),
+            Self::Perception(e) => e.severity(),
+            Self::AiTurn(e) => e.severity(),
             Self::HookChainTooDeep { .. } => ErrorSeverity::Fatal,
             Self::SystemActionNotFromSystem { .. } => ErrorSeverity::Validation,
             Self::ActorNotCurrent { .. } => ErrorSeverity::Validation,
+            Self::ProvableAiActor { .. } => ErrorSeverity::Validation,
         }
     }
 
@@ -259,9 +291,12 @@ impl GameError for ExecuteError {
             Self::Activation(e) => e.context(),
             Self::Deactivate(e) => e.context(),
             Self::RemoveFromWorld(e) => e.context(),
+            Self::Perception(e) => e.context(),
+            Self::AiTurn(e) => e.context(),
             Self::HookChainTooDeep { context, .. } => Some(context),
             Self::SystemActionNotFromSystem { context, .. } => Some(context),
             Self::ActorNotCurrent { context, .. } => Some(context),
+            Self::ProvableAiActor { context, .. } => Some(context),
         }
     }
 
@@ -272,9 +307,12 @@ impl GameError for ExecuteError {
             Self::Activation(e) => e.error_code(),
             Self::Deactivate(e) => e.error_code(),
             Self::RemoveFromWorld(e) => e.error_code(),
+            Self::Perception(e) => e.error_code(),
+            Self::AiTurn(e) => e.error_code(),
             Self::HookChainTooDeep { .. } => "EXECUTE_HOOK_CHAIN_TOO_DEEP",
             Self::SystemActionNotFromSystem { .. } => "EXECUTE_SYSTEM_ACTION_INVALID",
             Self::ActorNotCurrent { .. } => "EXECUTE_ACTOR_NOT_CURRENT",
+            Self::ProvableAiActor { .. } => "EXECUTE_PROVABLE_AI_ACTOR",
         }
     }
 }
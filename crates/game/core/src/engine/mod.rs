@@ -12,6 +12,7 @@ pub use errors::{ExecuteError, TransitionPhase, TransitionPhaseError};
 
 use crate::action::{Action, ActionResult};
 use crate::env::GameEnv;
+use crate::provider::{AiKind, ProviderKind};
 use crate::state::{GameState, StateDelta};
 
 /// Complete outcome of action execution.
@@ -51,6 +52,7 @@ impl<'a> GameEngine<'a> {
     /// Enforces mandatory actor validation before execution:
     /// - System actions must be from `EntityId::SYSTEM`
     /// - Non-system actions must be from `state.turn.current_actor`
+    /// - Actors driven by [`AiKind::Provable`] cannot submit character actions
     ///
     /// Returns `ExecutionOutcome` containing both state delta and action result.
     /// When running inside zkVM guest (`target_os = "zkvm"`), delta computation is skipped
@@ -108,8 +110,51 @@ impl<'a> GameEngine<'a> {
                         nonce,
                     ));
                 }
+
+                // Provable AI actors only act through AiTurn, so the transition
+                // derives their choice instead of trusting a submitted one
+                let provable = self
+                    .state
+                    .entities
+                    .actor(current_actor)
+                    .is_some_and(|actor| actor.provider_kind == ProviderKind::Ai(AiKind::Provable));
+                if provable {
+                    return Err(ExecuteError::provable_ai_actor(current_actor, nonce));
+                }
                 Ok(())
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::{ActionInput, ActionKind, CharacterAction};
+    use crate::env::ActorTemplate;
+    use crate::state::Position;
+    use crate::traits::TraitProfile;
+
+    #[test]
+    fn provable_ai_actors_cannot_submit_character_actions() {
+        let template = ActorTemplate::builder()
+            .provider_kind(ProviderKind::Ai(AiKind::Provable))
+            .trait_profile(TraitProfile::default())
+            .build();
+        let mut state = GameState::with_player();
+        let npc = state.add_npc(&template, Position::new(3, 3)).unwrap();
+        state.turn.current_actor = npc;
+        let before = state.clone();
+
+        let wait = Action::character(CharacterAction::new(
+            npc,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        let err = GameEngine::new(&mut state)
+            .execute(GameEnv::empty(), &wait)
+            .unwrap_err();
+        assert!(matches!(err, ExecuteError::ProvableAiActor { actor, .. } if actor == npc));
+        assert_eq!(state, before);
+    }
+}
@@ -40,7 +40,7 @@ where
 ///
 /// This is the internal implementation used by GameEngine::execute().
 /// Routes each action type to its transition.
-/// Returns Some(ActionResult) for character actions, None for system actions.
+/// Returns Some(ActionResult) for character actions and AI turns, None for other system actions.
 pub(super) fn execute_transition(
     action: &Action,
     state: &mut GameState,
@@ -84,6 +84,15 @@ pub(super) fn execute_transition(
                     drive_transition(transition, state, env)
                         .map_err(ExecuteError::RemoveFromWorld)?;
                 }
+                SystemActionKind::Perception(transition) => {
+                    drive_transition(transition, state, env).map_err(ExecuteError::Perception)?;
+                }
+                SystemActionKind::AiTurn(transition) => {
+                    // The derived character action produces a result like any other
+                    let result =
+                        drive_transition(transition, state, env).map_err(ExecuteError::AiTurn)?;
+                    return Ok(Some(result));
+                }
             }
             Ok(None)
         }
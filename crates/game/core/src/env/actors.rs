@@ -12,8 +12,8 @@ use arrayvec::ArrayVec;
 use crate::config::GameConfig;
 use crate::provider::ProviderKind;
 use crate::state::{
-    ActionAbility, ActorState, EntityId, Equipment, InventoryState, PassiveAbility, Position,
-    StatusEffects,
+    ActionAbility, ActorState, Awareness, EntityId, Equipment, InventoryState, PassiveAbility,
+    Position, Shop, StatusEffects,
 };
 use crate::stats::{CoreStats, ResourceCurrent, StatsSnapshot, compute_actor_bonuses};
 use crate::traits::{Faction, Species, TraitProfile};
@@ -64,6 +64,15 @@ pub struct ActorTemplate {
     ///
     /// After loading via ActorLoader, this is always `Some(...)`.
     pub trait_profile: Option<TraitProfile>,
+
+    /// Dialogue tree reference (see [`DialogueOracle`](super::DialogueOracle)).
+    /// `None` means the actor has nothing to say.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dialogue: Option<String>,
+
+    /// Merchant stock and pricing. `None` means the actor does not trade.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub shop: Option<Shop>,
 }
 
 impl ActorTemplate {
@@ -112,6 +121,10 @@ impl ActorTemplate {
             ),
             species: self.species,
             faction: self.faction,
+            dialogue: self.dialogue.clone(),
+            shop: self.shop.clone(),
+            awareness: Awareness::default(),
+            companion: None,
             ready_at: None,
         }
     }
@@ -142,6 +155,8 @@ pub struct ActorTemplateBuilder {
     archetype: Option<String>,
     temperament: Option<String>,
     trait_profile: Option<TraitProfile>,
+    dialogue: Option<String>,
+    shop: Option<Shop>,
 }
 
 impl ActorTemplateBuilder {
@@ -223,6 +238,18 @@ impl ActorTemplateBuilder {
         self
     }
 
+    /// Set dialogue tree reference
+    pub fn dialogue(mut self, dialogue: impl Into<String>) -> Self {
+        self.dialogue = Some(dialogue.into());
+        self
+    }
+
+    /// Set merchant shop
+    pub fn shop(mut self, shop: Shop) -> Self {
+        self.shop = Some(shop);
+        self
+    }
+
     /// Build the actor template
     pub fn build(self) -> ActorTemplate {
         use crate::provider::{AiKind, ProviderKind};
@@ -240,6 +267,8 @@ impl ActorTemplateBuilder {
             archetype: self.archetype.unwrap_or_else(|| "none".to_string()),
             temperament: self.temperament.unwrap_or_else(|| "neutral".to_string()),
             trait_profile: self.trait_profile,
+            dialogue: self.dialogue,
+            shop: self.shop,
         }
     }
 }
@@ -0,0 +1,156 @@
+//! Dialogue tree definitions and oracle interface.
+//!
+//! Dialogue trees are authored as static content (RON) and exposed through the
+//! [`DialogueOracle`]. A conversation is a walk through a tree: each node shows
+//! NPC text and a list of choices, each choice may be gated by conditions and
+//! may apply outcomes before moving to the next node.
+//!
+//! Conditions and outcomes are evaluated inside the `Talk` action so that
+//! everything a conversation does to `GameState` is validated and proven.
+
+use crate::state::{ActorState, ItemHandle, WorldState};
+use crate::traits::{Faction, TraitKind};
+
+/// A complete dialogue tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DialogueTree {
+    /// Identifier referenced by [`ActorTemplate::dialogue`](super::ActorTemplate::dialogue).
+    pub id: String,
+
+    /// Identifier of the node shown when the conversation starts.
+    pub root: String,
+
+    /// All nodes in the tree.
+    pub nodes: Vec<DialogueNode>,
+}
+
+impl DialogueTree {
+    /// Returns the node with the given identifier.
+    pub fn node(&self, id: &str) -> Option<&DialogueNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Returns the root node.
+    pub fn root_node(&self) -> Option<&DialogueNode> {
+        self.node(&self.root)
+    }
+}
+
+/// A single line of NPC dialogue with the player's possible replies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DialogueNode {
+    pub id: String,
+
+    /// What the NPC says.
+    pub text: String,
+
+    /// Player replies, in display order. An empty list ends the conversation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub choices: Vec<DialogueChoice>,
+}
+
+/// A player reply.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DialogueChoice {
+    /// What the player says.
+    pub text: String,
+
+    /// All conditions must hold for the choice to be offered.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub conditions: Vec<DialogueCondition>,
+
+    /// Applied in order when the choice is taken.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub outcomes: Vec<DialogueOutcome>,
+
+    /// Node to continue with. `None` ends the conversation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub next: Option<String>,
+}
+
+impl DialogueChoice {
+    /// Returns true if every condition holds.
+    pub fn is_available(&self, player: &ActorState, npc: &ActorState, world: &WorldState) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.is_met(player, npc, world))
+    }
+}
+
+/// Requirement for a dialogue choice to be offered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DialogueCondition {
+    /// The NPC's composed trait value (0..240) lies within `min..=max`
+    /// (personality-gated replies).
+    Trait { kind: TraitKind, min: u8, max: u8 },
+
+    /// The player holds at least `quantity` of the item.
+    HasItem { item: ItemHandle, quantity: u16 },
+
+    /// The story flag is set.
+    Flag(String),
+
+    /// The story flag is not set.
+    NotFlag(String),
+
+    /// The NPC currently belongs to the faction.
+    Faction(Faction),
+}
+
+impl DialogueCondition {
+    /// Evaluates the condition against the current participants and world.
+    pub fn is_met(&self, player: &ActorState, npc: &ActorState, world: &WorldState) -> bool {
+        match self {
+            Self::Trait { kind, min, max } => {
+                let value = npc.trait_profile.get(*kind);
+                (*min..=*max).contains(&value)
+            }
+            Self::HasItem { item, quantity } => {
+                player.inventory.quantity_of(*item) >= *quantity as u32
+            }
+            Self::Flag(flag) => world.has_flag(flag),
+            Self::NotFlag(flag) => !world.has_flag(flag),
+            Self::Faction(faction) => npc.faction == *faction,
+        }
+    }
+}
+
+/// State change applied when a dialogue choice is taken.
+///
+/// Quests are modelled as story flags (e.g. `SetFlag("quest.cellar.started")`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DialogueOutcome {
+    /// Add items to the player's inventory.
+    GiveItem { item: ItemHandle, quantity: u16 },
+
+    /// Remove items from the player's inventory.
+    TakeItem { item: ItemHandle, quantity: u16 },
+
+    /// Change the NPC's faction (e.g. `Neutral` → `Friendly` or `Hostile`).
+    SetFaction(Faction),
+
+    /// Set a story flag.
+    SetFlag(String),
+
+    /// Clear a story flag.
+    ClearFlag(String),
+}
+
+/// Oracle providing dialogue trees.
+pub trait DialogueOracle: Send + Sync {
+    /// Returns the dialogue tree with the given identifier.
+    fn dialogue(&self, id: &str) -> Option<DialogueTree>;
+
+    /// Returns all available dialogue identifiers.
+    ///
+    /// This is used for creating snapshots that need to capture all dialogues.
+    #[cfg(feature = "std")]
+    fn all_ids(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
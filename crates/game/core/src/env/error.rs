@@ -37,6 +37,10 @@ pub enum OracleError {
     #[error("RngOracle not available")]
     RngNotAvailable,
 
+    /// DialogueOracle is not available in the environment.
+    #[error("DialogueOracle not available")]
+    DialoguesNotAvailable,
+
     /// Position is outside the map bounds.
     #[error("position {0:?} is out of map bounds")]
     PositionOutOfBounds(Position),
@@ -59,8 +63,13 @@ impl GameError for OracleError {
         use OracleError::*;
         match self {
             // Missing oracles are fatal - engine cannot proceed
-            MapNotAvailable | ItemsNotAvailable | ActorsNotAvailable | ActionsNotAvailable
-            | ConfigNotAvailable | RngNotAvailable => ErrorSeverity::Fatal,
+            MapNotAvailable
+            | ItemsNotAvailable
+            | ActorsNotAvailable
+            | ActionsNotAvailable
+            | ConfigNotAvailable
+            | RngNotAvailable
+            | DialoguesNotAvailable => ErrorSeverity::Fatal,
 
             // Not found errors are validation errors - invalid references
             PositionOutOfBounds(_)
@@ -79,6 +88,7 @@ impl GameError for OracleError {
             ActionsNotAvailable => "ORACLE_ACTIONS_NOT_AVAILABLE",
             ConfigNotAvailable => "ORACLE_CONFIG_NOT_AVAILABLE",
             RngNotAvailable => "ORACLE_RNG_NOT_AVAILABLE",
+            DialoguesNotAvailable => "ORACLE_DIALOGUES_NOT_AVAILABLE",
             PositionOutOfBounds(_) => "ORACLE_POSITION_OUT_OF_BOUNDS",
             TileNotFound(_) => "ORACLE_TILE_NOT_FOUND",
             ItemNotFound(_) => "ORACLE_ITEM_NOT_FOUND",
@@ -109,6 +109,10 @@ pub struct ItemDefinition {
     pub handle: ItemHandle,
     pub kind: ItemKind,
     pub max_stack: u16,
+
+    /// Base trade value in gold. Items worth `0` cannot be sold.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub value: u32,
 }
 
 impl ItemDefinition {
@@ -117,8 +121,15 @@ impl ItemDefinition {
             handle,
             kind,
             max_stack,
+            value: 0,
         }
     }
+
+    /// Builder: set the base trade value.
+    pub fn with_value(mut self, value: u32) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 /// Item type with type-specific data.
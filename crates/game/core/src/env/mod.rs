@@ -1,11 +1,12 @@
 //! Traits describing read-only world data.
 //!
-//! Oracles expose static map geometry, item definitions, rule tables, and NPC
-//! templates. The [`Env`] aggregate bundles them so the engine can access
+//! Oracles expose static map geometry, item definitions, rule tables, NPC
+//! templates, and dialogue trees. The [`Env`] aggregate bundles them so the engine can access
 //! everything it needs without hard coupling to concrete implementations.
 mod actions;
 mod actors;
 mod config;
+mod dialogue;
 mod error;
 mod items;
 mod map;
@@ -15,22 +16,28 @@ mod snapshot;
 pub use actions::ActionOracle;
 pub use actors::{ActorOracle, ActorTemplate, ActorTemplateBuilder};
 pub use config::ConfigOracle;
+pub use dialogue::{
+    DialogueChoice, DialogueCondition, DialogueNode, DialogueOracle, DialogueOutcome, DialogueTree,
+};
 pub use error::OracleError;
 pub use items::{
     ArmorData, ArmorKind, AttackType, ConsumableData, ItemDefinition, ItemKind, ItemOracle,
     WeaponData, WeaponKind,
 };
 pub use map::{MapDimensions, MapOracle, StaticTile, TerrainKind};
-pub use rng::{PcgRng, RngOracle, compute_seed};
+pub use rng::{
+    PcgRng, RngOracle, SEED_COMMITMENT_DOMAIN, SEED_REVEAL_DOMAIN,
+    compute_revealed_seed_commitment, compute_seed, compute_seed_commitment, seed_reveal_salt,
+};
 pub use snapshot::{
-    ActionSnapshot, ActorsSnapshot, ConfigSnapshot, ItemsSnapshot, MapSnapshot, OracleSnapshot,
-    SnapshotActionOracle, SnapshotActorOracle, SnapshotConfigOracle, SnapshotItemOracle,
-    SnapshotMapOracle, SnapshotOracleBundle,
+    ActionSnapshot, ActorsSnapshot, ConfigSnapshot, DialoguesSnapshot, ItemsSnapshot, MapSnapshot,
+    OracleSnapshot, SnapshotActionOracle, SnapshotActorOracle, SnapshotConfigOracle,
+    SnapshotDialogueOracle, SnapshotItemOracle, SnapshotMapOracle, SnapshotOracleBundle,
 };
 
 /// Aggregates read-only oracles required by the reducer and action pipeline.
 #[derive(Clone, Copy, Debug)]
-pub struct Env<'a, M, I, T, A, C, R>
+pub struct Env<'a, M, I, T, A, C, R, D>
 where
     M: MapOracle + ?Sized,
     I: ItemOracle + ?Sized,
@@ -38,6 +45,7 @@ where
     A: ActorOracle + ?Sized,
     C: ConfigOracle + ?Sized,
     R: RngOracle + ?Sized,
+    D: DialogueOracle + ?Sized,
 {
     map: Option<&'a M>,
     items: Option<&'a I>,
@@ -45,6 +53,7 @@ where
     actors: Option<&'a A>,
     config: Option<&'a C>,
     rng: Option<&'a R>,
+    dialogues: Option<&'a D>,
 }
 
 pub type GameEnv<'a> = Env<
@@ -55,9 +64,10 @@ pub type GameEnv<'a> = Env<
     dyn ActorOracle + 'a,
     dyn ConfigOracle + 'a,
     dyn RngOracle + 'a,
+    dyn DialogueOracle + 'a,
 >;
 
-impl<'a, M, I, T, A, C, R> Env<'a, M, I, T, A, C, R>
+impl<'a, M, I, T, A, C, R, D> Env<'a, M, I, T, A, C, R, D>
 where
     M: MapOracle + ?Sized,
     I: ItemOracle + ?Sized,
@@ -65,6 +75,7 @@ where
     A: ActorOracle + ?Sized,
     C: ConfigOracle + ?Sized,
     R: RngOracle + ?Sized,
+    D: DialogueOracle + ?Sized,
 {
     pub fn new(
         map: Option<&'a M>,
@@ -73,6 +84,7 @@ where
         actors: Option<&'a A>,
         config: Option<&'a C>,
         rng: Option<&'a R>,
+        dialogues: Option<&'a D>,
     ) -> Self {
         Self {
             map,
@@ -81,6 +93,7 @@ where
             actors,
             config,
             rng,
+            dialogues,
         }
     }
 
@@ -91,6 +104,7 @@ where
         actors: &'a A,
         config: &'a C,
         rng: &'a R,
+        dialogues: &'a D,
     ) -> Self {
         Self::new(
             Some(map),
@@ -99,6 +113,7 @@ where
             Some(actors),
             Some(config),
             Some(rng),
+            Some(dialogues),
         )
     }
 
@@ -110,6 +125,7 @@ where
             actors: None,
             config: None,
             rng: None,
+            dialogues: None,
         }
     }
 
@@ -167,6 +183,15 @@ where
         self.rng.ok_or(OracleError::RngNotAvailable)
     }
 
+    /// Returns the DialogueOracle, or an error if not available.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OracleError::DialoguesNotAvailable` if no dialogue oracle was provided.
+    pub fn dialogues(&self) -> Result<&'a D, OracleError> {
+        self.dialogues.ok_or(OracleError::DialoguesNotAvailable)
+    }
+
     /// Returns the activation radius from the config oracle.
     ///
     /// # Errors
@@ -177,7 +202,7 @@ where
     }
 }
 
-impl<'a, M, I, T, A, C, R> Env<'a, M, I, T, A, C, R>
+impl<'a, M, I, T, A, C, R, D> Env<'a, M, I, T, A, C, R, D>
 where
     M: MapOracle + 'a,
     I: ItemOracle + 'a,
@@ -185,6 +210,7 @@ where
     A: ActorOracle + 'a,
     C: ConfigOracle + 'a,
     R: RngOracle + 'a,
+    D: DialogueOracle + 'a,
 {
     /// Converts this environment into a trait-object based `GameEnv` (consumes self).
     ///
@@ -196,13 +222,15 @@ where
         let actors: Option<&'a dyn ActorOracle> = self.actors.map(|actors| actors as _);
         let config: Option<&'a dyn ConfigOracle> = self.config.map(|config| config as _);
         let rng: Option<&'a dyn RngOracle> = self.rng.map(|rng| rng as _);
-        Env::new(map, items, actions, actors, config, rng)
+        let dialogues: Option<&'a dyn DialogueOracle> =
+            self.dialogues.map(|dialogues| dialogues as _);
+        Env::new(map, items, actions, actors, config, rng, dialogues)
     }
 
     /// Converts this environment into a trait-object based `GameEnv` (borrows self).
     ///
     /// Use this when you need to convert multiple times (e.g., in a loop).
-    /// Overhead: 6 pointer copies (48 bytes on 64-bit systems).
+    /// Overhead: 7 pointer copies (56 bytes on 64-bit systems).
     pub fn as_game_env(&self) -> GameEnv<'a> {
         let map: Option<&'a dyn MapOracle> = self.map.map(|map| map as _);
         let items: Option<&'a dyn ItemOracle> = self.items.map(|items| items as _);
@@ -210,6 +238,8 @@ where
         let actors: Option<&'a dyn ActorOracle> = self.actors.map(|actors| actors as _);
         let config: Option<&'a dyn ConfigOracle> = self.config.map(|config| config as _);
         let rng: Option<&'a dyn RngOracle> = self.rng.map(|rng| rng as _);
-        Env::new(map, items, actions, actors, config, rng)
+        let dialogues: Option<&'a dyn DialogueOracle> =
+            self.dialogues.map(|dialogues| dialogues as _);
+        Env::new(map, items, actions, actors, config, rng, dialogues)
     }
 }
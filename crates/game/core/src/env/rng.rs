@@ -145,3 +145,48 @@ pub fn compute_seed(game_seed: u64, nonce: u64, actor_id: u32, context: u32) ->
 
     hash
 }
+
+/// Domain tag prefixed to the seed commitment preimage.
+pub const SEED_COMMITMENT_DOMAIN: &[u8] = b"dungeon.seed_commitment.v1";
+
+/// Domain tag of the salt revealed with the seed.
+pub const SEED_REVEAL_DOMAIN: &[u8] = b"dungeon.seed_reveal.v1";
+
+/// Salt revealed with the game seed at finalization.
+///
+/// `SHA-256(SEED_REVEAL_DOMAIN || salt)`. The session salt itself also blinds
+/// hidden actions and state commitments, so it is never revealed; this hash
+/// cannot be inverted to recover it.
+pub fn seed_reveal_salt(salt: &[u8; 32]) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(SEED_REVEAL_DOMAIN);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+/// Compute the salted commitment to a game seed.
+///
+/// The commitment is taken over the salt revealed at finalization (see
+/// [`seed_reveal_salt`] and [`compute_revealed_seed_commitment`]). The session
+/// salt is kept private, so the 64-bit seed cannot be recovered by brute
+/// force from the published commitment. The zkVM guest recomputes this from
+/// the proven state, binding the journal's commitment to the seed that
+/// actually drove the RNG.
+pub fn compute_seed_commitment(game_seed: u64, salt: &[u8; 32]) -> [u8; 32] {
+    compute_revealed_seed_commitment(game_seed, &seed_reveal_salt(salt))
+}
+
+/// Seed commitment as checked against a revealed seed and salt.
+///
+/// `SHA-256(SEED_COMMITMENT_DOMAIN || reveal_salt || game_seed (u64 LE))`.
+pub fn compute_revealed_seed_commitment(game_seed: u64, reveal_salt: &[u8; 32]) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(SEED_COMMITMENT_DOMAIN);
+    hasher.update(reveal_salt);
+    hasher.update(game_seed.to_le_bytes());
+    hasher.finalize().into()
+}
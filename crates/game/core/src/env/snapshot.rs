@@ -21,8 +21,8 @@ use alloc::{collections::BTreeMap, vec::Vec};
 use std::{collections::BTreeMap, vec::Vec};
 
 use super::{
-    ActionOracle, ActorOracle, ConfigOracle, ItemDefinition, ItemOracle, MapDimensions, MapOracle,
-    StaticTile,
+    ActionOracle, ActorOracle, ConfigOracle, DialogueOracle, DialogueTree, ItemDefinition,
+    ItemOracle, MapDimensions, MapOracle, StaticTile,
 };
 use crate::{GameConfig, ItemHandle, Position};
 
@@ -42,6 +42,7 @@ pub struct OracleSnapshot {
     pub actors: ActorsSnapshot,
     pub actions: ActionSnapshot,
     pub config: ConfigSnapshot,
+    pub dialogues: DialoguesSnapshot,
 }
 
 impl OracleSnapshot {
@@ -51,6 +52,7 @@ impl OracleSnapshot {
         actors: ActorsSnapshot,
         actions: ActionSnapshot,
         config: ConfigSnapshot,
+        dialogues: DialoguesSnapshot,
     ) -> Self {
         Self {
             map,
@@ -58,6 +60,7 @@ impl OracleSnapshot {
             actors,
             actions,
             config,
+            dialogues,
         }
     }
 
@@ -74,6 +77,7 @@ impl OracleSnapshot {
         actors: &dyn ActorOracle,
         actions: &dyn ActionOracle,
         config: &dyn ConfigOracle,
+        dialogues: &dyn DialogueOracle,
         actor_ids: &[String],
     ) -> Self {
         Self::new(
@@ -82,13 +86,14 @@ impl OracleSnapshot {
             ActorsSnapshot::from_oracle(actors, actor_ids),
             ActionSnapshot::from_oracle(actions),
             ConfigSnapshot::from_oracle(config),
+            DialoguesSnapshot::from_oracle(dialogues),
         )
     }
 
     /// Computes a deterministic SHA-256 hash of the entire oracle snapshot.
     ///
     /// This is used as the "oracle root" for ZK proofs, providing a cryptographic
-    /// commitment to all static game content (maps, items, actors, tables, config).
+    /// commitment to all static game content (maps, items, actors, tables, config, dialogues).
     ///
     /// # Design
     ///
@@ -133,6 +138,11 @@ impl OracleSnapshot {
             hasher.update(&config_bytes);
         }
 
+        // 6. Dialogues snapshot (BTreeMap ensures deterministic order)
+        if let Ok(dialogue_bytes) = bincode::serialize(&self.dialogues) {
+            hasher.update(&dialogue_bytes);
+        }
+
         hasher.finalize().into()
     }
 }
@@ -189,12 +199,14 @@ impl ItemsSnapshot {
 
     /// Creates an items snapshot from an ItemOracle.
     ///
-    /// Includes all item definitions available in the oracle.
+    /// Includes all item definitions available in the oracle, ordered by handle
+    /// so the serialized snapshot (and the oracle root) is reproducible.
     #[cfg(feature = "std")]
     pub fn from_oracle(oracle: &dyn ItemOracle) -> Self {
         let all_defs = oracle.all_definitions();
-        let items: Vec<(ItemHandle, ItemDefinition)> =
+        let mut items: Vec<(ItemHandle, ItemDefinition)> =
             all_defs.into_iter().map(|def| (def.handle, def)).collect();
+        items.sort_by_key(|(handle, _)| handle.0);
 
         Self::new(items)
     }
@@ -206,6 +218,9 @@ type ActorId = alloc::string::String;
 #[cfg(feature = "std")]
 type ActorId = String;
 
+/// Dialogue definition IDs share the actor ID string representation.
+type DialogueId = ActorId;
+
 /// Snapshot of actors oracle data containing actor templates by definition ID.
 ///
 /// This snapshot stores all actor templates that need to be available in the zkVM guest.
@@ -236,6 +251,8 @@ impl ActorsSnapshot {
     ///
     /// * `oracle` - Actor oracle implementation
     /// * `def_ids` - List of actor definition IDs to snapshot
+    ///
+    /// Templates are ordered by definition ID regardless of `def_ids` order.
     #[cfg(feature = "std")]
     pub fn from_oracle(oracle: &dyn super::ActorOracle, def_ids: &[String]) -> Self {
         let mut templates = Vec::with_capacity(def_ids.len());
@@ -246,6 +263,7 @@ impl ActorsSnapshot {
             }
         }
 
+        templates.sort_by(|(a, _), (b, _)| a.cmp(b));
         Self::new(templates)
     }
 }
@@ -283,6 +301,36 @@ impl ActionSnapshot {
     }
 }
 
+/// Snapshot of dialogue oracle data, keyed by dialogue ID.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DialoguesSnapshot {
+    pub dialogues: BTreeMap<DialogueId, DialogueTree>,
+}
+
+impl DialoguesSnapshot {
+    pub fn new(dialogues: BTreeMap<DialogueId, DialogueTree>) -> Self {
+        Self { dialogues }
+    }
+
+    /// Create an empty snapshot with no dialogues.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Creates a dialogues snapshot from a DialogueOracle.
+    #[cfg(feature = "std")]
+    pub fn from_oracle(oracle: &dyn DialogueOracle) -> Self {
+        let dialogues = oracle
+            .all_ids()
+            .into_iter()
+            .filter_map(|id| oracle.dialogue(&id).map(|tree| (id, tree)))
+            .collect();
+
+        Self::new(dialogues)
+    }
+}
+
 /// Snapshot of config oracle data
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -430,6 +478,23 @@ impl<'a> ConfigOracle for SnapshotConfigOracle<'a> {
     }
 }
 
+/// Guest-side adapter for DialogueOracle backed by DialoguesSnapshot
+pub struct SnapshotDialogueOracle<'a> {
+    snapshot: &'a DialoguesSnapshot,
+}
+
+impl<'a> SnapshotDialogueOracle<'a> {
+    pub fn new(snapshot: &'a DialoguesSnapshot) -> Self {
+        Self { snapshot }
+    }
+}
+
+impl<'a> DialogueOracle for SnapshotDialogueOracle<'a> {
+    fn dialogue(&self, id: &str) -> Option<DialogueTree> {
+        self.snapshot.dialogues.get(id).cloned()
+    }
+}
+
 /// Bundle of all snapshot-backed oracle adapters.
 ///
 /// This owns all adapters to avoid lifetime issues in guest programs.
@@ -440,6 +505,7 @@ pub struct SnapshotOracleBundle<'a> {
     pub actors: SnapshotActorOracle<'a>,
     pub config: SnapshotConfigOracle<'a>,
     pub rng: super::PcgRng,
+    pub dialogues: SnapshotDialogueOracle<'a>,
 }
 
 impl<'a> SnapshotOracleBundle<'a> {
@@ -452,6 +518,7 @@ impl<'a> SnapshotOracleBundle<'a> {
             actors: SnapshotActorOracle::new(&snapshot.actors),
             config: SnapshotConfigOracle::new(&snapshot.config),
             rng: super::PcgRng, // PcgRng is stateless
+            dialogues: SnapshotDialogueOracle::new(&snapshot.dialogues),
         }
     }
 
@@ -466,6 +533,7 @@ impl<'a> SnapshotOracleBundle<'a> {
         SnapshotActorOracle<'a>,
         SnapshotConfigOracle<'a>,
         super::PcgRng,
+        SnapshotDialogueOracle<'a>,
     > {
         super::Env::with_all(
             &self.map,
@@ -474,6 +542,7 @@ impl<'a> SnapshotOracleBundle<'a> {
             &self.actors,
             &self.config,
             &self.rng,
+            &self.dialogues,
         )
     }
 }
@@ -5,6 +5,7 @@
 //! All state mutation flows through [`engine::GameEngine`], and supporting
 //! crates depend on the types re-exported here.
 pub mod action;
+pub mod ai;
 pub mod config;
 pub mod engine;
 pub mod env;
@@ -13,37 +14,47 @@ pub mod provider;
 pub mod state;
 pub mod stats;
 pub mod traits;
-#[cfg(feature = "serde")]
-pub use action::compute_actions_root;
 pub use action::{
     Action, ActionEffect, ActionError, ActionInput, ActionKind, ActionProfile, ActionResult,
-    ActionTag, ActionTransition, ActivationAction, ActivationError, CardinalDirection,
-    CharacterAction, DamageType, DeactivateAction, EffectContext, EffectKind, ExecutionPhase,
-    Formula, PrepareTurnAction, RemoveFromWorldAction, RemoveFromWorldError, ResourceCost,
-    RestoreResourceEffect, SystemActionKind, TargetingMode, TurnError, get_available_actions,
+    ActionTag, ActionTransition, ActivationAction, ActivationError, AiTurnAction, AiTurnError,
+    CardinalDirection, CharacterAction, CompanionCommand, DamageType, DeactivateAction,
+    EffectContext, EffectKind, ExecutionPhase, Formula, PerceptionAction, PerceptionError,
+    PrepareTurnAction, PublishedAction, RemoveFromWorldAction, RemoveFromWorldError, ResourceCost,
+    RestoreResourceEffect, Stimulus, SystemActionKind, TargetingMode, TradeOrder, TurnError,
+    get_available_actions,
 };
+#[cfg(feature = "serde")]
+pub use action::{compute_actions_root, compute_published_actions_root, publish_actions};
 pub use config::GameConfig;
 pub use engine::{
     ExecuteError, ExecutionOutcome, GameEngine, TransitionPhase, TransitionPhaseError,
 };
 pub use env::{
     ActionOracle, ActionSnapshot, ActorOracle, ActorTemplate, ActorTemplateBuilder, ActorsSnapshot,
-    ArmorData, ArmorKind, AttackType, ConfigOracle, ConfigSnapshot, ConsumableData, Env, GameEnv,
-    ItemDefinition, ItemKind, ItemOracle, ItemsSnapshot, MapDimensions, MapOracle, MapSnapshot,
-    OracleError, OracleSnapshot, PcgRng, RngOracle, SnapshotActionOracle, SnapshotActorOracle,
-    SnapshotConfigOracle, SnapshotItemOracle, SnapshotMapOracle, SnapshotOracleBundle, StaticTile,
-    TerrainKind, WeaponData, WeaponKind, compute_seed,
+    ArmorData, ArmorKind, AttackType, ConfigOracle, ConfigSnapshot, ConsumableData, DialogueChoice,
+    DialogueCondition, DialogueNode, DialogueOracle, DialogueOutcome, DialogueTree,
+    DialoguesSnapshot, Env, GameEnv, ItemDefinition, ItemKind, ItemOracle, ItemsSnapshot,
+    MapDimensions, MapOracle, MapSnapshot, OracleError, OracleSnapshot, PcgRng, RngOracle,
+    SEED_COMMITMENT_DOMAIN, SEED_REVEAL_DOMAIN, SnapshotActionOracle, SnapshotActorOracle,
+    SnapshotConfigOracle, SnapshotDialogueOracle, SnapshotItemOracle, SnapshotMapOracle,
+    SnapshotOracleBundle, StaticTile, TerrainKind, WeaponData, WeaponKind,
+    compute_revealed_seed_commitment, compute_seed, compute_seed_commitment, seed_reveal_salt,
 };
 pub use error::{ErrorContext, ErrorSeverity, GameError, NeverError};
 pub use provider::{AiKind, InteractiveKind, ProviderKind};
 pub use state::{
-    ActionAbilities, ActionAbility, ActorChanges, ActorFields, ActorState, CollectionChanges,
+    ActionAbilities, ActionAbility, ActorChanges, ActorFields, ActorState, AlertLevel, Awareness,
+    CollectionChanges, CollectionValues, Companion, CompanionOrder, Conversation, DeltaValues,
     EntitiesChanges, EntitiesState, EntityId, Equipment, EquipmentBuilder, GameState,
     InventorySlot, InventoryState, ItemChanges, ItemFields, ItemHandle, ItemState,
     OccupancyChanges, PassiveAbilities, PassiveAbility, PassiveKind, Position, PropChanges,
-    PropFields, PropKind, PropState, StateDelta, StateError, StatusEffect, StatusEffectKind,
-    StatusEffects, Tick, TileMap, TileView, TurnChanges, TurnFields, TurnState, WorldChanges,
-    WorldState,
+    PropFields, PropKind, PropState, PublicView, Shop, ShopStock, StateCommitment, StateDelta,
+    StateError, StatusEffect, StatusEffectKind, StatusEffects, Tick, TileMap, TileView,
+    TurnChanges, TurnFields, TurnState, WorldChanges, WorldState,
+};
+#[cfg(feature = "serde")]
+pub use state::{
+    InclusionProof, LeafKey, MERKLE_LEAF_DOMAIN, MERKLE_NODE_DOMAIN, MERKLE_ROOT_DOMAIN, StateLeaf,
 };
 pub use stats::{
     ActorBonuses, Bonus, BonusStack, CoreEffective, CoreStatBonuses, CoreStatKind, CoreStats,
@@ -96,6 +96,13 @@ pub enum AiKind {
     /// - Scoring: 0-100 utility score based on goal relevance
     /// - Selection: Highest scoring candidate wins
     Utility,
+
+    /// Deterministic in-engine AI (see `game_core::ai`)
+    ///
+    /// Decisions are derived inside the state transition by `AiTurnAction`, so the
+    /// zkVM guest proves the NPC followed its rules, not just that the action was legal.
+    /// No runtime provider is consulted for these actors.
+    Provable,
 }
 
 impl fmt::Display for ProviderKind {
@@ -124,6 +131,7 @@ impl fmt::Display for AiKind {
         let s = match self {
             Self::Wait => "wait",
             Self::Utility => "utility",
+            Self::Provable => "provable",
         };
         write!(f, "{}", s)
     }
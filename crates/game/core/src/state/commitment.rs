@@ -0,0 +1,141 @@
+//! Hiding state commitments that separate public views from private state.
+//!
+//! A [`StateCommitment`] is a two-leaf Merkle-style commitment:
+//!
+//! ```text
+//!                root = SHA256(STATE_COMMITMENT_DOMAIN || public_root || private_root)
+//!               /                                                  \
+//! public_root = SHA256(PUBLIC_VIEW_DOMAIN || view)   private_root = SHA256(PRIVATE_STATE_DOMAIN || blinding || state)
+//! ```
+//!
+//! The public leaf commits to a [`PublicView`], the facts anyone may learn
+//! about the state. The private leaf commits to the whole state behind a
+//! secret blinding factor, so the root reveals nothing about NPC positions,
+//! inventories or the seed, while still binding a proof chain to the exact
+//! state it executed. Opening the public leaf only requires the view and the
+//! private root.
+
+use super::{ActorState, EntityId, GameState, Tick};
+
+/// Domain tag of the commitment root.
+pub const STATE_COMMITMENT_DOMAIN: &[u8] = b"dungeon.state_commitment.v1";
+/// Domain tag of the public view leaf.
+pub const PUBLIC_VIEW_DOMAIN: &[u8] = b"dungeon.public_view.v1";
+/// Domain tag of the private state leaf.
+pub const PRIVATE_STATE_DOMAIN: &[u8] = b"dungeon.private_state.v1";
+
+/// The part of a game state that is safe to publish.
+///
+/// Covers the turn counters and the player's own actor: everything the
+/// player's published actions already reveal. Other actors, props, items,
+/// occupancy and the RNG seed stay private.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PublicView {
+    /// Sequential action counter.
+    pub nonce: u64,
+    /// Timeline clock.
+    pub clock: Tick,
+    /// The player actor, if still present.
+    pub player: Option<ActorState>,
+}
+
+impl PublicView {
+    /// Public leaf of the state commitment.
+    #[cfg(feature = "serde")]
+    pub fn compute_root(&self) -> [u8; 32] {
+        use sha2::{Digest, Sha256};
+
+        let serialized =
+            bincode::serialize(self).expect("PublicView serialization should never fail");
+        let mut hasher = Sha256::new();
+        hasher.update(PUBLIC_VIEW_DOMAIN);
+        hasher.update(&serialized);
+        hasher.finalize().into()
+    }
+}
+
+/// Public and private leaves of a state commitment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateCommitment {
+    /// Root of the [`PublicView`].
+    pub public_root: [u8; 32],
+    /// Blinded commitment to the full state.
+    pub private_root: [u8; 32],
+}
+
+impl StateCommitment {
+    /// Root committed in proof journals.
+    pub fn root(&self) -> [u8; 32] {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        hasher.update(STATE_COMMITMENT_DOMAIN);
+        hasher.update(self.public_root);
+        hasher.update(self.private_root);
+        hasher.finalize().into()
+    }
+
+    /// Check that `view` is the public view committed under `root`.
+    #[cfg(feature = "serde")]
+    pub fn opens(&self, root: &[u8; 32], view: &PublicView) -> bool {
+        self.public_root == view.compute_root() && &self.root() == root
+    }
+}
+
+impl GameState {
+    /// Projects the state onto its [`PublicView`].
+    pub fn public_view(&self) -> PublicView {
+        PublicView {
+            nonce: self.turn.nonce,
+            clock: self.turn.clock,
+            player: self.entities.actor(EntityId::PLAYER).cloned(),
+        }
+    }
+
+    /// Private leaf: the full state hidden behind `blinding`.
+    #[cfg(feature = "serde")]
+    pub fn compute_private_root(&self, blinding: &[u8; 32]) -> [u8; 32] {
+        use sha2::{Digest, Sha256};
+
+        let serialized =
+            bincode::serialize(self).expect("GameState serialization should never fail");
+        let mut hasher = Sha256::new();
+        hasher.update(PRIVATE_STATE_DOMAIN);
+        hasher.update(blinding);
+        hasher.update(&serialized);
+        hasher.finalize().into()
+    }
+
+    /// Hiding commitment to the state, split into public and private leaves.
+    ///
+    /// `blinding` must be secret and high-entropy; sessions use their seed
+    /// salt, which is never revealed (finalization reveals only the derived
+    /// [`seed_reveal_salt`](crate::seed_reveal_salt)).
+    #[cfg(feature = "serde")]
+    pub fn compute_state_commitment(&self, blinding: &[u8; 32]) -> StateCommitment {
+        StateCommitment {
+            public_root: self.public_view().compute_root(),
+            private_root: self.compute_private_root(blinding),
+        }
+    }
+
+    /// State root that proof journals commit to.
+    ///
+    /// The hiding [`StateCommitment`] root by default. With the `merkle-state`
+    /// feature, the Merkle root of [`GameState::compute_merkle_root`] instead,
+    /// which supports per-entity inclusion proofs but hides nothing.
+    #[cfg(feature = "serde")]
+    pub fn compute_journal_state_root(&self, blinding: &[u8; 32]) -> [u8; 32] {
+        #[cfg(feature = "merkle-state")]
+        {
+            let _ = blinding;
+            self.compute_merkle_root()
+        }
+        #[cfg(not(feature = "merkle-state"))]
+        {
+            self.compute_state_commitment(blinding).root()
+        }
+    }
+}
@@ -4,16 +4,22 @@ bitflags! {
     /// Tracks which fields of an [`ActorState`] changed during a state transition.
     ///
     /// Each bit represents a single field in the actor structure. Using bitflags
-    /// provides O(1) set/check operations and minimal memory footprint (~1 byte).
+    /// provides O(1) set/check operations and minimal memory footprint (~2 bytes).
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-    pub struct ActorFields: u8 {
+    pub struct ActorFields: u16 {
         const POSITION    = 1 << 0;
         const CORE_STATS  = 1 << 1;
         const RESOURCES   = 1 << 2;
         const BONUSES     = 1 << 3;
         const INVENTORY   = 1 << 4;
         const READY_AT    = 1 << 5;
+        const AWARENESS   = 1 << 6;
+        const COMPANION   = 1 << 7;
+        const FACTION     = 1 << 8;
+        const SHOP        = 1 << 9;
+        const STATUS      = 1 << 10;
+        const EQUIPMENT   = 1 << 11;
     }
 }
 
@@ -51,6 +51,24 @@ impl ActorChanges {
         if before.ready_at != after.ready_at {
             fields |= ActorFields::READY_AT;
         }
+        if before.awareness != after.awareness {
+            fields |= ActorFields::AWARENESS;
+        }
+        if before.companion != after.companion {
+            fields |= ActorFields::COMPANION;
+        }
+        if before.faction != after.faction {
+            fields |= ActorFields::FACTION;
+        }
+        if before.shop != after.shop {
+            fields |= ActorFields::SHOP;
+        }
+        if before.status_effects != after.status_effects {
+            fields |= ActorFields::STATUS;
+        }
+        if before.equipment != after.equipment {
+            fields |= ActorFields::EQUIPMENT;
+        }
 
         if fields.is_empty() {
             None
@@ -1,15 +1,17 @@
 mod bitmask;
 mod changes;
 mod collection;
+mod values;
 
 use std::collections::BTreeSet;
 
 use crate::action::Action;
-use crate::state::{EntitiesState, GameState, Tick, WorldState};
+use crate::state::{EntitiesState, GameState, StateError, Tick, WorldState};
 
 pub use bitmask::{ActorFields, ItemFields, PropFields, TurnFields};
 pub use changes::{ActorChanges, ItemChanges, OccupancyChanges, PropChanges, TurnChanges};
 pub use collection::CollectionChanges;
+pub use values::{CollectionValues, DeltaValues};
 
 use changes::{ActorChanges as AC, ItemChanges as IC, PropChanges as PC};
 use collection::diff_collection;
@@ -17,7 +19,8 @@ use collection::diff_collection;
 /// Minimal description of an executed action's impact on the deterministic state.
 ///
 /// The delta system uses **bitmask-based change tracking** to capture metadata about
-/// state transitions without storing actual values.
+/// state transitions, alongside the post-action [`DeltaValues`] of everything that
+/// changed so the transition can be replayed with [`StateDelta::apply`].
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateDelta {
@@ -35,6 +38,9 @@ pub struct StateDelta {
 
     /// Changes to world state (occupancy grid).
     pub world: WorldChanges,
+
+    /// New values of the changed fields.
+    pub values: DeltaValues,
 }
 
 impl StateDelta {
@@ -54,13 +60,73 @@ impl StateDelta {
     /// - Time: O(n) where n = number of entities
     /// - Space: O(k) where k = number of changed entities (typically k << n)
     pub fn from_states(action: Action, before: &GameState, after: &GameState) -> Self {
+        let world = WorldChanges::from_states(&before.world, &after.world);
+        let values = DeltaValues::from_states(before, after).with_occupancy(
+            &after.world,
+            world.occupancy.iter().map(|change| change.position),
+        );
+
         Self {
             action,
             clock: after.turn.clock,
             turn: TurnChanges::from_states(&before.turn, &after.turn),
             entities: EntitiesChanges::from_states(&before.entities, &after.entities),
-            world: WorldChanges::from_states(&before.world, &after.world),
+            world,
+            values,
+        }
+    }
+
+    /// Replays this delta on top of the state it was computed from.
+    ///
+    /// Applying a delta to its `before` state yields its `after` state exactly, so a
+    /// keyframe plus the deltas recorded since reconstructs any later state without
+    /// re-executing actions. Applying it to any other state is a logic error, reported
+    /// as [`StateError::DeltaMismatch`] when a removed entity cannot be found.
+    pub fn apply(&self, state: &mut GameState) -> Result<(), StateError> {
+        let values = &self.values;
+        let entities = &mut state.entities;
+
+        values::apply_collection(
+            &mut entities.actors,
+            &self.entities.actors.removed,
+            &values.actors,
+            |actor| actor.id,
+            values::actor_list_full,
+        )?;
+        values::apply_collection(
+            &mut entities.props,
+            &self.entities.props.removed,
+            &values.props,
+            |prop| prop.id,
+            values::prop_list_full,
+        )?;
+        values::apply_collection(
+            &mut entities.items,
+            &self.entities.items.removed,
+            &values.items,
+            |item| item.id,
+            values::item_list_full,
+        )?;
+
+        for (position, occupants) in &values.occupancy {
+            let tile_map = &mut state.world.tile_map;
+            tile_map.clear_occupants(position);
+            for &occupant in occupants {
+                if !tile_map.add_occupant(*position, occupant) {
+                    return Err(StateError::DeltaMismatch { entity: occupant });
+                }
+            }
+        }
+        if let Some(flags) = &values.flags {
+            state.world.flags = flags.clone();
+        }
+        if let Some(conversation) = &values.conversation {
+            state.world.conversation = conversation.clone();
         }
+
+        state.turn = values.turn.clone();
+        state.next_entity_id = values.next_entity_id;
+        Ok(())
     }
 
     /// Returns true if no state changes occurred (no-op action).
@@ -92,6 +158,7 @@ impl StateDelta {
             turn: TurnChanges::default(),
             entities: EntitiesChanges::empty(),
             world: WorldChanges::default(),
+            values: DeltaValues::default(),
         }
     }
 }
@@ -151,7 +218,7 @@ impl EntitiesChanges {
 
 /// Changes to world state.
 ///
-/// Currently tracks only occupancy grid changes. Future extensions may include:
+/// Tracks occupancy grid, story flag, and conversation changes. Future extensions may include:
 /// - Terrain modifications
 /// - Fog of war updates
 /// - Region state changes
@@ -160,21 +227,30 @@ impl EntitiesChanges {
 pub struct WorldChanges {
     /// Tile positions where occupancy changed.
     ///
-    /// The actual occupant lists are stored in before/after `WorldState` and
-    /// can be queried by position when needed (e.g., for ZK witness generation).
+    /// The new occupant lists of these positions are in [`DeltaValues::occupancy`].
     pub occupancy: Vec<OccupancyChanges>,
+
+    /// Whether any story flag was set or cleared.
+    pub flags: bool,
+
+    /// Whether the ongoing conversation started, advanced, or ended.
+    pub conversation: bool,
 }
 
 impl WorldChanges {
     fn from_states(before: &WorldState, after: &WorldState) -> Self {
         let occupancy = diff_occupancy(before, after);
-        Self { occupancy }
+        Self {
+            occupancy,
+            flags: before.flags != after.flags,
+            conversation: before.conversation != after.conversation,
+        }
     }
 
     /// Returns true if no world changes occurred.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.occupancy.is_empty()
+        self.occupancy.is_empty() && !self.flags && !self.conversation
     }
 }
 
@@ -188,10 +264,8 @@ impl WorldChanges {
 ///
 /// # Optimization Notes
 ///
-/// We store only positions, not the actual occupant lists, because:
-/// - Occupant lists can be large (multiple entities per tile)
-/// - Lists are already in before/after WorldState
-/// - ZK layer queries by position when building witnesses
+/// Only positions are stored here; the new occupant lists of these positions are
+/// recorded separately in [`DeltaValues::occupancy`].
 fn diff_occupancy(before: &WorldState, after: &WorldState) -> Vec<OccupancyChanges> {
     let mut positions = BTreeSet::new();
     positions.extend(before.tile_map.occupancy().keys().copied());
@@ -220,3 +294,116 @@ fn diff_occupancy(before: &WorldState, after: &WorldState) -> Vec<OccupancyChang
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::{ActionInput, ActionKind, CharacterAction};
+    use crate::env::ActorTemplate;
+    use crate::state::{
+        Conversation, EntityId, ItemHandle, ItemState, Position, PropKind, PropState,
+    };
+    use crate::traits::TraitProfile;
+
+    fn wait() -> Action {
+        Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ))
+    }
+
+    fn round_trip(before: &GameState, after: &GameState) {
+        let delta = StateDelta::from_states(wait(), before, after);
+        let mut replayed = before.clone();
+        delta.apply(&mut replayed).unwrap();
+        assert_eq!(&replayed, after);
+    }
+
+    fn populated() -> GameState {
+        let mut state = GameState::with_player();
+        state
+            .add_npc(
+                &ActorTemplate::builder()
+                    .trait_profile(TraitProfile::default())
+                    .build(),
+                Position::new(3, 3),
+            )
+            .unwrap();
+        for (offset, kind) in [PropKind::Door, PropKind::Switch].into_iter().enumerate() {
+            let id = state.allocate_entity_id().unwrap();
+            let prop = PropState::new(id, Position::new(offset as i32, 5), kind, false);
+            state.entities.props.push(prop).unwrap();
+        }
+        state
+    }
+
+    #[test]
+    fn apply_reproduces_after_state() {
+        let before = populated();
+        let mut after = before.clone();
+
+        after.turn.nonce += 1;
+        after.turn.clock += 100;
+        let player = after.entities.player_mut();
+        let from = player.position.unwrap();
+        let to = Position::new(from.x + 1, from.y);
+        player.position = Some(to);
+        after
+            .world
+            .tile_map
+            .remove_occupant(&from, EntityId::PLAYER);
+        after.world.tile_map.add_occupant(to, EntityId::PLAYER);
+        after.entities.props[1].is_active = true;
+        let item = after.allocate_entity_id().unwrap();
+        after
+            .entities
+            .items
+            .push(ItemState::new(item, to, ItemHandle(7), 2))
+            .unwrap();
+        after.world.flags.insert("gate_opened".into());
+        after.world.conversation = Some(Conversation::new(EntityId(1), "guard", "greeting"));
+
+        round_trip(&before, &after);
+        round_trip(&after, &before);
+    }
+
+    #[test]
+    fn apply_keeps_entity_order_across_removals() {
+        let before = populated();
+        let mut after = before.clone();
+
+        let removed = after.entities.props.remove(0).unwrap();
+        let id = after.allocate_entity_id().unwrap();
+        after
+            .entities
+            .props
+            .push(PropState::new(id, removed.position, PropKind::Hazard, true))
+            .unwrap();
+
+        let delta = StateDelta::from_states(wait(), &before, &after);
+        assert_eq!(delta.entities.props.removed, vec![removed.id]);
+        assert_eq!(delta.entities.props.added, vec![id]);
+        round_trip(&before, &after);
+
+        // Unchanged transitions still carry the turn state, and nothing else.
+        let delta = StateDelta::from_states(wait(), &after, &after);
+        assert!(delta.is_empty());
+        assert!(delta.values.props.changed.is_empty());
+        round_trip(&after, &after);
+    }
+
+    #[test]
+    fn apply_rejects_unrelated_state() {
+        let before = populated();
+        let mut after = before.clone();
+        let removed = after.entities.props.remove(0).unwrap();
+
+        let delta = StateDelta::from_states(wait(), &before, &after);
+        let mut unrelated = GameState::with_player();
+        assert_eq!(
+            delta.apply(&mut unrelated),
+            Err(StateError::DeltaMismatch { entity: removed.id })
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::config::GameConfig;
+use crate::state::types::{ActorState, ItemState, PropState, TurnState};
+use crate::state::{Conversation, EntityId, GameState, Position, StateError, WorldState};
+
+/// Post-action values of everything a [`StateDelta`](super::StateDelta) marks as changed.
+///
+/// The bitmasks say *what* changed; these values say *what it changed to*, which is
+/// enough to replay the transition on top of the `before` state with
+/// [`StateDelta::apply`](super::StateDelta::apply). Changed entities are stored whole
+/// rather than per field, keeping the format independent of the bitmask layout.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeltaValues {
+    /// Entity ID allocator after the action.
+    pub next_entity_id: u32,
+
+    /// Turn state after the action (the nonce advances on every action).
+    pub turn: TurnState,
+
+    pub actors: CollectionValues<ActorState>,
+    pub props: CollectionValues<PropState>,
+    pub items: CollectionValues<ItemState>,
+
+    /// New occupant lists of every position in `WorldChanges::occupancy`.
+    pub occupancy: Vec<(Position, Vec<EntityId>)>,
+
+    /// All story flags, when any was set or cleared.
+    pub flags: Option<BTreeSet<String>>,
+
+    /// The ongoing conversation, when it started, advanced, or ended.
+    pub conversation: Option<Option<Conversation>>,
+}
+
+/// Added and updated entities of one collection, keyed by their index in `after`.
+///
+/// Indices are ascending, so inserting added entities in order after the removals
+/// reproduces the exact ordering of the `after` collection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollectionValues<T> {
+    pub changed: Vec<(u32, T)>,
+}
+
+impl<T> Default for CollectionValues<T> {
+    fn default() -> Self {
+        Self {
+            changed: Vec::new(),
+        }
+    }
+}
+
+impl DeltaValues {
+    pub(super) fn from_states(before: &GameState, after: &GameState) -> Self {
+        Self {
+            next_entity_id: after.next_entity_id,
+            turn: after.turn.clone(),
+            actors: collect_changed(&before.entities.actors, &after.entities.actors, |a| a.id),
+            props: collect_changed(&before.entities.props, &after.entities.props, |p| p.id),
+            items: collect_changed(&before.entities.items, &after.entities.items, |i| i.id),
+            occupancy: Vec::new(),
+            flags: (before.world.flags != after.world.flags).then(|| after.world.flags.clone()),
+            conversation: (before.world.conversation != after.world.conversation)
+                .then(|| after.world.conversation.clone()),
+        }
+    }
+
+    /// Records the `after` occupant lists of the given changed positions.
+    pub(super) fn with_occupancy(
+        mut self,
+        after: &WorldState,
+        positions: impl Iterator<Item = Position>,
+    ) -> Self {
+        self.occupancy = positions
+            .map(|position| {
+                let occupants = after
+                    .tile_map
+                    .occupants(&position)
+                    .map(|slot| slot.to_vec())
+                    .unwrap_or_default();
+                (position, occupants)
+            })
+            .collect();
+        self
+    }
+}
+
+fn collect_changed<T, FId>(before: &[T], after: &[T], id_fn: FId) -> CollectionValues<T>
+where
+    T: Clone + PartialEq,
+    FId: Fn(&T) -> EntityId,
+{
+    let before_map: HashMap<EntityId, &T> = before.iter().map(|item| (id_fn(item), item)).collect();
+
+    let changed = after
+        .iter()
+        .enumerate()
+        .filter(|(_, entity)| before_map.get(&id_fn(entity)) != Some(entity))
+        .map(|(index, entity)| (index as u32, entity.clone()))
+        .collect();
+
+    CollectionValues { changed }
+}
+
+/// Replays one collection's changes: drop `removed`, then replace or insert `changed`.
+pub(super) fn apply_collection<T, const L: usize, const U: usize>(
+    collection: &mut bounded_vector::BoundedVec<T, L, U>,
+    removed: &[EntityId],
+    values: &CollectionValues<T>,
+    id_fn: impl Fn(&T) -> EntityId,
+    full: impl Fn(usize) -> StateError,
+) -> Result<(), StateError>
+where
+    T: Clone,
+{
+    for &id in removed {
+        let index = collection
+            .iter()
+            .position(|entity| id_fn(entity) == id)
+            .ok_or(StateError::DeltaMismatch { entity: id })?;
+        // Order-preserving removal keeps the remaining entities aligned with `after`.
+        collection
+            .remove(index)
+            .map_err(|_| StateError::DeltaMismatch { entity: id })?;
+    }
+
+    for (index, entity) in &values.changed {
+        let id = id_fn(entity);
+        if let Some(existing) = collection.iter_mut().find(|existing| id_fn(existing) == id) {
+            *existing = entity.clone();
+            continue;
+        }
+
+        let index = *index as usize;
+        if index > collection.len() {
+            return Err(StateError::DeltaMismatch { entity: id });
+        }
+        let len = collection.len();
+        collection.push(entity.clone()).map_err(|_| full(len))?;
+        collection[index..].rotate_right(1);
+    }
+
+    Ok(())
+}
+
+/// Capacity error constructors matching the ones raised when spawning entities.
+pub(super) fn actor_list_full(current: usize) -> StateError {
+    StateError::ActorListFull {
+        max: GameConfig::MAX_ACTORS,
+        current,
+    }
+}
+
+pub(super) fn prop_list_full(current: usize) -> StateError {
+    StateError::PropListFull {
+        max: GameConfig::MAX_PROPS,
+        current,
+    }
+}
+
+pub(super) fn item_list_full(current: usize) -> StateError {
+    StateError::ItemListFull {
+        max: GameConfig::MAX_WORLD_ITEMS,
+        current,
+    }
+}
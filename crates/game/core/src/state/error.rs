@@ -64,6 +64,13 @@ pub enum StateError {
         /// Map height.
         map_height: u32,
     },
+
+    /// A state delta does not fit the state it is applied to.
+    #[error("State delta does not match the state (entity {entity:?})")]
+    DeltaMismatch {
+        /// The entity whose recorded change could not be replayed.
+        entity: EntityId,
+    },
 }
 
 impl GameError for StateError {
@@ -78,6 +85,9 @@ impl GameError for StateError {
             // ID overflow is a fatal error - cannot continue
             EntityIdOverflow { .. } => ErrorSeverity::Fatal,
 
+            // A mismatched delta means corrupted or misordered storage
+            DeltaMismatch { .. } => ErrorSeverity::Fatal,
+
             // Position errors are validation errors
             PositionOccupied { .. } | PositionOutOfBounds { .. } => ErrorSeverity::Validation,
         }
@@ -92,6 +102,7 @@ impl GameError for StateError {
             EntityIdOverflow { .. } => "STATE_ENTITY_ID_OVERFLOW",
             PositionOccupied { .. } => "STATE_POSITION_OCCUPIED",
             PositionOutOfBounds { .. } => "STATE_POSITION_OUT_OF_BOUNDS",
+            DeltaMismatch { .. } => "STATE_DELTA_MISMATCH",
         }
     }
 }
@@ -0,0 +1,426 @@
+//! Merkleized state root with per-entity inclusion proofs.
+//!
+//! An alternative to [`GameState::compute_state_root`]: instead of hashing the
+//! whole state at once, every independently interesting piece of the state
+//! becomes a leaf of a binary SHA-256 Merkle tree, so a single leaf can be
+//! checked against the root without the rest of the state.
+//!
+//! # Leaves
+//!
+//! In order:
+//!
+//! 1. [`StateLeaf::Turn`]: seed, entity allocator and turn bookkeeping
+//! 2. one [`StateLeaf::Actor`] per actor, in storage order
+//! 3. one [`StateLeaf::Prop`] per prop, in storage order
+//! 4. one [`StateLeaf::Item`] per world item, in storage order
+//! 5. one [`StateLeaf::Occupancy`] per occupied tile, in position order
+//! 6. [`StateLeaf::World`]: story flags and the ongoing conversation
+//!
+//! # Hashing
+//!
+//! ```text
+//! leaf = SHA256(MERKLE_LEAF_DOMAIN || bincode(StateLeaf))
+//! node = SHA256(MERKLE_NODE_DOMAIN || left || right)
+//! root = SHA256(MERKLE_ROOT_DOMAIN || leaf_count (u32 LE) || tree_root)
+//! ```
+//!
+//! A node without a right sibling is promoted to the next level unchanged.
+//! Leaves and nodes use different domains, and the root binds the leaf count,
+//! so a proof cannot pass an inner node off as a leaf.
+//!
+//! Facts that span leaves need one proof per leaf: "the player had item X at
+//! nonce N" is the player's actor leaf plus the turn leaf for the nonce.
+
+use std::collections::BTreeSet;
+
+use sha2::{Digest, Sha256};
+
+use super::{
+    ActorState, Conversation, EntityId, GameState, ItemState, Position, PropState, TurnState,
+};
+
+/// Domain tag of the Merkle state root.
+pub const MERKLE_ROOT_DOMAIN: &[u8] = b"dungeon.state_merkle.v1";
+/// Domain tag of Merkle leaves.
+pub const MERKLE_LEAF_DOMAIN: &[u8] = b"dungeon.state_merkle.leaf.v1";
+/// Domain tag of inner Merkle nodes.
+pub const MERKLE_NODE_DOMAIN: &[u8] = b"dungeon.state_merkle.node.v1";
+
+/// One leaf of the Merkle state tree.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum StateLeaf {
+    /// Seed, entity allocator and turn bookkeeping.
+    Turn {
+        game_seed: u64,
+        next_entity_id: u32,
+        turn: TurnState,
+    },
+    Actor(Box<ActorState>),
+    Prop(PropState),
+    Item(ItemState),
+    /// Occupants of a single tile.
+    Occupancy {
+        position: Position,
+        occupants: Vec<EntityId>,
+    },
+    /// Story flags and the ongoing conversation.
+    World {
+        flags: BTreeSet<String>,
+        conversation: Option<Conversation>,
+    },
+}
+
+/// Borrowed mirror of [`StateLeaf`] with the same bincode encoding, so the
+/// tree can be hashed without cloning the state.
+#[derive(serde::Serialize)]
+enum LeafRef<'a> {
+    Turn {
+        game_seed: u64,
+        next_entity_id: u32,
+        turn: &'a TurnState,
+    },
+    Actor(&'a ActorState),
+    Prop(&'a PropState),
+    Item(&'a ItemState),
+    Occupancy {
+        position: Position,
+        occupants: &'a [EntityId],
+    },
+    World {
+        flags: &'a BTreeSet<String>,
+        conversation: &'a Option<Conversation>,
+    },
+}
+
+/// Identifies a leaf of the Merkle state tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeafKey {
+    Turn,
+    /// The actor, prop or item with this ID.
+    Entity(EntityId),
+    Occupancy(Position),
+    World,
+}
+
+impl StateLeaf {
+    /// Which leaf this is.
+    pub fn key(&self) -> LeafKey {
+        match self {
+            StateLeaf::Turn { .. } => LeafKey::Turn,
+            StateLeaf::Actor(actor) => LeafKey::Entity(actor.id),
+            StateLeaf::Prop(prop) => LeafKey::Entity(prop.id),
+            StateLeaf::Item(item) => LeafKey::Entity(item.id),
+            StateLeaf::Occupancy { position, .. } => LeafKey::Occupancy(*position),
+            StateLeaf::World { .. } => LeafKey::World,
+        }
+    }
+
+    /// Canonical encoding of the leaf, as hashed into the tree.
+    pub fn encode(&self) -> Vec<u8> {
+        self.borrowed().encode()
+    }
+
+    /// Leaf hash.
+    pub fn hash(&self) -> [u8; 32] {
+        self.borrowed().hash()
+    }
+
+    fn borrowed(&self) -> LeafRef<'_> {
+        match self {
+            StateLeaf::Turn {
+                game_seed,
+                next_entity_id,
+                turn,
+            } => LeafRef::Turn {
+                game_seed: *game_seed,
+                next_entity_id: *next_entity_id,
+                turn,
+            },
+            StateLeaf::Actor(actor) => LeafRef::Actor(actor.as_ref()),
+            StateLeaf::Prop(prop) => LeafRef::Prop(prop),
+            StateLeaf::Item(item) => LeafRef::Item(item),
+            StateLeaf::Occupancy {
+                position,
+                occupants,
+            } => LeafRef::Occupancy {
+                position: *position,
+                occupants,
+            },
+            StateLeaf::World {
+                flags,
+                conversation,
+            } => LeafRef::World {
+                flags,
+                conversation,
+            },
+        }
+    }
+}
+
+impl LeafRef<'_> {
+    fn key(&self) -> LeafKey {
+        match self {
+            LeafRef::Turn { .. } => LeafKey::Turn,
+            LeafRef::Actor(actor) => LeafKey::Entity(actor.id),
+            LeafRef::Prop(prop) => LeafKey::Entity(prop.id),
+            LeafRef::Item(item) => LeafKey::Entity(item.id),
+            LeafRef::Occupancy { position, .. } => LeafKey::Occupancy(*position),
+            LeafRef::World { .. } => LeafKey::World,
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("StateLeaf serialization should never fail")
+    }
+
+    fn hash(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(MERKLE_LEAF_DOMAIN);
+        hasher.update(self.encode());
+        hasher.finalize().into()
+    }
+
+    fn to_owned(&self) -> StateLeaf {
+        match self {
+            LeafRef::Turn {
+                game_seed,
+                next_entity_id,
+                turn,
+            } => StateLeaf::Turn {
+                game_seed: *game_seed,
+                next_entity_id: *next_entity_id,
+                turn: (*turn).clone(),
+            },
+            LeafRef::Actor(actor) => StateLeaf::Actor(Box::new((*actor).clone())),
+            LeafRef::Prop(prop) => StateLeaf::Prop((*prop).clone()),
+            LeafRef::Item(item) => StateLeaf::Item((*item).clone()),
+            LeafRef::Occupancy {
+                position,
+                occupants,
+            } => StateLeaf::Occupancy {
+                position: *position,
+                occupants: occupants.to_vec(),
+            },
+            LeafRef::World {
+                flags,
+                conversation,
+            } => StateLeaf::World {
+                flags: (*flags).clone(),
+                conversation: (*conversation).clone(),
+            },
+        }
+    }
+}
+
+/// Proof that a [`StateLeaf`] is part of a Merkle state root.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct InclusionProof {
+    /// The proven leaf.
+    pub leaf: StateLeaf,
+    /// Position of the leaf in the tree.
+    pub index: u32,
+    /// Number of leaves in the tree.
+    pub leaf_count: u32,
+    /// Sibling hashes from the leaf level up; promoted levels have none.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl InclusionProof {
+    /// Check the proof against a root from [`GameState::compute_merkle_root`].
+    pub fn verify(&self, root: &[u8; 32]) -> bool {
+        if self.index >= self.leaf_count {
+            return false;
+        }
+
+        let mut hash = self.leaf.hash();
+        let mut index = self.index;
+        let mut width = self.leaf_count;
+        let mut siblings = self.siblings.iter();
+        while width > 1 {
+            if index % 2 == 1 {
+                let Some(sibling) = siblings.next() else {
+                    return false;
+                };
+                hash = hash_node(sibling, &hash);
+            } else if index + 1 < width {
+                let Some(sibling) = siblings.next() else {
+                    return false;
+                };
+                hash = hash_node(&hash, sibling);
+            }
+            index /= 2;
+            width = width.div_ceil(2);
+        }
+
+        siblings.next().is_none() && hash_root(self.leaf_count, &hash) == *root
+    }
+}
+
+impl GameState {
+    /// Merkle state root over the leaves described in the [module docs](self).
+    pub fn compute_merkle_root(&self) -> [u8; 32] {
+        let leaves: Vec<_> = self.leaf_refs().iter().map(LeafRef::hash).collect();
+        let levels = merkle_levels(leaves);
+        let top = levels.last().expect("a state tree has at least two leaves")[0];
+        hash_root(levels[0].len() as u32, &top)
+    }
+
+    /// Inclusion proof for one leaf, or `None` if the state has no such leaf.
+    pub fn prove_inclusion(&self, key: LeafKey) -> Option<InclusionProof> {
+        let leaves = self.leaf_refs();
+        let index = leaves.iter().position(|leaf| leaf.key() == key)?;
+        let leaf = leaves[index].to_owned();
+        let levels = merkle_levels(leaves.iter().map(LeafRef::hash).collect());
+
+        let mut siblings = Vec::new();
+        let mut position = index;
+        for level in &levels[..levels.len() - 1] {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                siblings.push(level[sibling]);
+            }
+            position /= 2;
+        }
+
+        Some(InclusionProof {
+            leaf,
+            index: index as u32,
+            leaf_count: levels[0].len() as u32,
+            siblings,
+        })
+    }
+
+    /// Inclusion proof for the actor, prop or item with this ID.
+    pub fn prove_entity(&self, id: EntityId) -> Option<InclusionProof> {
+        self.prove_inclusion(LeafKey::Entity(id))
+    }
+
+    /// All leaves of the Merkle state tree, in tree order.
+    pub fn merkle_leaves(&self) -> Vec<StateLeaf> {
+        self.leaf_refs().iter().map(LeafRef::to_owned).collect()
+    }
+
+    fn leaf_refs(&self) -> Vec<LeafRef<'_>> {
+        let mut leaves = vec![LeafRef::Turn {
+            game_seed: self.game_seed,
+            next_entity_id: self.next_entity_id,
+            turn: &self.turn,
+        }];
+        leaves.extend(self.entities.actors.iter().map(LeafRef::Actor));
+        leaves.extend(self.entities.props.iter().map(LeafRef::Prop));
+        leaves.extend(self.entities.items.iter().map(LeafRef::Item));
+        leaves.extend(
+            self.world
+                .tile_map
+                .iter()
+                .map(|(position, occupants)| LeafRef::Occupancy {
+                    position: *position,
+                    occupants,
+                }),
+        );
+        leaves.push(LeafRef::World {
+            flags: &self.world.flags,
+            conversation: &self.world.conversation,
+        });
+        leaves
+    }
+}
+
+/// All tree levels, from the leaves up to the single top node.
+fn merkle_levels(leaves: Vec<[u8; 32]>) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![leaves];
+    while levels.last().is_some_and(|level| level.len() > 1) {
+        let next = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hash_node(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(MERKLE_NODE_DOMAIN);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+fn hash_root(leaf_count: u32, top: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(MERKLE_ROOT_DOMAIN);
+    hasher.update(leaf_count.to_le_bytes());
+    hasher.update(top);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{ItemHandle, PropKind};
+
+    fn state() -> GameState {
+        let mut state = GameState::with_player();
+        let position = Position::new(2, 3);
+        state
+            .entities
+            .props
+            .push(PropState::new(EntityId(7), position, PropKind::Door, true))
+            .unwrap();
+        state
+            .entities
+            .items
+            .push(ItemState::new(EntityId(8), position, ItemHandle(1), 2))
+            .unwrap();
+        state.world.tile_map.add_occupant(position, EntityId(7));
+        state
+    }
+
+    #[test]
+    fn every_leaf_proves_against_the_root() {
+        let state = state();
+        let root = state.compute_merkle_root();
+
+        let keys = [
+            LeafKey::Turn,
+            LeafKey::Entity(EntityId::PLAYER),
+            LeafKey::Entity(EntityId(7)),
+            LeafKey::Entity(EntityId(8)),
+            LeafKey::Occupancy(Position::new(2, 3)),
+            LeafKey::World,
+        ];
+        for key in keys {
+            let proof = state.prove_inclusion(key).unwrap();
+            assert_eq!(proof.leaf.key(), key);
+            assert!(proof.verify(&root), "{key:?}");
+        }
+        assert!(state.prove_entity(EntityId(9)).is_none());
+    }
+
+    #[test]
+    fn proofs_reject_other_states_and_forged_leaves() {
+        let state = state();
+        let proof = state.prove_entity(EntityId(8)).unwrap();
+
+        let mut changed = state.clone();
+        changed.turn.nonce += 1;
+        assert!(!proof.verify(&changed.compute_merkle_root()));
+
+        let mut forged = proof.clone();
+        if let StateLeaf::Item(item) = &mut forged.leaf {
+            item.quantity += 1;
+        }
+        assert!(!forged.verify(&state.compute_merkle_root()));
+
+        let mut moved = proof;
+        moved.index ^= 1;
+        assert!(!moved.verify(&state.compute_merkle_root()));
+    }
+}
@@ -3,23 +3,35 @@
 //! This module owns the data structures that describe entities, turn
 //! bookkeeping, overlays, and initialization helpers. Runtime layers clone or
 //! query this state but mutate it exclusively through the engine.
+pub mod commitment;
 pub mod delta;
 pub mod error;
+#[cfg(feature = "serde")]
+pub mod merkle;
 pub mod types;
 
 use crate::config::GameConfig;
 use crate::env::MapOracle;
 pub use bounded_vector::BoundedVec;
+pub use commitment::{
+    PRIVATE_STATE_DOMAIN, PUBLIC_VIEW_DOMAIN, PublicView, STATE_COMMITMENT_DOMAIN, StateCommitment,
+};
 pub use delta::{
-    ActorChanges, ActorFields, CollectionChanges, EntitiesChanges, ItemChanges, ItemFields,
-    OccupancyChanges, PropChanges, PropFields, StateDelta, TurnChanges, TurnFields, WorldChanges,
+    ActorChanges, ActorFields, CollectionChanges, CollectionValues, DeltaValues, EntitiesChanges,
+    ItemChanges, ItemFields, OccupancyChanges, PropChanges, PropFields, StateDelta, TurnChanges,
+    TurnFields, WorldChanges,
 };
 pub use error::StateError;
+#[cfg(feature = "serde")]
+pub use merkle::{
+    InclusionProof, LeafKey, MERKLE_LEAF_DOMAIN, MERKLE_NODE_DOMAIN, MERKLE_ROOT_DOMAIN, StateLeaf,
+};
 pub use types::{
-    ActionAbilities, ActionAbility, ActorState, EntitiesState, EntityId, Equipment,
-    EquipmentBuilder, InventorySlot, InventoryState, ItemHandle, ItemState, PassiveAbilities,
-    PassiveAbility, PassiveKind, Position, PropKind, PropState, StatusEffect, StatusEffectKind,
-    StatusEffects, Tick, TileMap, TileView, TurnState, WorldState,
+    ActionAbilities, ActionAbility, ActorState, AlertLevel, Awareness, Companion, CompanionOrder,
+    Conversation, EntitiesState, EntityId, Equipment, EquipmentBuilder, InventorySlot,
+    InventoryState, ItemHandle, ItemState, PassiveAbilities, PassiveAbility, PassiveKind, Position,
+    PropKind, PropState, Shop, ShopStock, StatusEffect, StatusEffectKind, StatusEffects, Tick,
+    TileMap, TileView, TurnState, WorldState,
 };
 
 /// Canonical snapshot of the deterministic game state.
@@ -274,8 +286,10 @@ impl GameState {
 
     /// Computes a deterministic SHA-256 hash of the entire game state.
     ///
-    /// This is used as the "state root" for ZK proofs, providing a cryptographic
-    /// commitment to the complete game state at a specific point in time.
+    /// This is a plain (non-hiding) fingerprint of the complete game state, used
+    /// to check saved states locally. Proof journals commit to
+    /// [`GameState::compute_state_commitment`] instead, which keeps everything
+    /// but the public view hidden.
     ///
     /// # Design Choice: Simple Hash vs Merkle Tree
     ///
@@ -285,8 +299,9 @@ impl GameState {
     /// - Simpler = fewer constraints = faster proving
     /// - SHA-256 is hardware-accelerated in RISC0 zkVM
     ///
-    /// For future custom circuits (Arkworks), Merkle trees may become valuable
-    /// to enable partial state updates and reduce circuit size.
+    /// For custom circuits and third-party checks of single facts, see
+    /// [`GameState::compute_merkle_root`], which supports per-entity inclusion
+    /// proofs.
     ///
     /// # Serialization
     ///
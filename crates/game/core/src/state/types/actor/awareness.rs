@@ -0,0 +1,90 @@
+//! Alert state for NPC perception and memory.
+//!
+//! Awareness records what an actor knows about the player: how alert it is,
+//! where the player was last sensed, and when that memory fades.
+//!
+//! # Tick-based Memory
+//!
+//! Like status effects, awareness stores `expires_at: Tick` rather than a
+//! turn counter. Once `current_tick >= expires_at` the actor is treated as
+//! [`AlertLevel::Unaware`] and the stored position is no longer reported.
+
+use crate::state::{Position, Tick};
+
+/// How alert an actor currently is.
+///
+/// Levels are ordered so that a stronger stimulus never downgrades an
+/// existing alert (`Alerted > Suspicious > Unaware`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlertLevel {
+    /// No knowledge of the player.
+    #[default]
+    Unaware,
+
+    /// Heard something; investigates the last known position.
+    Suspicious,
+
+    /// Saw the player; pursues and searches the last known position.
+    Alerted,
+}
+
+/// Deterministic perception memory stored in [`ActorState`](super::ActorState).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Awareness {
+    /// Alert level at the time of the last stimulus.
+    pub level: AlertLevel,
+
+    /// Where the player (or noise) was last sensed.
+    pub last_known_position: Option<Position>,
+
+    /// Tick at which this memory fades.
+    pub expires_at: Tick,
+}
+
+impl Awareness {
+    /// Returns the alert level in effect at `now`.
+    pub fn level_at(&self, now: Tick) -> AlertLevel {
+        if now >= self.expires_at {
+            AlertLevel::Unaware
+        } else {
+            self.level
+        }
+    }
+
+    /// Returns the remembered position if the memory has not expired.
+    pub fn last_known_position_at(&self, now: Tick) -> Option<Position> {
+        match self.level_at(now) {
+            AlertLevel::Unaware => None,
+            _ => self.last_known_position,
+        }
+    }
+
+    /// Records a stimulus at `position`.
+    ///
+    /// A weaker stimulus does not downgrade an active alert, but it still
+    /// extends the memory if it would expire later.
+    pub fn observe(&mut self, level: AlertLevel, position: Position, expires_at: Tick, now: Tick) {
+        let current = self.level_at(now);
+        if level >= current {
+            self.level = level;
+            self.last_known_position = Some(position);
+            self.expires_at = expires_at;
+        } else if expires_at > self.expires_at {
+            self.expires_at = expires_at;
+        }
+    }
+
+    /// Clears the memory if it has expired at `now`.
+    ///
+    /// Returns true if the awareness was reset.
+    pub fn forget_if_expired(&mut self, now: Tick) -> bool {
+        if self.level != AlertLevel::Unaware && now >= self.expires_at {
+            *self = Self::default();
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Companion bonds between actors.
+//!
+//! A companion is any actor bound to a leader: recruited allies, pets, and
+//! summoned creatures all use the same [`Companion`] record. The bond stores
+//! the leader's current order so AI decisions stay a pure function of state.
+
+use crate::state::{EntityId, Tick};
+
+/// Standing order a leader has given to a companion.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompanionOrder {
+    /// Stay close to the leader and engage enemies hostile to the leader.
+    #[default]
+    Follow,
+
+    /// Hold position; only fight enemies that come adjacent.
+    Stay,
+
+    /// Engage a specific target until it dies, then resume following.
+    Attack(EntityId),
+}
+
+/// Bond between a companion and its leader.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Companion {
+    /// Actor this companion follows and fights for.
+    pub leader: EntityId,
+
+    /// Current standing order.
+    pub order: CompanionOrder,
+
+    /// Tick at which the bond ends (summons). `None` means permanent.
+    pub expires_at: Option<Tick>,
+}
+
+impl Companion {
+    /// Creates a permanent bond (recruited ally or pet) following `leader`.
+    pub fn follower(leader: EntityId) -> Self {
+        Self {
+            leader,
+            order: CompanionOrder::Follow,
+            expires_at: None,
+        }
+    }
+
+    /// Creates a temporary bond for a summoned creature.
+    pub fn summoned(leader: EntityId, expires_at: Option<Tick>) -> Self {
+        Self {
+            leader,
+            order: CompanionOrder::Follow,
+            expires_at,
+        }
+    }
+
+    /// Returns true if the bond has expired at `now`.
+    pub fn is_expired(&self, now: Tick) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+}
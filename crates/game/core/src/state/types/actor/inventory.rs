@@ -26,19 +26,30 @@ impl InventorySlot {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InventoryState {
     pub items: ArrayVec<InventorySlot, { GameConfig::MAX_INVENTORY_SLOTS }>,
+
+    /// Currency carried by the actor. Gold does not occupy an item slot.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gold: u32,
 }
 
 impl InventoryState {
     pub fn new(items: ArrayVec<InventorySlot, { GameConfig::MAX_INVENTORY_SLOTS }>) -> Self {
-        Self { items }
+        Self { items, gold: 0 }
     }
 
     pub fn empty() -> Self {
         Self {
             items: ArrayVec::new(),
+            gold: 0,
         }
     }
 
+    /// Builder: set carried gold.
+    pub fn with_gold(mut self, gold: u32) -> Self {
+        self.gold = gold;
+        self
+    }
+
     /// Check if inventory is full.
     pub fn is_full(&self) -> bool {
         self.items.is_full()
@@ -99,6 +110,28 @@ impl InventoryState {
         Ok(())
     }
 
+    /// Total quantity of `handle` held across all slots.
+    pub fn quantity_of(&self, handle: ItemHandle) -> u32 {
+        self.items
+            .iter()
+            .filter(|slot| slot.handle == handle)
+            .map(|slot| slot.quantity as u32)
+            .sum()
+    }
+
+    /// Remove `quantity` of `handle`. Remove slot if quantity reaches 0.
+    ///
+    /// Returns error if the item is not held in sufficient quantity.
+    pub fn remove_item(&mut self, handle: ItemHandle, quantity: u16) -> Result<(), &'static str> {
+        let slot = self
+            .items
+            .iter()
+            .position(|slot| slot.handle == handle)
+            .ok_or("Item not in inventory")?;
+
+        self.decrease_quantity(slot, quantity)
+    }
+
     /// Remove item slot entirely.
     pub fn remove_slot(&mut self, slot: usize) -> Option<InventorySlot> {
         if slot < self.items.len() {
@@ -3,13 +3,19 @@
 //! This module contains all types and systems specific to actors:
 //! - ActorState: Main actor structure
 //! - Abilities: Active and passive abilities
+//! - Awareness: Alert level and last known player position
+//! - Companion: Leader bonds for allies, pets, and summons
 //! - Equipment: Weapon and armor system
 //! - Inventory: Item storage for actors
+//! - Shop: Merchant stock and pricing
 //! - Status: Status effects and conditions
 
 pub mod abilities;
+pub mod awareness;
+pub mod companion;
 pub mod equipment;
 pub mod inventory;
+pub mod shop;
 pub mod status;
 
 use arrayvec::ArrayVec;
@@ -17,8 +23,11 @@ use arrayvec::ArrayVec;
 pub use abilities::{
     ActionAbilities, ActionAbility, PassiveAbilities, PassiveAbility, PassiveKind,
 };
+pub use awareness::{AlertLevel, Awareness};
+pub use companion::{Companion, CompanionOrder};
 pub use equipment::{Equipment, EquipmentBuilder};
 pub use inventory::{InventorySlot, InventoryState};
+pub use shop::{Shop, ShopStock};
 pub use status::{StatusEffect, StatusEffectKind, StatusEffects};
 
 use super::{EntityId, Position, Tick};
@@ -92,6 +101,23 @@ pub struct ActorState {
     /// Faction - relationship/allegiance (mutable).
     pub faction: Faction,
 
+    /// Dialogue tree this actor speaks (immutable, copied from the template).
+    pub dialogue: Option<String>,
+
+    // === Trade ===
+    /// Merchant stock and pricing. None for actors that do not trade.
+    pub shop: Option<Shop>,
+
+    // === Perception ===
+    /// Alert level and last known player position.
+    ///
+    /// Updated by `PerceptionAction` so that NPC memory is part of proven state.
+    pub awareness: Awareness,
+
+    // === Allegiance ===
+    /// Leader bond for companions, pets, and summons. None for independent actors.
+    pub companion: Option<Companion>,
+
     // === Scheduling ===
     /// When this actor is scheduled to act next. None means not currently scheduled.
     pub ready_at: Option<Tick>,
@@ -119,6 +145,12 @@ impl ActorState {
         self
     }
 
+    /// Returns true if this actor is a companion of `leader`.
+    #[inline]
+    pub fn is_companion_of(&self, leader: EntityId) -> bool {
+        self.companion.is_some_and(|c| c.leader == leader)
+    }
+
     // ========================================================================
     // Action Ability Helpers
     // ========================================================================
@@ -0,0 +1,186 @@
+//! Merchant shop state.
+//!
+//! A merchant is any actor with a [`Shop`]. Stock lives in state so `Buy` and
+//! `Sell` actions are validated and proven like any other state change.
+//! Restocking is lazy: it is applied by the next trade after `restock_at`,
+//! with quantities rolled from the game seed.
+
+use arrayvec::ArrayVec;
+
+use crate::config::GameConfig;
+use crate::env::{RngOracle, compute_seed};
+use crate::state::{EntityId, ItemHandle, Tick};
+
+/// A stocked item and how much of it the merchant carries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShopStock {
+    pub item: ItemHandle,
+
+    /// Units currently for sale.
+    pub quantity: u16,
+
+    /// Upper bound for restocking. Items bought from the player that the
+    /// merchant does not normally carry have `max_quantity == 0`.
+    pub max_quantity: u16,
+}
+
+impl ShopStock {
+    pub fn new(item: ItemHandle, quantity: u16, max_quantity: u16) -> Self {
+        Self {
+            item,
+            quantity,
+            max_quantity,
+        }
+    }
+}
+
+/// Merchant stock and pricing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shop {
+    pub stock: ArrayVec<ShopStock, { GameConfig::MAX_SHOP_STOCK }>,
+
+    /// Percentage of item value the player pays when buying.
+    pub buy_price_pct: u16,
+
+    /// Percentage of item value the merchant pays when buying from the player.
+    pub sell_price_pct: u16,
+
+    /// Ticks between restocks. `0` disables restocking.
+    pub restock_interval: Tick,
+
+    /// Tick at which the next restock becomes due.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub restock_at: Tick,
+}
+
+impl Shop {
+    /// Price the player pays per unit of an item worth `value`.
+    pub fn buy_price(&self, value: u32) -> u32 {
+        value.saturating_mul(self.buy_price_pct as u32) / 100
+    }
+
+    /// Price the merchant pays per unit of an item worth `value`.
+    pub fn sell_price(&self, value: u32) -> u32 {
+        value.saturating_mul(self.sell_price_pct as u32) / 100
+    }
+
+    /// Units of `item` currently for sale.
+    pub fn stock_of(&self, item: ItemHandle) -> u16 {
+        self.stock
+            .iter()
+            .find(|entry| entry.item == item)
+            .map_or(0, |entry| entry.quantity)
+    }
+
+    /// Returns true if a restock is due at `now`.
+    pub fn restock_due(&self, now: Tick) -> bool {
+        self.restock_interval > 0 && now >= self.restock_at
+    }
+
+    /// Applies a pending restock.
+    ///
+    /// Each entry below its maximum gains `1..=missing` units, rolled from
+    /// `(game_seed, restock_at, merchant, entry index)` so replays restock
+    /// identically. The next restock is scheduled `restock_interval` after `now`.
+    pub fn restock(
+        &mut self,
+        now: Tick,
+        merchant: EntityId,
+        game_seed: u64,
+        rng: &(impl RngOracle + ?Sized),
+    ) {
+        if !self.restock_due(now) {
+            return;
+        }
+
+        for (index, entry) in self.stock.iter_mut().enumerate() {
+            let missing = entry.max_quantity.saturating_sub(entry.quantity);
+            if missing == 0 {
+                continue;
+            }
+            let seed = compute_seed(game_seed, self.restock_at, merchant.0, index as u32);
+            entry.quantity += rng.roll_die(seed, missing as u32) as u16;
+        }
+
+        self.restock_at = now.saturating_add(self.restock_interval);
+    }
+
+    /// Removes `quantity` units of `item` from stock.
+    pub fn take(&mut self, item: ItemHandle, quantity: u16) -> Result<(), &'static str> {
+        let entry = self
+            .stock
+            .iter_mut()
+            .find(|entry| entry.item == item)
+            .ok_or("Item not in stock")?;
+        if entry.quantity < quantity {
+            return Err("Insufficient stock");
+        }
+        entry.quantity -= quantity;
+        Ok(())
+    }
+
+    /// Adds `quantity` units of `item` to stock.
+    ///
+    /// Items the merchant does not carry get a new entry (never restocked).
+    pub fn put(&mut self, item: ItemHandle, quantity: u16) -> Result<(), &'static str> {
+        if let Some(entry) = self.stock.iter_mut().find(|entry| entry.item == item) {
+            entry.quantity = entry.quantity.saturating_add(quantity);
+            return Ok(());
+        }
+        self.stock
+            .try_push(ShopStock::new(item, quantity, 0))
+            .map_err(|_| "Shop stock is full")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::PcgRng;
+
+    fn potion_shop() -> Shop {
+        let mut stock = ArrayVec::new();
+        stock.push(ShopStock::new(ItemHandle(1), 0, 5));
+        stock.push(ShopStock::new(ItemHandle(2), 3, 3));
+        Shop {
+            stock,
+            buy_price_pct: 120,
+            sell_price_pct: 50,
+            restock_interval: 1000,
+            restock_at: 500,
+        }
+    }
+
+    #[test]
+    fn restock_is_deterministic_and_bounded() {
+        let merchant = EntityId(7);
+        let mut first = potion_shop();
+        let mut second = potion_shop();
+
+        first.restock(400, merchant, 42, &PcgRng);
+        assert_eq!(first, potion_shop(), "restock before restock_at is a no-op");
+
+        first.restock(600, merchant, 42, &PcgRng);
+        second.restock(600, merchant, 42, &PcgRng);
+        assert_eq!(first, second);
+        assert!((1..=5).contains(&first.stock_of(ItemHandle(1))));
+        assert_eq!(first.stock_of(ItemHandle(2)), 3);
+        assert_eq!(first.restock_at, 1600);
+    }
+
+    #[test]
+    fn sold_items_join_stock_without_restocking() {
+        let mut shop = potion_shop();
+        shop.put(ItemHandle(10), 1).unwrap();
+        assert_eq!(shop.stock_of(ItemHandle(10)), 1);
+
+        shop.take(ItemHandle(10), 1).unwrap();
+        shop.restock(600, EntityId(7), 42, &PcgRng);
+        assert_eq!(shop.stock_of(ItemHandle(10)), 0);
+        assert!(shop.take(ItemHandle(10), 1).is_err());
+        assert_eq!(shop.buy_price(10), 12);
+        assert_eq!(shop.sell_price(10), 5);
+    }
+}
@@ -0,0 +1,32 @@
+//! Conversation progress and story flags.
+//!
+//! Dialogue trees themselves are static content provided by the
+//! [`DialogueOracle`](crate::env::DialogueOracle). Only the cursor into the
+//! tree and the flags set by dialogue outcomes live in state, so every choice
+//! the player makes is validated and proven like any other action.
+
+use super::EntityId;
+
+/// An ongoing conversation between the player and an NPC.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Conversation {
+    /// The NPC being talked to.
+    pub npc: EntityId,
+
+    /// Dialogue tree identifier (see [`DialogueTree::id`](crate::env::DialogueTree::id)).
+    pub dialogue: String,
+
+    /// Identifier of the node currently shown to the player.
+    pub node: String,
+}
+
+impl Conversation {
+    pub fn new(npc: EntityId, dialogue: impl Into<String>, node: impl Into<String>) -> Self {
+        Self {
+            npc,
+            dialogue: dialogue.into(),
+            node: node.into(),
+        }
+    }
+}
@@ -93,6 +93,13 @@ impl EntitiesState {
         self.actors.iter_mut()
     }
 
+    /// Returns an iterator over the companions bound to `leader`.
+    pub fn companions_of(&self, leader: EntityId) -> impl Iterator<Item = &ActorState> {
+        self.actors
+            .iter()
+            .filter(move |actor| actor.is_companion_of(leader))
+    }
+
     /// Returns the position of an actor by ID.
     ///
     /// Returns `None` if the actor is not found or has no position.
@@ -1,5 +1,6 @@
 pub mod actor;
 pub mod common;
+pub mod dialogue;
 pub mod entities;
 pub mod item;
 pub mod turn;
@@ -12,6 +13,12 @@ pub use actor::{
     ActionAbility,
     // Main actor state
     ActorState,
+    // Perception
+    AlertLevel,
+    Awareness,
+    // Companions
+    Companion,
+    CompanionOrder,
     // Equipment
     Equipment,
     EquipmentBuilder,
@@ -21,6 +28,9 @@ pub use actor::{
     PassiveAbilities,
     PassiveAbility,
     PassiveKind,
+    // Merchants
+    Shop,
+    ShopStock,
     // Status effects
     StatusEffect,
     StatusEffectKind,
@@ -30,6 +40,9 @@ pub use actor::{
 // Re-export common types
 pub use common::{EntityId, Position, Tick};
 
+// Re-export dialogue types
+pub use dialogue::Conversation;
+
 // Re-export entity collection and prop types
 pub use entities::{EntitiesState, PropKind, PropState};
 
@@ -1,11 +1,11 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use arrayvec::ArrayVec;
 
 use crate::config::GameConfig;
 use crate::env::{MapOracle, StaticTile};
 
-use super::{EntityId, Position};
+use super::{Conversation, EntityId, Position};
 
 type OccupantSlots = ArrayVec<EntityId, { GameConfig::MAX_OCCUPANTS_PER_TILE }>;
 
@@ -14,11 +14,26 @@ type OccupantSlots = ArrayVec<EntityId, { GameConfig::MAX_OCCUPANTS_PER_TILE }>;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorldState {
     pub tile_map: TileMap,
+
+    /// Story flags set by dialogue outcomes (quest progress, world events).
+    pub flags: BTreeSet<String>,
+
+    /// The player's ongoing conversation, if any.
+    pub conversation: Option<Conversation>,
 }
 
 impl WorldState {
     pub fn new(tile_map: TileMap) -> Self {
-        Self { tile_map }
+        Self {
+            tile_map,
+            flags: BTreeSet::new(),
+            conversation: None,
+        }
+    }
+
+    /// Returns true if the story flag is set.
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
     }
 
     /// Produces a merged view combining static tile data with dynamic occupants.
@@ -45,9 +60,35 @@ impl WorldState {
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TileMap {
+    #[cfg_attr(feature = "serde", serde(with = "occupancy_pairs"))]
     occupancy: BTreeMap<Position, OccupantSlots>,
 }
 
+/// Serializes occupancy as `(position, occupants)` pairs.
+///
+/// Positions are not string keys, so a map would not survive self-describing
+/// formats such as JSON. Bincode encodes a sequence of pairs exactly like a
+/// map, so saved states and state roots are unaffected.
+#[cfg(feature = "serde")]
+mod occupancy_pairs {
+    use super::{BTreeMap, OccupantSlots, Position};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        occupancy: &BTreeMap<Position, OccupantSlots>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(occupancy)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<Position, OccupantSlots>, D::Error> {
+        Vec::<(Position, OccupantSlots)>::deserialize(deserializer)
+            .map(|pairs| pairs.into_iter().collect())
+    }
+}
+
 impl TileMap {
     pub fn new(occupancy: BTreeMap<Position, OccupantSlots>) -> Self {
         Self { occupancy }
@@ -61,6 +102,11 @@ impl TileMap {
         self.occupancy.get(position)
     }
 
+    /// Iterates occupied tiles in position order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Position, &OccupantSlots)> {
+        self.occupancy.iter()
+    }
+
     pub fn replace_occupants(&mut self, position: Position, occupants: OccupantSlots) {
         if occupants.is_empty() {
             self.occupancy.remove(&position);
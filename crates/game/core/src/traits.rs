@@ -30,6 +30,8 @@
     PartialEq,
     Eq,
     Hash,
+    PartialOrd,
+    Ord,
     strum::Display,
     strum::EnumString,
     strum::AsRefStr,
@@ -380,4 +382,31 @@ impl TraitProfile {
     pub fn values(&self) -> &[u8; TraitKind::COUNT] {
         &self.values
     }
+
+    // ========================================================================
+    // Perception
+    // ========================================================================
+
+    /// Sight radius in tiles (Manhattan), derived from Perception.
+    ///
+    /// Ranges from 6 (Perception 0) to 14 (Perception 240); neutral is 10.
+    pub fn sight_range(&self) -> u32 {
+        6 + self.get(TraitKind::Perception) as u32 * 8 / 240
+    }
+
+    /// Hearing radius in tiles (Manhattan), derived from Perception and Curiosity.
+    ///
+    /// Ranges from 3 to 9; neutral is 6. Loud noises extend this radius.
+    pub fn hearing_range(&self) -> u32 {
+        let perception = self.get(TraitKind::Perception) as u32;
+        let curiosity = self.get(TraitKind::Curiosity) as u32;
+        3 + (perception + curiosity) * 6 / 480
+    }
+
+    /// How long (in ticks) an alert persists after the last stimulus, derived from Caution.
+    ///
+    /// Ranges from 200 to 1000 ticks (roughly 2 to 10 turns); neutral is 600.
+    pub fn alert_duration(&self) -> crate::state::Tick {
+        200 + self.get(TraitKind::Caution) as crate::state::Tick * 800 / 240
+    }
 }
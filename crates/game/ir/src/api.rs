@@ -0,0 +1,177 @@
+//! Rule-facing API shared by both modes.
+
+use game_core::{CoreStatKind, EntityId, ResourceKind};
+
+use crate::IrError;
+use crate::backend::Backend;
+use crate::ir::{BinOp, StateKey, Table};
+
+/// Game rules written against this type run unchanged on any [`Backend`].
+///
+/// Helpers lower to backend primitives and add the range checks a circuit
+/// needs: stores of `u32` state fields assert 32 bits before writing.
+pub struct GameApi<B: Backend> {
+    backend: B,
+}
+
+impl<B: Backend> GameApi<B> {
+    pub fn new(backend: B) -> Self {
+        Self { backend }
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    pub fn into_backend(self) -> B {
+        self.backend
+    }
+
+    // ------------------------------------------------------------------
+    // State
+    // ------------------------------------------------------------------
+
+    pub fn constant(&mut self, value: u64) -> B::Value {
+        self.backend.constant(value)
+    }
+
+    pub fn load_resource(
+        &mut self,
+        actor: EntityId,
+        resource: ResourceKind,
+    ) -> Result<B::Value, IrError> {
+        self.backend.load(StateKey::Resource { actor, resource })
+    }
+
+    pub fn load_resource_max(
+        &mut self,
+        actor: EntityId,
+        resource: ResourceKind,
+    ) -> Result<B::Value, IrError> {
+        self.backend.load(StateKey::ResourceMax { actor, resource })
+    }
+
+    pub fn load_stat(&mut self, actor: EntityId, stat: CoreStatKind) -> Result<B::Value, IrError> {
+        self.backend.load(StateKey::Stat { actor, stat })
+    }
+
+    pub fn store_resource(
+        &mut self,
+        actor: EntityId,
+        resource: ResourceKind,
+        value: B::Value,
+    ) -> Result<(), IrError> {
+        self.backend.assert_range(value, 32)?;
+        self.backend
+            .store(StateKey::Resource { actor, resource }, value)
+    }
+
+    /// Damage of the weapon `actor` has equipped (unarmed if none).
+    pub fn weapon_damage(&mut self, actor: EntityId) -> Result<B::Value, IrError> {
+        let weapon = self.backend.load(StateKey::Weapon { actor })?;
+        self.backend.lookup(Table::WeaponDamage, weapon)
+    }
+
+    /// Deterministic die roll in `1..=sides`.
+    pub fn roll(&mut self, actor: EntityId, context: u32, sides: u32) -> Result<B::Value, IrError> {
+        self.backend.rand(actor, context, sides)
+    }
+
+    // ------------------------------------------------------------------
+    // Arithmetic
+    // ------------------------------------------------------------------
+
+    pub fn add(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        self.backend.binary(BinOp::Add, a, b)
+    }
+
+    pub fn sub(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        self.backend.binary(BinOp::Sub, a, b)
+    }
+
+    pub fn mul(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        self.backend.binary(BinOp::Mul, a, b)
+    }
+
+    pub fn div(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        self.backend.binary(BinOp::Div, a, b)
+    }
+
+    pub fn min(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        self.backend.binary(BinOp::Min, a, b)
+    }
+
+    pub fn max(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        self.backend.binary(BinOp::Max, a, b)
+    }
+
+    pub fn eq(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        self.backend.binary(BinOp::Eq, a, b)
+    }
+
+    pub fn lt(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        self.backend.binary(BinOp::Lt, a, b)
+    }
+
+    /// `a - b`, clamped at zero.
+    pub fn saturating_sub(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        let b = self.min(a, b)?;
+        self.sub(a, b)
+    }
+
+    /// `value`, clamped at `u32::MAX`.
+    pub fn clamp_u32(&mut self, value: B::Value) -> Result<B::Value, IrError> {
+        let cap = self.constant(u32::MAX as u64);
+        self.min(value, cap)
+    }
+
+    /// `a + b`, clamped at `u32::MAX`.
+    pub fn saturating_add_u32(&mut self, a: B::Value, b: B::Value) -> Result<B::Value, IrError> {
+        let sum = self.add(a, b)?;
+        self.clamp_u32(sum)
+    }
+
+    /// `value * factor / 100`, clamped at `u32::MAX`.
+    ///
+    /// `value` must fit in 32 bits, so the product cannot overflow.
+    pub fn scale_u32(&mut self, value: B::Value, factor: B::Value) -> Result<B::Value, IrError> {
+        let scaled = self.mul(value, factor)?;
+        let hundred = self.constant(100);
+        let result = self.div(scaled, hundred)?;
+        self.clamp_u32(result)
+    }
+
+    /// `value * percent / 100`, clamped at `u32::MAX`.
+    pub fn percent(&mut self, value: B::Value, percent: u32) -> Result<B::Value, IrError> {
+        let percent = self.constant(percent as u64);
+        self.scale_u32(value, percent)
+    }
+
+    /// Branch-free `if cond { then } else { otherwise }`.
+    pub fn select(
+        &mut self,
+        cond: B::Value,
+        then: B::Value,
+        otherwise: B::Value,
+    ) -> Result<B::Value, IrError> {
+        self.backend.select(cond, then, otherwise)
+    }
+
+    // ------------------------------------------------------------------
+    // Constraints
+    // ------------------------------------------------------------------
+
+    /// Requires `cond` to be `1`.
+    pub fn assert(&mut self, cond: B::Value, message: &str) -> Result<(), IrError> {
+        let one = self.constant(1);
+        self.backend.assert_eq(cond, one, message)
+    }
+
+    pub fn assert_eq(&mut self, a: B::Value, b: B::Value, message: &str) -> Result<(), IrError> {
+        self.backend.assert_eq(a, b, message)
+    }
+
+    pub fn assert_range(&mut self, value: B::Value, bits: u32) -> Result<(), IrError> {
+        self.backend.assert_range(value, bits)
+    }
+}
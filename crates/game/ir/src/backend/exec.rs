@@ -0,0 +1,155 @@
+//! Execution mode: operations read and mutate a live game state.
+
+use game_core::{
+    ActorState, CoreStatKind, EntityId, GameEnv, GameState, ItemHandle, ItemKind, ResourceKind,
+    compute_seed,
+};
+
+use super::Backend;
+use crate::IrError;
+use crate::ir::{BinOp, StateKey, Table};
+
+/// Damage of an unarmed attack, matching `Formula::WeaponDamage` in game-core.
+pub const UNARMED_DAMAGE: u64 = 5;
+
+/// Runs rules directly against a [`GameState`].
+pub struct ExecBackend<'a> {
+    state: &'a mut GameState,
+    env: &'a GameEnv<'a>,
+}
+
+impl<'a> ExecBackend<'a> {
+    pub fn new(state: &'a mut GameState, env: &'a GameEnv<'a>) -> Self {
+        Self { state, env }
+    }
+
+    pub fn state(&self) -> &GameState {
+        self.state
+    }
+
+    fn actor(&self, id: EntityId) -> Result<&ActorState, IrError> {
+        self.state
+            .entities
+            .actor(id)
+            .ok_or(IrError::ActorNotFound(id))
+    }
+
+    fn weapon_damage(&self, key: u64) -> Result<u64, IrError> {
+        if key == 0 {
+            return Ok(UNARMED_DAMAGE);
+        }
+        let failed = |reason: String| IrError::LookupFailed {
+            table: Table::WeaponDamage,
+            key,
+            reason,
+        };
+        let handle = u32::try_from(key - 1)
+            .map(ItemHandle)
+            .map_err(|_| failed("handle out of range".into()))?;
+        let definition = self
+            .env
+            .items()?
+            .definition(handle)
+            .ok_or_else(|| failed("no item definition".into()))?;
+        match definition.kind {
+            ItemKind::Weapon(weapon) => Ok(weapon.damage as u64),
+            kind => Err(failed(format!("item is not a weapon: {kind:?}"))),
+        }
+    }
+}
+
+impl Backend for ExecBackend<'_> {
+    type Value = u64;
+
+    fn constant(&mut self, value: u64) -> u64 {
+        value
+    }
+
+    fn load(&mut self, key: StateKey) -> Result<u64, IrError> {
+        let actor = self.actor(key.actor())?;
+        let value = match key {
+            StateKey::Resource { resource, .. } => {
+                (match resource {
+                    ResourceKind::Hp => actor.resources.hp,
+                    ResourceKind::Mp => actor.resources.mp,
+                    ResourceKind::Lucidity => actor.resources.lucidity,
+                }) as u64
+            }
+            StateKey::ResourceMax { resource, .. } => {
+                actor.snapshot().resource_max.get(resource) as u64
+            }
+            StateKey::Stat { stat, .. } => {
+                let core = actor.snapshot().core;
+                let value = match stat {
+                    CoreStatKind::Str => core.str,
+                    CoreStatKind::Int => core.int,
+                    CoreStatKind::Con => core.con,
+                    CoreStatKind::Dex => core.dex,
+                    CoreStatKind::Wil => core.wil,
+                    CoreStatKind::Ego => core.ego,
+                };
+                value.max(0) as u64
+            }
+            StateKey::Weapon { .. } => actor.equipment.weapon.map_or(0, |h| h.0 as u64 + 1),
+        };
+        Ok(value)
+    }
+
+    fn store(&mut self, key: StateKey, value: u64) -> Result<(), IrError> {
+        let StateKey::Resource { actor, resource } = key else {
+            return Err(IrError::ReadOnly(key));
+        };
+        let value = u32::try_from(value).map_err(|_| IrError::OutOfRange { value, bits: 32 })?;
+        let actor = self
+            .state
+            .entities
+            .actor_mut(actor)
+            .ok_or(IrError::ActorNotFound(actor))?;
+        match resource {
+            ResourceKind::Hp => actor.resources.hp = value,
+            ResourceKind::Mp => actor.resources.mp = value,
+            ResourceKind::Lucidity => actor.resources.lucidity = value,
+        }
+        Ok(())
+    }
+
+    fn lookup(&mut self, table: Table, key: u64) -> Result<u64, IrError> {
+        match table {
+            Table::WeaponDamage => self.weapon_damage(key),
+        }
+    }
+
+    fn rand(&mut self, actor: EntityId, context: u32, sides: u32) -> Result<u64, IrError> {
+        let seed = compute_seed(
+            self.state.game_seed,
+            self.state.turn.nonce,
+            actor.0,
+            context,
+        );
+        Ok(self.env.rng()?.roll_die(seed, sides) as u64)
+    }
+
+    fn binary(&mut self, op: BinOp, a: u64, b: u64) -> Result<u64, IrError> {
+        op.eval(a, b)
+    }
+
+    fn select(&mut self, cond: u64, then: u64, otherwise: u64) -> Result<u64, IrError> {
+        Ok(if cond != 0 { then } else { otherwise })
+    }
+
+    fn assert_eq(&mut self, a: u64, b: u64, message: &str) -> Result<(), IrError> {
+        if a == b {
+            Ok(())
+        } else {
+            Err(IrError::AssertionFailed(format!("{message} ({a} != {b})")))
+        }
+    }
+
+    fn assert_range(&mut self, value: u64, bits: u32) -> Result<(), IrError> {
+        if bits >= 64 || value >> bits == 0 {
+            Ok(())
+        } else {
+            Err(IrError::OutOfRange { value, bits })
+        }
+    }
+}
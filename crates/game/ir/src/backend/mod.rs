@@ -0,0 +1,51 @@
+//! Backends behind [`GameApi`](crate::GameApi).
+//!
+//! - [`ExecBackend`]: runs each operation against a live [`GameState`](game_core::GameState).
+//! - [`IRBackend`]: records each operation as a [`Node`](crate::Node) instead.
+
+mod exec;
+mod record;
+
+pub use exec::ExecBackend;
+pub use record::IRBackend;
+
+use core::fmt;
+
+use game_core::EntityId;
+
+use crate::IrError;
+use crate::ir::{BinOp, StateKey, Table};
+
+/// Primitive operations a rule may perform.
+///
+/// Every side effect and every value that reaches game state goes through one
+/// of these calls, so recording them captures the whole transition.
+pub trait Backend {
+    /// Value handle: a concrete number at runtime, an SSA value when recording.
+    type Value: Copy + fmt::Debug;
+
+    fn constant(&mut self, value: u64) -> Self::Value;
+
+    fn load(&mut self, key: StateKey) -> Result<Self::Value, IrError>;
+
+    fn store(&mut self, key: StateKey, value: Self::Value) -> Result<(), IrError>;
+
+    fn lookup(&mut self, table: Table, key: Self::Value) -> Result<Self::Value, IrError>;
+
+    /// Die roll in `1..=sides` for `actor`, distinct per `context`.
+    fn rand(&mut self, actor: EntityId, context: u32, sides: u32) -> Result<Self::Value, IrError>;
+
+    fn binary(&mut self, op: BinOp, a: Self::Value, b: Self::Value)
+    -> Result<Self::Value, IrError>;
+
+    fn select(
+        &mut self,
+        cond: Self::Value,
+        then: Self::Value,
+        otherwise: Self::Value,
+    ) -> Result<Self::Value, IrError>;
+
+    fn assert_eq(&mut self, a: Self::Value, b: Self::Value, message: &str) -> Result<(), IrError>;
+
+    fn assert_range(&mut self, value: Self::Value, bits: u32) -> Result<(), IrError>;
+}
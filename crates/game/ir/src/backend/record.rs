@@ -0,0 +1,90 @@
+//! IR mode: operations are recorded as GameIR nodes.
+
+use game_core::EntityId;
+
+use super::Backend;
+use crate::IrError;
+use crate::ir::{BinOp, Node, Program, StateKey, Table, ValueId};
+
+/// Records every operation into a [`Program`] without touching game state.
+///
+/// Values are symbolic, so rules that branch on them in Rust cannot be
+/// recorded faithfully; they must use [`GameApi::select`](crate::GameApi::select)
+/// instead.
+#[derive(Debug, Default)]
+pub struct IRBackend {
+    program: Program,
+}
+
+impl IRBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn into_program(self) -> Program {
+        self.program
+    }
+}
+
+impl Backend for IRBackend {
+    type Value = ValueId;
+
+    fn constant(&mut self, value: u64) -> ValueId {
+        self.program.push(Node::Const(value))
+    }
+
+    fn load(&mut self, key: StateKey) -> Result<ValueId, IrError> {
+        Ok(self.program.push(Node::Load(key)))
+    }
+
+    fn store(&mut self, key: StateKey, value: ValueId) -> Result<(), IrError> {
+        if !key.is_writable() {
+            return Err(IrError::ReadOnly(key));
+        }
+        self.program.push(Node::Store(key, value));
+        Ok(())
+    }
+
+    fn lookup(&mut self, table: Table, key: ValueId) -> Result<ValueId, IrError> {
+        Ok(self.program.push(Node::Lookup(table, key)))
+    }
+
+    fn rand(&mut self, actor: EntityId, context: u32, sides: u32) -> Result<ValueId, IrError> {
+        Ok(self.program.push(Node::Rand {
+            actor,
+            context,
+            sides,
+        }))
+    }
+
+    fn binary(&mut self, op: BinOp, a: ValueId, b: ValueId) -> Result<ValueId, IrError> {
+        Ok(self.program.push(Node::Binary(op, a, b)))
+    }
+
+    fn select(
+        &mut self,
+        cond: ValueId,
+        then: ValueId,
+        otherwise: ValueId,
+    ) -> Result<ValueId, IrError> {
+        Ok(self.program.push(Node::Select {
+            cond,
+            then,
+            otherwise,
+        }))
+    }
+
+    fn assert_eq(&mut self, a: ValueId, b: ValueId, message: &str) -> Result<(), IrError> {
+        self.program.push(Node::AssertEq(a, b, message.to_owned()));
+        Ok(())
+    }
+
+    fn assert_range(&mut self, value: ValueId, bits: u32) -> Result<(), IrError> {
+        self.program.push(Node::AssertRange(value, bits));
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+//! Errors raised while executing or replaying GameIR.
+
+use game_core::{EntityId, OracleError};
+
+use crate::ir::{BinOp, StateKey, Table, ValueId};
+
+/// Failure of a rule run through [`GameApi`](crate::GameApi) or of a replayed
+/// [`Program`](crate::Program).
+///
+/// The [`IRBackend`](crate::IRBackend) never sees concrete values, so while
+/// recording only [`IrError::ReadOnly`] can occur.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum IrError {
+    #[error("actor {0:?} not found")]
+    ActorNotFound(EntityId),
+
+    #[error("state key {0:?} is read-only")]
+    ReadOnly(StateKey),
+
+    #[error("value {value} does not fit in {bits} bits")]
+    OutOfRange { value: u64, bits: u32 },
+
+    #[error("arithmetic overflow in {0:?}")]
+    Overflow(BinOp),
+
+    #[error("division by zero")]
+    DivisionByZero,
+
+    #[error("lookup {table:?}[{key}] failed: {reason}")]
+    LookupFailed {
+        table: Table,
+        key: u64,
+        reason: String,
+    },
+
+    #[error("assertion failed: {0}")]
+    AssertionFailed(String),
+
+    #[error("value {0} is not defined before use")]
+    UndefinedValue(ValueId),
+
+    #[error(transparent)]
+    Oracle(#[from] OracleError),
+}
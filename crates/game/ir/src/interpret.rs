@@ -0,0 +1,163 @@
+//! Re-execution of recorded programs.
+
+use crate::IrError;
+use crate::backend::Backend;
+use crate::ir::{Node, Program, ValueId};
+
+/// Values produced by [`interpret`], indexed by [`ValueId`].
+#[derive(Clone, Debug)]
+pub struct Evaluation<V> {
+    values: Vec<Option<V>>,
+}
+
+impl<V: Copy> Evaluation<V> {
+    /// Result of the node `id`, or `None` for stores and asserts.
+    pub fn value(&self, id: ValueId) -> Option<V> {
+        self.values.get(id.index()).copied().flatten()
+    }
+}
+
+/// Replays `program` node by node on `backend`.
+///
+/// On an [`ExecBackend`](crate::ExecBackend) this reproduces the state changes
+/// of the original rule run; on an [`IRBackend`](crate::IRBackend) it copies the
+/// program.
+pub fn interpret<B: Backend>(
+    program: &Program,
+    backend: &mut B,
+) -> Result<Evaluation<B::Value>, IrError> {
+    let mut values: Vec<Option<B::Value>> = Vec::with_capacity(program.len());
+    let get = |values: &[Option<B::Value>], id: ValueId| {
+        values
+            .get(id.index())
+            .copied()
+            .flatten()
+            .ok_or(IrError::UndefinedValue(id))
+    };
+
+    for node in program.nodes() {
+        let value = match node {
+            Node::Const(value) => Some(backend.constant(*value)),
+            Node::Load(key) => Some(backend.load(*key)?),
+            Node::Store(key, value) => {
+                backend.store(*key, get(&values, *value)?)?;
+                None
+            }
+            Node::Lookup(table, key) => Some(backend.lookup(*table, get(&values, *key)?)?),
+            Node::Rand {
+                actor,
+                context,
+                sides,
+            } => Some(backend.rand(*actor, *context, *sides)?),
+            Node::Binary(op, a, b) => {
+                Some(backend.binary(*op, get(&values, *a)?, get(&values, *b)?)?)
+            }
+            Node::Select {
+                cond,
+                then,
+                otherwise,
+            } => Some(backend.select(
+                get(&values, *cond)?,
+                get(&values, *then)?,
+                get(&values, *otherwise)?,
+            )?),
+            Node::AssertEq(a, b, message) => {
+                backend.assert_eq(get(&values, *a)?, get(&values, *b)?, message)?;
+                None
+            }
+            Node::AssertRange(value, bits) => {
+                backend.assert_range(get(&values, *value)?, *bits)?;
+                None
+            }
+        };
+        values.push(value);
+    }
+
+    Ok(Evaluation { values })
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{EntityId, GameEnv, GameState, PcgRng, ResourceKind, RngOracle};
+
+    use super::*;
+    use crate::{ExecBackend, GameApi, IRBackend};
+
+    /// Heals the player by 1d6 (capped at max HP) and returns the roll.
+    fn heal<B: Backend>(api: &mut GameApi<B>) -> Result<B::Value, IrError> {
+        let roll = api.roll(EntityId::PLAYER, 0, 6)?;
+        let hp = api.load_resource(EntityId::PLAYER, ResourceKind::Hp)?;
+        let max = api.load_resource_max(EntityId::PLAYER, ResourceKind::Hp)?;
+        let healed = api.add(hp, roll)?;
+        let healed = api.min(healed, max)?;
+        api.store_resource(EntityId::PLAYER, ResourceKind::Hp, healed)?;
+        Ok(roll)
+    }
+
+    #[test]
+    fn replayed_rng_matches_execution() {
+        let rng = PcgRng;
+        let env = GameEnv::new(
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(&rng as &dyn RngOracle),
+            None,
+        );
+        let mut state = GameState::with_player();
+        state.game_seed = 42;
+        state.turn.nonce = 7;
+        state
+            .entities
+            .actor_mut(EntityId::PLAYER)
+            .unwrap()
+            .resources
+            .hp = 1;
+
+        let mut executed = state.clone();
+        let mut api = GameApi::new(ExecBackend::new(&mut executed, &env));
+        let roll = heal(&mut api).unwrap();
+        assert!((1..=6).contains(&roll));
+
+        let mut api = GameApi::new(IRBackend::new());
+        let roll_id = heal(&mut api).unwrap();
+        let program = api.into_backend().into_program();
+
+        let mut replayed = state.clone();
+        let evaluation = interpret(&program, &mut ExecBackend::new(&mut replayed, &env)).unwrap();
+        assert_eq!(evaluation.value(roll_id), Some(roll));
+        assert_eq!(replayed, executed);
+        assert_eq!(
+            program.footprint(),
+            [
+                crate::StateKey::Resource {
+                    actor: EntityId::PLAYER,
+                    resource: ResourceKind::Hp
+                },
+                crate::StateKey::ResourceMax {
+                    actor: EntityId::PLAYER,
+                    resource: ResourceKind::Hp
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn failed_assertion_aborts_replay() {
+        let mut api = GameApi::new(IRBackend::new());
+        let hp = api
+            .load_resource(EntityId::PLAYER, ResourceKind::Hp)
+            .unwrap();
+        let zero = api.constant(0);
+        let dead = api.eq(hp, zero).unwrap();
+        api.assert(dead, "player must be dead").unwrap();
+        let program = api.into_backend().into_program();
+
+        let env = GameEnv::empty();
+        let mut state = GameState::with_player();
+        let err = interpret(&program, &mut ExecBackend::new(&mut state, &env)).unwrap_err();
+        assert!(matches!(err, IrError::AssertionFailed(_)), "{err}");
+    }
+}
@@ -0,0 +1,248 @@
+//! GameIR node set.
+//!
+//! A [`Program`] is a flat list of [`Node`]s in SSA form: node `i` defines the
+//! value [`ValueId(i)`](ValueId), and operands only refer to earlier nodes.
+//! Nodes without a result (stores and asserts) still take an index so value ids
+//! stay equal to node positions.
+//!
+//! All values are unsigned integers. Comparison nodes produce `0` or `1`, and
+//! [`Node::Select`] treats any non-zero condition as true.
+
+use core::fmt;
+
+use game_core::{CoreStatKind, EntityId, ResourceKind};
+
+/// SSA value: the result of the node at this index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ValueId(pub u32);
+
+impl ValueId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl fmt::Display for ValueId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+/// Addressable field of the game state.
+///
+/// Each key names one field of an actor leaf in the Merkle state tree. Only
+/// current resources are writable; the other keys are derived from stats and
+/// equipment and can only be loaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StateKey {
+    /// Current value of a resource.
+    Resource {
+        actor: EntityId,
+        resource: ResourceKind,
+    },
+    /// Maximum of a resource after bonuses.
+    ResourceMax {
+        actor: EntityId,
+        resource: ResourceKind,
+    },
+    /// Effective core stat after bonuses. Negative stats load as `0`.
+    Stat { actor: EntityId, stat: CoreStatKind },
+    /// Equipped weapon: `0` when unarmed, otherwise item handle + 1.
+    Weapon { actor: EntityId },
+}
+
+impl StateKey {
+    pub fn actor(self) -> EntityId {
+        match self {
+            Self::Resource { actor, .. }
+            | Self::ResourceMax { actor, .. }
+            | Self::Stat { actor, .. }
+            | Self::Weapon { actor } => actor,
+        }
+    }
+
+    pub fn is_writable(self) -> bool {
+        matches!(self, Self::Resource { .. })
+    }
+}
+
+impl fmt::Display for StateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Resource { actor, resource } => write!(f, "resource[{}].{resource:?}", actor.0),
+            Self::ResourceMax { actor, resource } => {
+                write!(f, "resource_max[{}].{resource:?}", actor.0)
+            }
+            Self::Stat { actor, stat } => write!(f, "stat[{}].{stat:?}", actor.0),
+            Self::Weapon { actor } => write!(f, "weapon[{}]", actor.0),
+        }
+    }
+}
+
+/// Read-only table addressed by [`Node::Lookup`].
+///
+/// The IR names the table, not its contents; the values come from the oracles
+/// of whichever backend runs the program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Table {
+    /// Weapon damage keyed like [`StateKey::Weapon`] (`0` = unarmed).
+    WeaponDamage,
+}
+
+/// Binary arithmetic and comparison.
+///
+/// Arithmetic is checked: overflow, underflow and division by zero are errors
+/// rather than wrapping, so a circuit only has to cover the in-range case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Min,
+    Max,
+    /// `1` if equal, else `0`.
+    Eq,
+    /// `1` if left < right, else `0`.
+    Lt,
+}
+
+impl BinOp {
+    /// Evaluates the operation on concrete values.
+    pub fn eval(self, a: u64, b: u64) -> Result<u64, crate::IrError> {
+        use crate::IrError;
+
+        match self {
+            Self::Add => a.checked_add(b).ok_or(IrError::Overflow(self)),
+            Self::Sub => a.checked_sub(b).ok_or(IrError::Overflow(self)),
+            Self::Mul => a.checked_mul(b).ok_or(IrError::Overflow(self)),
+            Self::Div => a.checked_div(b).ok_or(IrError::DivisionByZero),
+            Self::Min => Ok(a.min(b)),
+            Self::Max => Ok(a.max(b)),
+            Self::Eq => Ok((a == b) as u64),
+            Self::Lt => Ok((a < b) as u64),
+        }
+    }
+
+    fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+            Self::Div => "div",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Eq => "eq",
+            Self::Lt => "lt",
+        }
+    }
+}
+
+/// One GameIR node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Node {
+    Const(u64),
+    Load(StateKey),
+    Store(StateKey, ValueId),
+    Lookup(Table, ValueId),
+    /// Die roll in `1..=sides`, seeded from the game seed, the action nonce,
+    /// the actor and a per-action context.
+    Rand {
+        actor: EntityId,
+        context: u32,
+        sides: u32,
+    },
+    Binary(BinOp, ValueId, ValueId),
+    Select {
+        cond: ValueId,
+        then: ValueId,
+        otherwise: ValueId,
+    },
+    AssertEq(ValueId, ValueId, String),
+    AssertRange(ValueId, u32),
+}
+
+impl Node {
+    /// Whether this node defines a value usable as an operand.
+    pub fn has_result(&self) -> bool {
+        !matches!(
+            self,
+            Self::Store(..) | Self::AssertEq(..) | Self::AssertRange(..)
+        )
+    }
+}
+
+/// Recorded GameIR trace of one rule application.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Program {
+    nodes: Vec<Node>,
+}
+
+impl Program {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// State keys this program loads or stores, in first-use order.
+    ///
+    /// This is the Merkle witness footprint a prover has to open.
+    pub fn footprint(&self) -> Vec<StateKey> {
+        let mut keys = Vec::new();
+        for node in &self.nodes {
+            if let Node::Load(key) | Node::Store(key, _) = node
+                && !keys.contains(key)
+            {
+                keys.push(*key);
+            }
+        }
+        keys
+    }
+
+    pub(crate) fn push(&mut self, node: Node) -> ValueId {
+        let id = ValueId(self.nodes.len() as u32);
+        self.nodes.push(node);
+        id
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, node) in self.nodes.iter().enumerate() {
+            if node.has_result() {
+                write!(f, "{} = ", ValueId(index as u32))?;
+            }
+            match node {
+                Node::Const(value) => writeln!(f, "const {value}")?,
+                Node::Load(key) => writeln!(f, "load {key}")?,
+                Node::Store(key, value) => writeln!(f, "store {key}, {value}")?,
+                Node::Lookup(table, key) => writeln!(f, "lookup {table:?}, {key}")?,
+                Node::Rand {
+                    actor,
+                    context,
+                    sides,
+                } => writeln!(f, "rand actor={} context={context} d{sides}", actor.0)?,
+                Node::Binary(op, a, b) => writeln!(f, "{} {a}, {b}", op.mnemonic())?,
+                Node::Select {
+                    cond,
+                    then,
+                    otherwise,
+                } => writeln!(f, "select {cond}, {then}, {otherwise}")?,
+                Node::AssertEq(a, b, message) => writeln!(f, "assert_eq {a}, {b} ; {message}")?,
+                Node::AssertRange(value, bits) => writeln!(f, "assert_range {value}, {bits}")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+//! GameIR: one rule implementation, two products.
+//!
+//! Rules written against [`GameApi`] run on either backend (see
+//! `docs/gameir.md`):
+//!
+//! - [`ExecBackend`] mutates a live [`GameState`](game_core::GameState), like
+//!   the game-core reducer.
+//! - [`IRBackend`] records the same calls as a [`Program`] of GameIR nodes:
+//!   state loads and stores, table lookups, RNG rolls, arithmetic and asserts.
+//!
+//! [`interpret`] replays a recorded program on any backend, which is how the
+//! two modes are checked against each other. Ported rules live in [`rules`].
+//!
+//! # Scope
+//!
+//! This is the first slice of the design. There is no ZK lowering yet, and the
+//! `Poseidon` / `CommitNextRoot` nodes from the spec are left out until a
+//! lowering needs them. Only `DamageEffect` is ported.
+
+pub mod api;
+pub mod backend;
+pub mod error;
+pub mod interpret;
+pub mod ir;
+pub mod rules;
+
+pub use api::GameApi;
+pub use backend::{Backend, ExecBackend, IRBackend};
+pub use error::IrError;
+pub use interpret::{Evaluation, interpret};
+pub use ir::{BinOp, Node, Program, StateKey, Table, ValueId};
+pub use rules::{Applied, EffectScope, Rule};
@@ -0,0 +1,266 @@
+//! Port of `DamageEffect::apply`.
+
+use game_core::action::effect::DamageEffect;
+use game_core::{CoreStatKind, ResourceKind};
+
+use super::formula::evaluate;
+use super::{Applied, EffectScope, Rule};
+use crate::IrError;
+use crate::api::GameApi;
+use crate::backend::Backend;
+
+impl Rule for DamageEffect {
+    fn apply<B: Backend>(
+        &self,
+        api: &mut GameApi<B>,
+        scope: &mut EffectScope<B::Value>,
+    ) -> Result<Applied<B::Value>, IrError> {
+        let planned = evaluate(&self.formula, api, scope)?;
+        let (hit, critical) = roll(self, api, scope)?;
+        let planned = match critical {
+            Some(critical) => {
+                let boosted = api.percent(planned, DamageEffect::CRIT_DAMAGE_PERCENT)?;
+                api.select(critical, boosted, planned)?
+            }
+            None => planned,
+        };
+
+        // Damage is capped at current HP and zero on a miss. Resistances are
+        // not applied in game-core yet either.
+        let hp = api.load_resource(scope.target, ResourceKind::Hp)?;
+        let mut actual = api.min(planned, hp)?;
+        if let Some(hit) = hit {
+            let zero = api.constant(0);
+            actual = api.select(hit, actual, zero)?;
+        }
+        let remaining = api.sub(hp, actual)?;
+        api.store_resource(scope.target, ResourceKind::Hp, remaining)?;
+
+        scope.accumulated_damage = api.add(scope.accumulated_damage, actual)?;
+
+        Ok(Applied::Damage { planned, actual })
+    }
+}
+
+/// Port of `DamageEffect::roll`: the hit and critical flags, `None` where the
+/// effect cannot miss or crit.
+///
+/// Both rolls are always made, since the RNG is a pure function of the seed;
+/// the critical flag is masked by the hit flag instead.
+fn roll<B: Backend>(
+    effect: &DamageEffect,
+    api: &mut GameApi<B>,
+    scope: &EffectScope<B::Value>,
+) -> Result<(Option<B::Value>, Option<B::Value>), IrError> {
+    let hit = if effect.can_miss {
+        // clamp(BASE + caster DEX - target DEX, MIN, MAX) without going negative
+        let base = api.constant(DamageEffect::BASE_HIT_CHANCE as u64);
+        let accuracy = api.load_stat(scope.caster, CoreStatKind::Dex)?;
+        let evasion = api.load_stat(scope.target, CoreStatKind::Dex)?;
+        let chance = api.add(base, accuracy)?;
+        let chance = api.saturating_sub(chance, evasion)?;
+        let min = api.constant(DamageEffect::MIN_HIT_CHANCE as u64);
+        let max = api.constant(DamageEffect::MAX_HIT_CHANCE as u64);
+        let chance = api.max(chance, min)?;
+        let chance = api.min(chance, max)?;
+
+        // roll <= chance
+        let roll = api.roll(scope.target, DamageEffect::HIT_ROLL, 100)?;
+        let missed = api.lt(chance, roll)?;
+        let zero = api.constant(0);
+        let one = api.constant(1);
+        Some(api.select(missed, zero, one)?)
+    } else {
+        None
+    };
+
+    let critical = if effect.can_crit {
+        // roll <= EGO / 4
+        let ego = api.load_stat(scope.caster, CoreStatKind::Ego)?;
+        let four = api.constant(4);
+        let chance = api.div(ego, four)?;
+        let roll = api.roll(scope.target, DamageEffect::CRIT_ROLL, 100)?;
+        let failed = api.lt(chance, roll)?;
+        let zero = api.constant(0);
+        let one = api.constant(1);
+        let critical = api.select(failed, zero, one)?;
+        Some(match hit {
+            Some(hit) => api.select(hit, critical, zero)?,
+            None => critical,
+        })
+    } else {
+        None
+    };
+
+    Ok((hit, critical))
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::action::AppliedValue;
+    use game_core::{
+        ActionInput, CoreStatKind, DamageType, EffectContext, EntityId, Formula, GameEnv,
+        GameState, PcgRng, ResourceKind, RngOracle,
+    };
+
+    use super::*;
+    use crate::{ExecBackend, IRBackend, interpret};
+
+    const TARGET: EntityId = EntityId(1);
+
+    fn state(target_hp: u32, game_seed: u64) -> GameState {
+        let mut state = GameState::with_player();
+        state.game_seed = game_seed;
+        let mut target = state.entities.actor(EntityId::PLAYER).unwrap().clone();
+        target.id = TARGET;
+        target.resources.hp = target_hp;
+        target.core_stats.dex = -20;
+        state.entities.actors.push(target).unwrap();
+        // Frequent crits for `rolled()`
+        state.entities.actors[0].core_stats.ego = 99;
+        state
+    }
+
+    fn effect() -> DamageEffect {
+        DamageEffect::new(
+            Formula::Sum(vec![
+                Formula::WeaponDamage { percent: 150 },
+                Formula::CasterStat {
+                    stat: CoreStatKind::Str,
+                    percent: 50,
+                },
+                Formula::Min(vec![
+                    Formula::TargetMissingResource {
+                        resource: ResourceKind::Hp,
+                        percent: 100,
+                    },
+                    Formula::Constant(4),
+                ]),
+                Formula::Product(vec![Formula::Constant(3), Formula::Constant(200)]),
+            ]),
+            DamageType::Physical,
+        )
+    }
+
+    /// Reads the target's Dex, whose base value is negative.
+    fn negative_stat() -> DamageEffect {
+        DamageEffect::new(
+            Formula::Sum(vec![
+                Formula::TargetStat {
+                    stat: CoreStatKind::Dex,
+                    percent: 200,
+                },
+                Formula::Constant(3),
+            ]),
+            DamageType::Physical,
+        )
+    }
+
+    /// A product past `u32::MAX`, which game-core clamps.
+    fn large_product() -> DamageEffect {
+        DamageEffect::new(
+            Formula::Product(vec![
+                Formula::Constant(u32::MAX),
+                Formula::Constant(200),
+                Formula::Constant(150),
+            ]),
+            DamageType::Physical,
+        )
+    }
+
+    /// `effect()` rolling to hit and to crit.
+    fn rolled() -> DamageEffect {
+        effect().with_crit().with_miss()
+    }
+
+    /// Applies the effect twice through game-core, returning both results.
+    fn core(effect: &DamageEffect, state: &mut GameState, env: &GameEnv) -> Vec<(u32, u32)> {
+        let input = ActionInput::Target(TARGET);
+        let mut ctx = EffectContext::new(EntityId::PLAYER, TARGET, state, env, &input);
+        (0..2)
+            .map(|_| match effect.apply(&mut ctx).unwrap() {
+                AppliedValue::Damage { planned, actual } => (planned, actual),
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect()
+    }
+
+    fn run<B: Backend>(
+        effect: &DamageEffect,
+        api: &mut GameApi<B>,
+    ) -> Result<Vec<Applied<B::Value>>, IrError> {
+        let mut scope = EffectScope::new(api, EntityId::PLAYER, TARGET);
+        (0..2)
+            .map(|_| Rule::apply(effect, api, &mut scope))
+            .collect()
+    }
+
+    #[test]
+    fn exec_and_ir_paths_match_game_core() {
+        let rng = PcgRng;
+        let env = GameEnv::new(
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(&rng as &dyn RngOracle),
+            None,
+        );
+        for effect in [effect(), negative_stat(), large_product(), rolled()] {
+            let mut api_ir = GameApi::new(IRBackend::new());
+            let recorded = run(&effect, &mut api_ir).unwrap();
+            let program = api_ir.into_backend().into_program();
+
+            // Second hit exhausts HP in the low-HP case; the seeds vary the
+            // hit and crit rolls.
+            let cases = [200, 12].map(|hp| (0..16).map(move |seed| (hp, seed)));
+            for (target_hp, game_seed) in cases.into_iter().flatten() {
+                let mut expected_state = state(target_hp, game_seed);
+                let expected = core(&effect, &mut expected_state, &env);
+
+                let mut exec_state = state(target_hp, game_seed);
+                let mut exec = GameApi::new(ExecBackend::new(&mut exec_state, &env));
+                let executed: Vec<_> = run(&effect, &mut exec)
+                    .unwrap()
+                    .into_iter()
+                    .map(|Applied::Damage { planned, actual }| (planned as u32, actual as u32))
+                    .collect();
+                assert_eq!(executed, expected);
+                assert_eq!(exec_state, expected_state);
+
+                let mut ir_state = state(target_hp, game_seed);
+                let evaluation =
+                    interpret(&program, &mut ExecBackend::new(&mut ir_state, &env)).unwrap();
+                let replayed: Vec<_> = recorded
+                    .iter()
+                    .map(|Applied::Damage { planned, actual }| {
+                        (
+                            evaluation.value(*planned).unwrap() as u32,
+                            evaluation.value(*actual).unwrap() as u32,
+                        )
+                    })
+                    .collect();
+                assert_eq!(replayed, expected);
+                assert_eq!(ir_state, expected_state);
+            }
+        }
+    }
+
+    #[test]
+    fn missing_target_fails_in_both_modes() {
+        let env = GameEnv::empty();
+        let mut state = GameState::with_player();
+        let mut exec = GameApi::new(ExecBackend::new(&mut state, &env));
+        assert_eq!(
+            run(&effect(), &mut exec).unwrap_err(),
+            IrError::ActorNotFound(TARGET)
+        );
+
+        let mut recorder = GameApi::new(IRBackend::new());
+        run(&effect(), &mut recorder).unwrap();
+        let program = recorder.into_backend().into_program();
+        let err = interpret(&program, &mut ExecBackend::new(&mut state, &env)).unwrap_err();
+        assert_eq!(err, IrError::ActorNotFound(TARGET));
+    }
+}
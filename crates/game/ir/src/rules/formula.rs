@@ -0,0 +1,112 @@
+//! Port of `game_core::action::formula::evaluate`.
+
+use game_core::{Formula, ResourceKind};
+
+use super::EffectScope;
+use crate::IrError;
+use crate::api::GameApi;
+use crate::backend::Backend;
+
+/// Evaluates `formula` for the caster and target of `scope`.
+///
+/// Negative stats count as `0` (see [`StateKey::Stat`](crate::StateKey::Stat)),
+/// and percentages and products are clamped at `u32::MAX`, as in game-core.
+pub fn evaluate<B: Backend>(
+    formula: &Formula,
+    api: &mut GameApi<B>,
+    scope: &EffectScope<B::Value>,
+) -> Result<B::Value, IrError> {
+    match formula {
+        Formula::Constant(value) => Ok(api.constant(*value as u64)),
+
+        Formula::CasterStat { stat, percent } => {
+            let value = api.load_stat(scope.caster, *stat)?;
+            api.percent(value, *percent)
+        }
+
+        Formula::TargetStat { stat, percent } => {
+            let value = api.load_stat(scope.target, *stat)?;
+            api.percent(value, *percent)
+        }
+
+        Formula::WeaponDamage { percent } => {
+            let damage = api.weapon_damage(scope.caster)?;
+            api.percent(damage, *percent)
+        }
+
+        Formula::FromPreviousDamage { percent } => api.percent(scope.accumulated_damage, *percent),
+
+        Formula::FromPreviousHealing { percent } => {
+            api.percent(scope.accumulated_healing, *percent)
+        }
+
+        Formula::CasterResource { resource, percent } => {
+            let current = api.load_resource(scope.caster, *resource)?;
+            api.percent(current, *percent)
+        }
+
+        Formula::TargetResource { resource, percent } => {
+            let current = api.load_resource(scope.target, *resource)?;
+            api.percent(current, *percent)
+        }
+
+        Formula::TargetMissingResource { resource, percent } => {
+            let missing = missing(api, scope, *resource)?;
+            api.percent(missing, *percent)
+        }
+
+        Formula::TargetMaxResource { resource, percent } => {
+            let max = api.load_resource_max(scope.target, *resource)?;
+            api.percent(max, *percent)
+        }
+
+        Formula::Sum(formulas) => {
+            let mut total = api.constant(0);
+            for f in formulas {
+                let value = evaluate(f, api, scope)?;
+                total = api.saturating_add_u32(total, value)?;
+            }
+            Ok(total)
+        }
+
+        Formula::Product(formulas) => {
+            let Some((first, rest)) = formulas.split_first() else {
+                return Ok(api.constant(0));
+            };
+            let mut result = evaluate(first, api, scope)?;
+            for f in rest {
+                let value = evaluate(f, api, scope)?;
+                result = api.scale_u32(result, value)?;
+            }
+            Ok(result)
+        }
+
+        Formula::Min(formulas) => {
+            let mut min = api.constant(u32::MAX as u64);
+            for f in formulas {
+                let value = evaluate(f, api, scope)?;
+                min = api.min(min, value)?;
+            }
+            Ok(min)
+        }
+
+        Formula::Max(formulas) => {
+            let mut max = api.constant(0);
+            for f in formulas {
+                let value = evaluate(f, api, scope)?;
+                max = api.max(max, value)?;
+            }
+            Ok(max)
+        }
+    }
+}
+
+fn missing<B: Backend>(
+    api: &mut GameApi<B>,
+    scope: &EffectScope<B::Value>,
+    resource: ResourceKind,
+) -> Result<B::Value, IrError> {
+    let max = api.load_resource_max(scope.target, resource)?;
+    let current = api.load_resource(scope.target, resource)?;
+    api.saturating_sub(max, current)
+}
@@ -0,0 +1,54 @@
+//! Game rules ported onto [`GameApi`].
+//!
+//! Each port mirrors the game-core implementation of the same rule and must
+//! produce the same state changes when run on an [`ExecBackend`](crate::ExecBackend).
+//! Only [`DamageEffect`](game_core::action::effect::DamageEffect) is ported so far.
+
+pub mod damage;
+pub mod formula;
+
+use game_core::EntityId;
+
+use crate::IrError;
+use crate::api::GameApi;
+use crate::backend::Backend;
+
+/// Per-action accumulators shared by effects, like game-core's `EffectContext`.
+#[derive(Clone, Copy, Debug)]
+pub struct EffectScope<V> {
+    pub caster: EntityId,
+    pub target: EntityId,
+    pub accumulated_damage: V,
+    pub accumulated_healing: V,
+}
+
+impl<V: Copy> EffectScope<V> {
+    pub fn new<B: Backend<Value = V>>(
+        api: &mut GameApi<B>,
+        caster: EntityId,
+        target: EntityId,
+    ) -> Self {
+        let zero = api.constant(0);
+        Self {
+            caster,
+            target,
+            accumulated_damage: zero,
+            accumulated_healing: zero,
+        }
+    }
+}
+
+/// Result of one applied rule, mirroring game-core's `AppliedValue`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Applied<V> {
+    Damage { planned: V, actual: V },
+}
+
+/// A game rule expressed against [`GameApi`].
+pub trait Rule {
+    fn apply<B: Backend>(
+        &self,
+        api: &mut GameApi<B>,
+        scope: &mut EffectScope<B::Value>,
+    ) -> Result<Applied<B::Value>, IrError>;
+}
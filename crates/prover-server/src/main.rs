@@ -0,0 +1,98 @@
+//! Proving daemon for `zk::RemoteProver` clients.
+//!
+//! Wraps the backend selected at build time and serves it over the protocol
+//! documented in `zk::remote`:
+//!
+//! ```bash
+//! PROVER_SERVER_SECRET=... cargo run -p prover-server --release --features risc0 -- \
+//!     --listen 127.0.0.1:7879 --workers 2
+//! ```
+//!
+//! Clients point `REMOTE_PROVER_URL` at the daemon and set the same
+//! `REMOTE_PROVER_SECRET`.
+//!
+//! # Transport security
+//!
+//! The daemon speaks plain HTTP: the secret, the seed salt and every state it
+//! proves cross the network unencrypted. Keep it on loopback and let clients
+//! reach it through an encrypted tunnel:
+//!
+//! ```bash
+//! ssh -N -L 7879:127.0.0.1:7879 <proving-box>   # then REMOTE_PROVER_URL=http://127.0.0.1:7879
+//! ```
+//!
+//! Listening on any other address is refused unless `--trusted-network`
+//! states that the network itself is trusted, e.g. a VPN or a private LAN
+//! between the player's machines.
+
+use std::net::ToSocketAddrs;
+
+use anyhow::{Result, bail};
+use clap::Parser;
+use zk::{OracleSnapshot, ProverServer, ZkProver};
+
+#[derive(Parser)]
+#[command(about = "Serve ZK proofs to remote game clients")]
+struct Args {
+    /// Address to listen on
+    #[arg(long, env = "PROVER_LISTEN_ADDR", default_value = "127.0.0.1:7879")]
+    listen: String,
+
+    /// Shared secret clients must present
+    #[arg(long, env = "PROVER_SERVER_SECRET", hide_env_values = true)]
+    secret: String,
+
+    /// Jobs proved concurrently
+    #[arg(long, default_value_t = 1)]
+    workers: usize,
+
+    /// Allow a non-loopback listen address; traffic is not encrypted
+    #[arg(long, env = "PROVER_TRUSTED_NETWORK")]
+    trusted_network: bool,
+}
+
+fn main() -> Result<()> {
+    dotenvy::dotenv().ok();
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()),
+        )
+        .init();
+
+    let args = Args::parse();
+    let exposed = args
+        .listen
+        .to_socket_addrs()?
+        .any(|addr| !addr.ip().is_loopback());
+    if exposed && !args.trusted_network {
+        bail!(
+            "refusing to serve plain HTTP on {}: listen on loopback and tunnel to it, \
+             or pass --trusted-network",
+            args.listen
+        );
+    }
+
+    let server = ProverServer::bind(&args.listen, args.secret, backend)?.with_workers(args.workers);
+    tracing::info!(
+        "Proving with {} on {}",
+        std::any::type_name::<ZkProver>(),
+        server.local_addr()
+    );
+
+    server.run();
+    Ok(())
+}
+
+/// Builds this daemon's backend for one job.
+fn backend(oracle_snapshot: OracleSnapshot, seed_salt: [u8; 32]) -> Box<dyn zk::Prover> {
+    #[cfg(feature = "stub")]
+    let prover = {
+        let _ = seed_salt;
+        ZkProver::new(oracle_snapshot)
+    };
+
+    #[cfg(not(feature = "stub"))]
+    let prover = ZkProver::new(oracle_snapshot, seed_salt);
+
+    Box::new(prover)
+}
@@ -54,6 +54,21 @@ pub enum RuntimeError {
     #[error("persistence error: {0}")]
     PersistenceError(String),
 
+    #[error("proving is not enabled")]
+    ProvingNotEnabled,
+
     #[error("blockchain integration is not enabled")]
     BlockchainNotEnabled,
+
+    #[error("proof aggregation failed")]
+    Aggregation(#[source] zk::ProofError),
+
+    #[error("network input I/O failed")]
+    Network(#[source] std::io::Error),
+
+    #[error("rewind failed at nonce {nonce}: {message}")]
+    Rewind { nonce: u64, message: String },
+
+    #[error("replay diverged: {0}")]
+    Replay(#[from] crate::providers::ReplayError),
 }
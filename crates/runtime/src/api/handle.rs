@@ -11,8 +11,10 @@ use game_core::{Action, EntityId, GameState};
 use super::errors::{Result, RuntimeError};
 use super::{ActionProvider, ProviderKind, ProviderRegistry};
 use crate::events::{Event, EventBus, Topic};
-use crate::repository::ActionBatch;
+use crate::repository::{ActionBatch, BranchId, BranchInfo, BranchSummary};
+use crate::workers::ProofQueueStatus;
 use crate::workers::persistence::Command as PersistenceCommand;
+use crate::workers::prover::Command as ProverCommand;
 use crate::workers::simulation::Command as SimulationCommand;
 
 /// Client-facing handle to interact with the runtime
@@ -29,6 +31,7 @@ use crate::workers::simulation::Command as SimulationCommand;
 pub struct RuntimeHandle {
     simulation_tx: mpsc::Sender<SimulationCommand>,
     persistence_tx: Option<mpsc::Sender<PersistenceCommand>>,
+    prover_tx: Option<mpsc::Sender<ProverCommand>>,
     event_bus: EventBus,
     providers: Arc<RwLock<ProviderRegistry>>,
     session_id: String,
@@ -42,6 +45,7 @@ impl RuntimeHandle {
     pub(crate) fn new(
         simulation_tx: mpsc::Sender<SimulationCommand>,
         persistence_tx: Option<mpsc::Sender<PersistenceCommand>>,
+        prover_tx: Option<mpsc::Sender<ProverCommand>>,
         event_bus: EventBus,
         providers: Arc<RwLock<ProviderRegistry>>,
         session_id: String,
@@ -53,6 +57,7 @@ impl RuntimeHandle {
         Self {
             simulation_tx,
             persistence_tx,
+            prover_tx,
             event_bus,
             providers,
             session_id,
@@ -95,6 +100,7 @@ impl RuntimeHandle {
     ///
     /// - `Topic::GameState` - Action execution and failures
     /// - `Topic::Proof` - ZK proof generation events
+    /// - `Topic::Game` - Domain events (damage, deaths, items, status effects)
     pub fn subscribe(&self, topic: Topic) -> broadcast::Receiver<Event> {
         self.event_bus.subscribe(topic)
     }
@@ -244,46 +250,163 @@ impl RuntimeHandle {
             .map_err(|e| RuntimeError::PersistenceError(e.to_string()))
     }
 
-    /// Restore game state from a checkpoint (fully load and replace current state).
+    /// List the session's branches with their saved states.
     ///
-    /// This combines loading state from disk and restoring it in the simulation worker.
-    /// OnChain checkpoints cannot be restored (they are finalized on-chain).
+    /// # Errors
+    ///
+    /// Returns an error if persistence is not enabled or the branch files
+    /// cannot be read.
+    pub async fn list_branches(&self) -> Result<Vec<BranchSummary>> {
+        let persistence_tx = self
+            .persistence_tx
+            .as_ref()
+            .ok_or(RuntimeError::PersistenceNotEnabled)?;
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+
+        persistence_tx
+            .send(PersistenceCommand::ListBranches { reply: reply_tx })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+
+        reply_rx
+            .await
+            .map_err(RuntimeError::ReplyChannelClosed)?
+            .map_err(|e| RuntimeError::PersistenceError(e.to_string()))
+    }
+
+    /// Rewind to any nonce of a branch's timeline and continue from there.
+    ///
+    /// The state is rebuilt from the nearest saved state at or before `nonce`
+    /// plus the logged actions after it. Nothing is truncated: unless `nonce`
+    /// is the tip of an inactive branch (which is resumed), further actions are
+    /// recorded on a new branch forked at `nonce`. Only the main branch is
+    /// proven, so finalized on-chain history is never rewritten.
+    ///
+    /// Returns the branch new actions are recorded on.
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Persistence is not enabled
-    /// - State file does not exist for the given nonce
-    /// - Checkpoint is in OnChain status (cannot roll back finalized state)
-    /// - Failed to restore state in simulation worker
+    /// - The branch does not exist or has no action logged at some nonce
+    ///   between its nearest saved state and `nonce`
+    /// - Re-executing the logged actions fails
+    pub async fn rewind(&self, branch: BranchId, nonce: u64) -> Result<BranchInfo> {
+        let persistence_tx = self
+            .persistence_tx
+            .as_ref()
+            .ok_or(RuntimeError::PersistenceNotEnabled)?;
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        persistence_tx
+            .send(PersistenceCommand::PlanRewind {
+                branch,
+                nonce,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+        let plan = reply_rx
+            .await
+            .map_err(RuntimeError::ReplyChannelClosed)?
+            .map_err(|e| RuntimeError::PersistenceError(e.to_string()))?;
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.simulation_tx
+            .send(SimulationCommand::Reconstruct {
+                base: Box::new(plan.base),
+                actions: plan.actions,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+        let state = reply_rx.await.map_err(RuntimeError::ReplyChannelClosed)??;
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        persistence_tx
+            .send(PersistenceCommand::SwitchBranch {
+                branch,
+                state: Box::new(state.clone()),
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+        let info = reply_rx
+            .await
+            .map_err(RuntimeError::ReplyChannelClosed)?
+            .map_err(|e| RuntimeError::PersistenceError(e.to_string()))?;
+
+        self.replace_state(state).await?;
+        Ok(info)
+    }
+
+    /// Restore the state at `nonce` of the active branch.
+    ///
+    /// Shorthand for [`rewind`](Self::rewind) on the active branch, kept for
+    /// callers that predate branches. Actions taken afterwards are recorded on
+    /// a new branch instead of overwriting the old timeline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`rewind`](Self::rewind).
     pub async fn restore_state(&self, nonce: u64) -> Result<()> {
-        use crate::repository::ActionBatchStatus;
+        let active = self
+            .list_branches()
+            .await?
+            .into_iter()
+            .find(|branch| branch.active)
+            .ok_or_else(|| RuntimeError::PersistenceError("No active branch".to_string()))?;
+
+        self.rewind(active.info.id, nonce).await.map(|_| ())
+    }
 
-        // First, check if this checkpoint is OnChain (cannot restore)
-        let checkpoints = self.list_all_checkpoints().await?;
-        if let Some(batch) = checkpoints.iter().find(|b| b.end_nonce == nonce)
-            && matches!(
-                batch.status,
-                ActionBatchStatus::OnChain { .. } | ActionBatchStatus::SubmittingOnchain { .. }
-            )
-        {
-            return Err(RuntimeError::PersistenceError(format!(
-                "Cannot restore checkpoint at nonce {} (on-chain or being submitted)",
-                nonce
-            )));
-        }
+    /// Rewind the active branch to the start of `entity`'s previous turn.
+    ///
+    /// Returns `None` (and leaves the game untouched) when `entity` has no
+    /// completed turn to undo.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`rewind`](Self::rewind).
+    pub async fn undo_turn(&self, entity: EntityId) -> Result<Option<BranchInfo>> {
+        let persistence_tx = self
+            .persistence_tx
+            .as_ref()
+            .ok_or(RuntimeError::PersistenceNotEnabled)?;
 
-        // Load the state from disk
-        let state = self.load_state(nonce).await?.ok_or_else(|| {
-            RuntimeError::PersistenceError(format!("No state found at nonce {}", nonce))
-        })?;
+        let current = self.query_state().await?.nonce();
+        let (reply_tx, reply_rx) = oneshot::channel();
+        persistence_tx
+            .send(PersistenceCommand::LastTurnStart {
+                entity,
+                before: current,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+        let (active, start) = reply_rx
+            .await
+            .map_err(RuntimeError::ReplyChannelClosed)?
+            .map_err(|e| RuntimeError::PersistenceError(e.to_string()))?;
 
-        // Then, restore it in the simulation worker
+        match start {
+            Some(nonce) => self.rewind(active, nonce).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Replace the simulation worker's state with the given state.
+    ///
+    /// Unlike [`rewind`](Self::rewind) this does not go through
+    /// persistence, so it also works for runtimes without a save directory
+    /// (e.g. session replays). Publishes `StateRestored` like a regular load.
+    pub async fn replace_state(&self, state: GameState) -> Result<()> {
         let (reply_tx, reply_rx) = oneshot::channel();
 
         self.simulation_tx
             .send(SimulationCommand::RestoreState {
-                state,
+                state: Box::new(state),
                 reply: reply_tx,
             })
             .await
@@ -330,10 +453,73 @@ impl RuntimeHandle {
             .map_err(|e| RuntimeError::PersistenceError(e.to_string()))
     }
 
+    /// Report the state of the proof job queue.
+    ///
+    /// Lists the batches waiting for a proof, being proven, waiting out a retry
+    /// backoff, and those that used up their retries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Proving is not enabled
+    /// - The prover worker has stopped
+    pub async fn proof_queue_status(&self) -> Result<ProofQueueStatus> {
+        let prover_tx = self
+            .prover_tx
+            .as_ref()
+            .ok_or(RuntimeError::ProvingNotEnabled)?;
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+
+        prover_tx
+            .send(ProverCommand::QueueStatus { reply: reply_tx })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+
+        reply_rx.await.map_err(RuntimeError::ReplyChannelClosed)
+    }
+
+    /// Fold consecutive batch proofs into a single proof.
+    ///
+    /// Runs [`zk::Prover::aggregate`] on the prover worker's backend. `proofs`
+    /// must be consecutive main-branch batches in nonce order; the result can
+    /// be verified once instead of once per batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Proving is not enabled
+    /// - The prover worker has stopped
+    /// - The proofs do not form a chain, or the backend cannot aggregate
+    pub async fn aggregate_proofs(&self, proofs: Vec<zk::ProofData>) -> Result<zk::ProofData> {
+        let prover_tx = self
+            .prover_tx
+            .as_ref()
+            .ok_or(RuntimeError::ProvingNotEnabled)?;
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+
+        prover_tx
+            .send(ProverCommand::Aggregate {
+                proofs,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)?;
+
+        reply_rx
+            .await
+            .map_err(RuntimeError::ReplyChannelClosed)?
+            .map_err(RuntimeError::Aggregation)
+    }
+
     /// Read action log for a specific batch.
     ///
-    /// Returns the raw action log bytes for the batch starting at start_nonce.
-    /// This is used for Walrus upload in the manual workflow.
+    /// Returns the published action log for the batch starting at start_nonce:
+    /// a bincode-serialized `Vec<PublishedAction>` in which actions that would
+    /// leak hidden state are replaced by blinded commitments. This is what the
+    /// batch proof's `actions_root` commits to, and what gets uploaded to
+    /// Walrus in the manual workflow.
     ///
     /// # Errors
     ///
@@ -495,7 +681,7 @@ impl RuntimeHandle {
         )
         .await?;
 
-        // 4. Get action log
+        // 4. Get the published (redacted) action log
         let action_log = self.get_action_log(start_nonce).await?;
 
         // 5. Upload to Walrus
@@ -588,35 +774,10 @@ impl RuntimeHandle {
         };
 
         // 3. Load proof file from disk using batch's proof filename
-        let session_dir = self.base_dir.join(&self.session_id);
-        let proof_file = batch.proof_filename();
-        let proof_path = session_dir.join("proofs").join(&proof_file);
+        let proof_data = self.load_proof(&batch)?;
 
-        let proof_bytes = std::fs::read(&proof_path).map_err(|e| {
-            RuntimeError::PersistenceError(format!(
-                "Failed to read proof file {}: {}",
-                proof_path.display(),
-                e
-            ))
-        })?;
-
-        // Deserialize proof data
-        let proof_data: zk::ProofData = bincode::deserialize(&proof_bytes).map_err(|e| {
-            RuntimeError::PersistenceError(format!("Failed to deserialize proof: {}", e))
-        })?;
-
-        // 4. Load action log from disk
-        let action_log_path = session_dir
-            .join("actions")
-            .join(batch.action_log_filename());
-
-        let action_log_bytes = std::fs::read(&action_log_path).map_err(|e| {
-            RuntimeError::PersistenceError(format!(
-                "Failed to read action log {}: {}",
-                action_log_path.display(),
-                e
-            ))
-        })?;
+        // 4. Get the published action log, as uploaded to Walrus
+        let action_log_bytes = self.get_action_log(start_nonce).await?;
 
         // 5. Update status to SubmittingOnchain
         self.update_batch_status(
@@ -674,6 +835,148 @@ impl RuntimeHandle {
         Ok(tx_digest.as_str().to_string())
     }
 
+    /// Submit every uploaded batch after the on-chain state with one aggregate proof.
+    ///
+    /// Takes the main-branch batches that follow the last on-chain batch and
+    /// are uploaded to Walrus, folds their proofs with [`Self::aggregate_proofs`]
+    /// and calls `game_session::update_aggregate`, so the whole run is
+    /// finalized in a single transaction instead of one per batch.
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction digest on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Blockchain integration or proving is not enabled
+    /// - The first batch after the on-chain state is not in BlobUploaded status
+    /// - A proof file cannot be read, or the proofs cannot be aggregated
+    /// - Blockchain submission fails
+    #[cfg(feature = "sui")]
+    pub async fn submit_aggregate_to_blockchain(&self) -> Result<String> {
+        use crate::repository::ActionBatchStatus;
+        use client_blockchain_sui::core::{ProofSubmission, SessionId};
+
+        let clients = self
+            .blockchain_clients
+            .as_ref()
+            .ok_or(RuntimeError::BlockchainNotEnabled)?;
+
+        // 1. Collect the uploaded run that continues the on-chain state
+        let mut batches = self.list_all_checkpoints().await?;
+        batches.sort_by_key(|batch| batch.start_nonce);
+        let run: Vec<(ActionBatch, String, String)> = batches
+            .into_iter()
+            .skip_while(ActionBatch::is_onchain)
+            .map_while(|batch| match batch.status.clone() {
+                ActionBatchStatus::BlobUploaded {
+                    blob_object_id,
+                    walrus_blob_id,
+                } => Some((batch, blob_object_id, walrus_blob_id)),
+                _ => None,
+            })
+            .collect();
+        if run.is_empty() {
+            return Err(RuntimeError::InvalidConfig(
+                "No uploaded batch follows the on-chain state; upload to Walrus first".to_string(),
+            ));
+        }
+
+        // 2. Load every proof; the contract checks that the batch journals chain
+        let mut proofs = Vec::with_capacity(run.len());
+        let mut blobs = Vec::with_capacity(run.len());
+        for (batch, blob_object_id, _) in &run {
+            let proof = self.load_proof(batch)?;
+            blobs.push((proof.journal.clone(), blob_object_id.clone()));
+            proofs.push(proof);
+        }
+
+        // 3. Fold the batch proofs into one
+        let aggregate = self.aggregate_proofs(proofs).await?;
+        let proof_submission =
+            ProofSubmission::from_proof_data(&aggregate, Vec::new()).map_err(|e| {
+                RuntimeError::PersistenceError(format!("Failed to create proof submission: {}", e))
+            })?;
+
+        // 4. Update statuses to SubmittingOnchain
+        for (batch, blob_object_id, walrus_blob_id) in &run {
+            self.update_batch_status(
+                batch.start_nonce,
+                ActionBatchStatus::SubmittingOnchain {
+                    blob_object_id: blob_object_id.clone(),
+                    walrus_blob_id: walrus_blob_id.clone(),
+                },
+            )
+            .await?;
+        }
+
+        // 5. Submit to blockchain
+        let session_id = SessionId::new(self.session_id.clone());
+        let tx_digest = match clients
+            .sui
+            .update_session_aggregate(&session_id, proof_submission, &blobs)
+            .await
+        {
+            Ok(digest) => digest,
+            Err(e) => {
+                // Revert statuses back to BlobUploaded on failure
+                for (batch, blob_object_id, walrus_blob_id) in &run {
+                    let _ = self
+                        .update_batch_status(
+                            batch.start_nonce,
+                            ActionBatchStatus::BlobUploaded {
+                                blob_object_id: blob_object_id.clone(),
+                                walrus_blob_id: walrus_blob_id.clone(),
+                            },
+                        )
+                        .await;
+                }
+                return Err(RuntimeError::PersistenceError(format!(
+                    "Failed to submit aggregate to blockchain: {}",
+                    e
+                )));
+            }
+        };
+
+        // 6. Update statuses to OnChain
+        for (batch, blob_object_id, walrus_blob_id) in run {
+            self.update_batch_status(
+                batch.start_nonce,
+                ActionBatchStatus::OnChain {
+                    blob_object_id,
+                    walrus_blob_id,
+                    tx_digest: tx_digest.as_str().to_string(),
+                },
+            )
+            .await?;
+        }
+
+        Ok(tx_digest.as_str().to_string())
+    }
+
+    /// Read and deserialize the proof file of a proven batch.
+    #[cfg(feature = "sui")]
+    fn load_proof(&self, batch: &ActionBatch) -> Result<zk::ProofData> {
+        let proof_path = self
+            .base_dir
+            .join(&self.session_id)
+            .join("proofs")
+            .join(batch.proof_filename());
+
+        let proof_bytes = std::fs::read(&proof_path).map_err(|e| {
+            RuntimeError::PersistenceError(format!(
+                "Failed to read proof file {}: {}",
+                proof_path.display(),
+                e
+            ))
+        })?;
+
+        bincode::deserialize(&proof_bytes).map_err(|e| {
+            RuntimeError::PersistenceError(format!("Failed to deserialize proof: {}", e))
+        })
+    }
+
     /// Create a new session on blockchain.
     ///
     /// Creates a new GameSession on-chain using state 0 (initial state) and saves
@@ -0,0 +1,403 @@
+//! Headless batch simulation for balance testing.
+//!
+//! [`BatchSimulation`] plays many seeded games of a [`Scenario`] without a
+//! frontend: the player is bound to the utility AI like every NPC, and each
+//! game runs until the player dies, every hostile actor is dead, or the player
+//! has taken [`BatchConfig::max_turns`] turns. Per-game [`GameOutcome`]s are
+//! folded into a [`BatchReport`] with win rate, turns survived, damage by
+//! [`ActionKind`] and deaths by [`Species`].
+//!
+//! Game `i` uses `base_seed + i` as its game seed, so a batch is reproducible
+//! and a single interesting game can be re-run on its own with
+//! [`BatchSimulation::run_game`]. Persistence and proving are off unless
+//! enabled in the [`BatchConfig`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use game_core::{
+    Action, ActionKind, AiKind, AiTurnAction, CharacterAction, EntityId, Faction, GameState,
+    ProviderKind, Species, SystemActionKind,
+};
+use serde::Serialize;
+use tokio::sync::broadcast::error::TryRecvError;
+use tokio::task::JoinSet;
+
+use crate::api::{Result, RuntimeError};
+use crate::events::{Event, GameEvent, GameStateEvent, Topic, extract_events};
+use crate::oracle::OracleBundle;
+use crate::providers::ai::UtilityAiProvider;
+use crate::runtime::{PersistenceSettings, ProvingSettings, Runtime, RuntimeConfig};
+use crate::scenario::Scenario;
+
+/// Settings for a batch of simulated games.
+#[derive(Debug, Clone)]
+pub struct BatchConfig {
+    /// Number of games to play.
+    pub games: u32,
+    /// Game seed of the first game; game `i` uses `base_seed + i`.
+    pub base_seed: u64,
+    /// Player turns after which a game is stopped undecided.
+    pub max_turns: u32,
+    /// Games played at the same time.
+    pub parallelism: usize,
+    /// Persistence settings of every game (default: disabled).
+    pub persistence: PersistenceSettings,
+    /// Proving settings of every game (default: disabled).
+    pub proving: ProvingSettings,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            games: 100,
+            base_seed: 0,
+            max_turns: 500,
+            parallelism: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            persistence: PersistenceSettings::default(),
+            proving: ProvingSettings::default(),
+        }
+    }
+}
+
+/// How a simulated game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameResult {
+    /// Every actor hostile to the player died.
+    Won,
+    /// The player died.
+    Lost,
+    /// The turn limit was reached first.
+    TurnLimit,
+}
+
+/// Statistics of one simulated game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameOutcome {
+    /// Game seed the game was played with.
+    pub seed: u64,
+    pub result: GameResult,
+    /// Turns taken by the player.
+    pub turns: u32,
+    /// Damage dealt by the player, by action kind.
+    pub player_damage: BTreeMap<ActionKind, u64>,
+    /// Damage dealt by every other actor, by action kind.
+    pub npc_damage: BTreeMap<ActionKind, u64>,
+    /// Actors that died, by species (the player included).
+    pub deaths: BTreeMap<Species, u32>,
+}
+
+/// Aggregate statistics of a batch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BatchReport {
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub turn_limits: u32,
+    /// Turns survived: fewest, mean and most player turns per game.
+    pub min_turns: u32,
+    pub mean_turns: f64,
+    pub max_turns: u32,
+    /// Total damage dealt by the player, by action kind.
+    pub player_damage: BTreeMap<ActionKind, u64>,
+    /// Total damage dealt by every other actor, by action kind.
+    pub npc_damage: BTreeMap<ActionKind, u64>,
+    /// Total deaths by species.
+    pub deaths: BTreeMap<Species, u32>,
+    /// Per-game outcomes in seed order.
+    pub outcomes: Vec<GameOutcome>,
+}
+
+impl BatchReport {
+    /// Fold per-game outcomes into a report.
+    pub fn from_outcomes(outcomes: Vec<GameOutcome>) -> Self {
+        let mut report = Self {
+            games: outcomes.len() as u32,
+            min_turns: outcomes.iter().map(|o| o.turns).min().unwrap_or(0),
+            max_turns: outcomes.iter().map(|o| o.turns).max().unwrap_or(0),
+            ..Self::default()
+        };
+
+        let mut total_turns = 0u64;
+        for outcome in &outcomes {
+            match outcome.result {
+                GameResult::Won => report.wins += 1,
+                GameResult::Lost => report.losses += 1,
+                GameResult::TurnLimit => report.turn_limits += 1,
+            }
+            total_turns += u64::from(outcome.turns);
+            merge(&mut report.player_damage, &outcome.player_damage);
+            merge(&mut report.npc_damage, &outcome.npc_damage);
+            for (species, count) in &outcome.deaths {
+                *report.deaths.entry(*species).or_default() += count;
+            }
+        }
+        if report.games > 0 {
+            report.mean_turns = total_turns as f64 / f64::from(report.games);
+        }
+
+        report.outcomes = outcomes;
+        report
+    }
+
+    /// Share of games won, between 0 and 1.
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            f64::from(self.wins) / f64::from(self.games)
+        }
+    }
+}
+
+fn merge(total: &mut BTreeMap<ActionKind, u64>, damage: &BTreeMap<ActionKind, u64>) {
+    for (kind, amount) in damage {
+        *total.entry(*kind).or_default() += amount;
+    }
+}
+
+impl fmt::Display for BatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Games:          {}", self.games)?;
+        writeln!(
+            f,
+            "Win rate:       {:.1}% ({} won, {} lost, {} hit the turn limit)",
+            self.win_rate() * 100.0,
+            self.wins,
+            self.losses,
+            self.turn_limits
+        )?;
+        writeln!(
+            f,
+            "Turns survived: min {} / mean {:.1} / max {}",
+            self.min_turns, self.mean_turns, self.max_turns
+        )?;
+
+        writeln!(f, "Damage by action (player / npcs):")?;
+        let kinds: std::collections::BTreeSet<_> = self
+            .player_damage
+            .keys()
+            .chain(self.npc_damage.keys())
+            .collect();
+        for kind in kinds {
+            writeln!(
+                f,
+                "  {:<16} {:>10} / {:>10}",
+                format!("{kind:?}"),
+                self.player_damage.get(kind).copied().unwrap_or(0),
+                self.npc_damage.get(kind).copied().unwrap_or(0)
+            )?;
+        }
+
+        writeln!(f, "Deaths by species:")?;
+        for (species, count) in &self.deaths {
+            writeln!(f, "  {:<16} {:>10}", species.to_string(), count)?;
+        }
+        Ok(())
+    }
+}
+
+/// Plays seeded games of a scenario without a frontend.
+#[derive(Clone)]
+pub struct BatchSimulation {
+    oracles: OracleBundle,
+    scenario: Arc<Scenario>,
+    config: BatchConfig,
+}
+
+impl BatchSimulation {
+    pub fn new(oracles: OracleBundle, scenario: Scenario, config: BatchConfig) -> Self {
+        Self {
+            oracles,
+            scenario: Arc::new(scenario),
+            config,
+        }
+    }
+
+    /// Play every game of the batch and aggregate the outcomes.
+    pub async fn run(&self) -> Result<BatchReport> {
+        let seeds = (0..self.config.games).map(|i| self.config.base_seed + u64::from(i));
+        let mut seeds = seeds.peekable();
+        let mut running = JoinSet::new();
+        let mut outcomes = Vec::with_capacity(self.config.games as usize);
+
+        loop {
+            while running.len() < self.config.parallelism.max(1)
+                && let Some(seed) = seeds.next()
+            {
+                let simulation = self.clone();
+                running.spawn(async move { simulation.run_game(seed).await });
+            }
+
+            match running.join_next().await {
+                Some(joined) => outcomes.push(joined.map_err(RuntimeError::WorkerJoin)??),
+                None => break,
+            }
+            if outcomes.len() % 100 == 0 {
+                tracing::info!("Simulated {}/{} games", outcomes.len(), self.config.games);
+            }
+        }
+
+        outcomes.sort_by_key(|outcome| outcome.seed);
+        Ok(BatchReport::from_outcomes(outcomes))
+    }
+
+    /// Play a single game with the given game seed.
+    pub async fn run_game(&self, seed: u64) -> Result<GameOutcome> {
+        let mut state = self.scenario.create_initial_state(&self.oracles)?;
+        state.game_seed = seed;
+
+        let ai_kind = ProviderKind::Ai(AiKind::Utility);
+        let mut runtime = Runtime::builder()
+            .config(RuntimeConfig {
+                session_id: format!("batch_{seed}"),
+                ..RuntimeConfig::default()
+            })
+            .persistence(self.config.persistence.clone())
+            .proving(self.config.proving.clone())
+            .oracles(self.oracles.clone())
+            .initial_state(state)
+            .provider(ai_kind, UtilityAiProvider::new())
+            .default_provider(ai_kind)
+            .entity_provider(EntityId::PLAYER, ai_kind)
+            .build()
+            .await?;
+        let mut events = runtime.handle().subscribe(Topic::GameState);
+
+        let mut outcome = GameOutcome {
+            seed,
+            result: GameResult::TurnLimit,
+            turns: 0,
+            player_damage: BTreeMap::new(),
+            npc_damage: BTreeMap::new(),
+            deaths: BTreeMap::new(),
+        };
+
+        while outcome.turns < self.config.max_turns {
+            runtime.step().await?;
+
+            let mut latest = None;
+            loop {
+                match events.try_recv() {
+                    Ok(Event::GameState(GameStateEvent::ActionExecuted {
+                        action,
+                        delta,
+                        before_state,
+                        after_state,
+                        action_result,
+                        ..
+                    })) => {
+                        if let Some((actor, kind)) = self.character_action(&action, &before_state) {
+                            let damage = if actor == EntityId::PLAYER {
+                                outcome.turns += 1;
+                                &mut outcome.player_damage
+                            } else {
+                                &mut outcome.npc_damage
+                            };
+                            let dealt = action_result.summary.total_damage;
+                            if dealt > 0 {
+                                *damage.entry(kind).or_default() += u64::from(dealt);
+                            }
+                        }
+
+                        for event in extract_events(&delta, &before_state, &after_state) {
+                            if let GameEvent::EntityDied { entity, .. } = event
+                                && let Some(actor) = before_state.entities.actor(entity)
+                            {
+                                *outcome.deaths.entry(actor.species).or_default() += 1;
+                            }
+                        }
+                        latest = Some(after_state);
+                    }
+                    Ok(_) => {}
+                    Err(TryRecvError::Lagged(skipped)) => {
+                        tracing::warn!("Batch game {} dropped {} events", seed, skipped);
+                    }
+                    Err(TryRecvError::Empty | TryRecvError::Closed) => break,
+                }
+            }
+
+            if let Some(result) = latest.as_deref().and_then(decided) {
+                outcome.result = result;
+                break;
+            }
+        }
+
+        runtime.shutdown().await?;
+        Ok(outcome)
+    }
+
+    /// Actor and kind of the character action behind a logged action.
+    ///
+    /// Provable AI turns are logged as a system action; the character action
+    /// they executed is derived again from the state before the turn.
+    fn character_action(
+        &self,
+        action: &Action,
+        before: &GameState,
+    ) -> Option<(EntityId, ActionKind)> {
+        match action {
+            Action::Character(CharacterAction { actor, kind, .. }) => Some((*actor, *kind)),
+            Action::System {
+                kind: SystemActionKind::AiTurn(_),
+            } => {
+                let resolved = AiTurnAction::resolve(before, &self.oracles.as_game_env());
+                Some((resolved.actor, resolved.kind))
+            }
+            Action::System { .. } => None,
+        }
+    }
+}
+
+/// Result of a finished game, or `None` while it is still undecided.
+fn decided(state: &GameState) -> Option<GameResult> {
+    let player_alive = state
+        .entities
+        .actor(EntityId::PLAYER)
+        .is_some_and(|player| player.is_alive());
+    if !player_alive {
+        return Some(GameResult::Lost);
+    }
+
+    let hostiles_left = state
+        .entities
+        .actors
+        .iter()
+        .any(|actor| actor.is_alive() && actor.faction.is_hostile_to(&Faction::Player));
+    (!hostiles_left).then_some(GameResult::Won)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(seed: u64, result: GameResult, turns: u32) -> GameOutcome {
+        GameOutcome {
+            seed,
+            result,
+            turns,
+            player_damage: BTreeMap::from([(ActionKind::MeleeAttack, 10)]),
+            npc_damage: BTreeMap::new(),
+            deaths: BTreeMap::from([(Species::Goblin, 2)]),
+        }
+    }
+
+    #[test]
+    fn report_aggregates_outcomes() {
+        let report = BatchReport::from_outcomes(vec![
+            outcome(0, GameResult::Won, 40),
+            outcome(1, GameResult::Lost, 10),
+            outcome(2, GameResult::TurnLimit, 100),
+            outcome(3, GameResult::Won, 50),
+        ]);
+
+        assert_eq!((report.wins, report.losses, report.turn_limits), (2, 1, 1));
+        assert_eq!(report.win_rate(), 0.5);
+        assert_eq!((report.min_turns, report.max_turns), (10, 100));
+        assert_eq!(report.mean_turns, 50.0);
+        assert_eq!(report.player_damage[&ActionKind::MeleeAttack], 40);
+        assert_eq!(report.deaths[&Species::Goblin], 8);
+    }
+}
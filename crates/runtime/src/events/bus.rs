@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{RwLock, broadcast};
 
+use super::game_event::GameEvent;
 use super::types::{ActionRef, GameStateEvent, ProofEvent};
 
 /// Topics for event routing
@@ -14,6 +15,8 @@ pub enum Topic {
     GameState,
     /// ZK proof events
     Proof,
+    /// Domain events (damage, deaths, items, status effects)
+    Game,
 }
 
 /// Event wrapper that carries the topic and typed event
@@ -28,6 +31,12 @@ pub enum Event {
     /// without duplicating the full action data. The full `ActionLogEntry` can be
     /// retrieved from actions.log using the `action_offset`.
     ActionRef(ActionRef),
+
+    /// Domain event describing part of an executed action.
+    ///
+    /// Published after the `ActionExecuted` event it was extracted from, so
+    /// frontends can react without diffing whole states.
+    Game(GameEvent),
 }
 
 impl Event {
@@ -36,6 +45,7 @@ impl Event {
             Event::GameState(_) => Topic::GameState,
             Event::Proof(_) => Topic::Proof,
             Event::ActionRef(_) => Topic::GameState, // ActionRef belongs to GameState topic
+            Event::Game(_) => Topic::Game,
         }
     }
 }
@@ -61,6 +71,7 @@ impl EventBus {
         // Pre-create channels for each topic
         channels.insert(Topic::GameState, broadcast::channel(capacity).0);
         channels.insert(Topic::Proof, broadcast::channel(capacity).0);
+        channels.insert(Topic::Game, broadcast::channel(capacity).0);
 
         Self {
             channels: Arc::new(RwLock::new(channels)),
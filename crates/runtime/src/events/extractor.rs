@@ -3,7 +3,10 @@
 //! This module provides functions to convert low-level StateDelta into
 //! high-level GameEvent instances.
 
-use game_core::{ActorFields, GameState, StateDelta, SystemActionKind};
+use game_core::{
+    Action, ActionResult, ActorFields, ActorState, EntityId, GameState, PropFields, StateDelta,
+    SystemActionKind, action::AppliedValue,
+};
 
 use super::game_event::{GameEvent, HealthThreshold};
 
@@ -106,6 +109,16 @@ pub fn extract_events(
             let new_hp = actor_after.resources.hp;
 
             if new_hp != old_hp {
+                if new_hp > old_hp {
+                    events.push(GameEvent::Healed {
+                        entity: actor_change.id,
+                        amount: new_hp - old_hp,
+                        hp_before: old_hp,
+                        hp_after: new_hp,
+                        source: Some(delta.action.actor()),
+                    });
+                }
+
                 if new_hp < old_hp {
                     // Damage taken
                     events.push(GameEvent::DamageTaken {
@@ -166,6 +179,36 @@ pub fn extract_events(
             }
         }
 
+        // Check for level-ups
+        if actor_change.fields.contains(ActorFields::CORE_STATS)
+            && actor_after.core_stats.level > actor_before.core_stats.level
+        {
+            events.push(GameEvent::LeveledUp {
+                entity: actor_change.id,
+                level: actor_after.core_stats.level,
+            });
+        }
+
+        if actor_change.fields.contains(ActorFields::STATUS) {
+            status_events(actor_before, actor_after, &mut events);
+        }
+
+        // Check for newly equipped weapon or armor
+        if actor_change.fields.contains(ActorFields::EQUIPMENT) {
+            let before = &actor_before.equipment;
+            let after = &actor_after.equipment;
+            for (old, new) in [(before.weapon, after.weapon), (before.armor, after.armor)] {
+                if let Some(item) = new
+                    && old != new
+                {
+                    events.push(GameEvent::ItemEquipped {
+                        entity: actor_change.id,
+                        item,
+                    });
+                }
+            }
+        }
+
         // Check for ready_at changes
         if actor_change.fields.contains(ActorFields::READY_AT) {
             let old_ready_at = actor_before.ready_at;
@@ -181,5 +224,178 @@ pub fn extract_events(
         }
     }
 
+    // Analyze prop changes (doors opened, switches flipped)
+    for prop_change in &delta.entities.props.updated {
+        if !prop_change.fields.contains(PropFields::IS_ACTIVE) {
+            continue;
+        }
+        if let Some(prop) = state_after.entities.prop(prop_change.id) {
+            events.push(GameEvent::PropStateChanged {
+                prop: prop.id,
+                kind: prop.kind.clone(),
+                is_active: prop.is_active,
+            });
+        }
+    }
+
     events
 }
+
+/// Extract game events that only the action result knows about.
+///
+/// Crits, misses and item usage are not visible in the state delta, so they
+/// are derived from the effect results of the executed action instead.
+pub fn extract_result_events(
+    action: &Action,
+    result: &ActionResult,
+    state_before: &GameState,
+) -> Vec<GameEvent> {
+    // AI turns execute as system actions on behalf of the current actor
+    let actor = match action {
+        Action::Character(action) => action.actor,
+        Action::System {
+            kind: SystemActionKind::AiTurn(_),
+        } => state_before.turn.current_actor,
+        Action::System { .. } => EntityId::SYSTEM,
+    };
+
+    result
+        .effects
+        .iter()
+        .filter_map(|effect| match effect.applied_value {
+            AppliedValue::Damage { .. } if !effect.success => Some(GameEvent::AttackMissed {
+                attacker: actor,
+                target: effect.target,
+            }),
+            AppliedValue::Damage { actual, .. } if effect.flags.critical => {
+                Some(GameEvent::CriticalHit {
+                    attacker: actor,
+                    target: effect.target,
+                    damage: actual,
+                })
+            }
+            AppliedValue::ItemAcquired {
+                handle, quantity, ..
+            } => Some(GameEvent::ItemPickedUp {
+                entity: actor,
+                item: handle,
+                quantity,
+            }),
+            AppliedValue::ItemUsed { handle, .. } => Some(GameEvent::ItemUsed {
+                entity: actor,
+                item: handle,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Status effects applied (or refreshed) and ended between two actor states.
+fn status_events(before: &ActorState, after: &ActorState, events: &mut Vec<GameEvent>) {
+    for effect in after.status_effects.iter() {
+        if !before.status_effects.iter().any(|old| old == effect) {
+            events.push(GameEvent::StatusApplied {
+                entity: after.id,
+                status: effect.kind,
+                expires_at: effect.expires_at,
+            });
+        }
+    }
+
+    for effect in before.status_effects.iter() {
+        if !after
+            .status_effects
+            .iter()
+            .any(|new| new.kind == effect.kind)
+        {
+            events.push(GameEvent::StatusExpired {
+                entity: after.id,
+                status: effect.kind,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::action::EffectResult;
+    use game_core::{ActionInput, ActionKind, CharacterAction, ItemHandle, StatusEffectKind};
+
+    use super::*;
+
+    #[test]
+    fn extracts_domain_events_from_delta_and_result() {
+        let mut before = GameState::with_player();
+        before.entities.player_mut().resources.hp = 5;
+        let mut after = before.clone();
+        let player = after.entities.player_mut();
+        player.resources.hp = 9;
+        player.core_stats.level += 1;
+        player.status_effects.add(StatusEffectKind::Poisoned, 50);
+
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::MeleeAttack,
+            ActionInput::Target(EntityId(3)),
+        ));
+        let delta = StateDelta::from_states(action.clone(), &before, &after);
+        let events = extract_events(&delta, &before, &after);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            GameEvent::Healed {
+                amount: 4,
+                hp_after: 9,
+                ..
+            }
+        )));
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, GameEvent::LeveledUp { level: 2, .. }))
+        );
+        assert!(events.iter().any(|event| matches!(
+            event,
+            GameEvent::StatusApplied {
+                status: StatusEffectKind::Poisoned,
+                expires_at: 50,
+                ..
+            }
+        )));
+
+        let hit = AppliedValue::Damage {
+            planned: 4,
+            actual: 4,
+        };
+        let result = ActionResult::with_summary(
+            vec![
+                EffectResult::new(EntityId(3), hit.clone()).with_critical(),
+                EffectResult::new(EntityId(4), hit).with_failure(),
+                EffectResult::new(
+                    EntityId::PLAYER,
+                    AppliedValue::ItemAcquired {
+                        item_id: EntityId(7),
+                        handle: ItemHandle(2),
+                        quantity: 3,
+                    },
+                ),
+            ],
+            Default::default(),
+        );
+        let events = extract_result_events(&action, &result, &before);
+        assert!(matches!(
+            events[..],
+            [
+                GameEvent::CriticalHit { damage: 4, .. },
+                GameEvent::AttackMissed {
+                    attacker: EntityId::PLAYER,
+                    ..
+                },
+                GameEvent::ItemPickedUp {
+                    entity: EntityId::PLAYER,
+                    item: ItemHandle(2),
+                    quantity: 3,
+                }
+            ]
+        ));
+    }
+}
@@ -2,15 +2,17 @@
 //!
 //! Events represent high-level occurrences in the game (entity died, damage taken, etc.)
 //! extracted from low-level state deltas. Event handlers react to these events to
-//! generate system actions.
+//! generate system actions, and the simulation worker publishes them on
+//! [`Topic::Game`](super::Topic::Game) for frontends.
 
-use game_core::{Action, EntityId, Position, Tick};
+use game_core::{Action, EntityId, ItemHandle, Position, PropKind, StatusEffectKind, Tick};
+use serde::{Deserialize, Serialize};
 
 /// High-level game events extracted from StateDelta.
 ///
 /// Events are computed from deltas after each action execution and are used
 /// by event handlers to generate reactive system actions.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GameEvent {
     /// An action was completed (always emitted for non-system actions).
     ActionCompleted {
@@ -28,6 +30,34 @@ pub enum GameEvent {
         source: Option<EntityId>,
     },
 
+    /// An entity regained HP.
+    Healed {
+        entity: EntityId,
+        amount: u32,
+        hp_before: u32,
+        hp_after: u32,
+        source: Option<EntityId>,
+    },
+
+    /// An attack dealt a critical hit.
+    ///
+    /// Emitted for damage effects flagged `critical`, i.e. hits of damage
+    /// effects that `can_crit` whose critical roll succeeded.
+    CriticalHit {
+        attacker: EntityId,
+        target: EntityId,
+        damage: u32,
+    },
+
+    /// An attack failed to connect.
+    ///
+    /// Emitted for damage effects that report failure, i.e. damage effects
+    /// that `can_miss` whose hit roll failed.
+    AttackMissed {
+        attacker: EntityId,
+        target: EntityId,
+    },
+
     /// An entity died (HP reached 0).
     EntityDied {
         entity: EntityId,
@@ -55,6 +85,42 @@ pub enum GameEvent {
         hp_percent: u32,
     },
 
+    /// A status effect was applied or refreshed on an entity.
+    StatusApplied {
+        entity: EntityId,
+        status: StatusEffectKind,
+        expires_at: Tick,
+    },
+
+    /// A status effect ended (expired or was removed).
+    StatusExpired {
+        entity: EntityId,
+        status: StatusEffectKind,
+    },
+
+    /// An entity picked an item up from the ground.
+    ItemPickedUp {
+        entity: EntityId,
+        item: ItemHandle,
+        quantity: u16,
+    },
+
+    /// An entity used an item from its inventory.
+    ItemUsed { entity: EntityId, item: ItemHandle },
+
+    /// An entity equipped an item as weapon or armor.
+    ItemEquipped { entity: EntityId, item: ItemHandle },
+
+    /// An entity gained a character level.
+    LeveledUp { entity: EntityId, level: i32 },
+
+    /// A prop (door, switch, hazard) was activated or deactivated.
+    PropStateChanged {
+        prop: EntityId,
+        kind: PropKind,
+        is_active: bool,
+    },
+
     /// An entity's ready_at timestamp was updated.
     ReadyAtUpdated {
         entity: EntityId,
@@ -64,7 +130,7 @@ pub enum GameEvent {
 }
 
 /// Health threshold levels for triggering effects.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HealthThreshold {
     /// 100% HP
     Full,
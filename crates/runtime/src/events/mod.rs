@@ -6,11 +6,17 @@
 mod bus;
 mod extractor;
 mod game_event;
+mod projection;
+mod stream;
 mod types;
 
 pub use bus::{Event, EventBus, Topic};
-pub use extractor::extract_events;
+pub use extractor::{extract_events, extract_result_events};
 pub use game_event::{GameEvent, HealthThreshold};
+pub use projection::{
+    Achievement, Achievements, EntityView, Projection, Projector, SessionStats, WorldView,
+};
+pub use stream::{EventFilter, EventStream, event_action};
 pub use types::{ActionRef, GameStateEvent, ProofEvent};
 
 // Re-export for backwards compatibility
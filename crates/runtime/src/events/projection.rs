@@ -0,0 +1,419 @@
+//! Read models rebuilt from the event log.
+//!
+//! A [`Projection`] folds events into a read model (statistics, achievements,
+//! a client's view). [`Projector`] feeds it from an [`EventRepository`] and
+//! remembers the offset it reached, so a consumer that was offline catches up
+//! from the log alone instead of querying the live runtime.
+
+use std::collections::BTreeMap;
+
+use game_core::{ActionKind, EntityId, Position, StatusEffectKind, Tick};
+
+use crate::repository::{EventRepository, Result};
+
+use super::bus::{Event, Topic};
+use super::game_event::{GameEvent, HealthThreshold};
+use super::stream::{EventFilter, EventStream, event_action};
+use super::types::{GameStateEvent, ProofEvent};
+
+/// Read model built by folding events in log order.
+pub trait Projection {
+    /// Events this projection consumes (default: all).
+    fn filter(&self) -> EventFilter {
+        EventFilter::all()
+    }
+
+    /// Fold one event into the read model.
+    fn apply(&mut self, event: &Event);
+}
+
+/// Drives a projection from an event log, tracking the offset reached.
+pub struct Projector<P> {
+    projection: P,
+    offset: u64,
+}
+
+impl<P: Projection> Projector<P> {
+    /// Project from the start of the log.
+    pub fn new(projection: P) -> Self {
+        Self::resume(projection, 0)
+    }
+
+    /// Continue a projection that already consumed the log up to `offset`.
+    pub fn resume(projection: P, offset: u64) -> Self {
+        Self { projection, offset }
+    }
+
+    /// Apply every event appended since the last call.
+    ///
+    /// Returns the number of events applied.
+    pub fn catch_up(&mut self, repo: &dyn EventRepository) -> Result<usize> {
+        let mut stream = EventStream::new(repo, self.offset).matching(self.projection.filter());
+        let mut applied = 0;
+        for entry in stream.by_ref() {
+            let (_, event) = entry?;
+            self.projection.apply(&event);
+            applied += 1;
+        }
+        self.offset = stream.offset();
+        Ok(applied)
+    }
+
+    /// Offset of the next unread event.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn projection(&self) -> &P {
+        &self.projection
+    }
+
+    pub fn into_inner(self) -> P {
+        self.projection
+    }
+}
+
+/// Session statistics: what was played, by whom, and what was proven.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionStats {
+    /// Executed actions per acting entity.
+    pub actions_by_actor: BTreeMap<EntityId, u64>,
+    /// Executed character actions per kind.
+    pub actions_by_kind: BTreeMap<ActionKind, u64>,
+    /// Executed system actions.
+    pub system_actions: u64,
+    /// Actions rejected by the engine.
+    pub failed_actions: u64,
+    /// Loads and rewinds.
+    pub restores: u64,
+    pub proofs_generated: u64,
+    pub proofs_failed: u64,
+    /// Nonce of the last executed action.
+    pub last_nonce: Option<u64>,
+    /// Game clock at the last executed action.
+    pub last_clock: Tick,
+}
+
+impl SessionStats {
+    pub fn total_actions(&self) -> u64 {
+        self.actions_by_actor.values().sum()
+    }
+
+    fn record_action(
+        &mut self,
+        nonce: u64,
+        clock: Tick,
+        actor: EntityId,
+        kind: Option<ActionKind>,
+    ) {
+        *self.actions_by_actor.entry(actor).or_default() += 1;
+        match kind {
+            Some(kind) => *self.actions_by_kind.entry(kind).or_default() += 1,
+            None => self.system_actions += 1,
+        }
+        self.last_nonce = Some(nonce);
+        self.last_clock = clock;
+    }
+}
+
+impl Projection for SessionStats {
+    fn apply(&mut self, event: &Event) {
+        match event {
+            Event::ActionRef(action_ref) => self.record_action(
+                action_ref.nonce,
+                action_ref.clock,
+                action_ref.actor,
+                action_ref.action_kind,
+            ),
+            Event::GameState(GameStateEvent::ActionExecuted { nonce, clock, .. }) => {
+                if let Some((actor, kind)) = event_action(event) {
+                    self.record_action(*nonce, *clock, actor, kind);
+                }
+            }
+            Event::GameState(GameStateEvent::ActionFailed { .. }) => self.failed_actions += 1,
+            Event::GameState(GameStateEvent::StateRestored { .. }) => self.restores += 1,
+            Event::Proof(ProofEvent::ProofGenerated { .. }) => self.proofs_generated += 1,
+            Event::Proof(ProofEvent::ProofFailed { .. }) => self.proofs_failed += 1,
+            Event::Proof(ProofEvent::ProofStarted { .. }) | Event::Game(_) => {}
+        }
+    }
+}
+
+/// Last known state of an entity, as a client would display it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntityView {
+    pub position: Option<Position>,
+    /// Unknown until the entity is damaged or healed.
+    pub hp: Option<u32>,
+    pub level: Option<i32>,
+    pub statuses: Vec<StatusEffectKind>,
+    pub dead: bool,
+}
+
+/// View model of the world rebuilt from domain events.
+///
+/// Entities appear once an event mentions them. A restore discards the view,
+/// since the log does not record the restored state.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorldView {
+    pub entities: BTreeMap<EntityId, EntityView>,
+}
+
+impl WorldView {
+    fn entity(&mut self, entity: EntityId) -> &mut EntityView {
+        self.entities.entry(entity).or_default()
+    }
+}
+
+impl Projection for WorldView {
+    fn apply(&mut self, event: &Event) {
+        let event = match event {
+            Event::Game(event) => event,
+            Event::GameState(GameStateEvent::StateRestored { .. }) => {
+                self.entities.clear();
+                return;
+            }
+            _ => return,
+        };
+
+        match event {
+            GameEvent::EntityMoved { entity, to, .. } => self.entity(*entity).position = *to,
+            GameEvent::EntityRemovedFromWorld { entity } => self.entity(*entity).position = None,
+            GameEvent::DamageTaken {
+                entity, hp_after, ..
+            }
+            | GameEvent::Healed {
+                entity, hp_after, ..
+            } => self.entity(*entity).hp = Some(*hp_after),
+            GameEvent::EntityDied { entity, .. } => {
+                let view = self.entity(*entity);
+                view.hp = Some(0);
+                view.dead = true;
+            }
+            GameEvent::LeveledUp { entity, level } => self.entity(*entity).level = Some(*level),
+            GameEvent::StatusApplied { entity, status, .. } => {
+                let statuses = &mut self.entity(*entity).statuses;
+                if !statuses.contains(status) {
+                    statuses.push(*status);
+                }
+            }
+            GameEvent::StatusExpired { entity, status } => {
+                self.entity(*entity).statuses.retain(|s| s != status);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Milestones of the player's run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Achievement {
+    /// Killed a first enemy.
+    FirstBlood,
+    /// Killed ten enemies.
+    Slayer,
+    /// Picked up ten items.
+    Collector,
+    /// Recovered after dropping to critical health.
+    NarrowEscape,
+    /// Reached level 5.
+    Veteran,
+}
+
+/// Player achievements, unlocked from domain events.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Achievements {
+    /// Unlocked achievements in unlock order.
+    pub unlocked: Vec<Achievement>,
+    kills: u64,
+    items_picked_up: u64,
+    critical: bool,
+}
+
+impl Achievements {
+    fn unlock(&mut self, achievement: Achievement) {
+        if !self.unlocked.contains(&achievement) {
+            self.unlocked.push(achievement);
+        }
+    }
+}
+
+impl Projection for Achievements {
+    fn filter(&self) -> EventFilter {
+        EventFilter::all().topic(Topic::Game)
+    }
+
+    fn apply(&mut self, event: &Event) {
+        let Event::Game(event) = event else {
+            return;
+        };
+
+        match event {
+            GameEvent::EntityDied {
+                entity,
+                killer: Some(EntityId::PLAYER),
+                ..
+            } if *entity != EntityId::PLAYER => {
+                self.kills += 1;
+                self.unlock(Achievement::FirstBlood);
+                if self.kills >= 10 {
+                    self.unlock(Achievement::Slayer);
+                }
+            }
+            GameEvent::ItemPickedUp {
+                entity: EntityId::PLAYER,
+                quantity,
+                ..
+            } => {
+                self.items_picked_up += u64::from(*quantity);
+                if self.items_picked_up >= 10 {
+                    self.unlock(Achievement::Collector);
+                }
+            }
+            GameEvent::HealthThresholdCrossed {
+                entity: EntityId::PLAYER,
+                threshold,
+                ..
+            } => match threshold {
+                HealthThreshold::Critical => self.critical = true,
+                HealthThreshold::Healthy | HealthThreshold::Full if self.critical => {
+                    self.critical = false;
+                    self.unlock(Achievement::NarrowEscape);
+                }
+                HealthThreshold::Dead => self.critical = false,
+                _ => {}
+            },
+            GameEvent::LeveledUp {
+                entity: EntityId::PLAYER,
+                level,
+            } if *level >= 5 => self.unlock(Achievement::Veteran),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{Action, ActionInput, CharacterAction};
+
+    use super::*;
+    use crate::events::{ActionRef, Topic};
+    use crate::repository::InMemoryEventRepository;
+
+    fn action_ref(nonce: u64, actor: EntityId, kind: Option<ActionKind>) -> Event {
+        Event::ActionRef(ActionRef {
+            nonce,
+            action_offset: 0,
+            clock: nonce * 10,
+            actor,
+            action_kind: kind,
+        })
+    }
+
+    #[test]
+    fn projections_catch_up_from_the_log() {
+        let mut log = InMemoryEventRepository::new("session".into());
+        let goblin = EntityId(3);
+        log.append(&action_ref(0, EntityId::PLAYER, Some(ActionKind::Move)))
+            .unwrap();
+        log.append(&action_ref(1, goblin, Some(ActionKind::MeleeAttack)))
+            .unwrap();
+        log.append(&Event::GameState(GameStateEvent::ActionFailed {
+            nonce: 2,
+            action: Action::character(CharacterAction::new(
+                EntityId::PLAYER,
+                ActionKind::Move,
+                ActionInput::None,
+            )),
+            phase: game_core::engine::TransitionPhase::PreValidate,
+            error: "blocked".into(),
+            clock: 20,
+        }))
+        .unwrap();
+
+        let mut stats = Projector::new(SessionStats::default());
+        assert_eq!(stats.catch_up(&log).unwrap(), 3);
+
+        // Only new events are applied on the next catch-up
+        log.append(&action_ref(2, EntityId::PLAYER, Some(ActionKind::Move)))
+            .unwrap();
+        assert_eq!(stats.catch_up(&log).unwrap(), 1);
+        let stats = stats.into_inner();
+        assert_eq!(stats.total_actions(), 3);
+        assert_eq!(stats.actions_by_kind[&ActionKind::Move], 2);
+        assert_eq!(stats.failed_actions, 1);
+        assert_eq!(stats.last_nonce, Some(2));
+
+        let player_moves: Vec<u64> = EventStream::new(&log, 0)
+            .matching(
+                EventFilter::all()
+                    .topic(Topic::GameState)
+                    .entity(EntityId::PLAYER)
+                    .action_kind(ActionKind::Move),
+            )
+            .map(|entry| entry.unwrap().0)
+            .collect();
+        assert_eq!(player_moves, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn view_and_achievements_follow_domain_events() {
+        let mut log = InMemoryEventRepository::new("session".into());
+        let goblin = EntityId(3);
+        let events = [
+            GameEvent::EntityMoved {
+                entity: goblin,
+                from: None,
+                to: Some(Position::new(2, 1)),
+            },
+            GameEvent::HealthThresholdCrossed {
+                entity: EntityId::PLAYER,
+                threshold: HealthThreshold::Critical,
+                hp_percent: 10,
+            },
+            GameEvent::DamageTaken {
+                entity: goblin,
+                amount: 4,
+                hp_before: 4,
+                hp_after: 0,
+                source: Some(EntityId::PLAYER),
+            },
+            GameEvent::EntityDied {
+                entity: goblin,
+                position: Some(Position::new(2, 1)),
+                killer: Some(EntityId::PLAYER),
+            },
+            GameEvent::HealthThresholdCrossed {
+                entity: EntityId::PLAYER,
+                threshold: HealthThreshold::Healthy,
+                hp_percent: 80,
+            },
+        ];
+        for event in events {
+            log.append(&Event::Game(event)).unwrap();
+        }
+
+        let mut view = Projector::new(WorldView::default());
+        view.catch_up(&log).unwrap();
+        let goblin_view = &view.projection().entities[&goblin];
+        assert_eq!(goblin_view.position, Some(Position::new(2, 1)));
+        assert_eq!(goblin_view.hp, Some(0));
+        assert!(goblin_view.dead);
+
+        let mut achievements = Projector::new(Achievements::default());
+        achievements.catch_up(&log).unwrap();
+        assert_eq!(
+            achievements.projection().unlocked,
+            vec![Achievement::FirstBlood, Achievement::NarrowEscape]
+        );
+
+        // A restore invalidates the view
+        log.append(&Event::GameState(GameStateEvent::StateRestored {
+            from_nonce: 4,
+            to_nonce: 1,
+        }))
+        .unwrap();
+        view.catch_up(&log).unwrap();
+        assert!(view.projection().entities.is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+//! Sequential reading of a persisted event log.
+//!
+//! [`EventStream`] walks an [`EventRepository`] from any offset and yields the
+//! events matching an [`EventFilter`] together with their offsets, so a reader
+//! can stop anywhere and resume later from the offset it reached.
+
+use game_core::{Action, ActionKind, EntityId};
+
+use crate::repository::{EventRepository, Result};
+
+use super::bus::{Event, Topic};
+use super::game_event::GameEvent;
+use super::types::{GameStateEvent, ProofEvent};
+
+/// Selects events by topic, acting entity and action kind.
+///
+/// Unset criteria match everything. Entity and action kind criteria only
+/// match events that refer to an action.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    pub topic: Option<Topic>,
+    pub entity: Option<EntityId>,
+    pub action_kind: Option<ActionKind>,
+}
+
+impl EventFilter {
+    /// Filter matching every event.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn topic(mut self, topic: Topic) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    pub fn entity(mut self, entity: EntityId) -> Self {
+        self.entity = Some(entity);
+        self
+    }
+
+    pub fn action_kind(mut self, kind: ActionKind) -> Self {
+        self.action_kind = Some(kind);
+        self
+    }
+
+    pub fn matches(&self, event: &Event) -> bool {
+        if self.topic.is_some_and(|topic| topic != event.topic()) {
+            return false;
+        }
+        if self.entity.is_none() && self.action_kind.is_none() {
+            return true;
+        }
+
+        let Some((actor, kind)) = event_action(event) else {
+            return false;
+        };
+        self.entity.is_none_or(|entity| entity == actor)
+            && self.action_kind.is_none_or(|wanted| kind == Some(wanted))
+    }
+}
+
+/// Actor and character action kind of the action an event refers to.
+pub fn event_action(event: &Event) -> Option<(EntityId, Option<ActionKind>)> {
+    let of = |action: &Action| {
+        let kind = match action {
+            Action::Character(action) => Some(action.kind),
+            Action::System { .. } => None,
+        };
+        (action.actor(), kind)
+    };
+
+    match event {
+        Event::ActionRef(action_ref) => Some((action_ref.actor, action_ref.action_kind)),
+        Event::GameState(GameStateEvent::ActionExecuted { action, .. })
+        | Event::GameState(GameStateEvent::ActionFailed { action, .. }) => Some(of(action)),
+        Event::GameState(GameStateEvent::StateRestored { .. }) => None,
+        Event::Game(GameEvent::ActionCompleted { action, .. }) => Some(of(action)),
+        Event::Game(_) => None,
+        Event::Proof(
+            ProofEvent::ProofStarted { action, .. }
+            | ProofEvent::ProofGenerated { action, .. }
+            | ProofEvent::ProofFailed { action, .. },
+        ) => Some(of(action)),
+    }
+}
+
+/// Iterator over `(offset, event)` pairs of an event log.
+///
+/// Stops at the current end of the log; call [`EventStream::offset`] to learn
+/// where to resume once more events were appended.
+pub struct EventStream<'a> {
+    repo: &'a dyn EventRepository,
+    offset: u64,
+    filter: EventFilter,
+    failed: bool,
+}
+
+impl<'a> EventStream<'a> {
+    pub fn new(repo: &'a dyn EventRepository, offset: u64) -> Self {
+        Self {
+            repo,
+            offset,
+            filter: EventFilter::default(),
+            failed: false,
+        }
+    }
+
+    /// Only yield events matching `filter`.
+    pub fn matching(mut self, filter: EventFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Offset of the next event to read.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl Iterator for EventStream<'_> {
+    type Item = Result<(u64, Event)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed {
+            let offset = self.offset;
+            match self.repo.read_at_offset(offset) {
+                Ok(Some((event, next))) => {
+                    self.offset = next;
+                    if self.filter.matches(&event) {
+                        return Some(Ok((offset, event)));
+                    }
+                }
+                Ok(None) => return None,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,166 @@
+//! Handler for expiring summoned companions.
+
+use game_core::action::{Action, DeactivateAction, RemoveFromWorldAction, SystemActionKind};
+
+use super::{EventContext, HandlerCriticality};
+use crate::events::GameEvent;
+use crate::providers::SystemActionHandler;
+
+/// Handler that dismisses summons whose companion bond has expired.
+///
+/// After every completed action, any companion still on the map whose bond
+/// expired at the current clock is cleaned up the same way as a dead entity:
+/// 1. Deactivate - Removes from active set and turn scheduling
+/// 2. RemoveFromWorld - Clears position and world occupancy
+///
+/// Permanent companions (`expires_at: None`) are never dismissed.
+#[derive(Debug, Clone, Copy)]
+pub struct CompanionHandler;
+
+impl SystemActionHandler for CompanionHandler {
+    fn name(&self) -> &'static str {
+        "companion"
+    }
+
+    fn priority(&self) -> i32 {
+        -40 // After death cleanup, before activation
+    }
+
+    fn criticality(&self) -> HandlerCriticality {
+        // Important: A lingering summon keeps acting, but state stays consistent.
+        HandlerCriticality::Important
+    }
+
+    fn generate_actions(&self, event: &GameEvent, ctx: &EventContext) -> Vec<Action> {
+        let GameEvent::ActionCompleted { .. } = event else {
+            return vec![];
+        };
+
+        let now = ctx.state_after.turn.clock;
+        let mut actions = Vec::new();
+
+        for actor in ctx.state_after.entities.all_actors() {
+            let expired = actor.companion.is_some_and(|bond| bond.is_expired(now));
+            if !expired || actor.position.is_none() {
+                continue;
+            }
+
+            tracing::info!(
+                target: "runtime::handlers::companion",
+                entity = ?actor.id,
+                "CompanionHandler: Dismissing expired summon"
+            );
+
+            if actor.ready_at.is_some() || ctx.state_after.turn.active_actors.contains(&actor.id) {
+                actions.push(Action::system(SystemActionKind::Deactivate(
+                    DeactivateAction::new(actor.id),
+                )));
+            }
+            actions.push(Action::system(SystemActionKind::RemoveFromWorld(
+                RemoveFromWorldAction::new(actor.id),
+            )));
+        }
+
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use game_core::{
+        ActionAbility, ActionInput, ActionKind, ActorTemplate, CharacterAction, EntityId, Faction,
+        GameConfig, GameEngine, GameState, Position, TraitProfile,
+    };
+
+    use super::*;
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, DialogueOracleImpl, ItemOracleImpl,
+        MapOracleImpl, OracleBundle,
+    };
+    use crate::providers::ai::{AiContext, scoring};
+
+    fn template(actions: &[ActionKind]) -> ActorTemplate {
+        ActorTemplate::builder()
+            .faction(Faction::UndeadLegion)
+            .actions(actions.iter().copied().map(ActionAbility::new).collect())
+            .trait_profile(TraitProfile::default())
+            .build()
+    }
+
+    fn raise_dead(boss: EntityId) -> Action {
+        Action::character(CharacterAction::new(
+            boss,
+            ActionKind::RaiseDead,
+            ActionInput::None,
+        ))
+    }
+
+    #[test]
+    fn content_summons_fight_for_the_caster_until_dismissed() {
+        let mut actors = ActorOracleImpl::new();
+        actors.add("skeleton_warrior", template(&[ActionKind::Wait]));
+        let oracles = OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(10, 10)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(actors),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+            Arc::new(DialogueOracleImpl::new()),
+        );
+
+        let mut state = GameState::with_player();
+        state
+            .world
+            .tile_map
+            .add_occupant(Position::ORIGIN, EntityId::PLAYER);
+        let boss = state
+            .add_npc(&template(&[ActionKind::RaiseDead]), Position::new(5, 5))
+            .unwrap();
+        state.entities.actor_mut(boss).unwrap().ready_at = Some(0);
+        state.turn.active_actors.insert(boss);
+        state.turn.current_actor = boss;
+
+        let score = |state: &GameState| {
+            let ctx = AiContext::new(boss, state, oracles.as_game_env());
+            scoring::score_for_attack(
+                ActionKind::RaiseDead,
+                &ActionInput::None,
+                EntityId::PLAYER,
+                &ctx,
+            )
+        };
+        assert_eq!(score(&state), 90, "no summons yet");
+
+        let before = state.clone();
+        GameEngine::new(&mut state)
+            .execute(oracles.as_game_env(), &raise_dead(boss))
+            .unwrap();
+
+        let summons: Vec<_> = state.entities.companions_of(boss).collect();
+        assert_eq!(summons.len(), 2);
+        for summon in &summons {
+            assert_eq!(summon.faction, Faction::UndeadLegion);
+            assert_eq!(summon.companion.unwrap().expires_at, Some(1000));
+            assert!(state.turn.active_actors.contains(&summon.id));
+        }
+        assert_eq!(score(&state), 0, "already has summons");
+
+        // Once the bond expires the handler clears the summons off the map
+        let mut expired = state.clone();
+        expired.turn.clock = 1000;
+        let ctx = EventContext {
+            state_before: &before,
+            state_after: &expired,
+            oracles: &oracles,
+        };
+        let event = GameEvent::ActionCompleted {
+            actor: boss,
+            action: raise_dead(boss),
+            cost: 0,
+        };
+        let dismissals = CompanionHandler.generate_actions(&event, &ctx);
+        assert_eq!(dismissals.len(), 4, "deactivate and remove each summon");
+    }
+}
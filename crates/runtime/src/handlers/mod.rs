@@ -7,10 +7,14 @@
 //! within game-core, so ActionCostHandler has been removed.
 
 mod activation;
+mod companion;
 mod death;
+mod perception;
 
 pub use activation::ActivationHandler;
+pub use companion::CompanionHandler;
 pub use death::DeathHandler;
+pub use perception::PerceptionHandler;
 
 use game_core::GameState;
 
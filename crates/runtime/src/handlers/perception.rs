@@ -0,0 +1,65 @@
+//! Handler for NPC perception updates.
+
+use game_core::{Action, PerceptionAction, SystemActionKind};
+
+use super::{EventContext, HandlerCriticality};
+use crate::events::GameEvent;
+use crate::providers::SystemActionHandler;
+
+/// Extra hearing range (in tiles) granted to combat noise.
+const COMBAT_NOISE_LOUDNESS: u32 = 4;
+
+/// Handler that keeps NPC awareness in sync with what they can sense.
+///
+/// # Behavior
+///
+/// - Any entity moves: trigger a Sight perception check (the player may have
+///   stepped into view, or an NPC may have moved into sight of the player)
+/// - Damage is dealt: emit combat noise at the victim's position so nearby
+///   NPCs investigate even without line of sight
+///
+/// Runs after [`ActivationHandler`](super::ActivationHandler) so newly
+/// activated NPCs perceive the player in the same pass.
+#[derive(Debug, Clone, Copy)]
+pub struct PerceptionHandler;
+
+impl SystemActionHandler for PerceptionHandler {
+    fn name(&self) -> &'static str {
+        "perception"
+    }
+
+    fn priority(&self) -> i32 {
+        10 // After activation
+    }
+
+    fn criticality(&self) -> HandlerCriticality {
+        // Optional: Missing a perception update only delays NPC reactions.
+        HandlerCriticality::Optional
+    }
+
+    fn generate_actions(&self, event: &GameEvent, ctx: &EventContext) -> Vec<Action> {
+        match event {
+            GameEvent::EntityMoved { .. } => {
+                vec![Action::system(SystemActionKind::Perception(
+                    PerceptionAction::sight(),
+                ))]
+            }
+            GameEvent::DamageTaken { entity, .. } => {
+                // Dead entities are removed from the map later, so fall back to
+                // the position before the hit.
+                let origin = ctx
+                    .state_after
+                    .actor_position(*entity)
+                    .or_else(|| ctx.state_before.actor_position(*entity));
+
+                match origin {
+                    Some(origin) => vec![Action::system(SystemActionKind::Perception(
+                        PerceptionAction::noise(origin, COMBAT_NOISE_LOUDNESS),
+                    ))],
+                    None => vec![],
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
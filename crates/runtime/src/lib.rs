@@ -9,6 +9,9 @@
 //! - [`runtime`] hosts the orchestrator and builder
 //! - [`api`] exposes the types downstream clients interact with
 //! - [`providers`] contains concrete action provider implementations
+//! - [`replay`] re-drives saved sessions from their action logs
+//! - [`batch`] plays many seeded games headless for balance testing
+//! - [`verify`] re-executes saved sessions to check they are deterministic
 //! - [`events`] provides topic-based event bus for flexible event routing
 //! - [`workers`] keeps background tasks internal to the crate
 //! - [`handlers`] provides event-based reactive action generation
@@ -17,15 +20,18 @@
 //! - [`types`] provides common type aliases for semantic clarity
 //! - [`blockchain`] provides blockchain client integration (optional, feature-gated)
 pub mod api;
+pub mod batch;
 pub mod blockchain;
 pub mod events;
 pub mod handlers;
 pub mod oracle;
 pub mod providers;
+pub mod replay;
 pub mod repository;
 pub mod runtime;
 pub mod scenario;
 pub mod types;
+pub mod verify;
 
 mod utils;
 mod workers;
@@ -34,23 +40,40 @@ pub use api::{
     ActionProvider, AiKind, InteractiveKind, ProviderKind, ProviderRegistry, Result, RuntimeError,
     RuntimeHandle,
 };
+pub use batch::{BatchConfig, BatchReport, BatchSimulation, GameOutcome, GameResult};
 #[cfg(feature = "sui")]
 pub use blockchain::BlockchainClients;
 pub use events::{
-    Event, EventBus, GameEvent, GameStateEvent, HealthThreshold, ProofEvent, Topic, extract_events,
+    Achievement, Achievements, ActionRef, EntityView, Event, EventBus, EventFilter, EventStream,
+    GameEvent, GameStateEvent, HealthThreshold, Projection, Projector, ProofEvent, SessionStats,
+    Topic, WorldView, extract_events, extract_result_events,
+};
+pub use handlers::{
+    ActivationHandler, CompanionHandler, DeathHandler, EventContext, HandlerCriticality,
+    PerceptionHandler,
 };
-pub use handlers::{ActivationHandler, DeathHandler, EventContext, HandlerCriticality};
 pub use oracle::{
-    ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, ItemOracleImpl, MapOracleImpl,
-    OracleBundle,
+    ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, DialogueOracleImpl, ItemOracleImpl,
+    MapOracleImpl, OracleBundle,
 };
 pub use providers::ai::{AiContext, UtilityAiProvider};
-pub use providers::{SystemActionHandler, SystemActionProvider};
+pub use providers::{
+    ClientMessage, NetworkAccess, NetworkActionProvider, NetworkServer, PROTOCOL_VERSION,
+    PublicEvent, ReplayError, ReplayLog, ReplayProvider, SystemActionHandler,
+    SystemActionProvider,
+};
+pub use replay::{ReplayCommand, ReplayControl, ReplayStatus, Replayer};
 pub use repository::{
     ActionBatch, ActionBatchRepository, ActionBatchStatus, ActionLogEntry, ActionLogReader,
-    ActionLogWriter, EventRepository, FileActionBatchRepository, FileActionLog,
-    FileActionLogReader, FileEventLog, FileStateRepository, InMemoryActionLogReader,
-    InMemoryStateRepo, RepositoryError, StateRepository,
+    ActionLogWriter, BranchId, BranchIndex, BranchInfo, BranchSummary, EventRepository,
+    FileActionBatchRepository, FileActionLog, FileActionLogReader, FileBranchRepository,
+    FileEventLog, FileStateRepository, InMemoryActionLogReader, InMemoryStateRepo, RepositoryError,
+    StateRepository,
+};
+#[cfg(feature = "sqlite")]
+pub use repository::{
+    SqliteActionBatchRepository, SqliteActionLog, SqliteActionLogReader, SqliteEventLog,
+    SqliteSession, SqliteStateRepository, SqliteStore,
 };
 pub use runtime::{
     BlockchainSessionData, PersistenceSettings, ProvingSettings, Runtime, RuntimeBuilder,
@@ -58,4 +81,10 @@ pub use runtime::{
 };
 pub use scenario::{EntityKind, EntityPlacement, Scenario};
 pub use types::{ByteOffset, DurationMs, Nonce, ProofSize, SessionId, StateHash, Timestamp};
-pub use workers::{CheckpointStrategy, PersistenceConfig, ProofMetrics};
+pub use verify::{Divergence, DivergenceKind, SessionVerifier, VerifyReport};
+pub use workers::{
+    CheckpointEvent, CheckpointStrategy, PersistenceConfig, ProofMetrics, ProofQueueStatus,
+    ProofRetryPolicy, StateStorage, StorageBackend,
+};
+#[cfg(feature = "remote-prover")]
+pub use zk::RemoteConfig;
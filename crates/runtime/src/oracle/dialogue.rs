@@ -0,0 +1,50 @@
+//! Dialogue oracle implementing [`game_core::DialogueOracle`].
+
+use std::collections::HashMap;
+
+use game_core::{DialogueOracle, DialogueTree};
+
+/// Oracle providing dialogue trees.
+pub struct DialogueOracleImpl {
+    dialogues: HashMap<String, DialogueTree>,
+}
+
+impl DialogueOracleImpl {
+    /// Create an empty oracle.
+    pub fn new() -> Self {
+        Self {
+            dialogues: HashMap::new(),
+        }
+    }
+
+    /// Add a dialogue tree, keyed by its `id`.
+    pub fn add(&mut self, tree: DialogueTree) {
+        self.dialogues.insert(tree.id.clone(), tree);
+    }
+
+    /// Get number of dialogue trees.
+    pub fn len(&self) -> usize {
+        self.dialogues.len()
+    }
+
+    /// Check if there are no dialogue trees.
+    pub fn is_empty(&self) -> bool {
+        self.dialogues.is_empty()
+    }
+}
+
+impl Default for DialogueOracleImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DialogueOracle for DialogueOracleImpl {
+    fn dialogue(&self, id: &str) -> Option<DialogueTree> {
+        self.dialogues.get(id).cloned()
+    }
+
+    fn all_ids(&self) -> Vec<String> {
+        self.dialogues.keys().cloned().collect()
+    }
+}
@@ -7,6 +7,7 @@
 mod actions;
 mod actors;
 mod config;
+mod dialogue;
 mod items;
 mod map;
 
@@ -16,6 +17,7 @@ use std::sync::Arc;
 pub use actions::ActionOracleImpl;
 pub use actors::ActorOracleImpl;
 pub use config::ConfigOracleImpl;
+pub use dialogue::DialogueOracleImpl;
 pub use items::ItemOracleImpl;
 pub use map::MapOracleImpl;
 
@@ -30,6 +32,7 @@ pub struct OracleBundle {
     pub actions: Arc<ActionOracleImpl>,
     pub actors: Arc<ActorOracleImpl>,
     pub config: Arc<ConfigOracleImpl>,
+    pub dialogues: Arc<DialogueOracleImpl>,
     rng: PcgRng,
 }
 
@@ -41,6 +44,7 @@ impl OracleBundle {
         actions: Arc<ActionOracleImpl>,
         actors: Arc<ActorOracleImpl>,
         config: Arc<ConfigOracleImpl>,
+        dialogues: Arc<DialogueOracleImpl>,
     ) -> Self {
         Self {
             map,
@@ -48,6 +52,7 @@ impl OracleBundle {
             actions,
             actors,
             config,
+            dialogues,
             rng: PcgRng, // PcgRng is stateless
         }
     }
@@ -61,6 +66,7 @@ impl OracleBundle {
             self.actors.as_ref(),
             self.config.as_ref(),
             &self.rng,
+            self.dialogues.as_ref(),
         )
         .into_game_env()
     }
@@ -73,7 +79,7 @@ impl OracleBundle {
     /// Creates an oracle snapshot for zkVM execution.
     ///
     /// Captures all oracle data in a serializable format suitable for zkVM guests.
-    /// Includes all actor templates available in the actor oracle.
+    /// Includes all actor templates and dialogue trees available in the oracles.
     ///
     /// # Usage
     ///
@@ -89,6 +95,7 @@ impl OracleBundle {
             self.actors.as_ref(),
             self.actions.as_ref(),
             self.config.as_ref(),
+            self.dialogues.as_ref(),
             &actor_ids,
         )
     }
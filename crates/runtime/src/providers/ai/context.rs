@@ -7,7 +7,10 @@
 //! - Helper methods for situation assessment
 //! - Access to trait profiles
 
-use game_core::{ActionKind, EntityId, GameEnv, GameState, TraitProfile};
+use game_core::{
+    ActionKind, AlertLevel, Companion, EntityId, Faction, GameEnv, GameState, Position,
+    TraitProfile,
+};
 
 /// Context for AI decision-making.
 ///
@@ -134,12 +137,12 @@ impl<'a> AiContext<'a> {
     ///
     /// # Returns
     ///
-    /// True if player is within 10 tiles (Manhattan distance).
+    /// True if player is within the entity's sight range
+    /// (see [`TraitProfile::sight_range`], 10 tiles for a neutral profile).
     ///
     /// # Future Improvements
     ///
     /// - Add line-of-sight checks (walls blocking vision)
-    /// - Consider perception traits (some NPCs see further)
     /// - Consider light levels (darkness reduces vision)
     pub fn can_see_player(&self) -> bool {
         // Calculate distance to player
@@ -150,21 +153,30 @@ impl<'a> AiContext<'a> {
             return false;
         }
 
-        // Sight range: 10 tiles (TODO: make configurable, trait-based)
-        const SIGHT_RANGE: u32 = 10;
-
-        let can_see = distance <= SIGHT_RANGE;
+        let sight_range = self.sight_range();
+        let can_see = distance <= sight_range;
 
         tracing::debug!(
-            "NPC {:?} checking vision to player: distance={}, can_see={}",
+            "NPC {:?} checking vision to player: distance={}, sight_range={}, can_see={}",
             self.entity,
             distance,
+            sight_range,
             can_see
         );
 
         can_see
     }
 
+    /// Gets the sight range of the current entity in tiles.
+    ///
+    /// Derived from the Perception trait; non-actors use a neutral profile.
+    pub fn sight_range(&self) -> u32 {
+        self.trait_profile()
+            .copied()
+            .unwrap_or_default()
+            .sight_range()
+    }
+
     /// Counts nearby allies within the specified range.
     ///
     /// # Arguments
@@ -275,6 +287,68 @@ impl<'a> AiContext<'a> {
         (dx + dy) as u32
     }
 
+    // ========================================================================
+    // Awareness (Perception Memory)
+    // ========================================================================
+
+    /// Gets the current alert level of this entity.
+    ///
+    /// Expired alerts are reported as [`AlertLevel::Unaware`] even if the
+    /// state has not been cleaned up yet.
+    pub fn alert_level(&self) -> AlertLevel {
+        self.state
+            .entities
+            .actor(self.entity)
+            .map(|actor| actor.awareness.level_at(self.state.turn.clock))
+            .unwrap_or_default()
+    }
+
+    /// Gets the last position where this entity sensed the player (or a noise).
+    ///
+    /// # Returns
+    ///
+    /// The remembered position, or None if the entity is unaware.
+    pub fn last_known_player_position(&self) -> Option<Position> {
+        self.state.entities.actor(self.entity).and_then(|actor| {
+            actor
+                .awareness
+                .last_known_position_at(self.state.turn.clock)
+        })
+    }
+
+    // ========================================================================
+    // Companions
+    // ========================================================================
+
+    /// Gets the leader bond of this entity, if it is a companion.
+    pub fn companion(&self) -> Option<Companion> {
+        self.state.entities.actor(self.entity)?.companion
+    }
+
+    /// Gets the enemy this companion should engage.
+    ///
+    /// Delegates to [`game_core::ai::companion_target`] so runtime and provable
+    /// companions pick the same targets.
+    pub fn companion_target(&self) -> Option<EntityId> {
+        game_core::ai::companion_target(self.state, self.entity)
+    }
+
+    /// Gets the faction this entity fights for.
+    ///
+    /// Companions fight for their leader's faction; everyone else for their own.
+    pub fn allegiance(&self) -> Faction {
+        let leader = self
+            .companion()
+            .map(|bond| bond.leader)
+            .unwrap_or(self.entity);
+
+        self.state
+            .entities
+            .actor(leader)
+            .map(|actor| actor.faction)
+            .unwrap_or(Faction::None)
+    }
+
     // ========================================================================
     // Trait Profile Access
     // ========================================================================
@@ -137,24 +137,49 @@ impl ActionCandidateGenerator {
             }
         };
 
-        // Check player as potential target
-        let player = ctx.state.entities.player();
-        let Some(player_pos) = player.position else {
-            return targets; // Player not on map
-        };
-        let dist = actor_pos.chebyshev_distance(player_pos);
+        // Companions fight for their leader; everyone else for themselves.
+        let allegiance = ctx.allegiance();
+        let companion = ctx.companion();
 
-        if dist <= range {
-            // TODO: Add actual LOS check when MapOracle supports it
-            if requires_los {
-                tracing::trace!("LOS check not implemented yet, assuming LOS exists for player");
+        for other in ctx.state.entities.all_actors() {
+            if other.id == actor || !other.is_alive() {
+                continue;
             }
+            let Some(other_pos) = other.position else {
+                continue;
+            };
 
-            targets.push(EntityId::PLAYER);
-            tracing::trace!("Player is valid target: distance={}, range={}", dist, range);
+            // NPCs only target the player; companions never turn on their own side.
+            let is_enemy = match companion {
+                Some(bond) => {
+                    other.id != bond.leader
+                        && other.companion.is_none_or(|b| b.leader != bond.leader)
+                        && allegiance.is_hostile_to(&other.faction)
+                }
+                None => other.id == EntityId::PLAYER,
+            };
+            if !is_enemy {
+                continue;
+            }
+
+            let dist = actor_pos.chebyshev_distance(other_pos);
+            if dist <= range {
+                // TODO: Add actual LOS check when MapOracle supports it
+                if requires_los {
+                    tracing::trace!("LOS check not implemented yet, assuming LOS exists");
+                }
+
+                targets.push(other.id);
+                tracing::trace!(
+                    "{:?} is valid target: distance={}, range={}",
+                    other.id,
+                    dist,
+                    range
+                );
+            }
         }
 
-        // TODO: Add other entities when entity iteration is available
+        // TODO: Add other entities when needed
         // - Allies (for healing, buffing)
         // - Other enemies
         // - Props (for interaction)
@@ -162,3 +187,42 @@ impl ActionCandidateGenerator {
         targets
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        ActorTemplate, Companion, Faction, GameEnv, GameState, Position, TraitProfile,
+    };
+
+    use super::*;
+
+    fn spawn(state: &mut GameState, faction: Faction, position: Position) -> EntityId {
+        let template = ActorTemplate::builder()
+            .faction(faction)
+            .trait_profile(TraitProfile::default())
+            .build();
+        state.add_npc(&template, position).unwrap()
+    }
+
+    fn targets(state: &GameState, entity: EntityId) -> Vec<EntityId> {
+        let ctx = AiContext::new(entity, state, GameEnv::empty());
+        ActionCandidateGenerator::find_valid_targets(entity, 1, false, &ctx)
+    }
+
+    #[test]
+    fn npcs_target_the_player_and_companions_target_enemies() {
+        let mut state = GameState::with_player();
+        let goblin = spawn(&mut state, Faction::GoblinClan, Position::new(1, 0));
+        let orc = spawn(&mut state, Faction::OrcHorde, Position::new(2, 0));
+        let wolf = spawn(&mut state, Faction::Friendly, Position::new(0, 1));
+        state.entities.actor_mut(wolf).unwrap().companion =
+            Some(Companion::summoned(EntityId::PLAYER, None));
+
+        // Feuding factions and the player's companions are not NPC targets
+        assert_eq!(targets(&state, goblin), [EntityId::PLAYER]);
+        assert_eq!(targets(&state, orc), []);
+
+        // Companions fight the leader's enemies, never the leader
+        assert_eq!(targets(&state, wolf), [goblin]);
+    }
+}
@@ -3,7 +3,7 @@
 //! Goals are concrete objectives that drive NPC behavior.
 //! Each goal represents a specific intent (e.g., "Attack Player", "Flee from Player").
 
-use game_core::{EntityId, Position};
+use game_core::{AlertLevel, CompanionOrder, EntityId, Position};
 
 use super::AiContext;
 
@@ -72,17 +72,19 @@ impl GoalSelector {
     ///
     /// # Decision Process
     ///
+    /// 0. **Companion**: Bound to a leader → Attack the companion target or follow
     /// 1. **Critical Survival**: Low HP + immediate danger → Flee or Heal
     /// 2. **Combat**: Enemy visible + sufficient courage → Attack or Flee
-    /// 3. **Exploration/Social**: No threats → Explore or interact
-    /// 4. **Default**: Nothing to do → Idle
+    /// 3. **Search**: Player out of sight but remembered → Move to last known position
+    /// 4. **Exploration/Social**: No threats → Explore or interact
+    /// 5. **Default**: Nothing to do → Idle
     ///
     /// # Personality Integration
     ///
     /// - **Bravery**: Affects fight vs flight threshold
     /// - **Aggression**: Influences attack initiative (TODO)
     /// - **Loyalty**: Prioritizes ally protection (TODO)
-    /// - **Curiosity**: Drives exploration (TODO)
+    /// - **Curiosity**: Whether suspicious NPCs investigate noises
     pub fn select(ctx: &AiContext) -> Goal {
         let my_hp_percent = ctx.hp_ratio();
         let player_distance = ctx.distance_to_player();
@@ -99,6 +101,32 @@ impl GoalSelector {
             can_see_player
         );
 
+        // ====================================================================
+        // Priority 0: Companion (Fight for and follow the leader)
+        // ====================================================================
+
+        if let Some(bond) = ctx.companion() {
+            if let Some(target) = ctx.companion_target() {
+                tracing::debug!("  → Goal: Attack (companion target)");
+                return Goal::Attack { target };
+            }
+
+            let leader_distance = ctx
+                .my_position()
+                .zip(ctx.state.actor_position(bond.leader))
+                .map(|(me, leader)| me.chebyshev_distance(leader));
+
+            if bond.order != CompanionOrder::Stay
+                && leader_distance.is_some_and(|d| d > game_core::ai::FOLLOW_DISTANCE)
+            {
+                tracing::debug!("  → Goal: ProtectAlly (follow leader)");
+                return Goal::ProtectAlly { ally: bond.leader };
+            }
+
+            tracing::debug!("  → Goal: Idle (companion holding position)");
+            return Goal::Idle;
+        }
+
         // ====================================================================
         // Priority 1: Critical Survival (Low HP + Immediate Danger)
         // ====================================================================
@@ -170,7 +198,34 @@ impl GoalSelector {
         }
 
         // ====================================================================
-        // Priority 3: Exploration/Social (No immediate threats)
+        // Priority 3: Search (Player remembered but not visible)
+        // ====================================================================
+
+        if let Some(last_known) = ctx.last_known_player_position()
+            && ctx.my_position() != Some(last_known)
+        {
+            // Alerted NPCs always search; suspicious ones only if curious enough
+            let investigates = match ctx.alert_level() {
+                AlertLevel::Alerted => true,
+                AlertLevel::Suspicious => trait_profile
+                    .map(|p| {
+                        p.get(game_content::traits::TraitKind::Curiosity)
+                            >= game_core::ai::INVESTIGATE_CURIOSITY
+                    })
+                    .unwrap_or(true),
+                AlertLevel::Unaware => false,
+            };
+
+            if investigates {
+                tracing::debug!("  → Goal: MoveTo (search last known position)");
+                return Goal::MoveTo {
+                    position: last_known,
+                };
+            }
+        }
+
+        // ====================================================================
+        // Priority 4: Exploration/Social (No immediate threats)
         // ====================================================================
 
         // TODO: Implement exploration goals when map/patrol system exists
@@ -184,3 +239,65 @@ impl GoalSelector {
         Goal::Idle
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        ActorTemplate, AiKind, GameEnv, GameState, ProviderKind, TraitKind, TraitProfile,
+    };
+
+    use super::*;
+
+    /// An NPC at (5, 5) that sensed something at (2, 5) and cannot see the player.
+    fn npc(curiosity: u8, level: AlertLevel) -> (GameState, EntityId) {
+        let mut traits = *TraitProfile::default().values();
+        traits[TraitKind::Curiosity.as_index()] = curiosity;
+        let template = ActorTemplate::builder()
+            .provider_kind(ProviderKind::Ai(AiKind::Utility))
+            .trait_profile(TraitProfile::from_raw(traits))
+            .build();
+
+        let mut state = GameState::with_player();
+        state.entities.actors[0].position = None;
+        let npc = state.add_npc(&template, Position::new(5, 5)).unwrap();
+        state.entities.actor_mut(npc).unwrap().awareness.observe(
+            level,
+            Position::new(2, 5),
+            100,
+            0,
+        );
+        (state, npc)
+    }
+
+    fn select(state: &GameState, npc: EntityId) -> Goal {
+        GoalSelector::select(&AiContext::new(npc, state, GameEnv::empty()))
+    }
+
+    #[test]
+    fn alerted_npcs_search_the_last_known_position() {
+        let search = Goal::MoveTo {
+            position: Position::new(2, 5),
+        };
+        let (mut state, npc) = npc(0, AlertLevel::Alerted);
+        assert_eq!(select(&state, npc), search);
+
+        state.turn.clock = 100;
+        assert_eq!(select(&state, npc), Goal::Idle, "memory has faded");
+    }
+
+    #[test]
+    fn only_curious_npcs_investigate_noises() {
+        let threshold = game_core::ai::INVESTIGATE_CURIOSITY;
+
+        let (state, bored) = npc(threshold - 1, AlertLevel::Suspicious);
+        assert_eq!(select(&state, bored), Goal::Idle);
+
+        let (state, curious) = npc(threshold, AlertLevel::Suspicious);
+        assert_eq!(
+            select(&state, curious),
+            Goal::MoveTo {
+                position: Position::new(2, 5)
+            }
+        );
+    }
+}
@@ -76,6 +76,15 @@ pub fn score_for_attack(
         } else {
             20
         }
+    }
+    // Summon reinforcements once, before joining the fight
+    else if profile.tags.contains(&game_core::ActionTag::Summon) {
+        let has_summons = ctx
+            .state
+            .entities
+            .companions_of(ctx.entity)
+            .any(|companion| companion.is_alive() && companion.position.is_some());
+        if has_summons { 0 } else { 90 }
     } else {
         10 // Other actions (Wait, etc.) are low priority for attacking
     }
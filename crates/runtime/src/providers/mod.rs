@@ -1,6 +1,13 @@
 //! Action provider implementations for different entity behaviors.
 
 pub mod ai;
+pub mod network;
+pub mod replay;
 pub mod system_action;
 
+pub use network::{
+    ClientMessage, MAX_LINE_SIZE, NetworkAccess, NetworkActionProvider, NetworkServer,
+    PROTOCOL_VERSION, PublicEvent, ServerMessage,
+};
+pub use replay::{ReplayError, ReplayLog, ReplayProvider};
 pub use system_action::{SystemActionHandler, SystemActionProvider};
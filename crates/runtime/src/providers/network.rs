@@ -0,0 +1,819 @@
+//! Network input provider that drives entities over a local socket.
+//!
+//! [`NetworkServer`] accepts TCP connections speaking a versioned JSON-lines
+//! protocol: every message is one JSON object terminated by `\n`, tagged by a
+//! `type` field. A session looks like this:
+//!
+//! ```text
+//! client: {"type":"hello","version":2,"secret":"..."}
+//! server: {"type":"hello","version":2,"session_id":"..."}
+//! server: {"type":"turn","entity":0,"nonce":12}
+//! client: {"type":"action","action":{"Character":{...}}}
+//! server: {"type":"event","event":{"ActionExecuted":{...}}}
+//! ```
+//!
+//! The `hello` must carry the shared secret the server was bound with;
+//! otherwise the connection is closed before anything else is read.
+//!
+//! After the handshake the server streams a [`PublicEvent`] for every event of
+//! the [`Topic::GameState`] and [`Topic::Proof`] topics, announces each turn of
+//! an entity bound to the [`NetworkActionProvider`], and answers `query_state`
+//! with the [`PublicView`] of the current state. Clients never see the full
+//! [`GameState`]: NPCs, inventories and the seed stay hidden as they do in
+//! published action logs. Actions are only accepted while a turn is pending
+//! for their actor; anything else is answered with an `error` message and the
+//! connection stays open. Lines longer than [`MAX_LINE_SIZE`] close the
+//! connection.
+//!
+//! The protocol is not encrypted, so [`NetworkServer::bind`] refuses
+//! non-loopback addresses unless [`NetworkAccess::trusted_network`] is set.
+//!
+//! Several clients may connect at once. All of them see the same events and
+//! turn prompts, and the first valid action for a pending turn wins.
+
+use std::net::SocketAddr;
+
+use async_trait::async_trait;
+use game_core::{Action, EntityId, GameEnv, GameState, PublicView, Tick};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, ToSocketAddrs};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{Mutex, mpsc, watch};
+
+use crate::api::{ActionProvider, Result, RuntimeError, RuntimeHandle};
+use crate::events::{Event, GameStateEvent, ProofBackend, ProofEvent, Topic};
+
+/// Version of the wire protocol, exchanged in the `hello` handshake.
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// Longest client line accepted, terminator included.
+pub const MAX_LINE_SIZE: u64 = 64 * 1024;
+
+/// Messages sent by clients.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Opens the connection; must be the first message.
+    Hello { version: u32, secret: String },
+    /// Action for the entity whose turn is pending.
+    Action { action: Action },
+    /// Request the public view of the current game state.
+    QueryState,
+}
+
+/// Messages sent by the server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Handshake reply.
+    Hello { version: u32, session_id: String },
+    /// A networked entity must act on the state with this nonce.
+    Turn { entity: EntityId, nonce: u64 },
+    /// Runtime event, redacted from the event bus.
+    Event { event: Box<PublicEvent> },
+    /// Reply to `query_state`.
+    State { view: Box<PublicView> },
+    /// A client message was rejected, or events were dropped.
+    Error { message: String },
+}
+
+/// Runtime event as network clients see it.
+///
+/// Actions that are not [public](Action::is_public) are reduced to their nonce,
+/// and states to their [`PublicView`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PublicEvent {
+    ActionExecuted {
+        nonce: u64,
+        /// `None` for hidden actions.
+        action: Option<Action>,
+        clock: Tick,
+        view: Box<PublicView>,
+    },
+    ActionFailed {
+        nonce: u64,
+        /// `None` for hidden actions.
+        action: Option<Action>,
+        /// `None` for hidden actions.
+        error: Option<String>,
+        clock: Tick,
+    },
+    StateRestored {
+        from_nonce: u64,
+        to_nonce: u64,
+    },
+    ProofStarted {
+        clock: Tick,
+    },
+    ProofGenerated {
+        clock: Tick,
+        backend: ProofBackend,
+        generation_time_ms: u64,
+    },
+    ProofFailed {
+        clock: Tick,
+        error: String,
+    },
+}
+
+impl PublicEvent {
+    /// Redacts a bus event, or returns `None` if clients should not see it.
+    pub fn redact(event: &Event) -> Option<Self> {
+        let event = match event {
+            Event::GameState(GameStateEvent::ActionExecuted {
+                nonce,
+                action,
+                clock,
+                after_state,
+                ..
+            }) => Self::ActionExecuted {
+                nonce: *nonce,
+                action: action.is_public().then(|| action.clone()),
+                clock: *clock,
+                view: Box::new(after_state.public_view()),
+            },
+            Event::GameState(GameStateEvent::ActionFailed {
+                nonce,
+                action,
+                error,
+                clock,
+                ..
+            }) => {
+                let public = action.is_public();
+                Self::ActionFailed {
+                    nonce: *nonce,
+                    action: public.then(|| action.clone()),
+                    error: public.then(|| error.clone()),
+                    clock: *clock,
+                }
+            }
+            Event::GameState(GameStateEvent::StateRestored {
+                from_nonce,
+                to_nonce,
+            }) => Self::StateRestored {
+                from_nonce: *from_nonce,
+                to_nonce: *to_nonce,
+            },
+            Event::Proof(ProofEvent::ProofStarted { clock, .. }) => {
+                Self::ProofStarted { clock: *clock }
+            }
+            Event::Proof(ProofEvent::ProofGenerated {
+                clock,
+                proof_data,
+                generation_time_ms,
+                ..
+            }) => Self::ProofGenerated {
+                clock: *clock,
+                backend: proof_data.backend,
+                generation_time_ms: *generation_time_ms,
+            },
+            Event::Proof(ProofEvent::ProofFailed { clock, error, .. }) => Self::ProofFailed {
+                clock: *clock,
+                error: error.clone(),
+            },
+            Event::ActionRef(_) | Event::Game(_) => return None,
+        };
+        Some(event)
+    }
+}
+
+/// Who may connect to a [`NetworkServer`].
+#[derive(Clone)]
+pub struct NetworkAccess {
+    secret: String,
+    trusted_network: bool,
+}
+
+impl NetworkAccess {
+    /// Clients must present `secret` in their `hello`.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+            trusted_network: false,
+        }
+    }
+
+    /// Allow non-loopback listen addresses (default: false).
+    ///
+    /// Traffic, the secret included, is not encrypted; only set this when the
+    /// network itself is trusted, e.g. a VPN or a private LAN.
+    pub fn trusted_network(mut self, trusted: bool) -> Self {
+        self.trusted_network = trusted;
+        self
+    }
+
+    /// Compares in constant time so the secret cannot be guessed byte by byte.
+    fn admits(&self, secret: &str) -> bool {
+        let (given, expected) = (secret.as_bytes(), self.secret.as_bytes());
+        given.len() == expected.len()
+            && given
+                .iter()
+                .zip(expected)
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+/// Turn waiting for a networked action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PendingTurn {
+    entity: EntityId,
+    nonce: u64,
+}
+
+/// Action provider fed by [`NetworkServer`] connections.
+pub struct NetworkActionProvider {
+    rx_action: Mutex<mpsc::Receiver<Action>>,
+    pending: watch::Sender<Option<PendingTurn>>,
+}
+
+#[async_trait]
+impl ActionProvider for NetworkActionProvider {
+    async fn provide_action(
+        &self,
+        entity: EntityId,
+        state: &GameState,
+        _env: GameEnv<'_>,
+    ) -> Result<Action> {
+        let mut rx = self.rx_action.lock().await;
+
+        // Drop actions that raced a previous turn before prompting clients
+        while rx.try_recv().is_ok() {}
+        self.pending.send_replace(Some(PendingTurn {
+            entity,
+            nonce: state.nonce(),
+        }));
+        let received = rx.recv().await;
+        self.pending.send_replace(None);
+
+        match received {
+            Some(action) if action.actor() == entity => Ok(action),
+            Some(action) => Err(RuntimeError::InvalidEntityId(action.actor())),
+            None => Err(RuntimeError::ActionProviderChannelClosed),
+        }
+    }
+}
+
+/// TCP server accepting network clients for a [`NetworkActionProvider`].
+pub struct NetworkServer {
+    listener: TcpListener,
+    access: NetworkAccess,
+    handle: RuntimeHandle,
+    tx_action: mpsc::Sender<Action>,
+    pending: watch::Receiver<Option<PendingTurn>>,
+}
+
+impl NetworkServer {
+    /// Bind a listener and create the provider it feeds.
+    ///
+    /// Fails if the secret is empty, or if `addr` is not a loopback address
+    /// and `access` does not trust the network.
+    ///
+    /// Register the provider under `Interactive(NetworkInput)` and bind the
+    /// entities clients should control, then drive the server with [`run`].
+    ///
+    /// [`run`]: NetworkServer::run
+    pub async fn bind(
+        addr: impl ToSocketAddrs,
+        access: NetworkAccess,
+        handle: RuntimeHandle,
+    ) -> Result<(Self, NetworkActionProvider)> {
+        if access.secret.is_empty() {
+            return Err(RuntimeError::InvalidConfig(
+                "network input secret must not be empty".to_string(),
+            ));
+        }
+        let addrs: Vec<SocketAddr> = tokio::net::lookup_host(addr)
+            .await
+            .map_err(RuntimeError::Network)?
+            .collect();
+        if let Some(exposed) = addrs.iter().find(|addr| !addr.ip().is_loopback())
+            && !access.trusted_network
+        {
+            return Err(RuntimeError::InvalidConfig(format!(
+                "refusing unencrypted network input on {exposed}: \
+                 listen on loopback or trust the network"
+            )));
+        }
+
+        let listener = TcpListener::bind(addrs.as_slice())
+            .await
+            .map_err(RuntimeError::Network)?;
+        let (tx_action, rx_action) = mpsc::channel(1);
+        let (pending_tx, pending) = watch::channel(None);
+
+        let provider = NetworkActionProvider {
+            rx_action: Mutex::new(rx_action),
+            pending: pending_tx,
+        };
+        let server = Self {
+            listener,
+            access,
+            handle,
+            tx_action,
+            pending,
+        };
+        Ok((server, provider))
+    }
+
+    /// Address the server listens on (useful when bound to port 0).
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        self.listener.local_addr().map_err(RuntimeError::Network)
+    }
+
+    /// Run the server on a background task.
+    pub fn spawn(self) -> tokio::task::JoinHandle<Result<()>> {
+        tokio::spawn(self.run())
+    }
+
+    /// Accept clients until the listener fails.
+    ///
+    /// Each connection is served on its own task; a failing connection is
+    /// logged and closed without affecting the others.
+    pub async fn run(self) -> Result<()> {
+        tracing::info!(
+            "Network input listening on {}",
+            self.listener.local_addr().map_err(RuntimeError::Network)?
+        );
+
+        loop {
+            let (stream, peer) = self
+                .listener
+                .accept()
+                .await
+                .map_err(RuntimeError::Network)?;
+            tracing::info!("Network client connected: {}", peer);
+
+            let connection = Connection {
+                access: self.access.clone(),
+                handle: self.handle.clone(),
+                tx_action: self.tx_action.clone(),
+                pending: self.pending.clone(),
+            };
+            tokio::spawn(async move {
+                let (reader, writer) = stream.into_split();
+                match connection.serve(reader, writer).await {
+                    Ok(()) => tracing::info!("Network client disconnected: {}", peer),
+                    Err(e) => tracing::warn!("Network client {} failed: {}", peer, e),
+                }
+            });
+        }
+    }
+}
+
+/// State shared by one client connection.
+struct Connection {
+    access: NetworkAccess,
+    handle: RuntimeHandle,
+    tx_action: mpsc::Sender<Action>,
+    pending: watch::Receiver<Option<PendingTurn>>,
+}
+
+impl Connection {
+    /// Serve one client until it disconnects or fails the handshake.
+    async fn serve<R, W>(mut self, reader: R, mut writer: W) -> Result<()>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut lines = LineReader::new(reader);
+
+        match lines.next_line().await? {
+            None => return Ok(()),
+            Some(line) => match serde_json::from_str(&line) {
+                Ok(ClientMessage::Hello { version, secret })
+                    if version == PROTOCOL_VERSION && self.access.admits(&secret) =>
+                {
+                    let hello = ServerMessage::Hello {
+                        version: PROTOCOL_VERSION,
+                        session_id: self.handle.session_id().to_string(),
+                    };
+                    send(&mut writer, &hello).await?;
+                }
+                Ok(ClientMessage::Hello { version, .. }) if version != PROTOCOL_VERSION => {
+                    let message = format!(
+                        "unsupported protocol version {version}, expected {PROTOCOL_VERSION}"
+                    );
+                    return send(&mut writer, &ServerMessage::Error { message }).await;
+                }
+                Ok(ClientMessage::Hello { .. }) => {
+                    let message = "invalid secret".to_string();
+                    return send(&mut writer, &ServerMessage::Error { message }).await;
+                }
+                _ => {
+                    let message = "expected hello".to_string();
+                    return send(&mut writer, &ServerMessage::Error { message }).await;
+                }
+            },
+        }
+
+        let mut game_events = self.handle.subscribe(Topic::GameState);
+        let mut proof_events = self.handle.subscribe(Topic::Proof);
+        let pending = *self.pending.borrow_and_update();
+        if let Some(turn) = pending {
+            send(&mut writer, &turn_message(turn)).await?;
+        }
+
+        loop {
+            tokio::select! {
+                line = lines.next_line() => {
+                    let Some(line) = line? else {
+                        return Ok(());
+                    };
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    if let Some(reply) = self.handle_line(&line).await? {
+                        send(&mut writer, &reply).await?;
+                    }
+                }
+                event = game_events.recv() => forward(&mut writer, event).await?,
+                event = proof_events.recv() => forward(&mut writer, event).await?,
+                changed = self.pending.changed() => {
+                    if changed.is_err() {
+                        // Provider dropped: the runtime is gone
+                        return Ok(());
+                    }
+                    let pending = *self.pending.borrow_and_update();
+                    if let Some(turn) = pending {
+                        send(&mut writer, &turn_message(turn)).await?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Handle one client line, returning the reply if there is one.
+    async fn handle_line(&self, line: &str) -> Result<Option<ServerMessage>> {
+        let message = match serde_json::from_str::<ClientMessage>(line) {
+            Ok(message) => message,
+            Err(e) => {
+                return Ok(Some(ServerMessage::Error {
+                    message: format!("invalid message: {e}"),
+                }));
+            }
+        };
+
+        match message {
+            ClientMessage::Hello { .. } => Ok(Some(ServerMessage::Error {
+                message: "already connected".to_string(),
+            })),
+            ClientMessage::QueryState => {
+                let state = self.handle.query_state().await?;
+                Ok(Some(ServerMessage::State {
+                    view: Box::new(state.public_view()),
+                }))
+            }
+            ClientMessage::Action { action } => {
+                let pending = *self.pending.borrow();
+                match pending {
+                    Some(turn) if turn.entity == action.actor() => {
+                        self.tx_action
+                            .send(action)
+                            .await
+                            .map_err(|_| RuntimeError::ActionProviderChannelClosed)?;
+                        Ok(None)
+                    }
+                    Some(turn) => Ok(Some(ServerMessage::Error {
+                        message: format!(
+                            "it is the turn of {:?}, not {:?}",
+                            turn.entity,
+                            action.actor()
+                        ),
+                    })),
+                    None => Ok(Some(ServerMessage::Error {
+                        message: "no turn is waiting for network input".to_string(),
+                    })),
+                }
+            }
+        }
+    }
+}
+
+fn turn_message(turn: PendingTurn) -> ServerMessage {
+    ServerMessage::Turn {
+        entity: turn.entity,
+        nonce: turn.nonce,
+    }
+}
+
+/// Forward a redacted bus event, reporting (but surviving) lagged receivers.
+async fn forward<W>(writer: &mut W, event: std::result::Result<Event, RecvError>) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    match event {
+        Ok(event) => match PublicEvent::redact(&event) {
+            Some(event) => {
+                send(
+                    writer,
+                    &ServerMessage::Event {
+                        event: Box::new(event),
+                    },
+                )
+                .await
+            }
+            None => Ok(()),
+        },
+        Err(RecvError::Lagged(skipped)) => {
+            let message = format!("client fell behind, {skipped} events dropped");
+            send(writer, &ServerMessage::Error { message }).await
+        }
+        // The bus lives as long as the runtime; nothing left to forward
+        Err(RecvError::Closed) => std::future::pending().await,
+    }
+}
+
+/// Reads client lines of at most [`MAX_LINE_SIZE`] bytes.
+struct LineReader<R> {
+    reader: BufReader<R>,
+    line: Vec<u8>,
+}
+
+impl<R: AsyncRead + Unpin> LineReader<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            line: Vec::new(),
+        }
+    }
+
+    /// Next line without its terminator, or `None` once the client hung up.
+    ///
+    /// Cancel safe: a partially read line is kept for the next call. Fails
+    /// instead of buffering a line longer than [`MAX_LINE_SIZE`], terminator
+    /// included.
+    async fn next_line(&mut self) -> Result<Option<String>> {
+        loop {
+            let remaining = MAX_LINE_SIZE - self.line.len() as u64;
+            let read = (&mut self.reader)
+                .take(remaining)
+                .read_until(b'\n', &mut self.line)
+                .await
+                .map_err(RuntimeError::Network)?;
+            if self.line.last() == Some(&b'\n') {
+                break;
+            }
+            if self.line.len() as u64 >= MAX_LINE_SIZE {
+                return Err(RuntimeError::Network(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("line exceeds {MAX_LINE_SIZE} bytes"),
+                )));
+            }
+            if read == 0 {
+                if self.line.is_empty() {
+                    return Ok(None);
+                }
+                break;
+            }
+        }
+
+        let mut line = String::from_utf8(std::mem::take(&mut self.line)).map_err(|e| {
+            RuntimeError::Network(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+        })?;
+        if line.ends_with('\n') {
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+        Ok(Some(line))
+    }
+}
+
+/// Write one message as a JSON line.
+async fn send<W>(writer: &mut W, message: &ServerMessage) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut line =
+        serde_json::to_vec(message).map_err(|e| RuntimeError::Network(std::io::Error::other(e)))?;
+    line.push(b'\n');
+    writer
+        .write_all(&line)
+        .await
+        .map_err(RuntimeError::Network)?;
+    writer.flush().await.map_err(RuntimeError::Network)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{InteractiveKind, ProviderKind};
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, DialogueOracleImpl, ItemOracleImpl,
+        MapOracleImpl, OracleBundle,
+    };
+    use crate::runtime::Runtime;
+    use game_core::{ActionInput, ActionKind, CharacterAction, GameConfig};
+    use std::sync::Arc;
+    use tokio::net::TcpStream;
+
+    const SECRET: &str = "correct horse battery staple";
+
+    fn test_oracles() -> OracleBundle {
+        OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(10, 10)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+            Arc::new(DialogueOracleImpl::new()),
+        )
+    }
+
+    async fn read_message<R: AsyncRead + Unpin>(lines: &mut LineReader<R>) -> ServerMessage {
+        let line = lines.next_line().await.unwrap().expect("connection closed");
+        serde_json::from_str(&line).unwrap()
+    }
+
+    async fn write_message<W: AsyncWrite + Unpin>(writer: &mut W, message: &ClientMessage) {
+        let mut line = serde_json::to_vec(message).unwrap();
+        line.push(b'\n');
+        writer.write_all(&line).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn client_plays_player_turn_over_localhost() {
+        let mut runtime = Runtime::builder()
+            .oracles(test_oracles())
+            .initial_state(GameState::with_player())
+            .build()
+            .await
+            .unwrap();
+
+        let handle = runtime.handle();
+        let (server, provider) =
+            NetworkServer::bind("127.0.0.1:0", NetworkAccess::new(SECRET), handle.clone())
+                .await
+                .unwrap();
+        let addr = server.local_addr().unwrap();
+        let kind = ProviderKind::Interactive(InteractiveKind::NetworkInput);
+        handle.register_provider(kind, provider).unwrap();
+        handle.set_default_provider(kind).unwrap();
+        server.spawn();
+
+        for hello in [
+            ClientMessage::Hello {
+                version: 1,
+                secret: SECRET.to_string(),
+            },
+            ClientMessage::Hello {
+                version: PROTOCOL_VERSION,
+                secret: "hunter2".to_string(),
+            },
+        ] {
+            let (reader, mut writer) = TcpStream::connect(addr).await.unwrap().into_split();
+            let mut lines = LineReader::new(reader);
+            write_message(&mut writer, &hello).await;
+            assert!(matches!(
+                read_message(&mut lines).await,
+                ServerMessage::Error { .. }
+            ));
+            assert!(lines.next_line().await.unwrap().is_none());
+        }
+
+        let (reader, mut writer) = TcpStream::connect(addr).await.unwrap().into_split();
+        let mut lines = LineReader::new(reader);
+        write_message(
+            &mut writer,
+            &ClientMessage::Hello {
+                version: PROTOCOL_VERSION,
+                secret: SECRET.to_string(),
+            },
+        )
+        .await;
+        assert!(matches!(
+            read_message(&mut lines).await,
+            ServerMessage::Hello {
+                version: PROTOCOL_VERSION,
+                ..
+            }
+        ));
+
+        tokio::spawn(async move { runtime.run().await });
+
+        let entity = loop {
+            if let ServerMessage::Turn { entity, .. } = read_message(&mut lines).await {
+                break entity;
+            }
+        };
+        let action = Action::character(CharacterAction::new(
+            entity,
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        write_message(
+            &mut writer,
+            &ClientMessage::Action {
+                action: action.clone(),
+            },
+        )
+        .await;
+
+        loop {
+            if let ServerMessage::Event { event } = read_message(&mut lines).await
+                && let PublicEvent::ActionExecuted {
+                    action: Some(executed),
+                    ..
+                }
+                | PublicEvent::ActionFailed {
+                    action: Some(executed),
+                    ..
+                } = *event
+                && executed == action
+            {
+                break;
+            }
+        }
+
+        let stranger = Action::character(CharacterAction::new(
+            EntityId(u32::MAX),
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        write_message(&mut writer, &ClientMessage::Action { action: stranger }).await;
+        // The runtime keeps playing, so events and prompts may arrive first
+        loop {
+            match read_message(&mut lines).await {
+                ServerMessage::Error { .. } => break,
+                ServerMessage::Event { .. } | ServerMessage::Turn { .. } => {}
+                other => panic!("unexpected reply {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn unsafe_bindings_are_refused() {
+        let runtime = Runtime::builder()
+            .oracles(test_oracles())
+            .initial_state(GameState::with_player())
+            .build()
+            .await
+            .unwrap();
+
+        let access = NetworkAccess::new(SECRET);
+        for (addr, access) in [
+            ("0.0.0.0:0", access.clone()),
+            ("127.0.0.1:0", NetworkAccess::new("")),
+        ] {
+            let result = NetworkServer::bind(addr, access, runtime.handle()).await;
+            assert!(matches!(result, Err(RuntimeError::InvalidConfig(_))));
+        }
+        let trusted = access.trusted_network(true);
+        assert!(
+            NetworkServer::bind("0.0.0.0:0", trusted, runtime.handle())
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn oversized_lines_are_refused() {
+        let longest = "x".repeat(MAX_LINE_SIZE as usize - 1);
+        let input = format!("short\r\n{longest}\n{longest}x\n");
+        let mut lines = LineReader::new(input.as_bytes());
+
+        assert_eq!(lines.next_line().await.unwrap().as_deref(), Some("short"));
+        assert_eq!(
+            lines.next_line().await.unwrap().as_deref(),
+            Some(longest.as_str())
+        );
+        assert!(matches!(
+            lines.next_line().await,
+            Err(RuntimeError::Network(_))
+        ));
+    }
+
+    #[test]
+    fn events_are_redacted_to_public_views() {
+        let state = GameState::with_player();
+        let hidden = Action::character(CharacterAction::new(
+            EntityId(7),
+            ActionKind::Wait,
+            ActionInput::None,
+        ));
+        let event = Event::GameState(GameStateEvent::ActionExecuted {
+            nonce: 3,
+            action: hidden.clone(),
+            delta: Box::new(game_core::StateDelta::from_states(
+                hidden.clone(),
+                &state,
+                &state,
+            )),
+            clock: 0,
+            before_state: Box::new(state.clone()),
+            after_state: Box::new(state.clone()),
+            action_result: game_core::ActionResult::new(),
+        });
+
+        match PublicEvent::redact(&event) {
+            Some(PublicEvent::ActionExecuted { action, view, .. }) => {
+                assert!(action.is_none());
+                assert_eq!(*view, state.public_view());
+            }
+            other => panic!("unexpected redaction {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Replay provider that re-drives a saved session from its action log.
+//!
+//! The persistence worker logs every executed action (character and system) to
+//! `actions/actions_{start}.bin`. Replaying a session only needs the character
+//! actions: system actions (turn preparation, cascades, provable AI turns) are
+//! regenerated by the engine, so [`ReplayProvider`] answers each turn with the
+//! character action logged at the current nonce.
+//!
+//! The provider never consumes entries; it looks actions up by the nonce of the
+//! state it is given. Rewinding a replay is therefore just restoring an earlier
+//! state, without any cursor to reset.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use game_core::{Action, EntityId, GameEnv, GameState, SystemActionKind};
+use thiserror::Error;
+
+use crate::api::{ActionProvider, Result, RuntimeError};
+use crate::repository::{ActionLogEntry, FileActionLogReader, RepositoryError};
+
+/// Reasons a replay stops matching the recorded session.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ReplayError {
+    #[error("no logged action at nonce {nonce}")]
+    MissingAction { nonce: u64 },
+
+    #[error("logged action at nonce {nonce} is a system action, expected a turn of {expected:?}")]
+    UnexpectedSystemAction { nonce: u64, expected: EntityId },
+
+    #[error("logged action at nonce {nonce} belongs to {logged:?}, but {expected:?} is acting")]
+    ActorMismatch {
+        nonce: u64,
+        expected: EntityId,
+        logged: EntityId,
+    },
+
+    #[error("turn ended at nonce {actual}, but the log continues at nonce {expected}")]
+    TurnLength { expected: u64, actual: u64 },
+
+    #[error("state root after nonce {nonce} does not match the saved state")]
+    StateRootMismatch { nonce: u64 },
+}
+
+/// Action log of a saved session, indexed by nonce.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayLog {
+    actions: BTreeMap<u64, Action>,
+}
+
+impl ReplayLog {
+    /// Build a log from entries in any order.
+    ///
+    /// Later entries win when a nonce appears twice (e.g. a session that was
+    /// restored and continued from an earlier checkpoint).
+    pub fn from_entries(entries: impl IntoIterator<Item = ActionLogEntry>) -> Self {
+        Self {
+            actions: entries
+                .into_iter()
+                .map(|entry| (entry.nonce, entry.action))
+                .collect(),
+        }
+    }
+
+    /// Read every `actions_*.bin` file of a session directory.
+    ///
+    /// Files are read in start-nonce order (file names are zero-padded).
+    pub fn load(session_dir: impl AsRef<Path>, session_id: &str) -> Result<Self> {
+        Ok(Self::from_entries(Self::load_entries(
+            session_dir,
+            session_id,
+        )?))
+    }
+
+    /// Read the raw entries of every `actions_*.bin` file of a session directory.
+    pub fn load_entries(
+        session_dir: impl AsRef<Path>,
+        session_id: &str,
+    ) -> Result<Vec<ActionLogEntry>> {
+        let actions_dir = session_dir.as_ref().join("actions");
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&actions_dir).map_err(RepositoryError::Io)? {
+            let path = entry.map_err(RepositoryError::Io)?.path();
+            let is_action_log = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("actions_") && name.ends_with(".bin"));
+            if is_action_log {
+                files.push(path);
+            }
+        }
+        files.sort();
+
+        let mut entries = Vec::new();
+        for path in files {
+            let mut reader = FileActionLogReader::new(&path, session_id.to_string())?;
+            entries.extend(reader.read_all()?);
+        }
+
+        tracing::info!(
+            "Loaded {} logged actions from {}",
+            entries.len(),
+            actions_dir.display()
+        );
+        Ok(entries)
+    }
+
+    /// Action logged at `nonce`.
+    pub fn action(&self, nonce: u64) -> Option<&Action> {
+        self.actions.get(&nonce)
+    }
+
+    /// Highest logged nonce.
+    pub fn last_nonce(&self) -> Option<u64> {
+        self.actions.keys().next_back().copied()
+    }
+
+    /// Number of logged actions.
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Whether the log holds no actions.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Nonces at which a complete turn starts.
+    ///
+    /// A turn starts with `PrepareTurn` and is complete when the acting entity's
+    /// action was logged right after it. A session saved while waiting for
+    /// input ends with an incomplete turn, which cannot be replayed.
+    pub fn turn_starts(&self) -> Vec<u64> {
+        self.actions
+            .iter()
+            .filter(|(nonce, action)| {
+                matches!(
+                    action,
+                    Action::System {
+                        kind: SystemActionKind::PrepareTurn(_)
+                    }
+                ) && self.actions.contains_key(&(*nonce + 1))
+            })
+            .map(|(nonce, _)| *nonce)
+            .collect()
+    }
+}
+
+/// Action provider that answers every turn from a [`ReplayLog`].
+///
+/// The runtime replaces provider errors with a Wait action, so failures are
+/// also recorded in a shared slot the replay driver checks after each step.
+#[derive(Clone)]
+pub struct ReplayProvider {
+    log: Arc<ReplayLog>,
+    fault: Arc<Mutex<Option<ReplayError>>>,
+}
+
+impl ReplayProvider {
+    pub fn new(log: Arc<ReplayLog>) -> Self {
+        Self {
+            log,
+            fault: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Take the first error recorded since the last call.
+    pub fn take_fault(&self) -> Option<ReplayError> {
+        self.fault.lock().ok().and_then(|mut fault| fault.take())
+    }
+
+    fn next_action(
+        &self,
+        entity: EntityId,
+        nonce: u64,
+    ) -> std::result::Result<Action, ReplayError> {
+        match self.log.action(nonce) {
+            None => Err(ReplayError::MissingAction { nonce }),
+            Some(Action::System { .. }) => Err(ReplayError::UnexpectedSystemAction {
+                nonce,
+                expected: entity,
+            }),
+            Some(action) if action.actor() != entity => Err(ReplayError::ActorMismatch {
+                nonce,
+                expected: entity,
+                logged: action.actor(),
+            }),
+            Some(action) => Ok(action.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl ActionProvider for ReplayProvider {
+    async fn provide_action(
+        &self,
+        entity: EntityId,
+        state: &GameState,
+        _env: GameEnv<'_>,
+    ) -> Result<Action> {
+        self.next_action(entity, state.nonce()).map_err(|error| {
+            if let Ok(mut fault) = self.fault.lock() {
+                fault.get_or_insert(error.clone());
+            }
+            RuntimeError::Replay(error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_core::{ActionInput, ActionKind, CharacterAction, PrepareTurnAction};
+
+    fn prepare() -> Action {
+        Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction))
+    }
+
+    fn wait(actor: EntityId) -> Action {
+        Action::character(CharacterAction::new(
+            actor,
+            ActionKind::Wait,
+            ActionInput::None,
+        ))
+    }
+
+    #[test]
+    fn turn_starts_skip_incomplete_turn() {
+        let log = ReplayLog::from_entries([
+            ActionLogEntry::new(0, prepare()),
+            ActionLogEntry::new(1, wait(EntityId::PLAYER)),
+            ActionLogEntry::new(2, prepare()),
+            ActionLogEntry::new(3, wait(EntityId(1))),
+            ActionLogEntry::new(4, prepare()),
+        ]);
+
+        assert_eq!(log.turn_starts(), vec![0, 2]);
+    }
+
+    #[test]
+    fn provider_rejects_actions_of_other_entities() {
+        let log = ReplayLog::from_entries([
+            ActionLogEntry::new(0, prepare()),
+            ActionLogEntry::new(1, wait(EntityId::PLAYER)),
+        ]);
+        let provider = ReplayProvider::new(Arc::new(log));
+
+        assert_eq!(
+            provider.next_action(EntityId::PLAYER, 1),
+            Ok(wait(EntityId::PLAYER))
+        );
+        assert_eq!(
+            provider.next_action(EntityId(1), 1),
+            Err(ReplayError::ActorMismatch {
+                nonce: 1,
+                expected: EntityId(1),
+                logged: EntityId::PLAYER,
+            })
+        );
+        assert_eq!(
+            provider.next_action(EntityId::PLAYER, 0),
+            Err(ReplayError::UnexpectedSystemAction {
+                nonce: 0,
+                expected: EntityId::PLAYER,
+            })
+        );
+        assert_eq!(
+            provider.next_action(EntityId::PLAYER, 2),
+            Err(ReplayError::MissingAction { nonce: 2 })
+        );
+    }
+}
@@ -72,12 +72,17 @@ impl SystemActionProvider {
     /// Default handlers:
     /// - DeathHandler: Remove dead entities from turn scheduling and world
     /// - ActivationHandler: Activate/deactivate NPCs based on player position
+    /// - PerceptionHandler: Update NPC awareness from sight and combat noise
     pub fn with_defaults() -> Self {
-        use crate::handlers::{ActivationHandler, DeathHandler};
+        use crate::handlers::{
+            ActivationHandler, CompanionHandler, DeathHandler, PerceptionHandler,
+        };
 
         let mut provider = Self::new();
         provider.add_handler(Box::new(DeathHandler));
+        provider.add_handler(Box::new(CompanionHandler));
         provider.add_handler(Box::new(ActivationHandler));
+        provider.add_handler(Box::new(PerceptionHandler));
         provider
     }
 
@@ -0,0 +1,400 @@
+//! Replay driver for saved sessions.
+//!
+//! [`Replayer`] owns a [`Runtime`] whose entities are all driven by a
+//! [`ReplayProvider`] and advances it one turn at a time through
+//! [`Runtime::step`]. Front-ends control playback through a [`ReplayControl`]
+//! (pause, speed, step forward/back) and watch a [`ReplayStatus`].
+//!
+//! # Verification
+//!
+//! Every `ActionExecuted` event is matched against the session's saved states:
+//! `state_{n}.bin` holds the state after the action with nonce `n` (and
+//! `state_0.bin` the genesis state), so its state root must equal the root of
+//! the event's `after_state`. The replay pauses on the first mismatch, on an
+//! action that no longer fits the turn order, or when a turn runs a different
+//! number of actions than recorded.
+//!
+//! # Rewinding
+//!
+//! Stepping back restores the latest saved state at or before the target turn
+//! and silently re-runs the turns in between. Only saved states taken at a turn
+//! boundary qualify; the genesis state always does.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{broadcast, mpsc, watch};
+
+use crate::api::{InteractiveKind, ProviderKind, Result, RuntimeError, RuntimeHandle};
+use crate::events::{Event, GameStateEvent, Topic};
+use crate::providers::{ReplayError, ReplayLog, ReplayProvider};
+use crate::repository::{FileStateRepository, StateRepository};
+use crate::runtime::Runtime;
+
+/// Delay between turns when a replay starts playing.
+const DEFAULT_TURN_DELAY: Duration = Duration::from_millis(500);
+/// Fastest playback speed.
+const MIN_TURN_DELAY: Duration = Duration::from_millis(25);
+/// Slowest playback speed.
+const MAX_TURN_DELAY: Duration = Duration::from_secs(4);
+
+/// Playback commands sent by front-ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayCommand {
+    /// Pause a running replay, or resume a paused one.
+    TogglePause,
+    /// Replay a single turn (also while paused).
+    StepForward,
+    /// Rewind by one turn and pause.
+    StepBack,
+    /// Halve the delay between turns.
+    Faster,
+    /// Double the delay between turns.
+    Slower,
+}
+
+/// Progress of a replay, published after every change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayStatus {
+    /// Turns replayed so far.
+    pub turn: usize,
+    /// Complete turns recorded in the log.
+    pub total_turns: usize,
+    /// Nonce of the current state.
+    pub nonce: u64,
+    /// Whether automatic playback is paused.
+    pub paused: bool,
+    /// Delay between turns during playback.
+    pub delay: Duration,
+    /// Saved states whose root matched the replayed state.
+    pub verified_states: usize,
+    /// Saved states in the session.
+    pub saved_states: usize,
+    /// First divergence from the recorded session, if any.
+    pub error: Option<ReplayError>,
+}
+
+impl ReplayStatus {
+    /// Whether every recorded turn has been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.turn >= self.total_turns
+    }
+
+    /// Whether the replay advances on its own.
+    pub fn is_playing(&self) -> bool {
+        !self.paused && self.error.is_none() && !self.is_finished()
+    }
+}
+
+/// Front-end side of a replay: sends commands and reads status.
+#[derive(Clone)]
+pub struct ReplayControl {
+    commands: mpsc::Sender<ReplayCommand>,
+    status: watch::Receiver<ReplayStatus>,
+}
+
+impl ReplayControl {
+    /// Send a playback command.
+    pub async fn send(&self, command: ReplayCommand) -> Result<()> {
+        self.commands
+            .send(command)
+            .await
+            .map_err(|_| RuntimeError::CommandChannelClosed)
+    }
+
+    /// Latest published status.
+    pub fn status(&self) -> ReplayStatus {
+        self.status.borrow().clone()
+    }
+
+    /// Receiver notified whenever the status changes.
+    pub fn subscribe(&self) -> watch::Receiver<ReplayStatus> {
+        self.status.clone()
+    }
+}
+
+/// Drives a runtime through a recorded session.
+///
+/// The runtime must start from the session's genesis state and must not have
+/// other providers bound to entities (the replay provider becomes the default).
+pub struct Replayer {
+    runtime: Runtime,
+    handle: RuntimeHandle,
+    provider: ReplayProvider,
+    turn_starts: Vec<u64>,
+    states: FileStateRepository,
+    /// Saved states by file nonce: (state nonce, state root).
+    saved_roots: BTreeMap<u64, (u64, [u8; 32])>,
+    /// Saved states at turn boundaries: state nonce → file nonce.
+    restore_points: BTreeMap<u64, u64>,
+    verified: BTreeSet<u64>,
+    events: broadcast::Receiver<Event>,
+    commands: mpsc::Receiver<ReplayCommand>,
+    status: ReplayStatus,
+    status_tx: watch::Sender<ReplayStatus>,
+}
+
+impl Replayer {
+    /// Prepare a replay of `session_id` stored under `session_dir`.
+    ///
+    /// Reads the session's action log and saved states, and registers the
+    /// replay provider as the runtime's default provider.
+    pub async fn new(
+        runtime: Runtime,
+        session_dir: impl AsRef<Path>,
+        session_id: &str,
+    ) -> Result<(Self, ReplayControl)> {
+        let session_dir = session_dir.as_ref();
+        let log = ReplayLog::load(session_dir, session_id)?;
+        let turn_starts = log.turn_starts();
+        let boundaries: HashSet<u64> = turn_starts.iter().copied().collect();
+
+        let states = FileStateRepository::new(session_dir.join("states"))?;
+        let mut saved_roots = BTreeMap::new();
+        let mut restore_points = BTreeMap::new();
+        for key in states.list_nonces()? {
+            let Some(state) = states.load(key)? else {
+                continue;
+            };
+            if boundaries.contains(&state.nonce()) {
+                restore_points.insert(state.nonce(), key);
+            }
+            saved_roots.insert(key, (state.nonce(), state.compute_state_root()));
+        }
+
+        let handle = runtime.handle();
+        let provider = ReplayProvider::new(Arc::new(log));
+        let replay_kind = ProviderKind::Interactive(InteractiveKind::Replay);
+        handle.register_provider(replay_kind, provider.clone())?;
+        handle.set_default_provider(replay_kind)?;
+
+        // The starting state is checked like any other saved state.
+        let initial_state = handle.query_state().await?;
+        let nonce = initial_state.nonce();
+        let mut verified = BTreeSet::new();
+        let mut error = None;
+        if let Some((&key, &(_, root))) = saved_roots
+            .iter()
+            .find(|(_, (state_nonce, _))| *state_nonce == nonce)
+        {
+            if root == initial_state.compute_state_root() {
+                verified.insert(key);
+            } else {
+                error = Some(ReplayError::StateRootMismatch { nonce: key });
+            }
+        }
+
+        let status = ReplayStatus {
+            turn: turn_starts.partition_point(|&start| start < nonce),
+            total_turns: turn_starts.len(),
+            nonce,
+            paused: error.is_some(),
+            delay: DEFAULT_TURN_DELAY,
+            verified_states: verified.len(),
+            saved_states: saved_roots.len(),
+            error,
+        };
+
+        tracing::info!(
+            "Replaying session {}: {} turns, {} saved states ({} restore points)",
+            session_id,
+            status.total_turns,
+            status.saved_states,
+            restore_points.len()
+        );
+
+        let (commands_tx, commands) = mpsc::channel(32);
+        let (status_tx, status_rx) = watch::channel(status.clone());
+        let events = handle.subscribe(Topic::GameState);
+
+        let replayer = Self {
+            runtime,
+            handle,
+            provider,
+            turn_starts,
+            states,
+            saved_roots,
+            restore_points,
+            verified,
+            events,
+            commands,
+            status,
+            status_tx,
+        };
+        let control = ReplayControl {
+            commands: commands_tx,
+            status: status_rx,
+        };
+        Ok((replayer, control))
+    }
+
+    /// Handle to the replayed runtime.
+    pub fn handle(&self) -> RuntimeHandle {
+        self.handle.clone()
+    }
+
+    /// Current progress.
+    pub fn status(&self) -> &ReplayStatus {
+        &self.status
+    }
+
+    /// Play back according to commands until every [`ReplayControl`] is dropped.
+    pub async fn run(mut self) -> Result<()> {
+        loop {
+            let playing = self.status.is_playing();
+            tokio::select! {
+                command = self.commands.recv() => match command {
+                    Some(command) => self.apply(command).await?,
+                    None => return Ok(()),
+                },
+                _ = tokio::time::sleep(self.status.delay), if playing => {
+                    self.step_forward().await?;
+                }
+            }
+        }
+    }
+
+    /// Replay every remaining turn without delay.
+    ///
+    /// Stops early at the first divergence, which is reported in the status.
+    pub async fn run_to_end(&mut self) -> Result<&ReplayStatus> {
+        while !self.status.is_finished() && self.status.error.is_none() {
+            self.step_turn().await?;
+        }
+        self.publish();
+        Ok(&self.status)
+    }
+
+    /// Replay the next turn.
+    ///
+    /// Does nothing once the replay finished or diverged.
+    pub async fn step_forward(&mut self) -> Result<()> {
+        if !self.status.is_finished() && self.status.error.is_none() {
+            self.step_turn().await?;
+        }
+        self.publish();
+        Ok(())
+    }
+
+    /// Rewind to the start of the previous turn and pause.
+    pub async fn step_back(&mut self) -> Result<()> {
+        self.status.paused = true;
+        let Some(target) = self.status.turn.checked_sub(1) else {
+            self.publish();
+            return Ok(());
+        };
+
+        let target_nonce = self.turn_starts[target];
+        let (&state_nonce, &key) = self
+            .restore_points
+            .range(..=target_nonce)
+            .next_back()
+            .ok_or_else(|| {
+                RuntimeError::PersistenceError(format!(
+                    "No saved state at or before nonce {}",
+                    target_nonce
+                ))
+            })?;
+        let state = self.states.load(key)?.ok_or_else(|| {
+            RuntimeError::PersistenceError(format!("No state found at nonce {}", key))
+        })?;
+
+        self.handle.replace_state(state).await?;
+        self.events = self.events.resubscribe();
+        self.provider.take_fault();
+        self.status.turn = self
+            .turn_starts
+            .partition_point(|&start| start < state_nonce);
+        self.status.nonce = state_nonce;
+        self.status.error = None;
+
+        while self.status.turn < target && self.status.error.is_none() {
+            self.step_turn().await?;
+        }
+        self.publish();
+        Ok(())
+    }
+
+    async fn apply(&mut self, command: ReplayCommand) -> Result<()> {
+        match command {
+            ReplayCommand::TogglePause => {
+                self.status.paused = !self.status.paused;
+                self.publish();
+            }
+            ReplayCommand::StepForward => self.step_forward().await?,
+            ReplayCommand::StepBack => self.step_back().await?,
+            ReplayCommand::Faster => {
+                self.status.delay = (self.status.delay / 2).max(MIN_TURN_DELAY);
+                self.publish();
+            }
+            ReplayCommand::Slower => {
+                self.status.delay = (self.status.delay * 2).min(MAX_TURN_DELAY);
+                self.publish();
+            }
+        }
+        Ok(())
+    }
+
+    /// Run one turn through the runtime and check it against the recording.
+    async fn step_turn(&mut self) -> Result<()> {
+        self.runtime.step().await?;
+        let nonce = self.handle.query_state().await?.nonce();
+        self.status.turn += 1;
+        self.status.nonce = nonce;
+
+        let error = self
+            .provider
+            .take_fault()
+            .or_else(|| self.check_turn_length(nonce))
+            .or_else(|| self.verify_saved_states());
+        if let Some(error) = error {
+            tracing::warn!("Replay diverged at turn {}: {}", self.status.turn, error);
+            self.status.error = Some(error);
+            self.status.paused = true;
+        }
+        self.status.verified_states = self.verified.len();
+        Ok(())
+    }
+
+    /// The next turn must start exactly where the recording started it.
+    fn check_turn_length(&self, nonce: u64) -> Option<ReplayError> {
+        let expected = *self.turn_starts.get(self.status.turn)?;
+        (expected != nonce).then_some(ReplayError::TurnLength {
+            expected,
+            actual: nonce,
+        })
+    }
+
+    /// Compare executed actions with the saved states taken after them.
+    fn verify_saved_states(&mut self) -> Option<ReplayError> {
+        loop {
+            match self.events.try_recv() {
+                Ok(Event::GameState(GameStateEvent::ActionExecuted {
+                    nonce, after_state, ..
+                })) => {
+                    let Some(&(state_nonce, root)) = self.saved_roots.get(&nonce) else {
+                        continue;
+                    };
+                    if state_nonce != after_state.nonce() {
+                        continue;
+                    }
+                    if root != after_state.compute_state_root() {
+                        return Some(ReplayError::StateRootMismatch { nonce });
+                    }
+                    self.verified.insert(nonce);
+                }
+                Ok(_) => {}
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    tracing::warn!("Replay skipped verification of {} events", skipped);
+                }
+                Err(_) => return None,
+            }
+        }
+    }
+
+    fn publish(&self) {
+        self.status_tx.send_replace(self.status.clone());
+    }
+}
@@ -26,6 +26,10 @@ pub enum RepositoryError {
     #[error("invalid offset {offset} for file size {file_size}")]
     InvalidOffset { offset: u64, file_size: u64 },
 
+    #[cfg(feature = "sqlite")]
+    #[error("SQLite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
     #[error("partial write detected at offset {offset}: expected {expected} bytes, found {actual}")]
     PartialWrite {
         offset: u64,
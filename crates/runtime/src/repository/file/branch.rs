@@ -0,0 +1,38 @@
+//! File-based storage of a session's branch index (`branches.json`).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::repository::{BranchIndex, RepositoryError, Result};
+
+/// Reads and writes `{session_dir}/branches.json`.
+///
+/// Sessions recorded before branching existed have no index file; they load
+/// as a single main branch.
+pub struct FileBranchRepository {
+    path: PathBuf,
+}
+
+impl FileBranchRepository {
+    pub fn new(session_dir: impl AsRef<Path>) -> Self {
+        Self {
+            path: session_dir.as_ref().join("branches.json"),
+        }
+    }
+
+    pub fn load(&self) -> Result<BranchIndex> {
+        if !self.path.exists() {
+            return Ok(BranchIndex::default());
+        }
+        let json = fs::read_to_string(&self.path)?;
+        serde_json::from_str(&json)
+            .map_err(|e| RepositoryError::Json(format!("Failed to deserialize branches: {}", e)))
+    }
+
+    pub fn save(&self, index: &BranchIndex) -> Result<()> {
+        let json = serde_json::to_string_pretty(index)
+            .map_err(|e| RepositoryError::Json(format!("Failed to serialize branches: {}", e)))?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
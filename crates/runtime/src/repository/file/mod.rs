@@ -2,11 +2,13 @@
 
 mod action_batch;
 mod action_reader;
+mod branch;
 mod log;
 mod state;
 
 pub use action_batch::FileActionBatchRepository;
 pub use action_reader::FileActionLogReader;
+pub use branch::FileBranchRepository;
 pub use state::FileStateRepository;
 
 // Append-only log repository (generic implementation)
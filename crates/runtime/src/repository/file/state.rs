@@ -1,11 +1,15 @@
 //! File-based StateRepository implementation.
 
-use std::fs;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
-use game_core::GameState;
+use game_core::{GameState, StateDelta};
+use serde::{Deserialize, Serialize};
 
-use crate::repository::{RepositoryError, Result, StateRepository};
+use crate::repository::{FileRepository, RepositoryError, Result, StateRepository};
 
 /// File-based implementation of StateRepository.
 ///
@@ -17,8 +21,62 @@ use crate::repository::{RepositoryError, Result, StateRepository};
 /// - Compact size
 /// - Fast serialization/deserialization
 /// - Support for complex types (HashMap with non-string keys)
+///
+/// # Delta Storage
+///
+/// With a keyframe interval above 1, only every N-th saved state is written in
+/// full. The states in between are stored as the [`StateDelta`]s recorded since
+/// that keyframe and rebuilt on load:
+///
+/// ```text
+/// states/
+///   ├── state_{nonce}.bin              keyframes (full snapshots)
+///   └── deltas/
+///       ├── index.log                  nonce → keyframe and state nonce (JSON lines)
+///       └── deltas_{keyframe}.log      deltas recorded after a keyframe
+/// ```
+///
+/// The index is append-only: each delta-stored state adds one line and
+/// dropping an entry appends a tombstone, so later lines win on load.
+///
+/// Loading is transparent, so every reader handles both layouts.
 pub struct FileStateRepository {
     base_dir: PathBuf,
+    /// Saved states per keyframe (1 = every state is a full snapshot).
+    keyframe_interval: u64,
+    /// Deltas recorded since the last keyframe written by this instance.
+    segment: Mutex<Option<DeltaSegment>>,
+    /// Index as written by this instance, read from disk on first use.
+    index: Mutex<Option<DeltaIndex>>,
+}
+
+/// Open delta log following a keyframe.
+struct DeltaSegment {
+    keyframe: u64,
+    log: FileRepository<StateDelta>,
+    /// State nonce reached by applying every recorded delta to the keyframe.
+    tip: u64,
+    /// States saved as deltas since the keyframe.
+    saved: u64,
+}
+
+/// Where a delta-stored state is rebuilt from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct DeltaCheckpoint {
+    /// Repository nonce of the keyframe.
+    keyframe: u64,
+    /// `turn.nonce` of the stored state.
+    state_nonce: u64,
+}
+
+type DeltaIndex = BTreeMap<u64, DeltaCheckpoint>;
+
+/// One line of the index log; `None` removes the nonce.
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexRecord {
+    nonce: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    checkpoint: Option<DeltaCheckpoint>,
 }
 
 impl FileStateRepository {
@@ -26,17 +84,125 @@ impl FileStateRepository {
     pub fn new(base_dir: impl AsRef<Path>) -> Result<Self> {
         let base_dir = base_dir.as_ref().to_path_buf();
         fs::create_dir_all(&base_dir).map_err(RepositoryError::Io)?;
-        Ok(Self { base_dir })
+        Ok(Self {
+            base_dir,
+            keyframe_interval: 1,
+            segment: Mutex::new(None),
+            index: Mutex::new(None),
+        })
+    }
+
+    /// Write a full snapshot only every `interval` saves, deltas otherwise.
+    pub fn with_keyframe_interval(mut self, interval: u64) -> Self {
+        self.keyframe_interval = interval.max(1);
+        self
     }
 
     /// Get the path to a state file.
     fn state_path(&self, nonce: u64) -> PathBuf {
         self.base_dir.join(format!("state_{}.bin", nonce))
     }
-}
 
-impl StateRepository for FileStateRepository {
-    fn save(&self, nonce: u64, state: &GameState) -> Result<()> {
+    fn deltas_dir(&self) -> PathBuf {
+        self.base_dir.join("deltas")
+    }
+
+    fn segment_filename(keyframe: u64) -> String {
+        format!("deltas_{}.log", keyframe)
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.deltas_dir().join("index.log")
+    }
+
+    /// Replay the index log from disk.
+    fn load_index(&self) -> Result<DeltaIndex> {
+        let path = self.index_path();
+        if !path.exists() {
+            return Ok(DeltaIndex::new());
+        }
+        let text = fs::read_to_string(&path).map_err(RepositoryError::Io)?;
+
+        let mut index = DeltaIndex::new();
+        // A line without its newline was cut short by a crash mid-append
+        for line in text.split_inclusive('\n').filter(|l| l.ends_with('\n')) {
+            let record: IndexRecord =
+                serde_json::from_str(line).map_err(|e| RepositoryError::Json(e.to_string()))?;
+            match record.checkpoint {
+                Some(checkpoint) => index.insert(record.nonce, checkpoint),
+                None => index.remove(&record.nonce),
+            };
+        }
+        Ok(index)
+    }
+
+    /// Apply `records` to the cached index and append them to the log.
+    fn update_index(&self, records: &[IndexRecord]) -> Result<()> {
+        let mut cached = self
+            .index
+            .lock()
+            .map_err(|_| RepositoryError::LockPoisoned)?;
+        let index = match cached.as_mut() {
+            Some(index) => index,
+            None => cached.insert(self.load_index()?),
+        };
+
+        let mut lines = String::new();
+        for record in records {
+            match record.checkpoint {
+                Some(checkpoint) => index.insert(record.nonce, checkpoint),
+                None => index.remove(&record.nonce),
+            };
+            lines.push_str(
+                &serde_json::to_string(record).map_err(|e| RepositoryError::Json(e.to_string()))?,
+            );
+            lines.push('\n');
+        }
+
+        fs::create_dir_all(self.deltas_dir()).map_err(RepositoryError::Io)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.index_path())
+            .map_err(RepositoryError::Io)?;
+        file.write_all(lines.as_bytes())
+            .map_err(RepositoryError::Io)?;
+        file.flush().map_err(RepositoryError::Io)
+    }
+
+    /// Cached index entries matching `filter`.
+    fn indexed_nonces(&self, filter: impl Fn(u64, &DeltaCheckpoint) -> bool) -> Result<Vec<u64>> {
+        let mut cached = self
+            .index
+            .lock()
+            .map_err(|_| RepositoryError::LockPoisoned)?;
+        let index = match cached.as_mut() {
+            Some(index) => index,
+            None => cached.insert(self.load_index()?),
+        };
+        Ok(index
+            .iter()
+            .filter(|(nonce, checkpoint)| filter(**nonce, checkpoint))
+            .map(|(nonce, _)| *nonce)
+            .collect())
+    }
+
+    /// Append tombstones for `nonces`.
+    fn remove_from_index(&self, nonces: Vec<u64>) -> Result<()> {
+        if nonces.is_empty() {
+            return Ok(());
+        }
+        let records: Vec<IndexRecord> = nonces
+            .into_iter()
+            .map(|nonce| IndexRecord {
+                nonce,
+                checkpoint: None,
+            })
+            .collect();
+        self.update_index(&records)
+    }
+
+    fn write_snapshot(&self, nonce: u64, state: &GameState) -> Result<()> {
         let path = self.state_path(nonce);
         let temp_path = path.with_extension("bin.tmp");
 
@@ -55,7 +221,7 @@ impl StateRepository for FileStateRepository {
         Ok(())
     }
 
-    fn load(&self, nonce: u64) -> Result<Option<GameState>> {
+    fn read_snapshot(&self, nonce: u64) -> Result<Option<GameState>> {
         let path = self.state_path(nonce);
 
         if !path.exists() {
@@ -71,23 +237,194 @@ impl StateRepository for FileStateRepository {
         Ok(Some(state))
     }
 
+    fn delete_snapshot(&self, nonce: u64) -> Result<()> {
+        let path = self.state_path(nonce);
+
+        if path.exists() {
+            fs::remove_file(&path).map_err(RepositoryError::Io)?;
+            tracing::debug!("Deleted state[{}]", nonce);
+        }
+
+        Ok(())
+    }
+
+    /// Start a new delta log after the keyframe saved at `nonce`.
+    fn open_segment(&self, nonce: u64, state: &GameState) -> Result<DeltaSegment> {
+        let dir = self.deltas_dir();
+        let filename = Self::segment_filename(nonce);
+        let path = dir.join(&filename);
+        if path.exists() {
+            fs::remove_file(&path).map_err(RepositoryError::Io)?;
+        }
+        Ok(DeltaSegment {
+            keyframe: nonce,
+            log: FileRepository::create(dir, filename)?,
+            tip: state.turn.nonce,
+            saved: 0,
+        })
+    }
+
+    /// Rebuild a delta-stored state from its keyframe.
+    fn rebuild(&self, nonce: u64, checkpoint: DeltaCheckpoint) -> Result<GameState> {
+        let mut state = self.read_snapshot(checkpoint.keyframe)?.ok_or_else(|| {
+            RepositoryError::CorruptedData(format!(
+                "keyframe state_{} of state[{}] is missing",
+                checkpoint.keyframe, nonce
+            ))
+        })?;
+
+        let path = self
+            .deltas_dir()
+            .join(Self::segment_filename(checkpoint.keyframe));
+        let bytes = fs::read(&path).map_err(RepositoryError::Io)?;
+        let mut offset = 0;
+        while state.turn.nonce < checkpoint.state_nonce {
+            let Some(delta) = read_delta(&bytes, &mut offset)? else {
+                return Err(RepositoryError::CorruptedData(format!(
+                    "{} ends at nonce {} before state[{}]",
+                    path.display(),
+                    state.turn.nonce,
+                    nonce
+                )));
+            };
+            delta.apply(&mut state).map_err(|e| {
+                RepositoryError::CorruptedData(format!(
+                    "failed to apply delta for nonce {}: {}",
+                    delta.values.turn.nonce, e
+                ))
+            })?;
+        }
+
+        tracing::debug!(
+            "Rebuilt state[{}] from keyframe state_{}",
+            nonce,
+            checkpoint.keyframe
+        );
+
+        Ok(state)
+    }
+}
+
+/// Read the `[u32 length][bincode]` entry at `offset`, advancing past it.
+fn read_delta(bytes: &[u8], offset: &mut usize) -> Result<Option<StateDelta>> {
+    let Some(len_bytes) = bytes.get(*offset..*offset + 4) else {
+        return Ok(None);
+    };
+    let len = u32::from_le_bytes(len_bytes.try_into().expect("4 bytes")) as usize;
+    let start = *offset + 4;
+    let Some(data) = bytes.get(start..start + len) else {
+        return Ok(None);
+    };
+    *offset = start + len;
+    bincode::deserialize(data)
+        .map(Some)
+        .map_err(|e| RepositoryError::Serialization(e.to_string()))
+}
+
+impl StateRepository for FileStateRepository {
+    fn save(&self, nonce: u64, state: &GameState) -> Result<()> {
+        let mut segment = self
+            .segment
+            .lock()
+            .map_err(|_| RepositoryError::LockPoisoned)?;
+
+        // Store as a delta when the recorded deltas lead exactly to `state`
+        if let Some(open) = segment.as_mut()
+            && open.tip == state.turn.nonce
+            && open.saved + 1 < self.keyframe_interval
+        {
+            // Deltas must be readable before the index points at them
+            open.log.flush()?;
+            open.saved += 1;
+
+            self.update_index(&[IndexRecord {
+                nonce,
+                checkpoint: Some(DeltaCheckpoint {
+                    keyframe: open.keyframe,
+                    state_nonce: state.turn.nonce,
+                }),
+            }])?;
+            self.delete_snapshot(nonce)?;
+
+            tracing::debug!(
+                "Saved state[{}] as deltas after keyframe state_{}",
+                nonce,
+                open.keyframe
+            );
+            return Ok(());
+        }
+
+        self.write_snapshot(nonce, state)?;
+
+        // Drop stale entries for this nonce and for a segment being replaced
+        let stale =
+            self.indexed_nonces(|key, checkpoint| key == nonce || checkpoint.keyframe == nonce)?;
+        self.remove_from_index(stale)?;
+
+        *segment = if self.keyframe_interval > 1 {
+            Some(self.open_segment(nonce, state)?)
+        } else {
+            None
+        };
+
+        Ok(())
+    }
+
+    fn record_delta(&self, delta: &StateDelta) -> Result<()> {
+        let mut segment = self
+            .segment
+            .lock()
+            .map_err(|_| RepositoryError::LockPoisoned)?;
+        let Some(open) = segment.as_mut() else {
+            return Ok(());
+        };
+
+        // A gap in the deltas forces the next save to write a keyframe
+        if delta.values.turn.nonce != open.tip + 1 {
+            *segment = None;
+            return Ok(());
+        }
+
+        open.log.append(delta)?;
+        open.tip = delta.values.turn.nonce;
+        Ok(())
+    }
+
+    fn load(&self, nonce: u64) -> Result<Option<GameState>> {
+        if let Some(state) = self.read_snapshot(nonce)? {
+            return Ok(Some(state));
+        }
+
+        match self.load_index()?.get(&nonce) {
+            Some(&checkpoint) => self.rebuild(nonce, checkpoint).map(Some),
+            None => Ok(None),
+        }
+    }
+
     fn exists(&self, nonce: u64) -> bool {
         self.state_path(nonce).exists()
+            || self
+                .load_index()
+                .is_ok_and(|index| index.contains_key(&nonce))
     }
 
+    /// Delete a state.
+    ///
+    /// Deleting a keyframe also makes the states stored as its deltas unloadable.
     fn delete(&self, nonce: u64) -> Result<()> {
-        let path = self.state_path(nonce);
+        self.delete_snapshot(nonce)?;
 
-        if path.exists() {
-            fs::remove_file(&path).map_err(RepositoryError::Io)?;
-            tracing::debug!("Deleted state[{}]", nonce);
+        let stale = self.indexed_nonces(|key, _| key == nonce)?;
+        if !stale.is_empty() {
+            self.remove_from_index(stale)?;
+            tracing::debug!("Deleted delta state[{}]", nonce);
         }
 
         Ok(())
     }
 
     fn list_nonces(&self) -> Result<Vec<u64>> {
-        let mut nonces = Vec::new();
+        let mut nonces: Vec<u64> = self.load_index()?.into_keys().collect();
 
         let entries = fs::read_dir(&self.base_dir).map_err(RepositoryError::Io)?;
 
@@ -106,6 +443,89 @@ impl StateRepository for FileStateRepository {
         }
 
         nonces.sort_unstable();
+        nonces.dedup();
         Ok(nonces)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use game_core::{Action, ActionInput, ActionKind, CharacterAction, EntityId, Position};
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /// Advance one action: the player steps east.
+    fn step(state: &GameState) -> (GameState, StateDelta) {
+        let mut next = state.clone();
+        next.turn.nonce += 1;
+        next.turn.clock += 10;
+        let player = next.entities.player_mut();
+        let from = player.position.unwrap();
+        let to = Position::new(from.x + 1, from.y);
+        player.position = Some(to);
+        next.world.tile_map.remove_occupant(&from, EntityId::PLAYER);
+        next.world.tile_map.add_occupant(to, EntityId::PLAYER);
+
+        let action = Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Move,
+            ActionInput::None,
+        ));
+        let delta = StateDelta::from_states(action, state, &next);
+        (next, delta)
+    }
+
+    #[test]
+    fn delta_states_rebuild_from_keyframes() {
+        let temp = TempDir::new().unwrap();
+        let repo = FileStateRepository::new(temp.path())
+            .unwrap()
+            .with_keyframe_interval(3);
+
+        let mut state = GameState::with_player();
+        repo.save(0, &state).unwrap();
+        let mut saved = vec![(0, state.clone())];
+        for action in 0..12u64 {
+            let (next, delta) = step(&state);
+            state = next;
+            // Action 7 is never recorded, forcing a keyframe at the next save
+            if action != 7 {
+                repo.record_delta(&delta).unwrap();
+            }
+            if action % 2 == 1 {
+                repo.save(action, &state).unwrap();
+                saved.push((action, state.clone()));
+            }
+        }
+
+        let keyframes: Vec<u64> = saved
+            .iter()
+            .map(|(nonce, _)| *nonce)
+            .filter(|nonce| repo.state_path(*nonce).exists())
+            .collect();
+        assert_eq!(keyframes, vec![0, 5, 7]);
+
+        // A separate reader rebuilds delta states the same way
+        let reader = FileStateRepository::new(temp.path()).unwrap();
+        assert_eq!(
+            reader.list_nonces().unwrap(),
+            saved.iter().map(|(nonce, _)| *nonce).collect::<Vec<_>>()
+        );
+        for (nonce, expected) in &saved {
+            assert!(reader.exists(*nonce));
+            assert_eq!(reader.load(*nonce).unwrap().as_ref(), Some(expected));
+        }
+
+        // The index only ever grows by one line per delta-stored state
+        let index = fs::read_to_string(repo.index_path()).unwrap();
+        assert_eq!(index.lines().count(), saved.len() - keyframes.len());
+
+        // Deleting appends a tombstone that fresh readers honour
+        repo.delete(3).unwrap();
+        let reader = FileStateRepository::new(temp.path()).unwrap();
+        assert!(!reader.exists(3));
+        assert_eq!(reader.load(3).unwrap(), None);
+        assert!(reader.exists(1));
+    }
+}
@@ -29,10 +29,13 @@
 //! - `types`: Shared data structures (Checkpoint, etc.)
 //! - `file`: File-based implementations
 //! - `memory`: In-memory implementation (testing)
+//! - `sqlite`: SQLite-backed implementations (`sqlite` feature)
 //! - `snapshot`: High-level facade service
 
 pub mod file;
 pub mod memory;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 pub mod types;
 
 mod error;
@@ -45,12 +48,22 @@ pub use traits::{
 };
 
 // Re-export shared types
-pub use types::{ActionBatch, ActionBatchStatus, ActionLogEntry};
+pub use types::{
+    ActionBatch, ActionBatchStatus, ActionLogEntry, BranchId, BranchIndex, BranchInfo,
+    BranchSummary,
+};
 
 // Re-export file implementations
 pub use file::{
-    FileActionBatchRepository, FileActionLog, FileActionLogReader, FileEventLog, FileRepository,
-    FileStateRepository,
+    FileActionBatchRepository, FileActionLog, FileActionLogReader, FileBranchRepository,
+    FileEventLog, FileRepository, FileStateRepository,
+};
+
+// Re-export SQLite implementations
+#[cfg(feature = "sqlite")]
+pub use sqlite::{
+    SqliteActionBatchRepository, SqliteActionLog, SqliteActionLogReader, SqliteEventLog,
+    SqliteSession, SqliteStateRepository, SqliteStore,
 };
 
 // Re-export memory implementations
@@ -0,0 +1,124 @@
+//! SQLite-backed ActionBatchRepository implementation.
+//!
+//! Batches are stored as JSON (like `batch_*.json` files) next to indexed
+//! columns for their nonce range and status.
+
+use rusqlite::{Connection, OptionalExtension, params};
+
+use super::SqliteStore;
+use crate::repository::{
+    ActionBatch, ActionBatchRepository, ActionBatchStatus, RepositoryError, Result,
+};
+
+/// Status column value; one per [`ActionBatchStatus`] variant.
+fn status_kind(status: &ActionBatchStatus) -> &'static str {
+    match status {
+        ActionBatchStatus::InProgress => "in_progress",
+        ActionBatchStatus::Complete => "complete",
+        ActionBatchStatus::Proving => "proving",
+        ActionBatchStatus::Proven { .. } => "proven",
+        ActionBatchStatus::UploadingToWalrus => "uploading_to_walrus",
+        ActionBatchStatus::BlobUploaded { .. } => "blob_uploaded",
+        ActionBatchStatus::SubmittingOnchain { .. } => "submitting_onchain",
+        ActionBatchStatus::OnChain { .. } => "on_chain",
+        ActionBatchStatus::Failed { .. } => "failed",
+    }
+}
+
+pub(super) fn encode(batch: &ActionBatch) -> Result<String> {
+    serde_json::to_string(batch)
+        .map_err(|e| RepositoryError::Json(format!("Failed to serialize batch: {}", e)))
+}
+
+fn decode(json: &str) -> Result<ActionBatch> {
+    serde_json::from_str(json)
+        .map_err(|e| RepositoryError::Json(format!("Failed to deserialize batch: {}", e)))
+}
+
+pub(super) fn insert(
+    conn: &Connection,
+    batch: &ActionBatch,
+    json: &str,
+) -> rusqlite::Result<usize> {
+    conn.execute(
+        "INSERT OR REPLACE INTO batches (session_id, start_nonce, end_nonce, status, data)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            batch.session_id,
+            batch.start_nonce,
+            batch.end_nonce,
+            status_kind(&batch.status),
+            json
+        ],
+    )
+}
+
+/// SQLite implementation of ActionBatchRepository.
+pub struct SqliteActionBatchRepository {
+    store: SqliteStore,
+}
+
+impl SqliteActionBatchRepository {
+    pub fn new(store: &SqliteStore) -> Self {
+        Self {
+            store: store.clone(),
+        }
+    }
+
+    fn query(&self, sql: &str, params: impl rusqlite::Params) -> Result<Vec<ActionBatch>> {
+        let rows: Vec<String> = self.store.with_conn(|conn| {
+            let mut stmt = conn.prepare(sql)?;
+            stmt.query_map(params, |row| row.get(0))?.collect()
+        })?;
+        rows.iter().map(|json| decode(json)).collect()
+    }
+}
+
+impl ActionBatchRepository for SqliteActionBatchRepository {
+    fn save(&self, batch: &ActionBatch) -> Result<()> {
+        self.store.register_session(&batch.session_id)?;
+        let json = encode(batch)?;
+        self.store.with_conn(|conn| insert(conn, batch, &json))?;
+        Ok(())
+    }
+
+    fn load(&self, session_id: &str, start_nonce: u64) -> Result<Option<ActionBatch>> {
+        let json: Option<String> = self.store.with_conn(|conn| {
+            conn.query_row(
+                "SELECT data FROM batches WHERE session_id = ?1 AND start_nonce = ?2",
+                params![session_id, start_nonce],
+                |row| row.get(0),
+            )
+            .optional()
+        })?;
+        json.as_deref().map(decode).transpose()
+    }
+
+    fn list(&self, session_id: &str) -> Result<Vec<ActionBatch>> {
+        self.query(
+            "SELECT data FROM batches WHERE session_id = ?1 ORDER BY start_nonce",
+            [session_id],
+        )
+    }
+
+    fn list_by_status(
+        &self,
+        session_id: &str,
+        status: ActionBatchStatus,
+    ) -> Result<Vec<ActionBatch>> {
+        self.query(
+            "SELECT data FROM batches WHERE session_id = ?1 AND status = ?2 ORDER BY start_nonce",
+            params![session_id, status_kind(&status)],
+        )
+    }
+
+    fn delete(&self, session_id: &str, start_nonce: u64) -> Result<()> {
+        self.store.with_conn(|conn| {
+            conn.execute(
+                "DELETE FROM batches WHERE session_id = ?1 AND start_nonce = ?2",
+                params![session_id, start_nonce],
+            )
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+//! SQLite-backed action and event logs.
+//!
+//! Entries are rows numbered by a per-session sequence; the sequence number
+//! takes the place of the byte offset used by file logs.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rusqlite::{OptionalExtension, params};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::SqliteStore;
+use crate::events::{Event, GameStateEvent};
+use crate::repository::traits::{ActionLogReader, ActionLogWriter, EventRepository};
+use crate::repository::{ActionLogEntry, RepositoryError, Result};
+
+fn encode<T: Serialize>(item: &T) -> Result<Vec<u8>> {
+    bincode::serialize(item).map_err(|e| RepositoryError::Serialization(e.to_string()))
+}
+
+fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    bincode::deserialize(bytes).map_err(|e| RepositoryError::Serialization(e.to_string()))
+}
+
+fn count(store: &SqliteStore, table: &str, session_id: &str) -> Result<u64> {
+    store.with_conn(|conn| {
+        conn.query_row(
+            &format!("SELECT COUNT(*) FROM {table} WHERE session_id = ?1"),
+            [session_id],
+            |row| row.get(0),
+        )
+    })
+}
+
+/// Nonce an event refers to, for the `events_by_nonce` index.
+fn event_nonce(event: &Event) -> Option<u64> {
+    match event {
+        Event::GameState(GameStateEvent::ActionExecuted { nonce, .. })
+        | Event::GameState(GameStateEvent::ActionFailed { nonce, .. }) => Some(*nonce),
+        Event::GameState(GameStateEvent::StateRestored { to_nonce, .. }) => Some(*to_nonce),
+        Event::ActionRef(action_ref) => Some(action_ref.nonce),
+        Event::Proof(_) | Event::Game(_) => None,
+    }
+}
+
+/// SQLite action log of one session (ActionLogWriter).
+///
+/// Rows are committed on append, so `flush` has nothing to do.
+pub struct SqliteActionLog {
+    store: SqliteStore,
+    session_id: String,
+    next_seq: u64,
+}
+
+impl SqliteActionLog {
+    /// Open a session's action log, continuing after its existing entries.
+    pub fn open(store: &SqliteStore, session_id: impl Into<String>) -> Result<Self> {
+        let session_id = session_id.into();
+        store.register_session(&session_id)?;
+        let next_seq =
+            store.with_conn(|conn| SqliteStore::next_seq(conn, "actions", &session_id))?;
+        Ok(Self {
+            store: store.clone(),
+            session_id,
+            next_seq,
+        })
+    }
+
+    /// Logged entries with `start <= nonce <= end`, in nonce order.
+    pub fn entries_in_range(&self, start: u64, end: u64) -> Result<Vec<ActionLogEntry>> {
+        let rows: Vec<Vec<u8>> = self.store.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                "SELECT data FROM actions
+                 WHERE session_id = ?1 AND nonce BETWEEN ?2 AND ?3
+                 ORDER BY nonce, seq",
+            )?;
+            stmt.query_map(params![self.session_id, start, end], |row| row.get(0))?
+                .collect()
+        })?;
+        rows.iter().map(|bytes| decode(bytes)).collect()
+    }
+}
+
+impl ActionLogWriter for SqliteActionLog {
+    fn append(&mut self, entry: &ActionLogEntry) -> Result<u64> {
+        let seq = self.next_seq;
+        let bytes = encode(entry)?;
+        self.store.with_conn(|conn| {
+            conn.execute(
+                "INSERT INTO actions (session_id, seq, nonce, data) VALUES (?1, ?2, ?3, ?4)",
+                params![self.session_id, seq, entry.nonce, bytes],
+            )
+        })?;
+        self.next_seq += 1;
+        Ok(seq)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> Result<u64> {
+        Ok(self.next_seq)
+    }
+
+    fn session_id(&self) -> &str {
+        &self.session_id
+    }
+}
+
+/// Sequential reader over a session's SQLite action log.
+pub struct SqliteActionLogReader {
+    store: SqliteStore,
+    session_id: String,
+    position: AtomicU64,
+    len: AtomicU64,
+}
+
+impl SqliteActionLogReader {
+    pub fn new(store: &SqliteStore, session_id: impl Into<String>) -> Result<Self> {
+        let session_id = session_id.into();
+        let len = count(store, "actions", &session_id)?;
+        Ok(Self {
+            store: store.clone(),
+            session_id,
+            position: AtomicU64::new(0),
+            len: AtomicU64::new(len),
+        })
+    }
+}
+
+impl ActionLogReader for SqliteActionLogReader {
+    fn read_next(&self) -> Result<Option<ActionLogEntry>> {
+        let position = self.position.load(Ordering::Acquire);
+        let bytes: Option<Vec<u8>> = self.store.with_conn(|conn| {
+            conn.query_row(
+                "SELECT data FROM actions WHERE session_id = ?1 AND seq = ?2",
+                params![self.session_id, position],
+                |row| row.get(0),
+            )
+            .optional()
+        })?;
+
+        let Some(bytes) = bytes else {
+            return Ok(None);
+        };
+        let entry = decode(&bytes)?;
+        self.position.store(position + 1, Ordering::Release);
+        Ok(Some(entry))
+    }
+
+    fn refresh(&self) -> Result<bool> {
+        let len = count(&self.store, "actions", &self.session_id)?;
+        let grew = len > self.len.swap(len, Ordering::AcqRel);
+        Ok(grew)
+    }
+
+    fn current_offset(&self) -> u64 {
+        self.position.load(Ordering::Acquire)
+    }
+
+    fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    fn has_more(&self) -> bool {
+        self.current_offset() < self.len.load(Ordering::Acquire)
+    }
+
+    fn seek(&self, offset: u64) -> Result<()> {
+        let len = count(&self.store, "actions", &self.session_id)?;
+        if offset > len {
+            return Err(RepositoryError::InvalidOffset {
+                offset,
+                file_size: len,
+            });
+        }
+        self.position.store(offset, Ordering::Release);
+        Ok(())
+    }
+}
+
+/// SQLite event log of one session (EventRepository).
+pub struct SqliteEventLog {
+    store: SqliteStore,
+    session_id: String,
+    next_seq: u64,
+}
+
+impl SqliteEventLog {
+    /// Open a session's event log, continuing after its existing events.
+    pub fn open(store: &SqliteStore, session_id: impl Into<String>) -> Result<Self> {
+        let session_id = session_id.into();
+        store.register_session(&session_id)?;
+        let next_seq =
+            store.with_conn(|conn| SqliteStore::next_seq(conn, "events", &session_id))?;
+        Ok(Self {
+            store: store.clone(),
+            session_id,
+            next_seq,
+        })
+    }
+
+    /// Events referring to a nonce in `start..=end`, in log order.
+    pub fn events_by_nonce(&self, start: u64, end: u64) -> Result<Vec<Event>> {
+        let rows: Vec<Vec<u8>> = self.store.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                "SELECT data FROM events
+                 WHERE session_id = ?1 AND nonce BETWEEN ?2 AND ?3
+                 ORDER BY seq",
+            )?;
+            stmt.query_map(params![self.session_id, start, end], |row| row.get(0))?
+                .collect()
+        })?;
+        rows.iter().map(|bytes| decode(bytes)).collect()
+    }
+}
+
+impl EventRepository for SqliteEventLog {
+    fn append(&mut self, event: &Event) -> Result<u64> {
+        let seq = self.next_seq;
+        let bytes = encode(event)?;
+        self.store.with_conn(|conn| {
+            conn.execute(
+                "INSERT INTO events (session_id, seq, nonce, data) VALUES (?1, ?2, ?3, ?4)",
+                params![self.session_id, seq, event_nonce(event), bytes],
+            )
+        })?;
+        self.next_seq += 1;
+        Ok(seq)
+    }
+
+    fn read_at_offset(&self, byte_offset: u64) -> Result<Option<(Event, u64)>> {
+        let bytes: Option<Vec<u8>> = self.store.with_conn(|conn| {
+            conn.query_row(
+                "SELECT data FROM events WHERE session_id = ?1 AND seq = ?2",
+                params![self.session_id, byte_offset],
+                |row| row.get(0),
+            )
+            .optional()
+        })?;
+        bytes
+            .map(|bytes| Ok((decode(&bytes)?, byte_offset + 1)))
+            .transpose()
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> Result<u64> {
+        Ok(self.next_seq)
+    }
+
+    fn session_id(&self) -> &str {
+        &self.session_id
+    }
+}
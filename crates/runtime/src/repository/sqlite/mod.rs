@@ -0,0 +1,289 @@
+//! SQLite-backed repository implementations (`sqlite` feature).
+//!
+//! The file repositories write one file per state, batch and action log,
+//! which adds up to thousands of small files per session. These
+//! implementations keep every session of a save directory in a single
+//! database instead:
+//!
+//! ```text
+//! sessions  (session_id, created_at)
+//! states    (session_id, nonce)        → bincode GameState
+//! batches   (session_id, start_nonce)  → JSON ActionBatch, indexed by status
+//! actions   (session_id, seq)          → bincode ActionLogEntry, indexed by nonce
+//! events    (session_id, seq)          → bincode Event, indexed by nonce
+//! ```
+//!
+//! All repositories created from one [`SqliteStore`] share its connection.
+//! Offsets reported by the log repositories are entry sequence numbers
+//! rather than byte offsets.
+
+mod action_batch;
+mod log;
+mod state;
+
+pub use action_batch::SqliteActionBatchRepository;
+pub use log::{SqliteActionLog, SqliteActionLogReader, SqliteEventLog};
+pub use state::SqliteStateRepository;
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use game_core::GameState;
+use rusqlite::{Connection, OptionalExtension, params};
+
+use crate::repository::{ActionBatch, RepositoryError, Result};
+use crate::types::Nonce;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS sessions (
+        session_id TEXT PRIMARY KEY,
+        created_at TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS states (
+        session_id TEXT NOT NULL,
+        nonce INTEGER NOT NULL,
+        data BLOB NOT NULL,
+        PRIMARY KEY (session_id, nonce)
+    );
+    CREATE TABLE IF NOT EXISTS batches (
+        session_id TEXT NOT NULL,
+        start_nonce INTEGER NOT NULL,
+        end_nonce INTEGER NOT NULL,
+        status TEXT NOT NULL,
+        data TEXT NOT NULL,
+        PRIMARY KEY (session_id, start_nonce)
+    );
+    CREATE INDEX IF NOT EXISTS batches_by_status ON batches (session_id, status, start_nonce);
+    CREATE TABLE IF NOT EXISTS actions (
+        session_id TEXT NOT NULL,
+        seq INTEGER NOT NULL,
+        nonce INTEGER NOT NULL,
+        data BLOB NOT NULL,
+        PRIMARY KEY (session_id, seq)
+    );
+    CREATE INDEX IF NOT EXISTS actions_by_nonce ON actions (session_id, nonce);
+    CREATE TABLE IF NOT EXISTS events (
+        session_id TEXT NOT NULL,
+        seq INTEGER NOT NULL,
+        nonce INTEGER,
+        data BLOB NOT NULL,
+        PRIMARY KEY (session_id, seq)
+    );
+    CREATE INDEX IF NOT EXISTS events_by_nonce ON events (session_id, nonce);
+";
+
+/// A session stored in a [`SqliteStore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqliteSession {
+    pub session_id: String,
+    /// Creation timestamp (ISO 8601 format)
+    pub created_at: String,
+    /// Highest saved state nonce, if any state was saved.
+    pub latest_state: Option<Nonce>,
+}
+
+/// Shared connection to a SQLite save database.
+#[derive(Clone)]
+pub struct SqliteStore {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteStore {
+    /// Open (or create) a database file and apply the schema.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        Self::init(conn)
+    }
+
+    /// Create a private in-memory database (testing).
+    pub fn in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Run `f` with exclusive access to the connection.
+    pub(super) fn with_conn<T>(
+        &self,
+        f: impl FnOnce(&mut Connection) -> rusqlite::Result<T>,
+    ) -> Result<T> {
+        let mut conn = self
+            .conn
+            .lock()
+            .map_err(|_| RepositoryError::LockPoisoned)?;
+        Ok(f(&mut conn)?)
+    }
+
+    /// Record a session if it is not known yet.
+    pub fn register_session(&self, session_id: &str) -> Result<()> {
+        let created_at = chrono::Utc::now().to_rfc3339();
+        self.with_conn(|conn| {
+            conn.execute(
+                "INSERT OR IGNORE INTO sessions (session_id, created_at) VALUES (?1, ?2)",
+                params![session_id, created_at],
+            )
+        })?;
+        Ok(())
+    }
+
+    /// All sessions with their latest saved state, most recent first.
+    pub fn sessions(&self) -> Result<Vec<SqliteSession>> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                "SELECT sessions.session_id, sessions.created_at, MAX(states.nonce)
+                 FROM sessions LEFT JOIN states USING (session_id)
+                 GROUP BY sessions.session_id
+                 ORDER BY sessions.created_at DESC, sessions.session_id DESC",
+            )?;
+            stmt.query_map([], |row| {
+                Ok(SqliteSession {
+                    session_id: row.get(0)?,
+                    created_at: row.get(1)?,
+                    latest_state: row.get(2)?,
+                })
+            })?
+            .collect()
+        })
+    }
+
+    /// Save the state at a batch's end and the completed batch atomically.
+    ///
+    /// Either both rows are written or neither is, so a crash mid-checkpoint
+    /// never leaves a completed batch without its state.
+    pub fn checkpoint(
+        &self,
+        state_nonce: Nonce,
+        state: &GameState,
+        batch: &ActionBatch,
+    ) -> Result<()> {
+        let state_bytes = state::encode(state)?;
+        let batch_json = action_batch::encode(batch)?;
+        self.with_conn(|conn| {
+            let tx = conn.transaction()?;
+            state::insert(&tx, &batch.session_id, state_nonce, &state_bytes)?;
+            action_batch::insert(&tx, batch, &batch_json)?;
+            tx.commit()
+        })
+    }
+
+    /// Next free sequence number of a session's rows in `table`.
+    fn next_seq(conn: &Connection, table: &str, session_id: &str) -> rusqlite::Result<u64> {
+        let max: Option<u64> = conn
+            .query_row(
+                &format!("SELECT MAX(seq) FROM {table} WHERE session_id = ?1"),
+                [session_id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        Ok(max.map_or(0, |seq| seq + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{Action, ActionInput, ActionKind, CharacterAction, EntityId};
+
+    use super::*;
+    use crate::events::{Event, GameStateEvent};
+    use crate::repository::{
+        ActionBatchRepository, ActionBatchStatus, ActionLogEntry, ActionLogReader, ActionLogWriter,
+        EventRepository, StateRepository,
+    };
+
+    fn wait() -> Action {
+        Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ))
+    }
+
+    #[test]
+    fn checkpoints_and_indexed_queries() {
+        let store = SqliteStore::in_memory().unwrap();
+        let states = SqliteStateRepository::new(&store, "session_1").unwrap();
+        let batches = SqliteActionBatchRepository::new(&store);
+
+        let mut state = GameState::with_player();
+        state.turn.nonce = 10;
+        let mut batch = ActionBatch::new("session_1".into(), 0);
+        batch.mark_complete(9);
+        store.checkpoint(9, &state, &batch).unwrap();
+        batches
+            .save(&ActionBatch::new("session_1".into(), 10))
+            .unwrap();
+
+        assert_eq!(states.load(9).unwrap().map(|s| s.nonce()), Some(10));
+        assert_eq!(states.list_nonces().unwrap(), vec![9]);
+        let complete = batches
+            .list_by_status("session_1", ActionBatchStatus::Complete)
+            .unwrap();
+        assert_eq!(complete.len(), 1);
+        assert_eq!(complete[0].end_nonce, 9);
+        assert_eq!(
+            batches
+                .get_current_batch("session_1")
+                .unwrap()
+                .map(|b| b.start_nonce),
+            Some(10)
+        );
+
+        SqliteStateRepository::new(&store, "session_2").unwrap();
+        let sessions = store.sessions().unwrap();
+        let latest = |id: &str| {
+            sessions
+                .iter()
+                .find(|session| session.session_id == id)
+                .map(|session| session.latest_state)
+        };
+        assert_eq!(latest("session_1"), Some(Some(9)));
+        assert_eq!(latest("session_2"), Some(None));
+    }
+
+    #[test]
+    fn logs_read_back_in_order() {
+        let store = SqliteStore::in_memory().unwrap();
+        let mut writer = SqliteActionLog::open(&store, "session_1").unwrap();
+        for nonce in 0..3 {
+            assert_eq!(
+                writer.append(&ActionLogEntry::new(nonce, wait())).unwrap(),
+                nonce
+            );
+        }
+
+        let reader = SqliteActionLogReader::new(&store, "session_1").unwrap();
+        reader.seek(1).unwrap();
+        assert_eq!(reader.read_next().unwrap().map(|e| e.nonce), Some(1));
+        assert_eq!(reader.read_next().unwrap().map(|e| e.nonce), Some(2));
+        assert!(reader.read_next().unwrap().is_none());
+
+        // A reopened writer continues the sequence
+        let mut writer = SqliteActionLog::open(&store, "session_1").unwrap();
+        assert_eq!(writer.append(&ActionLogEntry::new(3, wait())).unwrap(), 3);
+        assert!(reader.refresh().unwrap());
+        assert_eq!(reader.read_next().unwrap().map(|e| e.nonce), Some(3));
+        assert_eq!(writer.entries_in_range(1, 2).unwrap().len(), 2);
+
+        let mut events = SqliteEventLog::open(&store, "session_1").unwrap();
+        for (from_nonce, to_nonce) in [(5, 2), (8, 4)] {
+            events
+                .append(&Event::GameState(GameStateEvent::StateRestored {
+                    from_nonce,
+                    to_nonce,
+                }))
+                .unwrap();
+        }
+        assert_eq!(events.events_by_nonce(3, 10).unwrap().len(), 1);
+        let (_, next) = events.read_at_offset(0).unwrap().unwrap();
+        assert_eq!(next, 1);
+        assert!(events.read_at_offset(2).unwrap().is_none());
+    }
+}
@@ -0,0 +1,112 @@
+//! SQLite-backed StateRepository implementation.
+
+use game_core::GameState;
+use rusqlite::{Connection, OptionalExtension, params};
+
+use super::SqliteStore;
+use crate::repository::{RepositoryError, Result, StateRepository};
+
+pub(super) fn encode(state: &GameState) -> Result<Vec<u8>> {
+    bincode::serialize(state).map_err(|e| RepositoryError::Serialization(e.to_string()))
+}
+
+pub(super) fn insert(
+    conn: &Connection,
+    session_id: &str,
+    nonce: u64,
+    bytes: &[u8],
+) -> rusqlite::Result<usize> {
+    conn.execute(
+        "INSERT OR REPLACE INTO states (session_id, nonce, data) VALUES (?1, ?2, ?3)",
+        params![session_id, nonce, bytes],
+    )
+}
+
+/// SQLite implementation of StateRepository for one session.
+///
+/// States are keyed by nonce like `state_{nonce}.bin` files and stored in
+/// bincode format.
+pub struct SqliteStateRepository {
+    store: SqliteStore,
+    session_id: String,
+}
+
+impl SqliteStateRepository {
+    pub fn new(store: &SqliteStore, session_id: impl Into<String>) -> Result<Self> {
+        let session_id = session_id.into();
+        store.register_session(&session_id)?;
+        Ok(Self {
+            store: store.clone(),
+            session_id,
+        })
+    }
+}
+
+impl StateRepository for SqliteStateRepository {
+    fn save(&self, nonce: u64, state: &GameState) -> Result<()> {
+        let bytes = encode(state)?;
+        self.store
+            .with_conn(|conn| insert(conn, &self.session_id, nonce, &bytes))?;
+        tracing::debug!("Saved state[{}] of {}", nonce, self.session_id);
+        Ok(())
+    }
+
+    fn load(&self, nonce: u64) -> Result<Option<GameState>> {
+        let bytes: Option<Vec<u8>> = self.store.with_conn(|conn| {
+            conn.query_row(
+                "SELECT data FROM states WHERE session_id = ?1 AND nonce = ?2",
+                params![self.session_id, nonce],
+                |row| row.get(0),
+            )
+            .optional()
+        })?;
+
+        bytes
+            .map(|bytes| {
+                bincode::deserialize(&bytes)
+                    .map_err(|e| RepositoryError::Serialization(e.to_string()))
+            })
+            .transpose()
+    }
+
+    fn exists(&self, nonce: u64) -> bool {
+        self.store
+            .with_conn(|conn| {
+                conn.query_row(
+                    "SELECT 1 FROM states WHERE session_id = ?1 AND nonce = ?2",
+                    params![self.session_id, nonce],
+                    |_| Ok(()),
+                )
+                .optional()
+            })
+            .is_ok_and(|found| found.is_some())
+    }
+
+    fn delete(&self, nonce: u64) -> Result<()> {
+        self.store.with_conn(|conn| {
+            conn.execute(
+                "DELETE FROM states WHERE session_id = ?1 AND nonce = ?2",
+                params![self.session_id, nonce],
+            )
+        })?;
+        Ok(())
+    }
+
+    fn list_nonces(&self) -> Result<Vec<u64>> {
+        self.store.with_conn(|conn| {
+            let mut stmt =
+                conn.prepare("SELECT nonce FROM states WHERE session_id = ?1 ORDER BY nonce")?;
+            stmt.query_map([&self.session_id], |row| row.get(0))?
+                .collect()
+        })
+    }
+
+    fn delete_range(&self, start: u64, end: u64) -> Result<usize> {
+        self.store.with_conn(|conn| {
+            conn.execute(
+                "DELETE FROM states WHERE session_id = ?1 AND nonce BETWEEN ?2 AND ?3",
+                params![self.session_id, start, end],
+            )
+        })
+    }
+}
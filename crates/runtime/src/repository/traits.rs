@@ -1,8 +1,8 @@
 //! Repository contracts for saving and loading mutable runtime state.
 
-use game_core::GameState;
+use game_core::{GameState, StateDelta};
 
-use crate::events::Event;
+use crate::events::{Event, EventStream};
 
 use super::error::RepositoryError;
 
@@ -20,6 +20,15 @@ pub trait StateRepository: Send + Sync {
     /// Save a game state indexed by nonce
     fn save(&self, nonce: u64, state: &GameState) -> Result<()>;
 
+    /// Record the delta of an executed action, ahead of the next `save`
+    ///
+    /// Repositories that store checkpoints as deltas from a keyframe use this
+    /// to avoid writing full snapshots. Others ignore it.
+    fn record_delta(&self, delta: &StateDelta) -> Result<()> {
+        let _ = delta;
+        Ok(())
+    }
+
     /// Load a game state by nonce
     fn load(&self, nonce: u64) -> Result<Option<GameState>>;
 
@@ -64,6 +73,16 @@ pub trait EventRepository: Send + Sync {
     /// Returns `Some((event, next_offset))` where next_offset is the byte position after this entry.
     fn read_at_offset(&self, byte_offset: u64) -> Result<Option<(Event, u64)>>;
 
+    /// Stream events from `offset` up to the current end of the log
+    ///
+    /// Use [`EventStream::matching`] to filter by topic, entity or action kind.
+    fn stream(&self, offset: u64) -> EventStream<'_>
+    where
+        Self: Sized,
+    {
+        EventStream::new(self, offset)
+    }
+
     /// Flush buffered writes to disk
     fn flush(&mut self) -> Result<()>;
 
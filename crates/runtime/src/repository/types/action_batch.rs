@@ -48,6 +48,10 @@ pub struct ActionBatch {
 
     /// Current status of this batch
     pub status: ActionBatchStatus,
+
+    /// Proof attempts started so far, successful or not
+    #[serde(default)]
+    pub proof_attempts: u32,
 }
 
 /// Status of an action batch through its lifecycle.
@@ -118,6 +122,7 @@ impl ActionBatch {
             start_nonce,
             end_nonce: start_nonce, // Will be updated as actions are added
             status: ActionBatchStatus::InProgress,
+            proof_attempts: 0,
         }
     }
 
@@ -164,9 +169,10 @@ impl ActionBatch {
         self.status = ActionBatchStatus::Complete;
     }
 
-    /// Mark this batch as being proven.
+    /// Mark this batch as being proven, counting the attempt.
     pub fn mark_proving(&mut self) {
         self.status = ActionBatchStatus::Proving;
+        self.proof_attempts += 1;
     }
 
     /// Mark this batch as proven.
@@ -192,6 +198,15 @@ impl ActionBatch {
         matches!(self.status, ActionBatchStatus::Complete)
     }
 
+    /// Check if a proof of this batch may be (re)started: Complete, or Failed
+    /// after an earlier attempt.
+    pub fn can_start_proof(&self) -> bool {
+        matches!(
+            self.status,
+            ActionBatchStatus::Complete | ActionBatchStatus::Failed { .. }
+        )
+    }
+
     /// Check if this batch has a proof (Proven status).
     pub fn has_proof(&self) -> bool {
         matches!(self.status, ActionBatchStatus::Proven { .. })
@@ -275,6 +290,7 @@ mod tests {
 
         batch.mark_proving();
         assert!(matches!(batch.status, ActionBatchStatus::Proving));
+        assert_eq!(batch.proof_attempts, 1);
 
         batch.mark_proven("proof_file.bin".to_string(), 1000);
         assert!(batch.has_proof());
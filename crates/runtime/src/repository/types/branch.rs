@@ -0,0 +1,181 @@
+//! Branches of a session's action history.
+//!
+//! Rewinding a session never truncates its action log. Instead the runtime
+//! forks a new branch at the rewind nonce and records further actions there,
+//! so every timeline a player (or developer) explored stays on disk.
+//!
+//! # Layout
+//!
+//! The main branch is the canonical timeline and keeps the plain session
+//! layout, so proving, replays and verification only ever read it. Other
+//! branches live in their own directory with the same structure:
+//!
+//! ```text
+//! {base_dir}/{session_id}/
+//!   ├── actions/ states/ batches/      main branch
+//!   ├── branches.json                  branch index
+//!   └── branches/{id}/
+//!       └── actions/ states/ batches/  forked branch
+//! ```
+//!
+//! A forked branch shares the history of its parent up to its fork nonce and
+//! stores the state at that nonce as its first saved state.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::Nonce;
+
+/// Identifier of a session branch. The main branch is [`BranchId::MAIN`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BranchId(pub u32);
+
+impl BranchId {
+    /// The canonical branch, the only one covered by proofs.
+    pub const MAIN: Self = Self(0);
+
+    pub fn is_main(self) -> bool {
+        self == Self::MAIN
+    }
+
+    /// Directory holding this branch's actions, states and batches.
+    pub fn dir(self, session_dir: &Path) -> PathBuf {
+        if self.is_main() {
+            session_dir.to_path_buf()
+        } else {
+            session_dir.join("branches").join(self.0.to_string())
+        }
+    }
+}
+
+impl fmt::Display for BranchId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_main() {
+            write!(f, "main")
+        } else {
+            write!(f, "branch {}", self.0)
+        }
+    }
+}
+
+/// A branch and the point it was forked from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchInfo {
+    pub id: BranchId,
+    /// Branch this one was forked from (`None` for main).
+    pub parent: Option<BranchId>,
+    /// State nonce at which the branch starts (actions applied before it).
+    pub fork_nonce: Nonce,
+    /// Creation timestamp (ISO 8601 format)
+    pub created_at: String,
+}
+
+/// A branch as listed in the save menu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchSummary {
+    pub info: BranchInfo,
+    /// Whether new actions are currently recorded on this branch.
+    pub active: bool,
+    /// State nonces of the branch's saved states, ascending.
+    pub saved_nonces: Vec<Nonce>,
+    /// State nonce after the branch's last recorded action.
+    pub tip: Nonce,
+}
+
+/// All branches of a session and the one new actions are recorded on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchIndex {
+    pub active: BranchId,
+    pub branches: Vec<BranchInfo>,
+}
+
+impl Default for BranchIndex {
+    fn default() -> Self {
+        Self {
+            active: BranchId::MAIN,
+            branches: vec![BranchInfo {
+                id: BranchId::MAIN,
+                parent: None,
+                fork_nonce: 0,
+                created_at: String::new(),
+            }],
+        }
+    }
+}
+
+impl BranchIndex {
+    pub fn get(&self, id: BranchId) -> Option<&BranchInfo> {
+        self.branches.iter().find(|branch| branch.id == id)
+    }
+
+    /// Record a new branch forked from `parent` at `fork_nonce` and return its ID.
+    pub fn fork(&mut self, parent: BranchId, fork_nonce: Nonce, created_at: String) -> BranchId {
+        let id = BranchId(
+            self.branches
+                .iter()
+                .map(|branch| branch.id.0 + 1)
+                .max()
+                .unwrap_or(0),
+        );
+        self.branches.push(BranchInfo {
+            id,
+            parent: Some(parent),
+            fork_nonce,
+            created_at,
+        });
+        id
+    }
+
+    /// Branches whose history makes up `id`'s timeline, from `id` back to main.
+    ///
+    /// Each entry is a branch with the range of state nonces it contributes:
+    /// from its fork nonce up to (and including) the nonce where the next
+    /// branch in the list forked off it. Returns `None` for an unknown branch.
+    pub fn lineage(&self, id: BranchId) -> Option<Vec<(BranchId, Nonce, Nonce)>> {
+        let mut lineage = Vec::new();
+        let mut current = self.get(id)?;
+        let mut until = Nonce::MAX;
+        loop {
+            lineage.push((current.id, current.fork_nonce, until));
+            let Some(parent) = current.parent else {
+                return Some(lineage);
+            };
+            until = current.fork_nonce;
+            current = self.get(parent)?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lineage_walks_back_to_main() {
+        let mut index = BranchIndex::default();
+        let first = index.fork(BranchId::MAIN, 20, String::new());
+        let second = index.fork(first, 35, String::new());
+        let sibling = index.fork(BranchId::MAIN, 5, String::new());
+
+        assert_eq!(
+            (first, second, sibling),
+            (BranchId(1), BranchId(2), BranchId(3))
+        );
+        assert_eq!(
+            index.lineage(second),
+            Some(vec![
+                (second, 35, Nonce::MAX),
+                (first, 20, 35),
+                (BranchId::MAIN, 0, 20),
+            ])
+        );
+        assert_eq!(index.lineage(BranchId(9)), None);
+        assert_eq!(
+            second.dir(Path::new("s")),
+            Path::new("s").join("branches").join("2")
+        );
+        assert_eq!(BranchId::MAIN.dir(Path::new("s")), Path::new("s"));
+    }
+}
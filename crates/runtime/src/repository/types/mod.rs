@@ -2,6 +2,8 @@
 
 mod action_batch;
 mod action_log;
+mod branch;
 
 pub use action_batch::{ActionBatch, ActionBatchStatus};
 pub use action_log::ActionLogEntry;
+pub use branch::{BranchId, BranchIndex, BranchInfo, BranchSummary};
@@ -19,8 +19,8 @@ use crate::providers::SystemActionProvider;
 use crate::repository::ActionBatch;
 use crate::scenario::Scenario;
 use crate::workers::{
-    CheckpointStrategy, Command, PersistenceConfig, PersistenceWorker, ProofMetrics, ProverConfig,
-    ProverWorker, SimulationWorker,
+    CheckpointStrategy, Command, PersistenceConfig, PersistenceWorker, ProofMetrics,
+    ProofRetryPolicy, ProverConfig, ProverWorker, SimulationWorker, StateStorage, StorageBackend,
 };
 
 use serde::{Deserialize, Serialize};
@@ -35,6 +35,13 @@ type PersistenceWorkerResult = (
     Option<mpsc::Sender<crate::workers::persistence::Command>>,
 );
 
+/// Result type for prover worker creation: (worker handle, metrics, prover command sender)
+type ProverWorkerResult = (
+    Option<JoinHandle<()>>,
+    Option<ProofMetricsArc>,
+    Option<mpsc::Sender<crate::workers::prover::Command>>,
+);
+
 /// Core runtime configuration for channels and buffers.
 #[derive(Debug, Clone)]
 pub struct RuntimeConfig {
@@ -51,8 +58,12 @@ pub struct PersistenceSettings {
     pub enabled: bool,
     /// Base directory for persistence files (default: ./save_data)
     pub base_dir: std::path::PathBuf,
-    /// Number of actions between automatic checkpoints (default: 10)
-    pub checkpoint_interval: u64,
+    /// When action batches end (default: every 10 actions)
+    pub checkpoint_strategy: CheckpointStrategy,
+    /// Checkpoint state format (default: a full snapshot at every checkpoint)
+    pub state_storage: StateStorage,
+    /// Where states, batches and logs are stored (default: files under `base_dir`)
+    pub backend: StorageBackend,
 }
 
 /// ZK proving worker configuration.
@@ -62,6 +73,11 @@ pub struct ProvingSettings {
     pub enabled: bool,
     /// Optional directory to save generated proofs
     pub save_proofs_dir: Option<std::path::PathBuf>,
+    /// Retry policy for failed batch proofs
+    pub retry: ProofRetryPolicy,
+    /// Offload proving to a `prover-server` daemon (default: prove locally)
+    #[cfg(feature = "remote-prover")]
+    pub remote: Option<zk::RemoteConfig>,
 }
 
 /// Session initialization data stored at game creation.
@@ -76,9 +92,13 @@ pub struct SessionInit {
     pub created_at: String,
     /// Oracle root: SHA-256 hash of serialized oracle snapshot
     pub oracle_root: [u8; 32],
-    /// Random seed for RNG (kept secret)
-    pub seed: [u8; 32],
-    /// Seed commitment: SHA-256 hash of seed
+    /// RNG seed of the initial state (kept secret until finalization)
+    pub game_seed: u64,
+    /// Random session salt blinding hidden actions and state commitments
+    ///
+    /// Never revealed: finalization reveals [`SessionInit::reveal_salt`].
+    pub seed_salt: [u8; 32],
+    /// Salted seed commitment, see [`game_core::compute_seed_commitment`]
     pub seed_commitment: [u8; 32],
     /// Initial state root: hash of state_0
     pub initial_state_root: [u8; 32],
@@ -87,6 +107,34 @@ pub struct SessionInit {
     pub blockchain: Option<BlockchainSessionData>,
 }
 
+impl SessionInit {
+    /// Salt to reveal with the seed at finalization.
+    pub fn reveal_salt(&self) -> [u8; 32] {
+        game_core::seed_reveal_salt(&self.seed_salt)
+    }
+
+    /// Read `session_init.json` from a session directory.
+    pub fn load(session_dir: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = session_dir.as_ref().join("session_init.json");
+        let json = std::fs::read_to_string(&path).map_err(|e| {
+            RuntimeError::PersistenceError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&json).map_err(|e| {
+            RuntimeError::PersistenceError(format!("Failed to parse {}: {}", path.display(), e))
+        })
+    }
+
+    /// Oracle root of a snapshot: SHA-256 of its bincode serialization.
+    pub fn compute_oracle_root(snapshot: &zk::OracleSnapshot) -> Result<[u8; 32]> {
+        use sha2::{Digest, Sha256};
+
+        let bytes = bincode::serialize(snapshot).map_err(|e| {
+            RuntimeError::PersistenceError(format!("Failed to serialize oracle snapshot: {}", e))
+        })?;
+        Ok(Sha256::digest(&bytes).into())
+    }
+}
+
 /// Blockchain session metadata.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockchainSessionData {
@@ -119,7 +167,9 @@ impl Default for PersistenceSettings {
         Self {
             enabled: false,
             base_dir: Self::default_save_dir(),
-            checkpoint_interval: 10,
+            checkpoint_strategy: CheckpointStrategy::default(),
+            state_storage: StateStorage::default(),
+            backend: StorageBackend::default(),
         }
     }
 }
@@ -231,11 +281,27 @@ impl Runtime {
     /// - The entity's provider kind is not registered
     /// - Action execution fails
     pub async fn step(&mut self) -> Result<()> {
-        use game_core::{Action, ActionInput, ActionKind, CharacterAction};
+        use game_core::{
+            Action, ActionInput, ActionKind, AiKind, AiTurnAction, CharacterAction, ProviderKind,
+            SystemActionKind,
+        };
 
         // 1. Prepare turn (SimulationWorker determines which entity acts)
         let (entity, snapshot) = self.handle.prepare_next_turn().await?;
 
+        // Provable AI actors decide inside the engine; no provider is consulted
+        // and only the AiTurn marker is recorded.
+        let provider_kind = snapshot
+            .entities
+            .actor(entity)
+            .map(|actor| actor.provider_kind);
+        if provider_kind == Some(ProviderKind::Ai(AiKind::Provable)) {
+            self.handle
+                .execute_action(Action::system(SystemActionKind::AiTurn(AiTurnAction)))
+                .await?;
+            return Ok(());
+        }
+
         // 2. Get provider for this entity (from Runtime's registry)
         let provider = {
             let registry = self
@@ -380,6 +446,13 @@ impl RuntimeBuilder {
         self
     }
 
+    /// Prove on a remote daemon instead of in-process
+    #[cfg(feature = "remote-prover")]
+    pub fn remote_prover(mut self, config: zk::RemoteConfig) -> Self {
+        self.proving.remote = Some(config);
+        self
+    }
+
     /// Enable persistence worker for state/event/proof persistence
     pub fn enable_persistence(mut self, enable: bool) -> Self {
         self.persistence.enabled = enable;
@@ -400,7 +473,25 @@ impl RuntimeBuilder {
 
     /// Set checkpoint interval (number of actions between checkpoints)
     pub fn checkpoint_interval(mut self, interval: u64) -> Self {
-        self.persistence.checkpoint_interval = interval;
+        self.persistence.checkpoint_strategy = CheckpointStrategy::EveryNActions(interval);
+        self
+    }
+
+    /// Set when action batches end (actions, ticks, wall-clock time or game moments)
+    pub fn checkpoint_strategy(mut self, strategy: CheckpointStrategy) -> Self {
+        self.persistence.checkpoint_strategy = strategy;
+        self
+    }
+
+    /// Set how checkpoint states are stored (full snapshots or keyframes plus deltas)
+    pub fn state_storage(mut self, storage: StateStorage) -> Self {
+        self.persistence.state_storage = storage;
+        self
+    }
+
+    /// Set where states, batches and logs are stored (files or a SQLite database)
+    pub fn storage_backend(mut self, backend: StorageBackend) -> Self {
+        self.persistence.backend = backend;
         self
     }
 
@@ -474,11 +565,12 @@ impl RuntimeBuilder {
             }
         }
 
-        // Checkpoint interval validation
-        if self.persistence.enabled && self.persistence.checkpoint_interval == 0 {
-            return Err(RuntimeError::InvalidConfig(
-                "Checkpoint interval must be greater than 0".to_string(),
-            ));
+        // Checkpoint strategy validation
+        if self.persistence.enabled {
+            self.persistence
+                .checkpoint_strategy
+                .validate()
+                .map_err(RuntimeError::InvalidConfig)?;
         }
 
         Ok(())
@@ -554,10 +646,19 @@ impl RuntimeBuilder {
         #[cfg(feature = "sui")]
         let blockchain_clients_arc = blockchain_clients.map(Arc::new);
 
-        // Create RuntimeHandle with persistence channel
+        let (prover_worker_handle, proof_metrics, prover_cmd_tx) = Self::create_prover_worker(
+            &config,
+            &persistence,
+            &proving,
+            batch_complete_rx,
+            oracles.clone(),
+        )?;
+
+        // Create RuntimeHandle with persistence and prover channels
         let handle = RuntimeHandle::new(
             command_tx.clone(),
             persistence_cmd_tx,
+            prover_cmd_tx,
             event_bus.clone(),
             providers.clone(),
             config.session_id.clone(),
@@ -566,14 +667,6 @@ impl RuntimeBuilder {
             blockchain_clients_arc.clone(),
         );
 
-        let (prover_worker_handle, proof_metrics) = Self::create_prover_worker(
-            &config,
-            &persistence,
-            &proving,
-            batch_complete_rx,
-            oracles.clone(),
-        )?;
-
         Ok(Runtime {
             handle,
             workers: WorkerHandles {
@@ -625,11 +718,12 @@ impl RuntimeBuilder {
 
         let persistence_config =
             PersistenceConfig::new(config.session_id.clone(), persistence.base_dir.clone())
-                .with_strategy(CheckpointStrategy::EveryNActions(
-                    persistence.checkpoint_interval,
-                ));
+                .with_strategy(persistence.checkpoint_strategy.clone())
+                .with_state_storage(persistence.state_storage)
+                .with_backend(persistence.backend.clone());
 
-        let event_rx = event_bus.subscribe(Topic::GameState);
+        let subscriptions =
+            event_bus.subscribe_multiple(&[Topic::GameState, Topic::Proof, Topic::Game]);
 
         // PersistenceWorker has its own Command type, but we don't expose it
         // Keep the sender alive to prevent the worker from shutting down
@@ -642,7 +736,7 @@ impl RuntimeBuilder {
 
         let persistence_worker = PersistenceWorker::new(
             persistence_config,
-            event_rx,
+            subscriptions,
             persistence_cmd_rx,
             sim_command_tx,
             batch_complete_tx,
@@ -663,7 +757,8 @@ impl RuntimeBuilder {
 
     /// Create and spawn the prover worker (if enabled).
     ///
-    /// Returns the worker handle and metrics Arc, or None if proving is disabled.
+    /// Returns the worker handle, metrics Arc and command sender, or None if
+    /// proving is disabled.
     ///
     /// # Preconditions
     ///
@@ -674,9 +769,9 @@ impl RuntimeBuilder {
         proving: &ProvingSettings,
         batch_complete_rx: Option<mpsc::UnboundedReceiver<ActionBatch>>,
         _oracles: OracleBundle,
-    ) -> Result<(Option<JoinHandle<()>>, Option<ProofMetricsArc>)> {
+    ) -> Result<ProverWorkerResult> {
         if !proving.enabled {
-            return Ok((None, None));
+            return Ok((None, None, None));
         }
 
         // Persistence is guaranteed to be enabled by validate()
@@ -697,61 +792,103 @@ impl RuntimeBuilder {
         // (RISC0 proof generation is extremely CPU-intensive)
         let prover_config =
             ProverConfig::new(config.session_id.clone(), persistence.base_dir.clone())
-                .with_max_parallel(1); // TODO: Make this configurable via env var
+                .with_max_parallel(1) // TODO: Make this configurable via env var
+                .with_retry_policy(proving.retry)
+                .with_backend(persistence.backend.clone());
 
         // Create oracle snapshot for prover (includes all actors, items, maps, actions, config)
         let oracle_snapshot = _oracles.to_snapshot();
+        #[cfg(feature = "remote-prover")]
+        let remote_snapshot = oracle_snapshot.clone();
 
-        // Create prover instance (stub or risc0)
+        // The guest derives the journal's seed commitment from this salt
+        #[cfg(not(feature = "stub"))]
+        let seed_salt = SessionInit::load(persistence.base_dir.join(&config.session_id))?.seed_salt;
+
+        // Create prover instance (stub, native, risc0, sp1 or arkworks)
         #[cfg(feature = "stub")]
         let prover = {
             use zk::StubProver;
-            Arc::new(StubProver::new(oracle_snapshot))
+            StubProver::new(oracle_snapshot)
+        };
+
+        #[cfg(all(feature = "native", not(feature = "stub")))]
+        let prover = {
+            use zk::NativeProver;
+            NativeProver::new(oracle_snapshot, seed_salt)
         };
 
         #[cfg(all(feature = "risc0", not(feature = "stub")))]
         let prover = {
             use zk::Risc0Prover;
-            Arc::new(Risc0Prover::new(oracle_snapshot))
+            Risc0Prover::new(oracle_snapshot, seed_salt)
         };
 
         #[cfg(all(feature = "sp1", not(feature = "stub")))]
         let prover = {
             use zk::Sp1Prover;
-            Arc::new(Sp1Prover::new(oracle_snapshot))
+            Sp1Prover::new(oracle_snapshot, seed_salt)
+        };
+
+        #[cfg(all(feature = "arkworks", not(feature = "stub")))]
+        let prover = {
+            use zk::ArkworksProver;
+            ArkworksProver::new(oracle_snapshot, seed_salt)
+        };
+
+        // The local backend still verifies what a remote daemon sends back
+        #[cfg(feature = "remote-prover")]
+        let prover: Arc<dyn zk::Prover> = match &proving.remote {
+            Some(remote) => {
+                tracing::info!("Offloading proofs to {}", remote.endpoint);
+                let seed_salt =
+                    SessionInit::load(persistence.base_dir.join(&config.session_id))?.seed_salt;
+                Arc::new(zk::RemoteProver::new(
+                    remote.clone(),
+                    remote_snapshot,
+                    seed_salt,
+                    prover,
+                ))
+            }
+            None => Arc::new(prover),
         };
 
-        // Create command channel (keep sender alive to prevent shutdown)
+        #[cfg(not(feature = "remote-prover"))]
+        let prover = Arc::new(prover);
+
+        // Create command channel (the sender lives in RuntimeHandle; the worker
+        // stops once every handle is dropped)
         let (prover_cmd_tx, prover_cmd_rx) = mpsc::channel(8);
+        let metrics = Arc::new(ProofMetrics::new());
 
         // Create ProverWorker
-        let prover_worker =
-            ProverWorker::new(prover_config, prover, prover_cmd_rx, batch_complete_rx)
-                .map_err(|e| RuntimeError::InvalidConfig(e.to_string()))?;
+        let prover_worker = ProverWorker::new(
+            prover_config,
+            prover,
+            Arc::clone(&metrics),
+            prover_cmd_rx,
+            batch_complete_rx,
+        )
+        .map_err(|e| RuntimeError::InvalidConfig(e.to_string()))?;
 
         let handle = tokio::spawn(async move {
             prover_worker.run().await;
         });
 
-        // Keep the command sender alive by forgetting it
-        // (If we drop it, the worker will shut down immediately)
-        std::mem::forget(prover_cmd_tx);
-
-        // TODO: Re-add metrics
-        Ok((Some(handle), None))
+        Ok((Some(handle), Some(metrics), Some(prover_cmd_tx)))
     }
 
     /// Create session initialization file with cryptographic commitments.
     ///
     /// This method is called during Runtime::build() to create a session_init.json
-    /// file containing oracle_root, seed, seed_commitment, and initial_state_root.
+    /// file containing oracle_root, the seed and its salted commitment, and
+    /// initial_state_root.
     fn create_session_init_file(
         config: &RuntimeConfig,
         persistence: &PersistenceSettings,
         oracles: &OracleBundle,
         initial_state: &GameState,
     ) -> Result<()> {
-        use sha2::{Digest, Sha256};
         use std::fs;
         use std::io::Write;
 
@@ -772,22 +909,19 @@ impl RuntimeBuilder {
         tracing::info!("Creating session initialization file");
 
         // 1. Compute oracle_root: SHA-256 hash of serialized oracle snapshot
-        let oracle_snapshot = oracles.to_snapshot();
-        let oracle_bytes = bincode::serialize(&oracle_snapshot).map_err(|e| {
-            RuntimeError::PersistenceError(format!("Failed to serialize oracle snapshot: {}", e))
-        })?;
-        let oracle_root: [u8; 32] = Sha256::digest(&oracle_bytes).into();
+        let oracle_root = SessionInit::compute_oracle_root(&oracles.to_snapshot())?;
 
-        // 2. Generate random 32-byte seed
+        // 2. Generate the random 32-byte session salt
         use rand::RngCore;
-        let mut seed = [0u8; 32];
-        rand::thread_rng().fill_bytes(&mut seed);
+        let mut seed_salt = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut seed_salt);
 
-        // 3. Compute seed_commitment: SHA-256 hash of seed
-        let seed_commitment: [u8; 32] = Sha256::digest(seed).into();
+        // 3. Compute seed_commitment over the seed the proofs will execute with
+        let game_seed = initial_state.game_seed;
+        let seed_commitment = game_core::compute_seed_commitment(game_seed, &seed_salt);
 
-        // 4. Compute initial_state_root
-        let initial_state_root = initial_state.compute_state_root();
+        // 4. Compute initial_state_root (hiding commitment, as the proofs journal it)
+        let initial_state_root = initial_state.compute_journal_state_root(&seed_salt);
 
         // 5. Create SessionInit structure
         let created_at = chrono::Utc::now().to_rfc3339();
@@ -795,7 +929,8 @@ impl RuntimeBuilder {
             session_id: config.session_id.clone(),
             created_at,
             oracle_root,
-            seed,
+            game_seed,
+            seed_salt,
             seed_commitment,
             initial_state_root,
             blockchain: None, // Will be populated after blockchain session creation
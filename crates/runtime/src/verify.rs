@@ -0,0 +1,577 @@
+//! Deterministic re-execution of saved sessions.
+//!
+//! [`SessionVerifier`] replays a session's action log from its genesis state
+//! through [`GameEngine::execute`] with a snapshot-backed environment (the same
+//! oracles the prover sees) and compares the state root of the replayed state
+//! at every saved state. A mismatch means the engine no longer reproduces the
+//! recorded run, which would later surface as a failing proof.
+//!
+//! # Locating the divergence
+//!
+//! Saved states are sparse, so a root mismatch only says that something went
+//! wrong between the last matching saved state and the mismatching one. The
+//! field-level [`StateDelta`] between the recorded and the replayed state names
+//! what differs; the verifier then scans the actions of that interval for the
+//! first one whose own changes touch a differing field. That is a hint, not a
+//! verdict: the action that actually went wrong may be a later one writing
+//! the same field. An action that fails or is missing on replay is reported
+//! at its exact nonce.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use game_core::{
+    ActorChanges, CollectionChanges, EntityId, GameEngine, GameState, ItemChanges, PropChanges,
+    SnapshotOracleBundle, StateDelta,
+};
+
+use crate::api::{Result, RuntimeError};
+use crate::providers::ReplayLog;
+use crate::repository::{FileStateRepository, StateRepository};
+use crate::runtime::SessionInit;
+
+/// How replay parted from the recorded session.
+#[derive(Debug, Clone)]
+pub enum DivergenceKind {
+    /// The saved genesis state does not hash to `session_init.json`'s root.
+    Genesis,
+    /// No action was logged at the nonce, but later saved states exist.
+    MissingAction,
+    /// The logged action is rejected by the engine.
+    Execution(String),
+    /// The replayed state root differs from the saved state.
+    StateRoot {
+        /// Field-level difference from the saved (expected) to the replayed state.
+        diff: Box<StateDelta>,
+        /// Nonce of the first action in the interval whose own changes touch a
+        /// field in `diff`.
+        ///
+        /// A heuristic: that action need not be the wrong one, since a later
+        /// action may write the same field. `None` when no replayed action
+        /// touched the differing fields, i.e. the recorded run changed state
+        /// the replay never does.
+        first_touch: Option<u64>,
+    },
+}
+
+/// First point where replay disagrees with the recorded session.
+#[derive(Debug, Clone)]
+pub struct Divergence {
+    /// Nonce of the action that is missing or fails on replay.
+    ///
+    /// `None` for [`DivergenceKind::Genesis`] and [`DivergenceKind::StateRoot`],
+    /// where no single action is known to be at fault.
+    pub nonce: Option<u64>,
+    /// Nonce of the last saved state that matched.
+    pub last_verified: u64,
+    /// Nonce of the state where the divergence was detected.
+    pub detected_at: u64,
+    pub kind: DivergenceKind,
+}
+
+/// Result of verifying a session.
+#[derive(Debug, Clone)]
+pub struct VerifyReport {
+    /// Actions re-executed before stopping.
+    pub actions_replayed: u64,
+    /// Saved states whose root matched the replayed state.
+    pub verified_states: usize,
+    /// Saved states in the session.
+    pub saved_states: usize,
+    /// Whether the oracle snapshot matches `session_init.json`
+    /// (`None` when the session has no init file).
+    pub oracle_root_matches: Option<bool>,
+    pub divergence: Option<Divergence>,
+}
+
+impl VerifyReport {
+    /// Whether replay reproduced every saved state.
+    pub fn is_deterministic(&self) -> bool {
+        self.divergence.is_none()
+    }
+}
+
+/// Re-executes a recorded session and checks it against its saved states.
+pub struct SessionVerifier<S> {
+    log: ReplayLog,
+    states: S,
+    snapshot: zk::OracleSnapshot,
+    init: Option<SessionInit>,
+}
+
+impl SessionVerifier<FileStateRepository> {
+    /// Open `session_id` stored under `session_dir`.
+    ///
+    /// `snapshot` must be built from the content the session was played with;
+    /// the report says whether its root matches the recorded one.
+    pub fn open(
+        session_dir: impl AsRef<Path>,
+        session_id: &str,
+        snapshot: zk::OracleSnapshot,
+    ) -> Result<Self> {
+        let session_dir = session_dir.as_ref();
+        let log = ReplayLog::load(session_dir, session_id)?;
+        let states = FileStateRepository::new(session_dir.join("states"))?;
+        let init = if session_dir.join("session_init.json").exists() {
+            Some(SessionInit::load(session_dir)?)
+        } else {
+            None
+        };
+        Ok(Self::new(log, states, snapshot, init))
+    }
+}
+
+impl<S: StateRepository> SessionVerifier<S> {
+    pub fn new(
+        log: ReplayLog,
+        states: S,
+        snapshot: zk::OracleSnapshot,
+        init: Option<SessionInit>,
+    ) -> Self {
+        Self {
+            log,
+            states,
+            snapshot,
+            init,
+        }
+    }
+
+    /// Replay the whole log and compare every saved state.
+    ///
+    /// Stops at the first divergence.
+    pub fn verify(&self) -> Result<VerifyReport> {
+        let oracle_root_matches = match &self.init {
+            Some(init) => {
+                Some(SessionInit::compute_oracle_root(&self.snapshot)? == init.oracle_root)
+            }
+            None => None,
+        };
+
+        // Saved states by state nonce: (file key, state root).
+        let mut saved = BTreeMap::new();
+        for key in self.states.list_nonces()? {
+            if let Some(state) = self.states.load(key)? {
+                saved.insert(state.nonce(), (key, state.compute_state_root()));
+            }
+        }
+        let mut report = VerifyReport {
+            actions_replayed: 0,
+            verified_states: 0,
+            saved_states: saved.len(),
+            oracle_root_matches,
+            divergence: None,
+        };
+
+        let genesis = self.states.load(0)?.ok_or_else(|| {
+            RuntimeError::PersistenceError("session has no genesis state (state_0)".to_string())
+        })?;
+        if let Some(init) = &self.init
+            && genesis.compute_journal_state_root(&init.seed_salt) != init.initial_state_root
+        {
+            report.divergence = Some(Divergence {
+                nonce: None,
+                last_verified: genesis.nonce(),
+                detected_at: genesis.nonce(),
+                kind: DivergenceKind::Genesis,
+            });
+            return Ok(report);
+        }
+        report.verified_states += 1;
+
+        let last_saved = saved.keys().next_back().copied().unwrap_or(0);
+        let bundle = SnapshotOracleBundle::new(&self.snapshot);
+        let env = bundle.as_env();
+
+        // States replayed since the last verified saved state (inclusive).
+        let mut interval = vec![genesis];
+        loop {
+            let mut state = interval.last().expect("interval starts verified").clone();
+            let nonce = state.nonce();
+            let last_verified = interval[0].nonce();
+
+            let Some(action) = self.log.action(nonce) else {
+                if nonce < last_saved {
+                    report.divergence = Some(Divergence {
+                        nonce: Some(nonce),
+                        last_verified,
+                        detected_at: nonce,
+                        kind: DivergenceKind::MissingAction,
+                    });
+                }
+                return Ok(report);
+            };
+
+            if let Err(error) = GameEngine::new(&mut state).execute(env.as_game_env(), action) {
+                report.divergence = Some(Divergence {
+                    nonce: Some(nonce),
+                    last_verified,
+                    detected_at: nonce,
+                    kind: DivergenceKind::Execution(error.to_string()),
+                });
+                return Ok(report);
+            }
+            report.actions_replayed += 1;
+
+            match saved.get(&state.nonce()) {
+                Some(&(_, root)) if root == state.compute_state_root() => {
+                    report.verified_states += 1;
+                    interval = vec![state];
+                }
+                Some(&(key, _)) => {
+                    let recorded = self.states.load(key)?.ok_or_else(|| {
+                        RuntimeError::PersistenceError(format!("state_{key} disappeared"))
+                    })?;
+                    let diff = StateDelta::from_states(action.clone(), &recorded, &state);
+                    interval.push(state);
+                    report.divergence = Some(Divergence {
+                        nonce: None,
+                        last_verified,
+                        detected_at: interval.last().map_or(nonce, GameState::nonce),
+                        kind: DivergenceKind::StateRoot {
+                            first_touch: self.first_touching(&interval, &diff),
+                            diff: Box::new(diff),
+                        },
+                    });
+                    return Ok(report);
+                }
+                None => interval.push(state),
+            }
+        }
+    }
+
+    /// Nonce of the first action in `interval` whose own changes touch a field
+    /// in `diff`.
+    ///
+    /// Scans step by step rather than bisecting the cumulative delta: a field
+    /// that changes and then reverts drops out of the start-to-end delta, so
+    /// that predicate is not monotone.
+    fn first_touching(&self, interval: &[GameState], diff: &StateDelta) -> Option<u64> {
+        interval.windows(2).find_map(|pair| {
+            let (before, after) = (&pair[0], &pair[1]);
+            let action = self.log.action(before.nonce())?;
+            let step = StateDelta::from_states(action.clone(), before, after);
+            overlaps(&step, diff).then_some(before.nonce())
+        })
+    }
+}
+
+/// Whether two deltas change any field of the same entity or world location.
+fn overlaps(a: &StateDelta, b: &StateDelta) -> bool {
+    let turn = a.turn.fields.intersects(b.turn.fields)
+        || shares_id(&a.turn.activated, &b.turn.activated)
+        || shares_id(&a.turn.deactivated, &b.turn.deactivated);
+    let actors = collection_overlaps(
+        &a.entities.actors,
+        &b.entities.actors,
+        |c: &ActorChanges| c.id,
+        |x, y| x.fields.intersects(y.fields),
+    );
+    let props = collection_overlaps(
+        &a.entities.props,
+        &b.entities.props,
+        |c: &PropChanges| c.id,
+        |x, y| x.fields.intersects(y.fields),
+    );
+    let items = collection_overlaps(
+        &a.entities.items,
+        &b.entities.items,
+        |c: &ItemChanges| c.id,
+        |x, y| x.fields.intersects(y.fields),
+    );
+    let world = a
+        .world
+        .occupancy
+        .iter()
+        .any(|change| b.world.occupancy.contains(change))
+        || (a.world.flags && b.world.flags)
+        || (a.world.conversation && b.world.conversation);
+
+    turn || actors || props || items || world
+}
+
+fn collection_overlaps<T>(
+    a: &CollectionChanges<T>,
+    b: &CollectionChanges<T>,
+    id: impl Fn(&T) -> EntityId,
+    fields_overlap: impl Fn(&T, &T) -> bool,
+) -> bool {
+    let ids = |changes: &CollectionChanges<T>| {
+        changes
+            .added
+            .iter()
+            .chain(&changes.removed)
+            .copied()
+            .chain(changes.updated.iter().map(&id))
+            .collect::<Vec<_>>()
+    };
+    let (a_ids, b_ids) = (ids(a), ids(b));
+
+    // Creating or deleting an entity touches every field of it.
+    let structural = a
+        .added
+        .iter()
+        .chain(&a.removed)
+        .any(|entity| b_ids.contains(entity))
+        || b.added
+            .iter()
+            .chain(&b.removed)
+            .any(|entity| a_ids.contains(entity));
+
+    structural
+        || a.updated.iter().any(|x| {
+            b.updated
+                .iter()
+                .any(|y| id(x) == id(y) && fields_overlap(x, y))
+        })
+}
+
+fn shares_id(a: &[EntityId], b: &[EntityId]) -> bool {
+    a.iter().any(|entity| b.contains(entity))
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            DivergenceKind::Genesis => {
+                writeln!(f, "genesis state does not match session_init.json")
+            }
+            DivergenceKind::MissingAction => {
+                writeln!(f, "no action logged at nonce {}", self.detected_at)
+            }
+            DivergenceKind::Execution(error) => {
+                writeln!(f, "action at nonce {} failed: {}", self.detected_at, error)
+            }
+            DivergenceKind::StateRoot { diff, first_touch } => {
+                writeln!(
+                    f,
+                    "state root mismatch at nonce {} (last match at nonce {})",
+                    self.detected_at, self.last_verified
+                )?;
+                match first_touch {
+                    Some(nonce) => writeln!(
+                        f,
+                        "first action touching a differing field: nonce {nonce} \
+                         (a hint; a later action in the interval may be at fault)"
+                    )?,
+                    None => writeln!(f, "no replayed action touched the differing fields")?,
+                }
+                write_diff(f, diff)
+            }
+        }
+    }
+}
+
+/// Field-level listing of a recorded → replayed delta.
+fn write_diff(f: &mut fmt::Formatter<'_>, diff: &StateDelta) -> fmt::Result {
+    if diff.is_empty() {
+        return writeln!(
+            f,
+            "  no field-level difference (fields outside the delta differ)"
+        );
+    }
+
+    let turn = &diff.turn;
+    if !turn.fields.is_empty() {
+        writeln!(f, "  turn: {}", flag_names(turn.fields.iter_names()))?;
+    }
+    if !turn.activated.is_empty() {
+        writeln!(f, "  active only in replay: {:?}", turn.activated)?;
+    }
+    if !turn.deactivated.is_empty() {
+        writeln!(f, "  active only in recording: {:?}", turn.deactivated)?;
+    }
+
+    write_collection(f, "actor", &diff.entities.actors, |c| {
+        (c.id, flag_names(c.fields.iter_names()))
+    })?;
+    write_collection(f, "prop", &diff.entities.props, |c| {
+        (c.id, flag_names(c.fields.iter_names()))
+    })?;
+    write_collection(f, "item", &diff.entities.items, |c| {
+        (c.id, flag_names(c.fields.iter_names()))
+    })?;
+
+    for change in &diff.world.occupancy {
+        writeln!(f, "  occupancy at {:?}", change.position)?;
+    }
+    if diff.world.flags {
+        writeln!(f, "  story flags")?;
+    }
+    if diff.world.conversation {
+        writeln!(f, "  conversation")?;
+    }
+    Ok(())
+}
+
+fn write_collection<T>(
+    f: &mut fmt::Formatter<'_>,
+    label: &str,
+    changes: &CollectionChanges<T>,
+    describe: impl Fn(&T) -> (EntityId, String),
+) -> fmt::Result {
+    for entity in &changes.added {
+        writeln!(f, "  {label} {entity:?}: only in replay")?;
+    }
+    for entity in &changes.removed {
+        writeln!(f, "  {label} {entity:?}: only in recording")?;
+    }
+    for change in &changes.updated {
+        let (entity, fields) = describe(change);
+        writeln!(f, "  {label} {entity:?}: {fields}")?;
+    }
+    Ok(())
+}
+
+fn flag_names<'a>(names: impl Iterator<Item = (&'a str, impl Sized)>) -> String {
+    names.map(|(name, _)| name).collect::<Vec<_>>().join(" | ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use game_core::{
+        Action, ActionAbility, ActionInput, ActionKind, CardinalDirection, CharacterAction,
+        GameConfig, PrepareTurnAction, SystemActionKind,
+    };
+
+    use super::*;
+    use crate::oracle::{
+        ActionOracleImpl, ActorOracleImpl, ConfigOracleImpl, DialogueOracleImpl, ItemOracleImpl,
+        MapOracleImpl, OracleBundle,
+    };
+    use crate::repository::{ActionLogEntry, InMemoryStateRepo};
+
+    fn snapshot() -> zk::OracleSnapshot {
+        OracleBundle::new(
+            Arc::new(MapOracleImpl::test_map(10, 10)),
+            Arc::new(ItemOracleImpl::new()),
+            Arc::new(ActionOracleImpl::new()),
+            Arc::new(ActorOracleImpl::new()),
+            Arc::new(ConfigOracleImpl::new(GameConfig::default())),
+            Arc::new(DialogueOracleImpl::new()),
+        )
+        .to_snapshot()
+    }
+
+    fn player(kind: ActionKind, input: ActionInput) -> Action {
+        Action::character(CharacterAction::new(EntityId::PLAYER, kind, input))
+    }
+
+    /// Play `actions` from the genesis state, saving every second state.
+    fn record(snapshot: &zk::OracleSnapshot, actions: &[Action]) -> InMemoryStateRepo {
+        let bundle = SnapshotOracleBundle::new(snapshot);
+        let env = bundle.as_env();
+        let mut state = GameState::with_player();
+        let player = &mut state.entities.actors[0];
+        player.actions.push(ActionAbility::new(ActionKind::Wait));
+        player.actions.push(ActionAbility::new(ActionKind::Move));
+        let position = player.position.unwrap();
+        state
+            .world
+            .tile_map
+            .add_occupant(position, EntityId::PLAYER);
+        let states = InMemoryStateRepo::with_initial_state(state.clone());
+        for action in actions {
+            GameEngine::new(&mut state)
+                .execute(env.as_game_env(), action)
+                .unwrap();
+            if state.nonce().is_multiple_of(2) {
+                states.save(state.nonce(), &state).unwrap();
+            }
+        }
+        states
+    }
+
+    #[test]
+    fn locates_first_divergent_action() {
+        let snapshot = snapshot();
+        let prepare = Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction));
+        let wait = player(ActionKind::Wait, ActionInput::None);
+        let step = player(
+            ActionKind::Move,
+            ActionInput::Direction(CardinalDirection::East),
+        );
+        let recorded = [
+            prepare.clone(),
+            wait.clone(),
+            prepare.clone(),
+            wait.clone(),
+            prepare.clone(),
+            wait.clone(),
+            prepare.clone(),
+            wait.clone(),
+        ];
+        let states = record(&snapshot, &recorded);
+
+        let log = |actions: &[Action]| {
+            ReplayLog::from_entries(
+                actions
+                    .iter()
+                    .enumerate()
+                    .map(|(nonce, action)| ActionLogEntry::new(nonce as u64, action.clone())),
+            )
+        };
+
+        let clean = SessionVerifier::new(log(&recorded), states, snapshot.clone(), None)
+            .verify()
+            .unwrap();
+        assert!(clean.is_deterministic());
+        assert_eq!(clean.actions_replayed, 8);
+        assert_eq!(clean.verified_states, 5);
+
+        // The log says the player moved on its third turn, but the recorded
+        // states come from a run where it waited.
+        let mut tampered = recorded.clone();
+        tampered[5] = step;
+        let report =
+            SessionVerifier::new(log(&tampered), record(&snapshot, &recorded), snapshot, None)
+                .verify()
+                .unwrap();
+
+        let divergence = report.divergence.expect("replay should diverge");
+        assert_eq!(divergence.nonce, None);
+        assert_eq!(divergence.last_verified, 4);
+        assert_eq!(divergence.detected_at, 6);
+        assert_eq!(report.verified_states, 3);
+        let DivergenceKind::StateRoot { diff, first_touch } = divergence.kind else {
+            panic!("expected a state root mismatch");
+        };
+        assert_eq!(first_touch, Some(5));
+        assert!(diff.entities.actors.updated.iter().any(
+            |c| c.id == EntityId::PLAYER && c.fields.contains(game_core::ActorFields::POSITION)
+        ));
+    }
+
+    #[test]
+    fn reverted_changes_do_not_hide_the_first_touch() {
+        // Player HP goes 10 -> 5 -> 10 -> 10 -> 7: the start-to-end delta of
+        // the middle states misses the first two changes.
+        let wait = player(ActionKind::Wait, ActionInput::None);
+        let interval: Vec<_> = [10, 5, 10, 10, 7]
+            .into_iter()
+            .enumerate()
+            .map(|(nonce, hp)| {
+                let mut state = GameState::with_player();
+                state.turn.nonce = nonce as u64;
+                state.entities.actors[0].resources.hp = hp;
+                state
+            })
+            .collect();
+        let log = ReplayLog::from_entries(
+            (0..interval.len() as u64).map(|nonce| ActionLogEntry::new(nonce, wait.clone())),
+        );
+        let verifier = SessionVerifier::new(
+            log,
+            InMemoryStateRepo::with_initial_state(interval[0].clone()),
+            snapshot(),
+            None,
+        );
+
+        let mut recorded = interval[4].clone();
+        recorded.entities.actors[0].resources.hp = 9;
+        let diff = StateDelta::from_states(wait, &recorded, &interval[4]);
+        assert_eq!(verifier.first_touching(&interval, &diff), Some(0));
+    }
+}
@@ -0,0 +1,273 @@
+//! Checkpoint strategies deciding where action batches end.
+//!
+//! Batch boundaries set the granularity of proofs, so besides plain action
+//! counts a checkpoint can follow the game clock, wall-clock time, or
+//! meaningful game moments such as a boss falling.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use game_core::{GameState, Species, Tick};
+
+/// Checkpoint strategy determines when to create action batch boundaries
+///
+/// Strategies are checked after every persisted action, so a batch always
+/// contains at least one action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckpointStrategy {
+    /// Create checkpoint every N actions
+    EveryNActions(u64),
+
+    /// Create checkpoint once the game clock advanced N ticks
+    EveryNTicks(Tick),
+
+    /// Create checkpoint once this much real time passed
+    WallClockInterval(Duration),
+
+    /// Create checkpoint right after a game moment
+    OnEvent(CheckpointEvent),
+
+    /// Create checkpoint when any of the strategies asks for one
+    Any(Vec<CheckpointStrategy>),
+
+    /// Manual checkpoints only (via command)
+    Manual,
+}
+
+/// Game moments that end an action batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckpointEvent {
+    /// The player gained a character level
+    LevelUp,
+
+    /// A story flag was set (quest progress, new areas)
+    FlagSet,
+
+    /// An actor of [`Species::Boss`] died
+    BossDefeated,
+
+    /// The player's HP dropped below this percentage of its maximum
+    PlayerHpBelow(u8),
+}
+
+/// Progress of the current batch, checked against the strategy
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BatchProgress {
+    pub actions: u64,
+    pub start_clock: Tick,
+    pub started_at: Instant,
+}
+
+impl BatchProgress {
+    pub fn new(clock: Tick) -> Self {
+        Self {
+            actions: 0,
+            start_clock: clock,
+            started_at: Instant::now(),
+        }
+    }
+}
+
+impl Default for CheckpointStrategy {
+    fn default() -> Self {
+        Self::EveryNActions(10)
+    }
+}
+
+impl CheckpointStrategy {
+    /// Whether the action from `before` to `after` should end the batch
+    pub(crate) fn is_due(
+        &self,
+        progress: &BatchProgress,
+        before: &GameState,
+        after: &GameState,
+    ) -> bool {
+        match self {
+            Self::EveryNActions(n) => progress.actions >= *n,
+            Self::EveryNTicks(n) => after.turn.clock.saturating_sub(progress.start_clock) >= *n,
+            Self::WallClockInterval(interval) => progress.started_at.elapsed() >= *interval,
+            Self::OnEvent(event) => event.occurred(before, after),
+            Self::Any(strategies) => strategies
+                .iter()
+                .any(|strategy| strategy.is_due(progress, before, after)),
+            Self::Manual => false,
+        }
+    }
+
+    /// Reject strategies that would checkpoint after every action by accident
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::EveryNActions(0) => Err("Checkpoint interval must be greater than 0".into()),
+            Self::EveryNTicks(0) => Err("Checkpoint tick interval must be greater than 0".into()),
+            Self::WallClockInterval(interval) if interval.is_zero() => {
+                Err("Checkpoint wall-clock interval must be greater than 0".into())
+            }
+            Self::OnEvent(CheckpointEvent::PlayerHpBelow(percent)) if *percent > 100 => {
+                Err(format!("HP threshold {percent}% is above 100%"))
+            }
+            Self::Any(strategies) if strategies.is_empty() => {
+                Err("Composite checkpoint strategy is empty".into())
+            }
+            Self::Any(strategies) => strategies.iter().try_for_each(Self::validate),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl CheckpointEvent {
+    fn occurred(self, before: &GameState, after: &GameState) -> bool {
+        match self {
+            Self::LevelUp => {
+                after.entities.player().core_stats.level > before.entities.player().core_stats.level
+            }
+            Self::FlagSet => after
+                .world
+                .flags
+                .difference(&before.world.flags)
+                .next()
+                .is_some(),
+            Self::BossDefeated => before
+                .entities
+                .all_actors()
+                .filter(|actor| actor.species == Species::Boss && actor.is_alive())
+                .any(|boss| {
+                    after
+                        .entities
+                        .actor(boss.id)
+                        .is_none_or(|actor| !actor.is_alive())
+                }),
+            Self::PlayerHpBelow(percent) => {
+                let below = |state: &GameState| {
+                    let player = state.entities.player();
+                    let hp_max = player.snapshot().resource_max.hp_max;
+                    u64::from(player.resources.hp) * 100 < u64::from(hp_max) * u64::from(percent)
+                };
+                !below(before) && below(after)
+            }
+        }
+    }
+}
+
+/// Parses the `CHECKPOINT_STRATEGY` syntax: strategies joined by `|`.
+///
+/// ```text
+/// actions:10 | ticks:500 | secs:30 | level_up | flag_set | boss_defeated | hp_below:25 | manual
+/// ```
+impl FromStr for CheckpointStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut strategies = s
+            .split('|')
+            .map(|part| parse_single(part.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let strategy = if strategies.len() == 1 {
+            strategies.remove(0)
+        } else {
+            Self::Any(strategies)
+        };
+        strategy.validate()?;
+        Ok(strategy)
+    }
+}
+
+fn parse_single(s: &str) -> Result<CheckpointStrategy, String> {
+    let (kind, arg) = match s.split_once(':') {
+        Some((kind, arg)) => (kind.trim(), Some(arg.trim())),
+        None => (s, None),
+    };
+    let number = |arg: Option<&str>| -> Result<u64, String> {
+        let arg = arg.ok_or_else(|| format!("checkpoint strategy '{kind}' needs a value"))?;
+        arg.parse()
+            .map_err(|_| format!("invalid value '{arg}' for checkpoint strategy '{kind}'"))
+    };
+
+    let strategy = match kind {
+        "actions" => CheckpointStrategy::EveryNActions(number(arg)?),
+        "ticks" => CheckpointStrategy::EveryNTicks(number(arg)?),
+        "secs" => CheckpointStrategy::WallClockInterval(Duration::from_secs(number(arg)?)),
+        "level_up" => CheckpointStrategy::OnEvent(CheckpointEvent::LevelUp),
+        "flag_set" => CheckpointStrategy::OnEvent(CheckpointEvent::FlagSet),
+        "boss_defeated" => CheckpointStrategy::OnEvent(CheckpointEvent::BossDefeated),
+        "hp_below" => {
+            let percent = u8::try_from(number(arg)?)
+                .map_err(|_| format!("HP threshold '{}' is out of range", s))?;
+            CheckpointStrategy::OnEvent(CheckpointEvent::PlayerHpBelow(percent))
+        }
+        "manual" => CheckpointStrategy::Manual,
+        _ => return Err(format!("unknown checkpoint strategy '{s}'")),
+    };
+    Ok(strategy)
+}
+
+impl fmt::Display for CheckpointStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EveryNActions(n) => write!(f, "actions:{n}"),
+            Self::EveryNTicks(n) => write!(f, "ticks:{n}"),
+            Self::WallClockInterval(interval) => write!(f, "secs:{}", interval.as_secs()),
+            Self::OnEvent(CheckpointEvent::LevelUp) => write!(f, "level_up"),
+            Self::OnEvent(CheckpointEvent::FlagSet) => write!(f, "flag_set"),
+            Self::OnEvent(CheckpointEvent::BossDefeated) => write!(f, "boss_defeated"),
+            Self::OnEvent(CheckpointEvent::PlayerHpBelow(percent)) => {
+                write!(f, "hp_below:{percent}")
+            }
+            Self::Any(strategies) => {
+                for (i, strategy) in strategies.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "|")?;
+                    }
+                    write!(f, "{strategy}")?;
+                }
+                Ok(())
+            }
+            Self::Manual => write!(f, "manual"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strategies_parse_and_fire_on_game_moments() {
+        let strategy: CheckpointStrategy = "actions:50 | ticks:1000 | boss_defeated | hp_below:25"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            strategy,
+            CheckpointStrategy::Any(vec![
+                CheckpointStrategy::EveryNActions(50),
+                CheckpointStrategy::EveryNTicks(1000),
+                CheckpointStrategy::OnEvent(CheckpointEvent::BossDefeated),
+                CheckpointStrategy::OnEvent(CheckpointEvent::PlayerHpBelow(25)),
+            ])
+        );
+        assert_eq!(
+            strategy.to_string().parse::<CheckpointStrategy>(),
+            Ok(strategy.clone())
+        );
+        assert!("actions:0".parse::<CheckpointStrategy>().is_err());
+        assert!("hp_below:300".parse::<CheckpointStrategy>().is_err());
+        assert!("sometimes".parse::<CheckpointStrategy>().is_err());
+
+        let before = GameState::with_player();
+        let progress = BatchProgress::new(before.turn.clock);
+        let mut after = before.clone();
+        after.turn.clock += 10;
+        assert!(!strategy.is_due(&progress, &before, &after));
+
+        after.turn.clock += 1000;
+        assert!(strategy.is_due(&progress, &before, &after));
+
+        let mut after = before.clone();
+        let hp_max = after.entities.player().snapshot().resource_max.hp_max;
+        after.entities.player_mut().resources.hp = hp_max / 5;
+        assert!(strategy.is_due(&progress, &before, &after));
+        // Only crossing the threshold counts, not staying below it
+        assert!(!strategy.is_due(&progress, &after, &after));
+    }
+}
@@ -19,9 +19,18 @@ pub struct ProofMetrics {
     /// Total number of proof generations that failed
     failed: AtomicU64,
 
-    /// Number of actions currently queued for proving
+    /// Number of batches still to be proven (queued, proving or waiting to retry)
     queue_depth: AtomicU64,
 
+    /// Number of batches being proven right now
+    proving: AtomicU64,
+
+    /// Total number of retries scheduled after failed proofs
+    retries: AtomicU64,
+
+    /// Number of batches that used up their retries
+    exhausted: AtomicU64,
+
     /// Total time spent generating proofs (sum of all durations, in nanoseconds)
     total_proving_time_nanos: AtomicU64,
 
@@ -66,6 +75,21 @@ impl ProofMetrics {
         }
     }
 
+    /// Sets the number of batches being proven.
+    pub fn set_proving(&self, proving: u64) {
+        self.proving.store(proving, Ordering::Relaxed);
+    }
+
+    /// Records a retry scheduled after a failed proof.
+    pub fn record_retry(&self) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Sets the number of batches that used up their retries.
+    pub fn set_exhausted(&self, exhausted: u64) {
+        self.exhausted.store(exhausted, Ordering::Relaxed);
+    }
+
     /// Returns snapshot of current generated count.
     pub fn generated(&self) -> u64 {
         self.generated.load(Ordering::Relaxed)
@@ -81,6 +105,21 @@ impl ProofMetrics {
         self.queue_depth.load(Ordering::Relaxed)
     }
 
+    /// Returns number of batches being proven.
+    pub fn proving(&self) -> u64 {
+        self.proving.load(Ordering::Relaxed)
+    }
+
+    /// Returns total number of retries scheduled.
+    pub fn retries(&self) -> u64 {
+        self.retries.load(Ordering::Relaxed)
+    }
+
+    /// Returns number of batches that used up their retries.
+    pub fn exhausted(&self) -> u64 {
+        self.exhausted.load(Ordering::Relaxed)
+    }
+
     /// Returns peak queue depth observed.
     pub fn peak_queue_depth(&self) -> u64 {
         self.peak_queue_depth.load(Ordering::Relaxed)
@@ -125,6 +164,9 @@ impl ProofMetrics {
             generated: self.generated(),
             failed: self.failed(),
             queue_depth: self.queue_depth(),
+            proving: self.proving(),
+            retries: self.retries(),
+            exhausted: self.exhausted(),
             peak_queue_depth: self.peak_queue_depth(),
             avg_proving_time: self.avg_proving_time(),
             success_rate: self.success_rate(),
@@ -138,6 +180,9 @@ pub struct MetricsSnapshot {
     pub generated: u64,
     pub failed: u64,
     pub queue_depth: u64,
+    pub proving: u64,
+    pub retries: u64,
+    pub exhausted: u64,
     pub peak_queue_depth: u64,
     pub avg_proving_time: Duration,
     pub success_rate: f64,
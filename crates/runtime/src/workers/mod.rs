@@ -3,12 +3,18 @@
 //! The simulation worker executes gameplay commands, while additional workers
 //! (e.g., prover) can be added to offload specialized duties.
 
+mod checkpoint;
 mod metrics;
 pub mod persistence;
-mod prover;
+mod proof_queue;
+pub mod prover;
 pub mod simulation;
+mod storage;
 
+pub use checkpoint::{CheckpointEvent, CheckpointStrategy};
 pub use metrics::ProofMetrics;
-pub use persistence::{CheckpointStrategy, PersistenceConfig, PersistenceWorker};
+pub use persistence::{PersistenceConfig, PersistenceWorker, StateStorage};
+pub use proof_queue::{ProofQueueStatus, ProofRetryPolicy};
 pub use prover::{ProverConfig, ProverWorker};
 pub use simulation::{Command, SimulationWorker};
+pub use storage::StorageBackend;
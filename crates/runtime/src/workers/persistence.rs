@@ -13,6 +13,8 @@
 //! # State Management
 //!
 //! - Only end_nonce state is saved per batch
+//! - With [`StateStorage::Delta`], most of those states are stored as the
+//!   deltas recorded since the last full keyframe and rebuilt on load
 //! - ProverWorker loads previous batch's end state (= current batch's start state)
 //! - First batch requires genesis state (nonce 0)
 //!
@@ -28,24 +30,48 @@
 //!   │   └── ...
 //!   ├── states/
 //!   │   ├── state_{nonce}.bin
+//!   │   ├── deltas/            (keyframe deltas and their index)
 //!   │   └── ...
-//!   └── events/
-//!       └── events_{session}.log
+//!   ├── events/
+//!   │   └── events_{session}.log
+//!   ├── branches.json
+//!   └── branches/{id}/         (same actions/, batches/, states/ layout)
 //! ```
+//!
+//! With [`StorageBackend::Sqlite`] actions, batches, states and events go to
+//! the database instead; the session directory keeps `branches.json`. A
+//! checkpoint's state and completed batch are then written in one transaction.
+//!
+//! # Branches
+//!
+//! Actions, batches and states are recorded on the session's active branch
+//! (see [`BranchIndex`]). Rewinding forks a new branch instead of truncating
+//! the log; only batches of the main branch are handed to the prover. The event
+//! log is shared by all branches.
+//!
+//! # Event Log
+//!
+//! Executed actions are logged as [`ActionRef`]s; proof and domain events
+//! ([`Topic::Proof`], [`Topic::Game`]) are logged as published, so projections
+//! can rebuild read models from the log alone.
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 
-use game_core::GameState;
+use game_core::{Action, EntityId, GameState};
 use tokio::sync::{broadcast, mpsc, oneshot};
 use tokio::time::{Duration, sleep};
 use tracing::{debug, error, info, warn};
 
-use crate::events::{Event, GameStateEvent};
+use crate::events::{ActionRef, Event, GameStateEvent, Topic};
+use crate::providers::ReplayLog;
 use crate::repository::{
-    ActionBatch, ActionBatchRepository, ActionLogEntry, FileActionBatchRepository, FileActionLog,
-    FileEventLog, FileStateRepository, StateRepository,
+    ActionBatch, ActionBatchRepository, ActionLogEntry, ActionLogWriter, BranchId, BranchIndex,
+    BranchInfo, BranchSummary, EventRepository, FileBranchRepository, StateRepository,
 };
+use crate::workers::checkpoint::{BatchProgress, CheckpointStrategy};
 use crate::workers::simulation::Command as SimCommand;
+use crate::workers::storage::{SessionStorage, StorageBackend};
 
 /// Result type for persistence operations
 pub type Result<T> = std::result::Result<T, PersistenceError>;
@@ -62,6 +88,9 @@ pub enum PersistenceError {
     #[error("Failed to save batch: {0}")]
     BatchSave(String),
 
+    #[error("Failed to save checkpoint at nonce {nonce}: {error}")]
+    Checkpoint { nonce: u64, error: String },
+
     #[error("Failed to append event: {0}")]
     EventAppend(String),
 
@@ -83,6 +112,19 @@ pub enum PersistenceError {
     #[error("Failed to send command to SimulationWorker")]
     CommandSend,
 
+    #[error("Unknown branch: {0}")]
+    UnknownBranch(BranchId),
+
+    #[error("Cannot rewind {branch} to nonce {nonce}: {reason}")]
+    Rewind {
+        branch: BranchId,
+        nonce: u64,
+        reason: String,
+    },
+
+    #[error("Failed to read session init: {0}")]
+    SessionInit(String),
+
     #[error(transparent)]
     Repository(#[from] crate::repository::RepositoryError),
 
@@ -90,19 +132,24 @@ pub enum PersistenceError {
     Io(#[from] std::io::Error),
 }
 
-/// Checkpoint strategy determines when to create action batch boundaries
-#[derive(Debug, Clone)]
-pub enum CheckpointStrategy {
-    /// Create checkpoint every N actions
-    EveryNActions(u64),
+/// How checkpoint states are written to disk
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StateStorage {
+    /// Full snapshot at every checkpoint
+    #[default]
+    Full,
 
-    /// Manual checkpoints only (via command)
-    Manual,
+    /// Full keyframe every N checkpoints, recorded deltas in between
+    Delta { keyframe_interval: u64 },
 }
 
-impl Default for CheckpointStrategy {
-    fn default() -> Self {
-        Self::EveryNActions(10)
+impl StateStorage {
+    /// Checkpoints per full snapshot (1 for [`StateStorage::Full`])
+    pub fn keyframe_interval(self) -> u64 {
+        match self {
+            Self::Full => 1,
+            Self::Delta { keyframe_interval } => keyframe_interval,
+        }
     }
 }
 
@@ -117,6 +164,12 @@ pub struct PersistenceConfig {
 
     /// Checkpoint strategy
     pub strategy: CheckpointStrategy,
+
+    /// Checkpoint state storage format
+    pub state_storage: StateStorage,
+
+    /// Where states, batches and logs are stored
+    pub backend: StorageBackend,
 }
 
 impl PersistenceConfig {
@@ -126,6 +179,8 @@ impl PersistenceConfig {
             session_id,
             base_dir,
             strategy: CheckpointStrategy::default(),
+            state_storage: StateStorage::default(),
+            backend: StorageBackend::default(),
         }
     }
 
@@ -134,6 +189,18 @@ impl PersistenceConfig {
         self.strategy = strategy;
         self
     }
+
+    /// Set checkpoint state storage format
+    pub fn with_state_storage(mut self, state_storage: StateStorage) -> Self {
+        self.state_storage = state_storage;
+        self
+    }
+
+    /// Set the storage backend
+    pub fn with_backend(mut self, backend: StorageBackend) -> Self {
+        self.backend = backend;
+        self
+    }
 }
 
 /// Commands that can be sent to the persistence worker
@@ -166,45 +233,91 @@ pub enum Command {
         reply: oneshot::Sender<Result<()>>,
     },
 
-    /// Read action log for a batch (for manual workflow)
+    /// Read the publishable action log of a batch (for manual workflow)
     GetActionLog {
         start_nonce: u64,
         reply: oneshot::Sender<Result<Vec<u8>>>,
     },
 
+    /// List the session's branches with their saved states
+    ListBranches {
+        reply: oneshot::Sender<Result<Vec<BranchSummary>>>,
+    },
+
+    /// Find the saved state and logged actions that rebuild `branch` at `nonce`
+    PlanRewind {
+        branch: BranchId,
+        nonce: u64,
+        reply: oneshot::Sender<Result<RewindPlan>>,
+    },
+
+    /// Find the start of `entity`'s latest complete turn before `before` on the active branch
+    ///
+    /// Replies with the active branch and the turn start, if any.
+    LastTurnStart {
+        entity: EntityId,
+        before: u64,
+        reply: oneshot::Sender<Result<(BranchId, Option<u64>)>>,
+    },
+
+    /// Record further actions on a branch continuing from `state`
+    ///
+    /// Resumes `branch` if `state` is at its tip, otherwise forks a new branch.
+    SwitchBranch {
+        branch: BranchId,
+        state: Box<GameState>,
+        reply: oneshot::Sender<Result<BranchInfo>>,
+    },
+
     /// Shutdown the worker gracefully
     Shutdown,
 }
 
+/// Saved state plus the logged actions that lead from it to a rewind target.
+pub struct RewindPlan {
+    pub base: GameState,
+    pub actions: Vec<Action>,
+}
+
 /// Background worker that handles all persistence operations
 pub struct PersistenceWorker {
     config: PersistenceConfig,
+    session_dir: PathBuf,
+    storage: SessionStorage,
 
-    // Repositories
-    state_repo: FileStateRepository,
-    batch_repo: FileActionBatchRepository,
-    event_repo: FileEventLog,
+    // Repositories (state and batches of the active branch)
+    state_repo: Box<dyn StateRepository>,
+    batch_repo: Box<dyn ActionBatchRepository>,
+    main_batch_repo: Box<dyn ActionBatchRepository>,
+    event_repo: Box<dyn EventRepository>,
+    branch_repo: FileBranchRepository,
+    branches: BranchIndex,
 
     // Current batch tracking
     current_batch: Option<ActionBatch>,
-    current_action_log: Option<FileActionLog>,
+    current_action_log: Option<Box<dyn ActionLogWriter>>,
 
-    // Communication channels
-    event_rx: broadcast::Receiver<Event>,
+    // Communication channels (game state events, then logged-only topics)
+    event_rx: Option<broadcast::Receiver<Event>>,
+    proof_rx: Option<broadcast::Receiver<Event>>,
+    game_rx: Option<broadcast::Receiver<Event>>,
     command_rx: mpsc::Receiver<Command>,
     sim_command_tx: mpsc::Sender<SimCommand>,
     batch_complete_tx: mpsc::UnboundedSender<ActionBatch>,
 
     // Checkpoint tracking
     strategy: CheckpointStrategy,
-    actions_since_checkpoint: u64,
+    progress: BatchProgress,
 }
 
 impl PersistenceWorker {
     /// Create a new persistence worker
+    ///
+    /// `subscriptions` holds the event bus receivers per topic; actions come
+    /// from [`Topic::GameState`], the other topics are only logged.
     pub fn new(
         config: PersistenceConfig,
-        event_rx: broadcast::Receiver<Event>,
+        mut subscriptions: HashMap<Topic, broadcast::Receiver<Event>>,
         command_rx: mpsc::Receiver<Command>,
         sim_command_tx: mpsc::Sender<SimCommand>,
         batch_complete_tx: mpsc::UnboundedSender<ActionBatch>,
@@ -215,26 +328,36 @@ impl PersistenceWorker {
         // Create session directory: base_dir/{session_id}/
         let session_dir = base_dir.join(session_id);
 
-        // Create repository instances under session directory
-        let state_repo = FileStateRepository::new(session_dir.join("states"))?;
-        let batch_repo = FileActionBatchRepository::new(session_dir.join("batches"))?;
-
-        let event_filename = format!("events_{}.log", session_id);
-        let event_repo = FileEventLog::open_or_create(session_dir.join("events"), &event_filename)?;
+        // Create repository instances for the active branch
+        let storage = SessionStorage::open(&config.backend, base_dir, session_id)?;
+        let branch_repo = FileBranchRepository::new(&session_dir);
+        let branches = branch_repo.load()?;
+        let state_repo =
+            storage.states(branches.active, config.state_storage.keyframe_interval())?;
+        let batch_repo = storage.batches(branches.active)?;
+        let main_batch_repo = storage.batches(BranchId::MAIN)?;
+        let event_repo = storage.events()?;
 
         Ok(Self {
             strategy: config.strategy.clone(),
             config,
+            session_dir,
+            storage,
             state_repo,
             batch_repo,
+            main_batch_repo,
             event_repo,
+            branch_repo,
+            branches,
             current_batch: None,
             current_action_log: None,
-            event_rx,
+            event_rx: subscriptions.remove(&Topic::GameState),
+            proof_rx: subscriptions.remove(&Topic::Proof),
+            game_rx: subscriptions.remove(&Topic::Game),
             command_rx,
             sim_command_tx,
             batch_complete_tx,
-            actions_since_checkpoint: 0,
+            progress: BatchProgress::new(0),
         })
     }
 
@@ -255,6 +378,7 @@ impl PersistenceWorker {
         };
 
         let current_nonce = current_state.turn.nonce;
+        self.progress = BatchProgress::new(current_state.turn.clock);
 
         // Save genesis state only if we're starting from nonce 0
         if current_nonce == 0 {
@@ -278,8 +402,12 @@ impl PersistenceWorker {
 
         loop {
             tokio::select! {
+                // Game state first, so actions are logged before the domain
+                // events published after them
+                biased;
+
                 // Handle incoming events from event bus
-                event = self.event_rx.recv() => {
+                event = async { self.event_rx.as_mut().unwrap().recv().await }, if self.event_rx.is_some() => {
                     match event {
                         Ok(event) => {
                             if let Err(e) = self.handle_event_with_retry(event).await {
@@ -301,6 +429,18 @@ impl PersistenceWorker {
                     }
                 }
 
+                event = async { self.game_rx.as_mut().unwrap().recv().await }, if self.game_rx.is_some() => {
+                    if !self.log_published(event, Topic::Game).await {
+                        self.game_rx = None;
+                    }
+                }
+
+                event = async { self.proof_rx.as_mut().unwrap().recv().await }, if self.proof_rx.is_some() => {
+                    if !self.log_published(event, Topic::Proof).await {
+                        self.proof_rx = None;
+                    }
+                }
+
                 // Handle commands
                 cmd = self.command_rx.recv() => {
                     match cmd {
@@ -319,12 +459,12 @@ impl PersistenceWorker {
                             let _ = reply.send(result);
                         }
                         Some(Command::ListAllCheckpoints { reply }) => {
-                            let result = self.batch_repo.list(&self.config.session_id)
+                            let result = self.main_batch_repo.list(&self.config.session_id)
                                 .map_err(PersistenceError::from);
                             let _ = reply.send(result);
                         }
                         Some(Command::GetCheckpoint { start_nonce, reply }) => {
-                            let result = self.batch_repo.load(&self.config.session_id, start_nonce)
+                            let result = self.main_batch_repo.load(&self.config.session_id, start_nonce)
                                 .map_err(PersistenceError::from);
                             let _ = reply.send(result);
                         }
@@ -341,6 +481,19 @@ impl PersistenceWorker {
                             let result = self.read_action_log(start_nonce).await;
                             let _ = reply.send(result);
                         }
+                        Some(Command::ListBranches { reply }) => {
+                            let _ = reply.send(self.list_branches());
+                        }
+                        Some(Command::PlanRewind { branch, nonce, reply }) => {
+                            let _ = reply.send(self.plan_rewind(branch, nonce));
+                        }
+                        Some(Command::LastTurnStart { entity, before, reply }) => {
+                            let _ = reply.send(self.last_turn_start(entity, before));
+                        }
+                        Some(Command::SwitchBranch { branch, state, reply }) => {
+                            let result = self.switch_branch(branch, &state).await;
+                            let _ = reply.send(result);
+                        }
                         Some(Command::Shutdown) => {
                             info!("Shutdown command received");
                             break;
@@ -439,6 +592,30 @@ impl PersistenceWorker {
         Ok(())
     }
 
+    /// Log an event of a topic that is recorded but not acted on.
+    ///
+    /// These topics are best-effort on the bus, so lost events are only
+    /// reported. Returns false once the topic is closed.
+    async fn log_published(
+        &mut self,
+        event: std::result::Result<Event, broadcast::error::RecvError>,
+        topic: Topic,
+    ) -> bool {
+        match event {
+            Ok(event) => {
+                if let Err(e) = self.handle_event_with_retry(event).await {
+                    panic!("FATAL persistence error: {}", e);
+                }
+                true
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("Event log missed {} {:?} events", skipped, topic);
+                true
+            }
+            Err(broadcast::error::RecvError::Closed) => false,
+        }
+    }
+
     /// Handle an event with exponential backoff retry
     async fn handle_event_with_retry(&mut self, event: Event) -> Result<()> {
         const MAX_RETRIES: u32 = 5;
@@ -488,12 +665,22 @@ impl PersistenceWorker {
                 if let GameStateEvent::ActionExecuted {
                     nonce,
                     action,
+                    delta,
+                    clock,
+                    before_state,
                     after_state,
                     ..
                 } = game_event
                 {
-                    self.handle_action_executed(*nonce, action.clone(), after_state)
-                        .await?;
+                    self.handle_action_executed(
+                        *nonce,
+                        *clock,
+                        action.clone(),
+                        delta,
+                        before_state,
+                        after_state,
+                    )
+                    .await?;
                 }
             }
             _ => {
@@ -515,7 +702,10 @@ impl PersistenceWorker {
     async fn handle_action_executed(
         &mut self,
         nonce: u64,
+        clock: game_core::Tick,
         action: game_core::Action,
+        delta: &game_core::StateDelta,
+        before_state: &game_core::GameState,
         after_state: &game_core::GameState,
     ) -> Result<()> {
         // Ensure we have a current batch and action log
@@ -530,8 +720,10 @@ impl PersistenceWorker {
 
         // Append to action log
         let entry = ActionLogEntry::new(nonce, action);
+        let mut action_offset = 0;
         if let Some(log) = self.current_action_log.as_mut() {
-            log.append(&entry)
+            action_offset = log
+                .append(&entry)
                 .map_err(|e| PersistenceError::ActionLogAppend(e.to_string()))?;
 
             // Flush immediately for ProverWorker
@@ -539,7 +731,33 @@ impl PersistenceWorker {
                 .map_err(|e| PersistenceError::ActionLogFlush(e.to_string()))?;
         }
 
-        self.actions_since_checkpoint += 1;
+        // Reference the action from the event timeline
+        let action_ref = Event::ActionRef(ActionRef {
+            nonce,
+            action_offset,
+            clock,
+            actor: entry.action.actor(),
+            action_kind: match &entry.action {
+                Action::Character(action) => Some(action.kind),
+                Action::System { .. } => None,
+            },
+        });
+        self.event_repo
+            .append(&action_ref)
+            .map_err(|e| PersistenceError::EventAppend(e.to_string()))?;
+        self.event_repo
+            .flush()
+            .map_err(|e| PersistenceError::EventFlush(e.to_string()))?;
+
+        // Keep the delta ahead of the checkpoint that may store it
+        self.state_repo
+            .record_delta(delta)
+            .map_err(|e| PersistenceError::StateSave {
+                nonce,
+                error: e.to_string(),
+            })?;
+
+        self.progress.actions += 1;
 
         debug!(
             "Persisted action: nonce={}, actor={:?}",
@@ -548,7 +766,9 @@ impl PersistenceWorker {
         );
 
         // Check if we should checkpoint
-        if self.should_checkpoint()
+        if self
+            .strategy
+            .is_due(&self.progress, before_state, after_state)
             && let Err(e) = self.create_checkpoint(after_state).await
         {
             error!("Failed to create checkpoint: {}", e);
@@ -557,20 +777,29 @@ impl PersistenceWorker {
         Ok(())
     }
 
-    /// Check if we should create a checkpoint based on strategy
-    fn should_checkpoint(&self) -> bool {
-        match self.strategy {
-            CheckpointStrategy::EveryNActions(n) => self.actions_since_checkpoint >= n,
-            CheckpointStrategy::Manual => false,
-        }
-    }
-
     /// Create a checkpoint by completing current batch and starting new one
     ///
     /// The `state` parameter should be the after_state from the last ActionExecuted event.
     async fn create_checkpoint(&mut self, state: &game_core::GameState) -> Result<u64> {
         debug!("Creating checkpoint...");
 
+        let end_nonce = self.complete_current_batch(state)?;
+
+        // Start new batch
+        self.start_new_batch(end_nonce + 1).await?;
+
+        // Reset batch progress
+        self.progress = BatchProgress::new(state.turn.clock);
+
+        Ok(end_nonce)
+    }
+
+    /// Save the state at the current batch's end and mark the batch complete.
+    ///
+    /// Completed batches of the main branch are handed to the ProverWorker.
+    fn complete_current_batch(&mut self, state: &game_core::GameState) -> Result<u64> {
+        self.current_action_log = None;
+
         // Get current batch
         let mut batch = self
             .current_batch
@@ -579,46 +808,41 @@ impl PersistenceWorker {
 
         let end_nonce = batch.end_nonce;
 
-        // Save state at end_nonce
-        self.state_repo
-            .save(end_nonce, state)
-            .map_err(|e| PersistenceError::StateSave {
+        // Save state at end_nonce and the completed batch together
+        batch.mark_complete(end_nonce);
+        self.storage
+            .checkpoint(
+                self.state_repo.as_ref(),
+                self.batch_repo.as_ref(),
+                end_nonce,
+                state,
+                &batch,
+            )
+            .map_err(|e| PersistenceError::Checkpoint {
                 nonce: end_nonce,
                 error: e.to_string(),
             })?;
 
-        // Close current action log
-        self.current_action_log = None;
-
-        // Mark batch as complete
-        batch.mark_complete(end_nonce);
-        self.batch_repo
-            .save(&batch)
-            .map_err(|e| PersistenceError::BatchSave(e.to_string()))?;
-
         info!(
-            "Checkpoint created: session={}, nonce={}, actions={}",
+            "Checkpoint created: session={}, branch={}, nonce={}, actions={}",
             self.config.session_id,
+            self.branches.active,
             end_nonce,
             batch.action_count()
         );
 
-        // Notify ProverWorker about the completed batch
+        // Notify ProverWorker about the completed batch (proofs only cover main)
         // Use unbounded channel so batch information is never lost
         // ProverWorker has internal queue management and will process at its own pace
-        if let Err(e) = self.batch_complete_tx.send(batch.clone()) {
+        if self.branches.active.is_main()
+            && let Err(e) = self.batch_complete_tx.send(batch)
+        {
             warn!(
                 "Failed to notify ProverWorker about completed batch (channel closed): {}",
                 e
             );
         }
 
-        // Start new batch
-        self.start_new_batch(end_nonce + 1).await?;
-
-        // Reset counter
-        self.actions_since_checkpoint = 0;
-
         Ok(end_nonce)
     }
 
@@ -635,7 +859,7 @@ impl PersistenceWorker {
 
         // Load the batch
         let mut batch = self
-            .batch_repo
+            .main_batch_repo
             .load(&self.config.session_id, start_nonce)
             .map_err(PersistenceError::from)?
             .ok_or_else(|| {
@@ -646,7 +870,7 @@ impl PersistenceWorker {
         batch.update_status(new_status);
 
         // Save updated batch
-        self.batch_repo
+        self.main_batch_repo
             .save(&batch)
             .map_err(|e| PersistenceError::BatchSave(e.to_string()))?;
 
@@ -658,38 +882,48 @@ impl PersistenceWorker {
         Ok(())
     }
 
-    /// Read action log bytes for a batch (for manual workflow)
+    /// Read the publishable action log of a batch.
+    ///
+    /// Actions that would reveal hidden information are replaced by blinded
+    /// commitments (see [`game_core::PublishedAction`]), so the result is what
+    /// the batch proof's `actions_root` commits to and is safe to upload.
     async fn read_action_log(&self, start_nonce: u64) -> Result<Vec<u8>> {
-        use std::io::Read;
-
         debug!("Reading action log for batch at nonce {}", start_nonce);
 
         // Load batch to get filename
         let batch = self
-            .batch_repo
+            .main_batch_repo
             .load(&self.config.session_id, start_nonce)
             .map_err(PersistenceError::from)?
             .ok_or_else(|| {
                 PersistenceError::BatchSave(format!("Batch not found at nonce {}", start_nonce))
             })?;
 
-        // Construct action log path
-        let session_dir = self.config.base_dir.join(&self.config.session_id);
-        let action_log_path = session_dir
-            .join("actions")
-            .join(batch.action_log_filename());
-
-        // Read action log file
-        let mut file = std::fs::File::open(&action_log_path).map_err(PersistenceError::Io)?;
-
-        let mut buffer = Vec::new();
-        file.read_to_end(&mut buffer)
-            .map_err(PersistenceError::Io)?;
+        // Read the batch's actions
+        let actions: Vec<Action> = self
+            .storage
+            .batch_entries(BranchId::MAIN, &batch)?
+            .into_iter()
+            .map(|entry| entry.action)
+            .collect();
+
+        // Redact with the session's seed salt, as the guest does
+        let seed_salt = crate::SessionInit::load(&self.session_dir)
+            .map_err(|e| PersistenceError::SessionInit(e.to_string()))?
+            .seed_salt;
+        let published = game_core::publish_actions(batch.start_nonce, &actions, &seed_salt);
+        let buffer = bincode::serialize(&published)
+            .map_err(|e| PersistenceError::BatchSave(e.to_string()))?;
 
         debug!(
-            "Action log read: {} bytes from {}",
+            "Published action log: {} of {} action(s) hidden, {} bytes from batch {}",
+            published
+                .iter()
+                .filter(|entry| matches!(entry, game_core::PublishedAction::Hidden(_)))
+                .count(),
+            published.len(),
             buffer.len(),
-            action_log_path.display()
+            batch.start_nonce
         );
 
         Ok(buffer)
@@ -700,14 +934,14 @@ impl PersistenceWorker {
         debug!("Starting new batch at nonce {}", start_nonce);
 
         // Create new batch
-        let batch = ActionBatch::new(self.config.session_id.clone(), start_nonce);
+        let active = self.branches.active;
+        let batch = ActionBatch::new(self.storage.branch_key(active), start_nonce);
 
-        // Create new action log file
-        let session_dir = self.config.base_dir.join(&self.config.session_id);
-        let action_log_filename = batch.action_log_filename();
-        let action_log =
-            FileActionLog::open_or_create(session_dir.join("actions"), &action_log_filename)
-                .map_err(|e| PersistenceError::ActionLogCreate(e.to_string()))?;
+        // Create new action log
+        let action_log = self
+            .storage
+            .action_log(active, &batch)
+            .map_err(|e| PersistenceError::ActionLogCreate(e.to_string()))?;
 
         // Save initial batch state
         self.batch_repo
@@ -718,10 +952,415 @@ impl PersistenceWorker {
         self.current_action_log = Some(action_log);
 
         info!(
-            "New batch started: session={}, start_nonce={}",
-            self.config.session_id, start_nonce
+            "New batch started: session={}, branch={}, start_nonce={}",
+            self.config.session_id, self.branches.active, start_nonce
         );
 
         Ok(())
     }
+
+    /// Persist events already published before a command arrived.
+    ///
+    /// Commands and events race in the main loop; branch switches must not
+    /// move actions of the old branch onto the new one.
+    async fn drain_events(&mut self) -> Result<()> {
+        loop {
+            let Some(event_rx) = self.event_rx.as_mut() else {
+                return Ok(());
+            };
+            match event_rx.try_recv() {
+                Ok(event) => self.handle_event_with_retry(event).await?,
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    panic!(
+                        "🚨 FATAL: PersistenceWorker lost {} events while switching branches!",
+                        skipped
+                    );
+                }
+                Err(_) => return Ok(()),
+            }
+        }
+    }
+
+    fn branch_states(&self, branch: BranchId) -> Result<Box<dyn StateRepository>> {
+        Ok(self.storage.states(branch, 1)?)
+    }
+
+    fn branch_entries(&self, branch: BranchId) -> Result<Vec<ActionLogEntry>> {
+        self.storage.branch_entries(branch).map_err(|e| {
+            PersistenceError::ActionLogCreate(format!("Failed to read {} log: {}", branch, e))
+        })
+    }
+
+    /// Action log of `branch`'s whole timeline, including inherited history.
+    fn lineage_log(&self, branch: BranchId) -> Result<ReplayLog> {
+        let lineage = self
+            .branches
+            .lineage(branch)
+            .ok_or(PersistenceError::UnknownBranch(branch))?;
+
+        let mut entries = Vec::new();
+        for &(id, from, until) in lineage.iter().rev() {
+            entries.extend(
+                self.branch_entries(id)?
+                    .into_iter()
+                    .filter(|entry| (from..until).contains(&entry.nonce)),
+            );
+        }
+        Ok(ReplayLog::from_entries(entries))
+    }
+
+    fn list_branches(&self) -> Result<Vec<BranchSummary>> {
+        let mut summaries = Vec::new();
+        for info in &self.branches.branches {
+            let states = self.branch_states(info.id)?;
+            let mut saved_nonces = Vec::new();
+            for key in states.list_nonces()? {
+                if let Some(state) = states.load(key)? {
+                    saved_nonces.push(state.nonce());
+                }
+            }
+            saved_nonces.sort_unstable();
+
+            let tip = ReplayLog::from_entries(self.branch_entries(info.id)?)
+                .last_nonce()
+                .map_or(info.fork_nonce, |nonce| nonce + 1);
+
+            summaries.push(BranchSummary {
+                info: info.clone(),
+                active: info.id == self.branches.active,
+                saved_nonces,
+                tip,
+            });
+        }
+        Ok(summaries)
+    }
+
+    /// Latest saved state of `branch`'s timeline at or before `nonce`, and the
+    /// logged actions from there to `nonce`.
+    fn plan_rewind(&self, branch: BranchId, nonce: u64) -> Result<RewindPlan> {
+        let lineage = self
+            .branches
+            .lineage(branch)
+            .ok_or(PersistenceError::UnknownBranch(branch))?;
+        let rewind_error = |reason: String| PersistenceError::Rewind {
+            branch,
+            nonce,
+            reason,
+        };
+
+        // Branches further down the lineage only hold older states, so the
+        // first branch with a candidate has the closest one.
+        let mut base = None;
+        for &(id, from, until) in &lineage {
+            if nonce < from {
+                continue;
+            }
+            let limit = nonce.min(until);
+            let states = self.branch_states(id)?;
+            let mut keys = states.list_nonces()?;
+            keys.sort_unstable_by(|a, b| b.cmp(a));
+            for key in keys {
+                // state_{k} holds the state after action k (state_0 the genesis state)
+                if key > limit {
+                    continue;
+                }
+                if let Some(state) = states.load(key)?
+                    && state.nonce() <= limit
+                {
+                    base = Some(state);
+                    break;
+                }
+            }
+            if base.is_some() {
+                break;
+            }
+        }
+        let base = base.ok_or_else(|| rewind_error("no saved state at or before it".into()))?;
+
+        let log = self.lineage_log(branch)?;
+        let actions = (base.nonce()..nonce)
+            .map(|action_nonce| {
+                log.action(action_nonce)
+                    .cloned()
+                    .ok_or_else(|| rewind_error(format!("no action logged at {}", action_nonce)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(RewindPlan { base, actions })
+    }
+
+    fn last_turn_start(&self, entity: EntityId, before: u64) -> Result<(BranchId, Option<u64>)> {
+        let active = self.branches.active;
+        let log = self.lineage_log(active)?;
+        let start = log
+            .turn_starts()
+            .into_iter()
+            .filter(|start| {
+                start + 1 < before
+                    && log
+                        .action(start + 1)
+                        .is_some_and(|action| action.actor() == entity)
+            })
+            .max();
+        Ok((active, start))
+    }
+
+    /// Continue recording from `state` on `branch`.
+    ///
+    /// Completes the active branch's batch first. `state` at the tip of an
+    /// inactive branch resumes that branch; anywhere else a new branch is
+    /// forked from `branch` at `state`'s nonce, with `state` as its first
+    /// saved state.
+    async fn switch_branch(&mut self, branch: BranchId, state: &GameState) -> Result<BranchInfo> {
+        let info = self
+            .branches
+            .get(branch)
+            .cloned()
+            .ok_or(PersistenceError::UnknownBranch(branch))?;
+        let nonce = state.nonce();
+
+        self.drain_events().await?;
+        let current = self.query_current_state().await?;
+        if branch == self.branches.active && nonce == current.nonce() {
+            return Ok(info);
+        }
+
+        match &self.current_batch {
+            Some(batch) if batch.action_count() > 0 => {
+                self.complete_current_batch(&current)?;
+            }
+            _ => {
+                self.current_batch = None;
+                self.current_action_log = None;
+            }
+        }
+        self.progress = BatchProgress::new(state.turn.clock);
+
+        let tip = ReplayLog::from_entries(self.branch_entries(branch)?)
+            .last_nonce()
+            .map_or(info.fork_nonce, |last| last + 1);
+        let target = if branch != self.branches.active && nonce == tip {
+            info
+        } else {
+            let id = self
+                .branches
+                .fork(branch, nonce, chrono::Utc::now().to_rfc3339());
+            self.branch_states(id)?
+                .save(nonce.saturating_sub(1), state)
+                .map_err(|e| PersistenceError::StateSave {
+                    nonce,
+                    error: e.to_string(),
+                })?;
+            self.branches
+                .get(id)
+                .cloned()
+                .expect("branch was just forked")
+        };
+
+        self.branches.active = target.id;
+        self.branch_repo.save(&self.branches)?;
+        self.state_repo = self
+            .storage
+            .states(target.id, self.config.state_storage.keyframe_interval())?;
+        self.batch_repo = self.storage.batches(target.id)?;
+        self.start_new_batch(nonce).await?;
+
+        info!(
+            "Switched to {} at nonce {} (forked from {:?} at {})",
+            target.id, nonce, target.parent, target.fork_nonce
+        );
+        Ok(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{ActionInput, ActionKind, CharacterAction};
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn wait() -> Action {
+        Action::character(CharacterAction::new(
+            EntityId::PLAYER,
+            ActionKind::Wait,
+            ActionInput::None,
+        ))
+    }
+
+    fn state_at(nonce: u64) -> GameState {
+        let mut state = GameState::with_player();
+        state.turn.nonce = nonce;
+        state
+    }
+
+    fn log_actions(worker: &PersistenceWorker, branch: BranchId, nonces: std::ops::Range<u64>) {
+        let batch = ActionBatch::new(worker.storage.branch_key(branch), nonces.start);
+        let mut log = worker.storage.action_log(branch, &batch).unwrap();
+        for nonce in nonces {
+            log.append(&ActionLogEntry::new(nonce, wait())).unwrap();
+        }
+        log.flush().unwrap();
+    }
+
+    fn worker(config: PersistenceConfig) -> PersistenceWorker {
+        let (_command_tx, command_rx) = mpsc::channel(1);
+        let (sim_command_tx, _sim_command_rx) = mpsc::channel(1);
+        let (batch_complete_tx, _batch_complete_rx) = mpsc::unbounded_channel();
+        PersistenceWorker::new(
+            config,
+            HashMap::new(),
+            command_rx,
+            sim_command_tx,
+            batch_complete_tx,
+        )
+        .unwrap()
+    }
+
+    fn assert_rewind_plans(mut worker: PersistenceWorker) {
+        // Main: genesis and the state after action 2, actions 0..8
+        worker.state_repo.save(0, &state_at(0)).unwrap();
+        worker.state_repo.save(2, &state_at(3)).unwrap();
+        log_actions(&worker, BranchId::MAIN, 0..8);
+
+        // Branch 1 forked at nonce 5, with its own actions 5..7
+        let branch = worker.branches.fork(BranchId::MAIN, 5, String::new());
+        worker
+            .branch_states(branch)
+            .unwrap()
+            .save(4, &state_at(5))
+            .unwrap();
+        log_actions(&worker, branch, 5..7);
+
+        let plan = worker.plan_rewind(branch, 7).unwrap();
+        assert_eq!((plan.base.nonce(), plan.actions.len()), (5, 2));
+
+        // Before the fork the branch shares main's history
+        let plan = worker.plan_rewind(branch, 4).unwrap();
+        assert_eq!((plan.base.nonce(), plan.actions.len()), (3, 1));
+
+        let plan = worker.plan_rewind(BranchId::MAIN, 8).unwrap();
+        assert_eq!((plan.base.nonce(), plan.actions.len()), (3, 5));
+
+        // Branch 1 has no action logged at 7
+        assert!(matches!(
+            worker.plan_rewind(branch, 8),
+            Err(PersistenceError::Rewind { nonce: 8, .. })
+        ));
+        assert!(matches!(
+            worker.plan_rewind(BranchId(9), 1),
+            Err(PersistenceError::UnknownBranch(BranchId(9)))
+        ));
+    }
+
+    async fn assert_checkpoint_saves_state_and_batch(mut worker: PersistenceWorker) {
+        worker.start_new_batch(0).await.unwrap();
+        worker.current_batch.as_mut().unwrap().end_nonce = 3;
+
+        assert_eq!(worker.complete_current_batch(&state_at(4)).unwrap(), 3);
+        assert_eq!(
+            worker.state_repo.load(3).unwrap().map(|s| s.nonce()),
+            Some(4)
+        );
+        let complete = worker
+            .batch_repo
+            .list_by_status(
+                &worker.storage.branch_key(BranchId::MAIN),
+                crate::repository::ActionBatchStatus::Complete,
+            )
+            .unwrap();
+        assert_eq!(complete.len(), 1);
+        assert_eq!(complete[0].end_nonce, 3);
+    }
+
+    #[tokio::test]
+    async fn checkpoint_saves_state_and_batch() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = PersistenceConfig::new("test".into(), temp_dir.path().to_path_buf());
+        assert_checkpoint_saves_state_and_batch(worker(config)).await;
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn sqlite_checkpoint_saves_state_and_batch() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = PersistenceConfig::new("test".into(), temp_dir.path().to_path_buf())
+            .with_backend(StorageBackend::Sqlite {
+                path: temp_dir.path().join("saves.db"),
+            });
+        assert_checkpoint_saves_state_and_batch(worker(config)).await;
+    }
+
+    #[test]
+    fn plan_rewind_follows_branch_lineage() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_rewind_plans(worker(PersistenceConfig::new(
+            "test".into(),
+            temp_dir.path().to_path_buf(),
+        )));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn sqlite_backend_records_branches_apart() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = PersistenceConfig::new("test".into(), temp_dir.path().to_path_buf())
+            .with_backend(StorageBackend::Sqlite {
+                path: temp_dir.path().join("saves.db"),
+            });
+        assert_rewind_plans(worker(config));
+
+        // Nothing but session metadata is written to the session directory
+        assert!(!temp_dir.path().join("test").join("actions").exists());
+        assert!(!temp_dir.path().join("test").join("states").exists());
+    }
+
+    #[tokio::test]
+    async fn proof_and_domain_events_are_logged() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = PersistenceConfig::new("test".into(), temp_dir.path().to_path_buf());
+        let bus = crate::events::EventBus::new();
+        let (command_tx, command_rx) = mpsc::channel(1);
+        let (sim_command_tx, mut sim_command_rx) = mpsc::channel(1);
+        let (batch_complete_tx, _batch_complete_rx) = mpsc::unbounded_channel();
+        let worker = PersistenceWorker::new(
+            config,
+            bus.subscribe_multiple(&[Topic::GameState, Topic::Proof, Topic::Game]),
+            command_rx,
+            sim_command_tx,
+            batch_complete_tx,
+        )
+        .unwrap();
+        let storage = worker.storage.clone();
+
+        // Stand in for the simulation worker's state queries
+        tokio::spawn(async move {
+            while let Some(command) = sim_command_rx.recv().await {
+                if let SimCommand::QueryState { reply } = command {
+                    let _ = reply.send(GameState::with_player());
+                }
+            }
+        });
+        let running = tokio::spawn(worker.run());
+
+        bus.publish(Event::Game(crate::events::GameEvent::LeveledUp {
+            entity: EntityId::PLAYER,
+            level: 2,
+        }));
+        bus.publish(Event::Proof(crate::events::ProofEvent::ProofFailed {
+            action: wait(),
+            clock: 0,
+            error: "out of cycles".into(),
+        }));
+        command_tx.send(Command::Shutdown).await.unwrap();
+        running.await.unwrap();
+
+        let log = storage.events().unwrap();
+        let mut stats = crate::events::Projector::new(crate::events::SessionStats::default());
+        let mut view = crate::events::Projector::new(crate::events::WorldView::default());
+        assert_eq!(stats.catch_up(log.as_ref()).unwrap(), 2);
+        view.catch_up(log.as_ref()).unwrap();
+        assert_eq!(stats.projection().proofs_failed, 1);
+        assert_eq!(view.projection().entities[&EntityId::PLAYER].level, Some(2));
+    }
 }
@@ -0,0 +1,392 @@
+//! Durable proof job queue for the prover worker.
+//!
+//! The [`ActionBatchRepository`] is the queue's source of truth: a batch is an
+//! open job while its status is `Complete`, `Proving` or `Failed`, so jobs
+//! survive restarts without a separate journal. In memory the queue only keeps
+//! scheduling data (which batch is running and when a failed one may retry).
+//!
+//! - [`ProofQueue::recover`] rebuilds the queue on startup. `Proving` batches
+//!   were interrupted by a crash; they are recorded as failed attempts and
+//!   retried like any other failure.
+//! - Jobs are handed out oldest first (lowest start nonce).
+//! - A failed attempt is saved as `Failed { retry_count }` and retried after an
+//!   exponential backoff, until [`ProofRetryPolicy::max_retries`] is used up.
+//!   The attempt count lives in [`ActionBatch::proof_attempts`], so it is not
+//!   reset by a restart.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tracing::{info, warn};
+
+use crate::repository::{ActionBatch, ActionBatchRepository, ActionBatchStatus, Result};
+use crate::types::Nonce;
+
+/// How failed proofs are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofRetryPolicy {
+    /// Retries after the first failed attempt (default: 5)
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each further retry (default: 10s)
+    pub base_delay: Duration,
+    /// Upper bound of the delay (default: 10min)
+    pub max_delay: Duration,
+}
+
+impl Default for ProofRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_delay: Duration::from_secs(10),
+            max_delay: Duration::from_secs(600),
+        }
+    }
+}
+
+impl ProofRetryPolicy {
+    /// Delay after `failures` failed attempts: `base_delay * 2^(failures - 1)`,
+    /// capped at `max_delay`.
+    pub fn backoff(&self, failures: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(failures.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+
+    /// Whether a batch may be attempted again after `failures` failed attempts.
+    pub fn allows_retry(&self, failures: u32) -> bool {
+        failures <= self.max_retries
+    }
+}
+
+/// Snapshot of the proof queue, oldest batch first in every list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProofQueueStatus {
+    /// Batches waiting for a proving slot.
+    pub queued: Vec<Nonce>,
+    /// Batches being proven.
+    pub proving: Vec<Nonce>,
+    /// Failed batches waiting out their backoff, with the time left.
+    pub retrying: Vec<(Nonce, Duration)>,
+    /// Batches that failed on every allowed attempt and are not retried.
+    pub exhausted: Vec<Nonce>,
+}
+
+impl ProofQueueStatus {
+    /// Batches that still have to be proven (queued, proving or retrying).
+    pub fn depth(&self) -> usize {
+        self.queued.len() + self.proving.len() + self.retrying.len()
+    }
+}
+
+/// Outcome of [`ProofQueue::fail`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// The batch is retried after this delay.
+    Retry(Duration),
+    /// The batch used up its retries.
+    Exhausted,
+}
+
+/// Proof jobs of one session, persisted through the batch repository.
+pub struct ProofQueue {
+    session_id: String,
+    batch_repo: Arc<dyn ActionBatchRepository>,
+    policy: ProofRetryPolicy,
+    /// Jobs not running, with the earliest time they may start.
+    waiting: BTreeMap<Nonce, Instant>,
+    proving: BTreeSet<Nonce>,
+    exhausted: BTreeSet<Nonce>,
+}
+
+impl ProofQueue {
+    pub fn new(
+        session_id: String,
+        batch_repo: Arc<dyn ActionBatchRepository>,
+        policy: ProofRetryPolicy,
+    ) -> Self {
+        Self {
+            session_id,
+            batch_repo,
+            policy,
+            waiting: BTreeMap::new(),
+            proving: BTreeSet::new(),
+            exhausted: BTreeSet::new(),
+        }
+    }
+
+    /// Queue every unproven batch found in the repository.
+    ///
+    /// Batches that are already queued or running are left alone, so this is
+    /// also safe to call while the worker is running. Returns the number of
+    /// newly queued batches.
+    pub fn recover(&mut self, now: Instant) -> Result<usize> {
+        let mut queued = 0;
+        for mut batch in self.batch_repo.list(&self.session_id)? {
+            let start_nonce = batch.start_nonce;
+            if self.contains(start_nonce) {
+                continue;
+            }
+
+            match &batch.status {
+                ActionBatchStatus::Complete => {}
+                ActionBatchStatus::Proving => {
+                    warn!(
+                        "Batch {} was left in Proving by an interrupted run",
+                        start_nonce
+                    );
+                    batch.mark_failed("proof interrupted".to_string(), batch.proof_attempts);
+                    self.batch_repo.save(&batch)?;
+                }
+                ActionBatchStatus::Failed { .. } => {}
+                _ => continue,
+            }
+
+            if matches!(batch.status, ActionBatchStatus::Failed { .. })
+                && !self.policy.allows_retry(batch.proof_attempts)
+            {
+                self.exhausted.insert(start_nonce);
+                continue;
+            }
+
+            // The backoff of an earlier run is not kept; retry right away.
+            self.waiting.insert(start_nonce, now);
+            queued += 1;
+        }
+
+        if queued > 0 {
+            info!("Recovered {} unproven batch(es) from repository", queued);
+        }
+        Ok(queued)
+    }
+
+    /// Queue a batch that just became Complete.
+    pub fn push(&mut self, batch: &ActionBatch, now: Instant) {
+        if !self.contains(batch.start_nonce) {
+            self.waiting.insert(batch.start_nonce, now);
+        }
+    }
+
+    /// Take the oldest batch that may start at `now` and mark it running.
+    pub fn pop_ready(&mut self, now: Instant) -> Option<Nonce> {
+        let start_nonce = self
+            .waiting
+            .iter()
+            .find(|(_, ready_at)| **ready_at <= now)
+            .map(|(start_nonce, _)| *start_nonce)?;
+        self.waiting.remove(&start_nonce);
+        self.proving.insert(start_nonce);
+        Some(start_nonce)
+    }
+
+    /// Remove a running batch that was proven.
+    pub fn complete(&mut self, start_nonce: Nonce) {
+        self.proving.remove(&start_nonce);
+    }
+
+    /// Remove a running batch that must not be retried (e.g. already proven).
+    pub fn discard(&mut self, start_nonce: Nonce) {
+        self.proving.remove(&start_nonce);
+    }
+
+    /// Record a failed attempt of a running batch and schedule its retry.
+    ///
+    /// The failure is saved to the repository first. If that fails the batch
+    /// is still retried after the base delay, and the error is returned.
+    pub fn fail(
+        &mut self,
+        start_nonce: Nonce,
+        error: String,
+        now: Instant,
+    ) -> Result<RetryDecision> {
+        self.proving.remove(&start_nonce);
+
+        let batch = match self.record_failure(start_nonce, error) {
+            Ok(batch) => batch,
+            Err(e) => {
+                self.waiting
+                    .insert(start_nonce, now + self.policy.base_delay);
+                return Err(e);
+            }
+        };
+
+        let failures = batch.map_or(0, |batch| batch.proof_attempts);
+        if self.policy.allows_retry(failures) {
+            let delay = self.policy.backoff(failures);
+            self.waiting.insert(start_nonce, now + delay);
+            Ok(RetryDecision::Retry(delay))
+        } else {
+            self.exhausted.insert(start_nonce);
+            Ok(RetryDecision::Exhausted)
+        }
+    }
+
+    fn record_failure(&self, start_nonce: Nonce, error: String) -> Result<Option<ActionBatch>> {
+        let Some(mut batch) = self.batch_repo.load(&self.session_id, start_nonce)? else {
+            return Ok(None);
+        };
+        // Failures before the attempt started (e.g. I/O while loading) leave
+        // the status alone; the batch is simply tried again.
+        if matches!(batch.status, ActionBatchStatus::Proving) {
+            batch.mark_failed(error, batch.proof_attempts);
+            self.batch_repo.save(&batch)?;
+        }
+        Ok(Some(batch))
+    }
+
+    /// Batches still to be proven (waiting or running).
+    pub fn depth(&self) -> usize {
+        self.waiting.len() + self.proving.len()
+    }
+
+    pub fn running(&self) -> usize {
+        self.proving.len()
+    }
+
+    pub fn exhausted(&self) -> usize {
+        self.exhausted.len()
+    }
+
+    pub fn status(&self, now: Instant) -> ProofQueueStatus {
+        let mut status = ProofQueueStatus {
+            proving: self.proving.iter().copied().collect(),
+            exhausted: self.exhausted.iter().copied().collect(),
+            ..ProofQueueStatus::default()
+        };
+        for (&start_nonce, &ready_at) in &self.waiting {
+            if ready_at <= now {
+                status.queued.push(start_nonce);
+            } else {
+                status.retrying.push((start_nonce, ready_at - now));
+            }
+        }
+        status
+    }
+
+    fn contains(&self, start_nonce: Nonce) -> bool {
+        self.waiting.contains_key(&start_nonce)
+            || self.proving.contains(&start_nonce)
+            || self.exhausted.contains(&start_nonce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::repository::FileActionBatchRepository;
+
+    const SESSION: &str = "session";
+
+    fn setup() -> (TempDir, Arc<FileActionBatchRepository>) {
+        let temp = TempDir::new().unwrap();
+        let repo = FileActionBatchRepository::new(temp.path().join("batches")).unwrap();
+        (temp, Arc::new(repo))
+    }
+
+    fn save(repo: &FileActionBatchRepository, start_nonce: Nonce, status: ActionBatchStatus) {
+        let mut batch = ActionBatch::new(SESSION.to_string(), start_nonce);
+        batch.mark_complete(start_nonce + 9);
+        batch.status = status;
+        repo.save(&batch).unwrap();
+    }
+
+    fn policy() -> ProofRetryPolicy {
+        ProofRetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(3),
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let policy = policy();
+        let delays: Vec<_> = (1..=4).map(|n| policy.backoff(n).as_secs()).collect();
+        assert_eq!(delays, [1, 2, 3, 3]);
+        assert_eq!(policy.backoff(u32::MAX), policy.max_delay);
+    }
+
+    #[test]
+    fn recovers_interrupted_batches_oldest_first() {
+        let (_temp, repo) = setup();
+        save(&repo, 20, ActionBatchStatus::Complete);
+        save(&repo, 10, ActionBatchStatus::Proving);
+        save(
+            &repo,
+            0,
+            ActionBatchStatus::Proven {
+                proof_file: "proof.bin".into(),
+                generation_time_ms: 1,
+            },
+        );
+        save(&repo, 30, ActionBatchStatus::InProgress);
+
+        let now = Instant::now();
+        let mut queue = ProofQueue::new(SESSION.into(), repo.clone(), policy());
+        assert_eq!(queue.recover(now).unwrap(), 2);
+        assert!(matches!(
+            repo.load(SESSION, 10).unwrap().unwrap().status,
+            ActionBatchStatus::Failed { .. }
+        ));
+
+        assert_eq!(queue.pop_ready(now), Some(10));
+        assert_eq!(queue.pop_ready(now), Some(20));
+        assert_eq!(queue.pop_ready(now), None);
+
+        // Recovering again while both run does not duplicate them.
+        assert_eq!(queue.recover(now).unwrap(), 0);
+        assert_eq!(queue.depth(), 2);
+    }
+
+    #[test]
+    fn failures_back_off_until_retries_are_used_up() {
+        let (_temp, repo) = setup();
+        save(&repo, 0, ActionBatchStatus::Complete);
+        let mut queue = ProofQueue::new(SESSION.into(), repo.clone(), policy());
+        let mut now = Instant::now();
+        queue.recover(now).unwrap();
+
+        let mut decisions = Vec::new();
+        while let Some(start_nonce) = queue.pop_ready(now) {
+            // What the prover task does before proving.
+            let mut batch = repo.load(SESSION, start_nonce).unwrap().unwrap();
+            assert!(batch.can_start_proof());
+            batch.mark_proving();
+            repo.save(&batch).unwrap();
+
+            let decision = queue.fail(start_nonce, "boom".into(), now).unwrap();
+            assert_eq!(queue.pop_ready(now), None, "retried before backoff");
+            decisions.push(decision);
+            now += queue
+                .status(now)
+                .retrying
+                .first()
+                .map_or(Duration::ZERO, |(_, delay)| *delay);
+        }
+
+        assert_eq!(
+            decisions,
+            [
+                RetryDecision::Retry(Duration::from_secs(1)),
+                RetryDecision::Retry(Duration::from_secs(2)),
+                RetryDecision::Exhausted,
+            ]
+        );
+        assert_eq!(
+            repo.load(SESSION, 0).unwrap().unwrap().status,
+            ActionBatchStatus::Failed {
+                error: "boom".into(),
+                retry_count: 3
+            }
+        );
+        assert_eq!(queue.status(now).exhausted, [0]);
+
+        // The attempt count is persisted, so a restart does not retry it either.
+        let mut restarted = ProofQueue::new(SESSION.into(), repo, policy());
+        assert_eq!(restarted.recover(now).unwrap(), 0);
+        assert_eq!(restarted.exhausted(), 1);
+    }
+}
@@ -4,31 +4,37 @@
 //!
 //! # Workflow
 //!
-//! 1. Poll for Complete action batches
+//! 1. Queue Complete action batches (see [`ProofQueue`]; unproven batches left
+//!    by an earlier run are recovered on startup)
 //! 2. Load start state (previous batch's end state)
 //! 3. Read all actions from the batch's action log
 //! 4. Generate proof for the entire batch
-//! 5. Save proof file and update batch status to Proven
+//! 5. Save proof file and update batch status to Proven, or to Failed and
+//!    retry it later with exponential backoff
 //!
 //! # Proof Generation Strategy
 //!
 //! Currently generates a single proof for the entire batch. Future optimization
 //! could support incremental proving or parallel proof generation for large batches.
 
-use std::collections::VecDeque;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::{self, JoinSet};
 use tracing::{debug, error, info, warn};
 
 use game_core::GameState;
 
+use super::ProofMetrics;
+use super::proof_queue::{ProofQueue, ProofQueueStatus, ProofRetryPolicy, RetryDecision};
+use super::storage::{SessionStorage, StorageBackend};
 use crate::repository::{
-    ActionBatch, ActionBatchRepository, FileActionBatchRepository, FileActionLogReader,
-    FileStateRepository, StateRepository,
+    ActionBatch, ActionBatchRepository, ActionLogEntry, BranchId, StateRepository,
 };
+use crate::types::Nonce;
 
 use zk::{ProofData, Prover};
 
@@ -46,6 +52,12 @@ pub struct ProverConfig {
 
     /// Maximum number of batches to prove in parallel
     pub max_parallel: usize,
+
+    /// Retry policy for failed proofs
+    pub retry: ProofRetryPolicy,
+
+    /// Where the persistence worker stores batches, states and logs
+    pub backend: StorageBackend,
 }
 
 impl ProverConfig {
@@ -55,6 +67,8 @@ impl ProverConfig {
             session_id,
             base_dir,
             max_parallel: 1,
+            retry: ProofRetryPolicy::default(),
+            backend: StorageBackend::default(),
         }
     }
 
@@ -63,14 +77,37 @@ impl ProverConfig {
         self.max_parallel = max;
         self
     }
+
+    /// Set the retry policy for failed proofs
+    pub fn with_retry_policy(mut self, retry: ProofRetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Set the storage backend
+    pub fn with_backend(mut self, backend: StorageBackend) -> Self {
+        self.backend = backend;
+        self
+    }
 }
 
 /// Commands that can be sent to the prover worker
 #[allow(dead_code)]
 pub enum Command {
-    /// Prove all Complete batches from repository
+    /// Queue all unproven batches from repository
     ProveBatches,
 
+    /// Report the state of the proof queue
+    QueueStatus {
+        reply: oneshot::Sender<ProofQueueStatus>,
+    },
+
+    /// Fold consecutive batch proofs into one (see [`Prover::aggregate`])
+    Aggregate {
+        proofs: Vec<ProofData>,
+        reply: oneshot::Sender<std::result::Result<ProofData, zk::ProofError>>,
+    },
+
     /// Shutdown the worker gracefully
     Shutdown,
 }
@@ -79,9 +116,10 @@ pub enum Command {
 pub struct ProverWorker {
     config: ProverConfig,
 
-    // Repositories (shared across parallel tasks)
-    batch_repo: Arc<FileActionBatchRepository>,
-    state_repo: Arc<FileStateRepository>,
+    // Repositories of the main branch (shared across parallel tasks)
+    storage: SessionStorage,
+    batch_repo: Arc<dyn ActionBatchRepository>,
+    state_repo: Arc<dyn StateRepository>,
 
     // Prover instance (shared across parallel tasks)
     prover: Arc<dyn Prover>,
@@ -90,11 +128,12 @@ pub struct ProverWorker {
     command_rx: mpsc::Receiver<Command>,
     batch_complete_rx: mpsc::UnboundedReceiver<ActionBatch>,
 
-    // Track running tasks to avoid blocking on completion
-    running_tasks: Vec<tokio::task::JoinHandle<Result<()>>>,
+    // Durable job queue (backed by batch_repo) and the proofs it is running
+    queue: ProofQueue,
+    running_tasks: JoinSet<Result<Duration>>,
+    task_batches: HashMap<task::Id, Nonce>,
 
-    // Queue for batches when max_parallel is reached
-    pending_batches: VecDeque<ActionBatch>,
+    metrics: Arc<ProofMetrics>,
 }
 
 impl ProverWorker {
@@ -102,28 +141,32 @@ impl ProverWorker {
     pub fn new(
         config: ProverConfig,
         prover: Arc<dyn Prover>,
+        metrics: Arc<ProofMetrics>,
         command_rx: mpsc::Receiver<Command>,
         batch_complete_rx: mpsc::UnboundedReceiver<ActionBatch>,
     ) -> Result<Self> {
-        let base_dir = &config.base_dir;
-        let session_id = &config.session_id;
-
-        // Create session directory
-        let session_dir = base_dir.join(session_id);
-
-        // Create repository instances
-        let batch_repo = FileActionBatchRepository::new(session_dir.join("batches"))?;
-        let state_repo = FileStateRepository::new(session_dir.join("states"))?;
+        // Create repository instances (proofs only cover the main branch)
+        let storage = SessionStorage::open(&config.backend, &config.base_dir, &config.session_id)?;
+        let batch_repo: Arc<dyn ActionBatchRepository> = storage.batches(BranchId::MAIN)?.into();
+        let state_repo: Arc<dyn StateRepository> = storage.states(BranchId::MAIN, 1)?.into();
+        let queue = ProofQueue::new(
+            config.session_id.clone(),
+            Arc::clone(&batch_repo),
+            config.retry,
+        );
 
         Ok(Self {
             config,
-            batch_repo: Arc::new(batch_repo),
-            state_repo: Arc::new(state_repo),
+            storage,
+            batch_repo,
+            state_repo,
             prover,
             command_rx,
             batch_complete_rx,
-            running_tasks: Vec::new(),
-            pending_batches: VecDeque::new(),
+            queue,
+            running_tasks: JoinSet::new(),
+            task_batches: HashMap::new(),
+            metrics,
         })
     }
 
@@ -134,26 +177,43 @@ impl ProverWorker {
             self.config.session_id, self.config.max_parallel
         );
 
-        // Proving pace controller: process queue at controlled rate (1 second intervals)
-        // This is the ONLY place where batches are actually processed from the queue
-        let mut cleanup_interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
-        cleanup_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        // Pick up batches an earlier run left unproven
+        if let Err(e) = self.queue.recover(Instant::now()) {
+            error!("Failed to recover proof queue: {}", e);
+        }
+        self.update_metrics();
+
+        // Proving pace controller: start queued batches at a controlled rate (1 second
+        // intervals). This also picks up failed batches once their backoff has passed.
+        let mut schedule_interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+        schedule_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
         loop {
             tokio::select! {
                 // Queue completed batches from PersistenceWorker
-                // Processing happens at controlled pace via cleanup_interval
+                // Processing happens at controlled pace via schedule_interval
                 Some(batch) = self.batch_complete_rx.recv() => {
-                    self.pending_batches.push_back(batch);
+                    self.queue.push(&batch, Instant::now());
+                    self.update_metrics();
                 }
 
                 cmd = self.command_rx.recv() => {
                     match cmd {
                         Some(Command::ProveBatches) => {
-                            if let Err(e) = self.handle_prove_batches().await {
+                            if let Err(e) = self.handle_prove_batches() {
                                 error!("Failed to prove batches: {}", e);
                             }
                         }
+                        Some(Command::QueueStatus { reply }) => {
+                            let _ = reply.send(self.queue.status(Instant::now()));
+                        }
+                        Some(Command::Aggregate { proofs, reply }) => {
+                            // Aggregation is as heavy as proving; keep the loop responsive
+                            let prover = Arc::clone(&self.prover);
+                            task::spawn_blocking(move || {
+                                let _ = reply.send(prover.aggregate(&proofs));
+                            });
+                        }
                         Some(Command::Shutdown) => {
                             info!("Shutdown command received");
                             break;
@@ -165,10 +225,20 @@ impl ProverWorker {
                     }
                 }
 
-                // Proving pace controller: process queue at controlled rate
-                // This is where ProverWorker actually processes batches at its own pace
-                _ = cleanup_interval.tick() => {
-                    self.cleanup_completed_tasks();
+                Some(joined) = self.running_tasks.join_next_with_id() => {
+                    // A panicked task counts as a failed attempt of its batch
+                    let (id, result) = match joined {
+                        Ok((id, result)) => (id, result),
+                        Err(e) => (e.id(), Err(ProverError::TaskFailed(e.to_string()))),
+                    };
+                    match self.task_batches.remove(&id) {
+                        Some(start_nonce) => self.finish_task(start_nonce, result),
+                        None => error!("Finished proof task {} has no batch", id),
+                    }
+                    self.process_pending_batches();
+                }
+
+                _ = schedule_interval.tick() => {
                     self.process_pending_batches();
                 }
 
@@ -176,64 +246,44 @@ impl ProverWorker {
             }
         }
 
+        // Running proofs keep going on the blocking pool; a batch cut short by
+        // process exit stays in Proving and is recovered on the next start.
         info!("ProverWorker stopped");
     }
 
-    /// Load all Complete batches from repository and queue them for proving
-    async fn handle_prove_batches(&mut self) -> Result<()> {
-        use crate::repository::ActionBatchStatus;
+    /// Queue all unproven batches from repository
+    fn handle_prove_batches(&mut self) -> Result<()> {
+        info!("Loading unproven batches from repository...");
 
-        info!("Loading Complete batches from repository...");
-
-        // Load all Complete batches from repository
-        let batches = self
-            .batch_repo
-            .list_by_status(&self.config.session_id, ActionBatchStatus::Complete)?;
-
-        if batches.is_empty() {
-            info!("No Complete batches found");
-            return Ok(());
-        }
-
-        info!(
-            "Found {} Complete batch(es), queuing for proving",
-            batches.len()
-        );
-
-        // Queue all batches
-        for batch in batches {
-            self.pending_batches.push_back(batch);
+        let queued = self.queue.recover(Instant::now())?;
+        if queued == 0 {
+            info!("No unproven batches found");
         }
 
-        // Clean up completed tasks to free slots
-        self.cleanup_completed_tasks();
-
-        // Process queue in order (FIFO)
         self.process_pending_batches();
-
         Ok(())
     }
 
-    /// Process pending batches from the queue if slots are available
+    /// Start queued batches, oldest first, while slots are available
     fn process_pending_batches(&mut self) {
         while self.running_tasks.len() < self.config.max_parallel {
-            if let Some(batch) = self.pending_batches.pop_front() {
-                info!(
-                    "Processing queued batch {} ({} remaining in queue)",
-                    batch.start_nonce,
-                    self.pending_batches.len()
-                );
-                self.spawn_proof_task(batch);
-            } else {
+            let Some(start_nonce) = self.queue.pop_ready(Instant::now()) else {
                 break;
-            }
+            };
+            info!(
+                "Processing queued batch {} ({} remaining in queue)",
+                start_nonce,
+                self.queue.depth() - self.queue.running()
+            );
+            self.spawn_proof_task(start_nonce);
         }
+        self.update_metrics();
     }
 
     /// Spawn a proof generation task for a batch
-    fn spawn_proof_task(&mut self, batch: ActionBatch) {
-        let start_nonce = batch.start_nonce;
+    fn spawn_proof_task(&mut self, start_nonce: Nonce) {
         let config = self.config.clone();
+        let storage = self.storage.clone();
         let batch_repo = Arc::clone(&self.batch_repo);
         let state_repo = Arc::clone(&self.state_repo);
         let prover = Arc::clone(&self.prover);
@@ -242,18 +292,10 @@ impl ProverWorker {
         // This prevents blocking tokio runtime with:
         // 1. CPU-intensive proof generation (RISC0 zkVM)
         // 2. Synchronous I/O operations (file reads/writes)
-        let task = tokio::task::spawn_blocking(move || {
-            if let Err(e) =
-                Self::prove_batch_blocking(start_nonce, config, batch_repo, state_repo, prover)
-            {
-                error!("Proof generation failed for batch {}: {}", start_nonce, e);
-                Err(e)
-            } else {
-                Ok(())
-            }
+        let task = self.running_tasks.spawn_blocking(move || {
+            Self::prove_batch_blocking(start_nonce, config, storage, batch_repo, state_repo, prover)
         });
-
-        self.running_tasks.push(task);
+        self.task_batches.insert(task.id(), start_nonce);
 
         info!(
             "Spawned proof task for batch {} ({}/{} slots used)",
@@ -263,29 +305,42 @@ impl ProverWorker {
         );
     }
 
-    /// Clean up completed tasks from the running_tasks list
-    fn cleanup_completed_tasks(&mut self) {
-        let before = self.running_tasks.len();
-
-        // Remove finished tasks and log any errors
-        self.running_tasks.retain_mut(|task| {
-            if task.is_finished() {
-                // Task is done, log result if needed (can't access result without blocking)
-                false // Remove from list
-            } else {
-                true // Keep in list
+    /// Record the outcome of a finished proof task
+    fn finish_task(&mut self, start_nonce: Nonce, result: Result<Duration>) {
+        match result {
+            Ok(proving_time) => {
+                self.queue.complete(start_nonce);
+                self.metrics.record_success(proving_time);
+            }
+            Err(e) if e.is_retryable() => {
+                error!("Proof generation failed for batch {}: {}", start_nonce, e);
+                self.metrics.record_failure();
+                match self.queue.fail(start_nonce, e.to_string(), Instant::now()) {
+                    Ok(RetryDecision::Retry(delay)) => {
+                        self.metrics.record_retry();
+                        info!("Retrying batch {} in {:?}", start_nonce, delay);
+                    }
+                    Ok(RetryDecision::Exhausted) => {
+                        error!(
+                            "Giving up on batch {} after {} retries",
+                            start_nonce, self.config.retry.max_retries
+                        );
+                    }
+                    Err(e) => error!("Failed to record proof failure of {}: {}", start_nonce, e),
+                }
+            }
+            Err(e) => {
+                warn!("Dropping batch {} from proof queue: {}", start_nonce, e);
+                self.queue.discard(start_nonce);
             }
-        });
-
-        let cleaned = before - self.running_tasks.len();
-        if cleaned > 0 {
-            debug!(
-                "Cleaned up {} completed proof task(s), {}/{} slots now in use",
-                cleaned,
-                self.running_tasks.len(),
-                self.config.max_parallel
-            );
         }
+        self.update_metrics();
+    }
+
+    fn update_metrics(&self) {
+        self.metrics.set_queue_depth(self.queue.depth() as u64);
+        self.metrics.set_proving(self.queue.running() as u64);
+        self.metrics.set_exhausted(self.queue.exhausted() as u64);
     }
 
     /// Generate proof for a specific batch (blocking version)
@@ -298,10 +353,11 @@ impl ProverWorker {
     fn prove_batch_blocking(
         start_nonce: u64,
         config: ProverConfig,
-        batch_repo: Arc<FileActionBatchRepository>,
-        state_repo: Arc<FileStateRepository>,
+        storage: SessionStorage,
+        batch_repo: Arc<dyn ActionBatchRepository>,
+        state_repo: Arc<dyn StateRepository>,
         prover: Arc<dyn Prover>,
-    ) -> Result<()> {
+    ) -> Result<Duration> {
         info!("Starting proof generation for batch {}", start_nonce);
 
         // Load batch metadata
@@ -309,15 +365,15 @@ impl ProverWorker {
             .load(&config.session_id, start_nonce)?
             .ok_or(ProverError::BatchNotFound { start_nonce })?;
 
-        // Check batch is in Complete state
-        if !batch.is_ready_for_proving() {
+        // Check batch is Complete, or Failed and being retried
+        if !batch.can_start_proof() {
             return Err(ProverError::BatchNotReady {
                 start_nonce,
                 status: batch.status,
             });
         }
 
-        // Mark batch as Proving
+        // Mark batch as Proving (persists the attempt before any work is done)
         batch.mark_proving();
         batch_repo.save(&batch)?;
 
@@ -337,19 +393,15 @@ impl ProverWorker {
                 nonce: batch.end_nonce,
             })?;
 
-        // Open action log reader
-        let session_dir = config.base_dir.join(&config.session_id);
-        let action_log_path = session_dir
-            .join("actions")
-            .join(batch.action_log_filename());
-
-        let mut reader = FileActionLogReader::new(&action_log_path, config.session_id.clone())?;
+        // Read the batch's actions
+        let entries = storage.batch_entries(BranchId::MAIN, &batch)?;
 
         // Generate proof
         let proof_start = Instant::now();
         let proof_data =
-            Self::generate_batch_proof(&batch, &start_state, &end_state, &mut reader, &prover)?;
-        let generation_time_ms = proof_start.elapsed().as_millis() as u64;
+            Self::generate_batch_proof(&batch, &start_state, &end_state, entries, &prover)?;
+        let proving_time = proof_start.elapsed();
+        let generation_time_ms = proving_time.as_millis() as u64;
 
         // Debug mode: Verify the generated proof immediately
         #[cfg(debug_assertions)]
@@ -371,6 +423,7 @@ impl ProverWorker {
         }
 
         // Save proof file
+        let session_dir = config.base_dir.join(&config.session_id);
         let proof_filename = batch.proof_filename();
         let proof_path = session_dir.join("proofs").join(&proof_filename);
 
@@ -394,7 +447,7 @@ impl ProverWorker {
             generation_time_ms
         );
 
-        Ok(())
+        Ok(proving_time)
     }
 
     /// Generate a proof for all actions in a batch (blocking version)
@@ -405,7 +458,7 @@ impl ProverWorker {
         batch: &ActionBatch,
         start_state: &GameState,
         end_state: &GameState,
-        reader: &mut FileActionLogReader,
+        entries: Vec<ActionLogEntry>,
         prover: &Arc<dyn Prover>,
     ) -> Result<ProofData> {
         let action_count = batch.action_count();
@@ -415,21 +468,13 @@ impl ProverWorker {
             action_count, batch.start_nonce
         );
 
-        // Read all actions at once
-        let entries = reader.read_all()?;
-
         if entries.is_empty() {
             return Err(ProverError::NoActions {
                 start_nonce: batch.start_nonce,
             });
         }
 
-        // Filter actions within this batch
-        let batch_actions: Vec<_> = entries
-            .into_iter()
-            .filter(|entry| entry.nonce >= batch.start_nonce && entry.nonce <= batch.end_nonce)
-            .map(|entry| entry.action)
-            .collect();
+        let batch_actions: Vec<_> = entries.into_iter().map(|entry| entry.action).collect();
 
         if batch_actions.len() != action_count as usize {
             warn!(
@@ -500,4 +545,179 @@ pub enum ProverError {
 
     #[error(transparent)]
     Io(#[from] std::io::Error),
+
+    #[error("Proof task panicked or was cancelled: {0}")]
+    TaskFailed(String),
+}
+
+impl ProverError {
+    /// Whether proving the batch again may succeed.
+    ///
+    /// A missing batch, or one that is not waiting for a proof (e.g. already
+    /// proven), is dropped from the queue instead.
+    pub fn is_retryable(&self) -> bool {
+        !matches!(
+            self,
+            Self::BatchNotFound { .. } | Self::BatchNotReady { .. }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{Action, ActionInput, ActionKind, CharacterAction, EntityId};
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::repository::ActionBatchStatus;
+
+    type ProofResult<T> = std::result::Result<T, zk::ProofError>;
+
+    struct PanickingProver;
+
+    impl Prover for PanickingProver {
+        fn prove(&self, _: &GameState, _: &[Action], _: &GameState) -> ProofResult<ProofData> {
+            panic!("prover crashed");
+        }
+
+        fn aggregate(&self, _: &[ProofData]) -> ProofResult<ProofData> {
+            unreachable!()
+        }
+
+        fn verify(&self, _: &ProofData) -> ProofResult<bool> {
+            unreachable!()
+        }
+    }
+
+    /// Aggregates by concatenating proof bytes, keeping the last journal.
+    struct ConcatProver;
+
+    impl Prover for ConcatProver {
+        fn prove(&self, _: &GameState, _: &[Action], _: &GameState) -> ProofResult<ProofData> {
+            unreachable!()
+        }
+
+        fn aggregate(&self, proofs: &[ProofData]) -> ProofResult<ProofData> {
+            let last = proofs
+                .last()
+                .ok_or_else(|| zk::ProofError::AggregationError("no proofs".into()))?;
+            Ok(ProofData {
+                bytes: proofs.iter().flat_map(|p| p.bytes.clone()).collect(),
+                ..last.clone()
+            })
+        }
+
+        fn verify(&self, _: &ProofData) -> ProofResult<bool> {
+            unreachable!()
+        }
+    }
+
+    #[tokio::test]
+    async fn aggregate_command_folds_proofs_on_the_worker_backend() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProverConfig::new("test".into(), temp_dir.path().to_path_buf());
+        let (command_tx, command_rx) = mpsc::channel(1);
+        let (_batch_complete_tx, batch_complete_rx) = mpsc::unbounded_channel();
+        let worker = ProverWorker::new(
+            config,
+            Arc::new(ConcatProver),
+            Arc::new(ProofMetrics::new()),
+            command_rx,
+            batch_complete_rx,
+        )
+        .unwrap();
+        let running = tokio::spawn(worker.run());
+
+        let proof = |byte: u8| ProofData {
+            bytes: vec![byte],
+            backend: zk::ProofBackend::Stub,
+            journal: vec![byte; 168],
+            journal_digest: [byte; 32],
+        };
+        let (reply, aggregate) = oneshot::channel();
+        command_tx
+            .send(Command::Aggregate {
+                proofs: vec![proof(1), proof(2)],
+                reply,
+            })
+            .await
+            .unwrap();
+        let aggregate = aggregate.await.unwrap().unwrap();
+        assert_eq!(aggregate.bytes, vec![1, 2]);
+        assert_eq!(aggregate.journal_digest, [2; 32]);
+
+        let (reply, empty) = oneshot::channel();
+        command_tx
+            .send(Command::Aggregate {
+                proofs: Vec::new(),
+                reply,
+            })
+            .await
+            .unwrap();
+        assert!(empty.await.unwrap().is_err());
+
+        command_tx.send(Command::Shutdown).await.unwrap();
+        running.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn panicking_prover_fails_the_attempt() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProverConfig::new("test".into(), temp_dir.path().to_path_buf());
+        let metrics = Arc::new(ProofMetrics::new());
+        let (command_tx, command_rx) = mpsc::channel(1);
+        let (batch_complete_tx, batch_complete_rx) = mpsc::unbounded_channel();
+        let worker = ProverWorker::new(
+            config,
+            Arc::new(PanickingProver),
+            Arc::clone(&metrics),
+            command_rx,
+            batch_complete_rx,
+        )
+        .unwrap();
+
+        // Batch 0 with a single wait and both of its states
+        let mut batch = ActionBatch::new(worker.storage.branch_key(BranchId::MAIN), 0);
+        let mut log = worker.storage.action_log(BranchId::MAIN, &batch).unwrap();
+        let wait = CharacterAction::new(EntityId::PLAYER, ActionKind::Wait, ActionInput::None);
+        log.append(&ActionLogEntry::new(0, Action::character(wait)))
+            .unwrap();
+        log.flush().unwrap();
+        batch.mark_complete(0);
+        worker.batch_repo.save(&batch).unwrap();
+        worker
+            .state_repo
+            .save(0, &GameState::with_player())
+            .unwrap();
+        let batch_repo = Arc::clone(&worker.batch_repo);
+
+        let running = tokio::spawn(worker.run());
+        batch_complete_tx.send(batch).unwrap();
+
+        let status = tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                let (reply, status) = oneshot::channel();
+                command_tx
+                    .send(Command::QueueStatus { reply })
+                    .await
+                    .unwrap();
+                let status = status.await.unwrap();
+                if !status.retrying.is_empty() {
+                    return status;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("the panicked attempt is scheduled for a retry");
+        command_tx.send(Command::Shutdown).await.unwrap();
+        running.await.unwrap();
+
+        assert!(status.proving.is_empty());
+        assert_eq!(status.retrying[0].0, 0);
+        assert_eq!((metrics.failed(), metrics.retries()), (1, 1));
+        let batch = batch_repo.load("test", 0).unwrap().unwrap();
+        assert!(matches!(batch.status, ActionBatchStatus::Failed { .. }));
+        assert_eq!(batch.proof_attempts, 1);
+    }
 }
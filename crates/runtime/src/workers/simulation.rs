@@ -12,7 +12,7 @@ use game_core::{
 use tracing::{debug, error, warn};
 
 use crate::api::{Result, RuntimeError};
-use crate::events::{Event, EventBus, GameStateEvent};
+use crate::events::{Event, EventBus, GameStateEvent, extract_events, extract_result_events};
 use crate::handlers::HandlerCriticality;
 use crate::oracle::OracleBundle;
 use crate::providers::SystemActionProvider;
@@ -33,9 +33,17 @@ pub enum Command {
     QueryState { reply: oneshot::Sender<GameState> },
     /// Restore game state from a checkpoint (load game).
     RestoreState {
-        state: GameState,
+        state: Box<GameState>,
         reply: oneshot::Sender<Result<()>>,
     },
+    /// Re-execute logged actions on top of a saved state (rewind).
+    ///
+    /// Runs silently: nothing is published and the worker's state is untouched.
+    Reconstruct {
+        base: Box<GameState>,
+        actions: Vec<Action>,
+        reply: oneshot::Sender<Result<GameState>>,
+    },
 }
 
 /// Background task that processes gameplay commands.
@@ -109,11 +117,21 @@ impl SimulationWorker {
                 }
             }
             Command::RestoreState { state, reply } => {
-                let result = self.handle_restore_state(state);
+                let result = self.handle_restore_state(*state);
                 if reply.send(result).is_err() {
                     debug!("RestoreState reply channel closed (caller dropped)");
                 }
             }
+            Command::Reconstruct {
+                base,
+                actions,
+                reply,
+            } => {
+                let result = self.reconstruct(*base, &actions);
+                if reply.send(result).is_err() {
+                    debug!("Reconstruct reply channel closed (caller dropped)");
+                }
+            }
         }
     }
 
@@ -198,6 +216,10 @@ impl SimulationWorker {
         let delta = outcome.delta;
         let action_result = outcome.action_result.unwrap_or_default();
 
+        // Domain events follow the ActionExecuted event they were extracted from
+        let mut game_events = extract_events(&delta, &before_state, &after_state);
+        game_events.extend(extract_result_events(action, &action_result, &before_state));
+
         // Publish ActionExecuted event for ALL actions (player, NPC, system)
         // This ensures ProverWorker can generate proofs for every state transition
         event_bus.publish(Event::GameState(GameStateEvent::ActionExecuted {
@@ -209,6 +231,9 @@ impl SimulationWorker {
             after_state: Box::new(after_state),
             action_result,
         }));
+        for event in game_events {
+            event_bus.publish(Event::Game(event));
+        }
 
         Ok(delta)
     }
@@ -236,6 +261,20 @@ impl SimulationWorker {
         Ok(())
     }
 
+    /// Replays `actions` on `state` without publishing events.
+    fn reconstruct(&self, mut state: GameState, actions: &[Action]) -> Result<GameState> {
+        for action in actions {
+            let nonce = state.nonce();
+            GameEngine::new(&mut state)
+                .execute(self.oracles.as_game_env(), action)
+                .map_err(|e| RuntimeError::Rewind {
+                    nonce,
+                    message: e.to_string(),
+                })?;
+        }
+        Ok(state)
+    }
+
     /// Handles player/NPC action with full workflow:
     /// execute → cascading system actions
     ///
@@ -432,6 +471,10 @@ impl SimulationWorker {
             ExecuteError::RemoveFromWorld(phase_error) => {
                 (phase_error.phase, phase_error.error.to_string())
             }
+            ExecuteError::Perception(phase_error) => {
+                (phase_error.phase, phase_error.error.to_string())
+            }
+            ExecuteError::AiTurn(phase_error) => (phase_error.phase, phase_error.error.to_string()),
             ExecuteError::HookChainTooDeep {
                 hook_name, depth, ..
             } => {
@@ -464,6 +507,14 @@ impl SimulationWorker {
                 );
                 return;
             }
+            ExecuteError::ProvableAiActor { actor, .. } => {
+                error!(
+                    target: "runtime::worker",
+                    actor = ?actor,
+                    "Character action submitted for provable AI actor"
+                );
+                return;
+            }
         };
 
         if phase == TransitionPhase::PreValidate {
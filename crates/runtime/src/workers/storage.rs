@@ -0,0 +1,226 @@
+//! Storage backend selection for the persistence and prover workers.
+//!
+//! Both workers record the same session, so they open their repositories
+//! through [`SessionStorage`] instead of constructing file repositories
+//! directly. Session metadata (`session_init.json`, `branches.json`) and proof
+//! files stay in the session directory on every backend.
+
+use std::path::{Path, PathBuf};
+
+use game_core::GameState;
+
+use crate::providers::ReplayLog;
+use crate::repository::{
+    ActionBatch, ActionBatchRepository, ActionLogEntry, ActionLogWriter, BranchId, EventRepository,
+    FileActionBatchRepository, FileActionLog, FileActionLogReader, FileEventLog,
+    FileStateRepository, RepositoryError, Result, StateRepository,
+};
+#[cfg(feature = "sqlite")]
+use crate::repository::{
+    SqliteActionBatchRepository, SqliteActionLog, SqliteEventLog, SqliteStateRepository,
+    SqliteStore,
+};
+
+/// Where sessions keep their states, batches and logs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum StorageBackend {
+    /// One file per state, batch and action log under `{base_dir}/{session_id}/`
+    #[default]
+    File,
+
+    /// Every session in a single SQLite database (`sqlite` feature)
+    ///
+    /// States are always stored as full snapshots.
+    #[cfg(feature = "sqlite")]
+    Sqlite { path: PathBuf },
+}
+
+/// A session's repositories on the configured backend.
+#[derive(Clone)]
+pub(crate) enum SessionStorage {
+    File {
+        session_id: String,
+        session_dir: PathBuf,
+    },
+    #[cfg(feature = "sqlite")]
+    Sqlite {
+        session_id: String,
+        store: SqliteStore,
+    },
+}
+
+impl SessionStorage {
+    /// Open `session_id` on `backend`.
+    pub(crate) fn open(
+        backend: &StorageBackend,
+        base_dir: &Path,
+        session_id: &str,
+    ) -> Result<Self> {
+        match backend {
+            StorageBackend::File => Ok(Self::File {
+                session_id: session_id.to_string(),
+                session_dir: base_dir.join(session_id),
+            }),
+            #[cfg(feature = "sqlite")]
+            StorageBackend::Sqlite { path } => Ok(Self::Sqlite {
+                session_id: session_id.to_string(),
+                store: SqliteStore::open(path)?,
+            }),
+        }
+    }
+
+    /// Session key under which `branch`'s batches are recorded.
+    ///
+    /// File repositories are scoped by directory and keep the session id; in
+    /// a database, branches other than main get a key of their own.
+    #[cfg_attr(not(feature = "sqlite"), allow(unused_variables))]
+    pub(crate) fn branch_key(&self, branch: BranchId) -> String {
+        match self {
+            Self::File { session_id, .. } => session_id.clone(),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite { session_id, .. } if branch.is_main() => session_id.clone(),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite { session_id, .. } => format!("{}/{}", session_id, branch.0),
+        }
+    }
+
+    /// States of `branch`, keyframing every `keyframe_interval` saves on disk.
+    pub(crate) fn states(
+        &self,
+        branch: BranchId,
+        keyframe_interval: u64,
+    ) -> Result<Box<dyn StateRepository>> {
+        match self {
+            Self::File { session_dir, .. } => Ok(Box::new(
+                FileStateRepository::new(branch.dir(session_dir).join("states"))?
+                    .with_keyframe_interval(keyframe_interval),
+            )),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite { store, .. } => Ok(Box::new(SqliteStateRepository::new(
+                store,
+                self.branch_key(branch),
+            )?)),
+        }
+    }
+
+    /// Batches of `branch`.
+    pub(crate) fn batches(&self, branch: BranchId) -> Result<Box<dyn ActionBatchRepository>> {
+        match self {
+            Self::File { session_dir, .. } => Ok(Box::new(FileActionBatchRepository::new(
+                branch.dir(session_dir).join("batches"),
+            )?)),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite { store, .. } => Ok(Box::new(SqliteActionBatchRepository::new(store))),
+        }
+    }
+
+    /// Save the state at a completed batch's end together with the batch.
+    ///
+    /// `states` and `batches` must be this storage's repositories of the
+    /// batch's branch. On SQLite both rows are written in one transaction, so
+    /// a crash never leaves one without the other. Files cannot do that; the
+    /// state is written first, so a crash in between leaves an unreferenced
+    /// state rather than a complete batch without its state.
+    pub(crate) fn checkpoint(
+        &self,
+        states: &dyn StateRepository,
+        batches: &dyn ActionBatchRepository,
+        nonce: u64,
+        state: &GameState,
+        batch: &ActionBatch,
+    ) -> Result<()> {
+        match self {
+            Self::File { .. } => {
+                states.save(nonce, state)?;
+                batches.save(batch)
+            }
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite { store, .. } => store.checkpoint(nonce, state, batch),
+        }
+    }
+
+    /// The session's event log, shared by all branches.
+    pub(crate) fn events(&self) -> Result<Box<dyn EventRepository>> {
+        match self {
+            Self::File {
+                session_id,
+                session_dir,
+            } => Ok(Box::new(FileEventLog::open_or_create(
+                session_dir.join("events"),
+                format!("events_{}.log", session_id),
+            )?)),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite { session_id, store } => {
+                Ok(Box::new(SqliteEventLog::open(store, session_id.clone())?))
+            }
+        }
+    }
+
+    /// Action log that records `batch` on `branch`.
+    pub(crate) fn action_log(
+        &self,
+        branch: BranchId,
+        batch: &ActionBatch,
+    ) -> Result<Box<dyn ActionLogWriter>> {
+        match self {
+            Self::File { session_dir, .. } => Ok(Box::new(FileActionLog::open_or_create(
+                branch.dir(session_dir).join("actions"),
+                batch.action_log_filename(),
+            )?)),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite { store, .. } => Ok(Box::new(SqliteActionLog::open(
+                store,
+                self.branch_key(branch),
+            )?)),
+        }
+    }
+
+    /// Every action logged on `branch` (not including inherited history).
+    pub(crate) fn branch_entries(&self, branch: BranchId) -> Result<Vec<ActionLogEntry>> {
+        match self {
+            Self::File {
+                session_id,
+                session_dir,
+            } => {
+                let dir = branch.dir(session_dir);
+                if !dir.join("actions").exists() {
+                    return Ok(Vec::new());
+                }
+                ReplayLog::load_entries(&dir, session_id)
+                    .map_err(|e| RepositoryError::CorruptedData(e.to_string()))
+            }
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite { store, .. } => SqliteActionLog::open(store, self.branch_key(branch))?
+                .entries_in_range(0, i64::MAX as u64),
+        }
+    }
+
+    /// Actions of a batch recorded on `branch`, in nonce order.
+    pub(crate) fn batch_entries(
+        &self,
+        branch: BranchId,
+        batch: &ActionBatch,
+    ) -> Result<Vec<ActionLogEntry>> {
+        let entries = match self {
+            Self::File {
+                session_id,
+                session_dir,
+            } => FileActionLogReader::new(
+                branch
+                    .dir(session_dir)
+                    .join("actions")
+                    .join(batch.action_log_filename()),
+                session_id.clone(),
+            )?
+            .read_all()?,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite { store, .. } => SqliteActionLog::open(store, self.branch_key(branch))?
+                .entries_in_range(batch.start_nonce, batch.end_nonce)?,
+        };
+
+        Ok(entries
+            .into_iter()
+            .filter(|entry| entry.nonce >= batch.start_nonce && entry.nonce <= batch.end_nonce)
+            .collect())
+    }
+}
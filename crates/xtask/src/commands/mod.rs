@@ -7,13 +7,21 @@ mod extract_vk;
 mod inspect_proof;
 mod read_actions;
 mod read_state;
+mod session_stats;
+mod simulate;
 pub mod sui;
 mod tail_logs;
+mod verify_proof;
+mod verify_session;
 
 pub use clean::Clean;
 pub use extract_vk::ExtractVk;
 pub use inspect_proof::InspectProof;
 pub use read_actions::ReadActions;
 pub use read_state::ReadState;
+pub use session_stats::SessionStats;
+pub use simulate::Simulate;
 pub use sui::{Keygen as SuiKeygen, Setup as SuiSetup};
 pub use tail_logs::TailLogs;
+pub use verify_proof::VerifyProof;
+pub use verify_session::VerifySession;
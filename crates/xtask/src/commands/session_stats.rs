@@ -0,0 +1,105 @@
+//! Session statistics rebuilt from the event log
+//!
+//! Projects a session's `events.log` into play statistics without running the
+//! game, optionally following the log while the session is still being played.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use console::style;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use runtime::{FileEventLog, Projector};
+
+use crate::dirs;
+
+/// Rebuild play statistics of a session from its event log
+#[derive(Parser)]
+pub struct SessionStats {
+    /// Session ID to analyze (e.g., session_1763197900)
+    #[arg(value_name = "SESSION")]
+    session: String,
+
+    /// Custom data directory (defaults to platform-specific location)
+    #[arg(short, long, value_name = "DIR")]
+    data_dir: Option<PathBuf>,
+
+    /// Keep following the log and print updated statistics as events arrive
+    #[arg(short, long)]
+    follow: bool,
+
+    /// Poll interval in milliseconds when following
+    #[arg(long, default_value = "1000")]
+    poll_interval: u64,
+}
+
+impl SessionStats {
+    pub fn execute(self) -> Result<()> {
+        let data_dir = match self.data_dir {
+            Some(dir) => dir,
+            None => dirs::data_dir()?,
+        };
+        let events_dir = data_dir.join(&self.session).join("events");
+        let filename = format!("events_{}.log", self.session);
+        if !events_dir.join(&filename).exists() {
+            anyhow::bail!(
+                "Event log not found: {}",
+                events_dir.join(&filename).display()
+            );
+        }
+
+        let log = FileEventLog::open(&events_dir, &filename)
+            .with_context(|| format!("Failed to open event log of {}", self.session))?;
+        let mut projector = Projector::new(runtime::SessionStats::default());
+        projector
+            .catch_up(&log)
+            .context("Failed to read event log")?;
+        print_stats(&self.session, projector.projection());
+
+        while self.follow {
+            std::thread::sleep(Duration::from_millis(self.poll_interval));
+            if projector
+                .catch_up(&log)
+                .context("Failed to read event log")?
+                > 0
+            {
+                println!();
+                print_stats(&self.session, projector.projection());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn print_stats(session: &str, stats: &runtime::SessionStats) {
+    println!("{} {}", style("Session").cyan().bold(), session);
+    match stats.last_nonce {
+        Some(nonce) => println!(
+            "  Actions: {} (last nonce {}, clock {})",
+            stats.total_actions(),
+            nonce,
+            stats.last_clock
+        ),
+        None => println!("  Actions: 0"),
+    }
+    println!("  System actions: {}", stats.system_actions);
+    println!("  Failed actions: {}", stats.failed_actions);
+    println!("  Restores: {}", stats.restores);
+    println!(
+        "  Proofs: {} generated, {} failed",
+        stats.proofs_generated, stats.proofs_failed
+    );
+
+    if !stats.actions_by_kind.is_empty() {
+        println!("  By kind:");
+        for (kind, count) in &stats.actions_by_kind {
+            println!("    {:<16} {}", format!("{kind:?}"), count);
+        }
+    }
+    if !stats.actions_by_actor.is_empty() {
+        println!("  By actor:");
+        for (actor, count) in &stats.actions_by_actor {
+            println!("    {:<16} {}", format!("{actor:?}"), count);
+        }
+    }
+}
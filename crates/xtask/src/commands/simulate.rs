@@ -0,0 +1,101 @@
+//! Headless batch simulation for balance testing
+//!
+//! Plays many seeded games of a scenario with the player driven by the
+//! utility AI and prints aggregate statistics.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use console::style;
+use std::path::PathBuf;
+
+use client_bootstrap::{ContentOracleFactory, OracleFactory};
+use runtime::{BatchConfig, BatchSimulation, Scenario};
+
+/// Play seeded games headless and report balance statistics
+#[derive(Parser)]
+pub struct Simulate {
+    /// Number of games to play
+    #[arg(short, long, default_value = "1000")]
+    games: u32,
+
+    /// Game seed of the first game (game N uses SEED + N)
+    #[arg(short, long, default_value = "0")]
+    seed: u64,
+
+    /// Player turns after which a game is stopped undecided
+    #[arg(short = 't', long, default_value = "500")]
+    max_turns: u32,
+
+    /// Games played at the same time (defaults to the number of CPUs)
+    #[arg(short = 'j', long)]
+    jobs: Option<usize>,
+
+    /// Game content directory (defaults to CONTENT_DATA_DIR or crates/game/content/data)
+    #[arg(long, value_name = "DIR")]
+    content_dir: Option<PathBuf>,
+
+    /// Map to load from the content directory
+    #[arg(long, default_value = "test_dungeon")]
+    map: String,
+
+    /// Scenario file (defaults to scenarios/test_scenario.ron in the content directory)
+    #[arg(long, value_name = "FILE")]
+    scenario: Option<PathBuf>,
+
+    /// Print the full report (including per-game outcomes) as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+impl Simulate {
+    pub fn execute(self) -> Result<()> {
+        let content_dir = match self.content_dir {
+            Some(dir) => dir,
+            None => std::env::var("CONTENT_DATA_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| {
+                    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../game/content/data")
+                }),
+        };
+        let scenario_path = self
+            .scenario
+            .unwrap_or_else(|| content_dir.join("scenarios/test_scenario.ron"));
+
+        let oracles = ContentOracleFactory::new(&content_dir, self.map.as_str()).build();
+        let scenario = Scenario::load_from_file(&scenario_path)
+            .with_context(|| format!("Failed to load scenario: {}", scenario_path.display()))?;
+
+        let mut config = BatchConfig {
+            games: self.games,
+            base_seed: self.seed,
+            max_turns: self.max_turns,
+            ..BatchConfig::default()
+        };
+        if let Some(jobs) = self.jobs {
+            config.parallelism = jobs;
+        }
+
+        if !self.json {
+            println!(
+                "{} {} games of {} (seeds {}..{}, max {} turns)",
+                style("Simulating").cyan().bold(),
+                config.games,
+                scenario_path.display(),
+                config.base_seed,
+                config.base_seed + u64::from(config.games),
+                config.max_turns
+            );
+        }
+
+        let simulation = BatchSimulation::new(oracles, scenario, config);
+        let report = tokio::runtime::Runtime::new()?.block_on(simulation.run())?;
+
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            println!();
+            print!("{report}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+//! Verify published proof files without the game client
+//!
+//! Checks a single proof, or every `proof_<start>_<end>.bin` batch proof in a
+//! directory together with their journal linkage, against a verifying key.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use console::style;
+use zk::{JournalExpectations, JournalFields, ProofData, ProofVerifier, VerifyingKey};
+
+/// Verify a proof file or a directory of sequential batch proofs
+#[derive(Parser)]
+pub struct VerifyProof {
+    /// Proof file, or a directory of `proof_<start>_<end>.bin` batch proofs
+    #[arg(value_name = "PATH")]
+    path: PathBuf,
+
+    /// Verifying key file: RISC0 image ID (hex) or bincode SP1 verifying key
+    #[arg(long, value_name = "FILE")]
+    vk: Option<PathBuf>,
+
+    /// Expected oracle root (hex)
+    #[arg(long, value_name = "HEX", value_parser = parse_root)]
+    oracle_root: Option<[u8; 32]>,
+
+    /// Expected state root before the (first) proof (hex)
+    #[arg(long, value_name = "HEX", value_parser = parse_root)]
+    prev_state_root: Option<[u8; 32]>,
+
+    /// Expected nonce after the (last) proof
+    #[arg(long)]
+    nonce: Option<u64>,
+
+    /// Accept stub and native proofs, which only carry a journal
+    #[arg(long)]
+    allow_insecure: bool,
+}
+
+impl VerifyProof {
+    pub fn execute(self) -> Result<()> {
+        let key = match &self.vk {
+            Some(path) => read_key(path)?,
+            None => VerifyingKey::default(),
+        };
+        let verifier = ProofVerifier::new(key).allow_insecure(self.allow_insecure);
+        let expected = JournalExpectations {
+            oracle_root: self.oracle_root,
+            prev_state_root: self.prev_state_root,
+            new_nonce: self.nonce,
+        };
+
+        let files = if self.path.is_dir() {
+            batch_proofs(&self.path)?
+        } else {
+            vec![self.path.clone()]
+        };
+        let proofs = files
+            .iter()
+            .map(|path| read_proof(path))
+            .collect::<Result<Vec<_>>>()?;
+
+        println!(
+            "{} {} proof(s) in {}",
+            style("Verifying").cyan().bold(),
+            proofs.len(),
+            self.path.display()
+        );
+        if let Some(backend) = proofs.first().map(|proof| proof.backend) {
+            println!("  Backend: {:?}", backend);
+        }
+
+        // Verify one by one first so a failure names the offending file
+        let mut journals = Vec::with_capacity(proofs.len());
+        for (path, proof) in files.iter().zip(&proofs) {
+            let fields = verifier
+                .verify(proof, &JournalExpectations::default())
+                .with_context(|| format!("{} failed verification", path.display()))?;
+            println!(
+                "  {} {} (nonce {})",
+                style("ok").green(),
+                path.file_name().unwrap_or_default().to_string_lossy(),
+                fields.new_nonce
+            );
+            journals.push(fields);
+        }
+        verifier.verify_chain(&proofs, &expected)?;
+
+        print_summary(&journals);
+        println!();
+        println!("{}", style("Proofs verified").green().bold());
+        Ok(())
+    }
+}
+
+fn print_summary(journals: &[JournalFields]) {
+    let (Some(first), Some(last)) = (journals.first(), journals.last()) else {
+        return;
+    };
+    println!();
+    println!("  Oracle root:      {}", hex::encode(first.oracle_root));
+    println!("  Seed commitment:  {}", hex::encode(first.seed_commitment));
+    println!("  Prev state root:  {}", hex::encode(first.prev_state_root));
+    println!("  Final state root: {}", hex::encode(last.new_state_root));
+    println!("  Final nonce:      {}", last.new_nonce);
+}
+
+/// Batch proof files in `dir`, ordered by start nonce.
+fn batch_proofs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut batches = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let start = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("proof_")?.strip_suffix(".bin"))
+            .and_then(|range| range.split_once('_'))
+            .and_then(|(start, _)| start.parse::<u64>().ok());
+        if let Some(start) = start {
+            batches.push((start, path));
+        }
+    }
+    if batches.is_empty() {
+        anyhow::bail!("No proof_<start>_<end>.bin files in {}", dir.display());
+    }
+    batches.sort();
+    Ok(batches.into_iter().map(|(_, path)| path).collect())
+}
+
+fn read_proof(path: &Path) -> Result<ProofData> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read proof file: {}", path.display()))?;
+    bincode::deserialize(&bytes)
+        .with_context(|| format!("Failed to deserialize ProofData: {}", path.display()))
+}
+
+/// Reads a key file, accepting hex text (as published for image IDs) or raw bytes.
+fn read_key(path: &Path) -> Result<VerifyingKey> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read verifying key: {}", path.display()))?;
+    let decoded = std::str::from_utf8(&bytes)
+        .ok()
+        .and_then(|text| hex::decode(text.trim().trim_start_matches("0x")).ok());
+    Ok(VerifyingKey(decoded.unwrap_or(bytes)))
+}
+
+fn parse_root(value: &str) -> Result<[u8; 32], String> {
+    let bytes = hex::decode(value.trim_start_matches("0x")).map_err(|e| e.to_string())?;
+    bytes
+        .try_into()
+        .map_err(|bytes: Vec<u8>| format!("expected 32 bytes, got {}", bytes.len()))
+}
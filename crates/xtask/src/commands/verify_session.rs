@@ -0,0 +1,96 @@
+//! Deterministic replay check of a saved session
+//!
+//! Re-executes the session's action log from its genesis state and compares
+//! state roots at every saved state. On a mismatch it lists the differing
+//! fields and the first action that touched one of them.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use console::style;
+use std::path::PathBuf;
+
+use client_bootstrap::{ContentOracleFactory, OracleFactory};
+use runtime::SessionVerifier;
+
+use crate::dirs;
+
+/// Replay a saved session and check that every saved state is reproduced
+#[derive(Parser)]
+pub struct VerifySession {
+    /// Session ID to verify (e.g., session_1763197900)
+    #[arg(value_name = "SESSION")]
+    session: String,
+
+    /// Custom data directory (defaults to platform-specific location)
+    #[arg(short, long, value_name = "DIR")]
+    data_dir: Option<PathBuf>,
+
+    /// Game content directory (defaults to CONTENT_DATA_DIR or crates/game/content/data)
+    #[arg(long, value_name = "DIR")]
+    content_dir: Option<PathBuf>,
+
+    /// Map the session was played on
+    #[arg(long, default_value = "test_dungeon")]
+    map: String,
+}
+
+impl VerifySession {
+    pub fn execute(self) -> Result<()> {
+        let data_dir = match self.data_dir {
+            Some(dir) => dir,
+            None => dirs::data_dir()?,
+        };
+        let session_dir = data_dir.join(&self.session);
+        if !session_dir.exists() {
+            anyhow::bail!("Session directory not found: {}", session_dir.display());
+        }
+
+        let content_dir = match self.content_dir {
+            Some(dir) => dir,
+            None => std::env::var("CONTENT_DATA_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| {
+                    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../game/content/data")
+                }),
+        };
+        let snapshot = ContentOracleFactory::new(&content_dir, self.map.as_str())
+            .build()
+            .to_snapshot();
+
+        println!(
+            "{} {}",
+            style("Verifying").cyan().bold(),
+            session_dir.display()
+        );
+        let report = SessionVerifier::open(&session_dir, &self.session, snapshot)
+            .and_then(|verifier| verifier.verify())
+            .with_context(|| format!("Failed to verify session {}", self.session))?;
+
+        match report.oracle_root_matches {
+            Some(true) => println!("  Oracle root matches session_init.json"),
+            Some(false) => println!(
+                "  {} oracle root differs from session_init.json (content changed since recording?)",
+                style("warning:").yellow().bold()
+            ),
+            None => println!("  No session_init.json, oracle root not checked"),
+        }
+        println!("  Actions replayed: {}", report.actions_replayed);
+        println!(
+            "  Saved states verified: {}/{}",
+            report.verified_states, report.saved_states
+        );
+        println!();
+
+        match report.divergence {
+            None => {
+                println!("{}", style("Replay is deterministic").green().bold());
+                Ok(())
+            }
+            Some(divergence) => {
+                println!("{}", style("Replay diverged").red().bold());
+                print!("{divergence}");
+                anyhow::bail!("session {} is not reproducible", self.session)
+            }
+        }
+    }
+}
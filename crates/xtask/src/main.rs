@@ -10,7 +10,8 @@ mod utils;
 use anyhow::Result;
 use clap::Parser;
 use commands::{
-    Clean, ExtractVk, InspectProof, ReadActions, ReadState, SuiKeygen, SuiSetup, TailLogs,
+    Clean, ExtractVk, InspectProof, ReadActions, ReadState, SessionStats, Simulate, SuiKeygen,
+    SuiSetup, TailLogs, VerifyProof, VerifySession,
 };
 
 /// Development tasks for Dungeon project
@@ -43,6 +44,18 @@ enum Command {
     /// Extract SP1 Groth16 VK from proof
     ExtractVk(ExtractVk),
 
+    /// Play seeded games headless and report balance statistics
+    Simulate(Simulate),
+
+    /// Replay a saved session and check that it is deterministic
+    VerifySession(VerifySession),
+
+    /// Verify proof files (and their linkage) against a verifying key
+    VerifyProof(VerifyProof),
+
+    /// Rebuild play statistics of a session from its event log
+    SessionStats(SessionStats),
+
     /// Sui blockchain commands
     #[command(subcommand)]
     Sui(SuiCommand),
@@ -71,6 +84,10 @@ fn main() -> Result<()> {
         Command::ReadActions(cmd) => cmd.execute(),
         Command::InspectProof(cmd) => cmd.run(),
         Command::ExtractVk(cmd) => cmd.execute(),
+        Command::Simulate(cmd) => cmd.execute(),
+        Command::VerifySession(cmd) => cmd.execute(),
+        Command::VerifyProof(cmd) => cmd.execute(),
+        Command::SessionStats(cmd) => cmd.execute(),
         Command::Sui(sui_cmd) => match sui_cmd {
             SuiCommand::Keygen(cmd) => cmd.execute(),
             SuiCommand::Setup(cmd) => cmd.execute(),
@@ -9,6 +9,11 @@
 //!
 //! - `RISC0_SKIP_BUILD=1`: Skip RISC0 guest compilation (generates placeholders)
 //!
+//! # Guest Features
+//!
+//! The `merkle-state` feature is forwarded to the state-transition guest so
+//! host and guest agree on the journaled state roots.
+//!
 //! # Generated Constants
 //!
 //! Outputs to `OUT_DIR/methods.rs`:
@@ -16,9 +21,12 @@
 //! **RISC0:**
 //! - `STATE_TRANSITION_ELF: &[u8]` - State transition guest program binary
 //! - `STATE_TRANSITION_ID: [u32; 8]` - State transition program identifier
+//! - `AGGREGATION_ELF: &[u8]` - Aggregation guest program binary
+//! - `AGGREGATION_ID: [u32; 8]` - Aggregation program identifier
 //!
 //! **SP1:**
 //! - `STATE_TRANSITION_ELF: &[u8]` - State transition guest program binary
+//! - `AGGREGATION_ELF: &[u8]` - Aggregation guest program binary
 
 fn main() {
     use std::env;
@@ -64,6 +72,16 @@ fn is_clippy_or_check() -> bool {
             .unwrap_or(false)
 }
 
+/// Cargo features of the state-transition guest
+#[cfg(any(feature = "risc0", feature = "sp1"))]
+fn guest_features() -> Vec<String> {
+    if std::env::var("CARGO_FEATURE_MERKLE_STATE").is_ok() {
+        vec!["merkle-state".to_string()]
+    } else {
+        Vec::new()
+    }
+}
+
 /// Generate RISC0 placeholder constants when guest build is skipped
 fn generate_risc0_placeholder() {
     use std::env;
@@ -74,6 +92,8 @@ fn generate_risc0_placeholder() {
         methods_path,
         r#"pub const STATE_TRANSITION_ELF: &[u8] = &[];
 pub const STATE_TRANSITION_ID: [u32; 8] = [0; 8];
+pub const AGGREGATION_ELF: &[u8] = &[];
+pub const AGGREGATION_ID: [u32; 8] = [0; 8];
 "#,
     )
     .expect("Failed to write RISC0 placeholder methods.rs");
@@ -88,6 +108,7 @@ fn generate_sp1_placeholder() {
     std::fs::write(
         methods_path,
         r#"pub const STATE_TRANSITION_ELF: &[u8] = &[];
+pub const AGGREGATION_ELF: &[u8] = &[];
 "#,
     )
     .expect("Failed to write SP1 placeholder methods.rs");
@@ -111,7 +132,15 @@ fn build_risc0_guest() {
         );
     }
 
-    risc0_build::embed_methods();
+    let mut guest_options = std::collections::HashMap::new();
+    guest_options.insert(
+        "state-transition",
+        risc0_build::GuestOptions {
+            features: guest_features(),
+            ..Default::default()
+        },
+    );
+    risc0_build::embed_methods_with_options(guest_options);
 }
 
 /// Stub for when risc0 feature is not enabled (should never be called)
@@ -123,29 +152,36 @@ fn build_risc0_guest() {
 /// Build SP1 guest program (only compiled when sp1 feature is enabled)
 #[cfg(feature = "sp1")]
 fn build_sp1_guest() {
-    use sp1_build::build_program;
+    use sp1_build::{BuildArgs, build_program, build_program_with_args};
     use std::env;
     use std::fs;
     use std::path::Path;
 
     println!("cargo:rerun-if-changed=methods/sp1/");
 
-    // Build SP1 guest program - this generates the ELF binary
-    build_program("methods/sp1/state-transition");
+    // Build SP1 guest programs - this generates the ELF binaries
+    build_program_with_args(
+        "methods/sp1/state-transition",
+        BuildArgs {
+            features: guest_features(),
+            ..Default::default()
+        },
+    );
+    build_program("methods/sp1/aggregation");
 
     // SP1's build_program doesn't automatically generate methods.rs like RISC0's embed_methods
     // We need to manually generate it
     let out_dir = env::var("OUT_DIR").unwrap();
     let methods_path = Path::new(&out_dir).join("methods.rs");
 
-    // The ELF binary is created by build_program in methods/sp1/target/elf-compilation/
-    // Relative path from OUT_DIR (target/debug/build/zk-*/out) to the ELF file
-    let elf_path = "../../../../../crates/zk/methods/sp1/target/elf-compilation/riscv32im-succinct-zkvm-elf/release/state-transition";
+    // The ELF binaries are created by build_program in methods/sp1/target/elf-compilation/
+    // Relative path from OUT_DIR (target/debug/build/zk-*/out) to the ELF files
+    let elf_dir = "../../../../../crates/zk/methods/sp1/target/elf-compilation/riscv32im-succinct-zkvm-elf/release";
 
-    // Generate methods.rs that includes the ELF as a byte slice
+    // Generate methods.rs that includes the ELFs as byte slices
     let methods_content = format!(
-        r#"pub const STATE_TRANSITION_ELF: &[u8] = include_bytes!("{}");"#,
-        elf_path
+        r#"pub const STATE_TRANSITION_ELF: &[u8] = include_bytes!("{elf_dir}/state-transition");
+pub const AGGREGATION_ELF: &[u8] = include_bytes!("{elf_dir}/aggregation");"#
     );
 
     fs::write(methods_path, methods_content).expect("Failed to write SP1 methods.rs");
@@ -10,9 +10,9 @@
 //! ```text
 //! 1. oracle_root       (32 bytes) - Commitment to static game content
 //! 2. seed_commitment   (32 bytes) - Commitment to RNG seed
-//! 3. prev_state_root   (32 bytes) - State hash before execution
-//! 4. actions_root      (32 bytes) - Commitment to action sequence (Walrus blob_id)
-//! 5. new_state_root    (32 bytes) - State hash after execution
+//! 3. prev_state_root   (32 bytes) - State commitment root before execution
+//! 4. actions_root      (32 bytes) - Root of the published action sequence (Walrus blob)
+//! 5. new_state_root    (32 bytes) - State commitment root after execution
 //! 6. new_nonce         (8 bytes)  - Action counter after execution
 //!
 //! Total: 168 bytes
@@ -36,18 +36,19 @@
 //! ```text
 //! Host → zkVM Guest:
 //!   - OracleSnapshot (static game content)
-//!   - seed_commitment (RNG seed commitment)
+//!   - seed_salt (private salt of the seed commitment)
 //!   - start_state (GameState at batch start)
 //!   - actions (Vec<Action> to execute sequentially)
 //!
 //! Guest executes:
 //!   1. Compute oracle_root from OracleSnapshot
-//!   2. Compute prev_state_root from start_state
-//!   3. Compute actions_root from actions
-//!   4. Execute each action sequentially
-//!   5. Compute new_state_root from final state
-//!   6. Get new_nonce from final state
-//!   7. Commit all 6 fields to journal in order
+//!   2. Compute seed_commitment from start_state.game_seed and seed_salt
+//!   3. Compute prev_state_root as the commitment root of start_state
+//!   4. Compute actions_root from the published (redacted) actions
+//!   5. Execute each action sequentially
+//!   6. Compute new_state_root as the commitment root of the final state
+//!   7. Get new_nonce from final state
+//!   8. Commit all 6 fields to journal in order
 //!
 //! zkVM → Host:
 //!   - Receipt (proof + journal):
@@ -62,6 +63,20 @@
 //!   - Receipt is valid (cryptographic verification)
 //!   - Journal fields match expected values
 //!
+//! # State Commitment Modes
+//!
+//! State roots come from `GameState::compute_journal_state_root`:
+//! - default: hiding commitment over the public view and the salted full state
+//! - `merkle-state` feature: Merkle root with per-entity inclusion proofs
+//!
+//! The host must be built with the same mode (`zk/merkle-state`).
+//!
+//! # NPC Decisions
+//!
+//! Actors with provider kind `Ai(Provable)` appear in the batch only as
+//! `SystemActionKind::AiTurn` markers. The guest re-derives their actions with
+//! `game_core::ai`, so the proof covers the NPC's choice, not just its legality.
+//!
 //! # Design Rationale
 //!
 //! Batch proving is more efficient than individual action proofs:
@@ -85,7 +100,10 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
-use game_core::{compute_actions_root, Action, GameEngine, GameState, OracleSnapshot, SnapshotOracleBundle};
+use game_core::{
+    compute_published_actions_root, compute_seed_commitment, publish_actions, Action, GameEngine,
+    GameState, OracleSnapshot, SnapshotOracleBundle,
+};
 use risc0_zkvm::guest::env;
 
 risc0_zkvm::guest::entry!(main);
@@ -95,8 +113,8 @@ pub fn main() {
     // 1. Oracle snapshot (static game content)
     let oracle_snapshot: OracleSnapshot = env::read();
 
-    // 2. Seed commitment (RNG seed commitment)
-    let seed_commitment: [u8; 32] = env::read();
+    // 2. Seed salt (private input, never leaves the guest)
+    let seed_salt: [u8; 32] = env::read();
 
     // 3. Game state at batch start
     let mut state: GameState = env::read();
@@ -111,11 +129,18 @@ pub fn main() {
     // Compute oracle root (commitment to static game content)
     let oracle_root = oracle_snapshot.compute_oracle_root();
 
-    // Compute previous state root (state before execution)
-    let prev_state_root = state.compute_state_root();
+    // Derive the seed commitment from the proven state rather than trusting the
+    // host, so the journal commits to the seed that actually drives the RNG
+    let seed_commitment = compute_seed_commitment(state.game_seed, &seed_salt);
+
+    // Compute previous state root (hiding commitment by default: only the
+    // public view can be opened; Merkle root with the `merkle-state` feature)
+    let prev_state_root = state.compute_journal_state_root(&seed_salt);
 
-    // Compute actions root (commitment to action sequence - simulates Walrus blob_id)
-    let actions_root = compute_actions_root(&actions);
+    // Compute actions root over the published log: private actions appear
+    // only as blinded commitments, matching what is uploaded to Walrus
+    let actions_root =
+        compute_published_actions_root(&publish_actions(state.nonce(), &actions, &seed_salt));
 
     // ========================================================================
     // EXECUTE ACTIONS
@@ -148,8 +173,8 @@ pub fn main() {
     // COMPUTE ROOTS AFTER EXECUTION
     // ========================================================================
 
-    // Compute new state root (state after execution)
-    let new_state_root = state.compute_state_root();
+    // Compute new state root (same commitment mode, state after execution)
+    let new_state_root = state.compute_journal_state_root(&seed_salt);
 
     // Get new nonce (action counter after execution)
     let new_nonce = state.nonce();
@@ -0,0 +1,110 @@
+//! SP1 zkVM guest program for proof aggregation.
+//!
+//! This program runs inside the SP1 zkVM and proves that a chain of
+//! compressed state-transition proofs is valid and contiguous, so a whole session can
+//! be verified on-chain with a single proof.
+//!
+//! # Journal Structure
+//!
+//! Same 168-byte layout as the state-transition guest:
+//!
+//! ```text
+//! 1. oracle_root       (32 bytes) - Shared by every batch
+//! 2. seed_commitment   (32 bytes) - Shared by every batch
+//! 3. prev_state_root   (32 bytes) - First batch's prev_state_root
+//! 4. actions_root      (32 bytes) - SHA256(domain || vkey_hash || actions_root_1 || ...)
+//! 5. new_state_root    (32 bytes) - Last batch's new_state_root
+//! 6. new_nonce         (8 bytes)  - Last batch's new_nonce
+//! ```
+//!
+//! # Verification Model
+//!
+//! ```text
+//! Host → zkVM Guest:
+//!   - vkey_hash (state-transition guest verifying key hash)
+//!   - journals (Vec<Vec<u8>>, one per batch, in nonce order)
+//!   - proofs (compressed, written with SP1Stdin::write_proof)
+//!
+//! Guest executes:
+//!   1. Verify each batch proof against vkey_hash and its public values
+//!   2. Check every batch shares oracle_root and seed_commitment
+//!   3. Check each new_state_root is the next batch's prev_state_root
+//!   4. Check new_nonce strictly increases
+//!   5. Fold the actions roots and commit the aggregate journal
+//! ```
+//!
+//! The verifying key hash is folded into the actions root, so a verifier recomputing it
+//! from the published action logs also pins the program that executed them.
+//!
+//! This logic MUST match `zk::aggregate_journals` on the host.
+
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sha2::{Digest, Sha256};
+
+/// Must match `zk::AGGREGATE_ACTIONS_DOMAIN`.
+const AGGREGATE_ACTIONS_DOMAIN: &[u8] = b"dungeon.aggregate_actions.v1";
+
+fn nonce(journal: &[u8]) -> u64 {
+    u64::from_le_bytes(journal[160..168].try_into().unwrap())
+}
+
+pub fn main() {
+    // Read inputs from host in order
+    // 1. Verifying key hash of the state-transition guest
+    let batch_vkey: [u32; 8] = sp1_zkvm::io::read();
+
+    // 2. Batch public values, in chain order
+    let journals: Vec<Vec<u8>> = sp1_zkvm::io::read();
+    assert!(!journals.is_empty(), "No batch proofs to aggregate");
+
+    let mut actions_hasher = Sha256::new();
+    actions_hasher.update(AGGREGATE_ACTIONS_DOMAIN);
+    for word in batch_vkey {
+        actions_hasher.update(word.to_le_bytes());
+    }
+
+    let first = &journals[0];
+    let mut prev = first;
+    for (index, journal) in journals.iter().enumerate() {
+        assert!(journal.len() == 168, "Batch {} journal is not 168 bytes", index);
+
+        // Verify the batch proof (read from the host's write_proof calls)
+        let public_values_digest: [u8; 32] = Sha256::digest(journal).into();
+        sp1_zkvm::lib::verify::verify_sp1_proof(&batch_vkey, &public_values_digest);
+
+        if index > 0 {
+            assert!(
+                journal[0..64] == first[0..64],
+                "Batch {} was proven against a different oracle root or seed commitment",
+                index
+            );
+            assert!(
+                journal[64..96] == prev[128..160],
+                "Batch {} does not start where batch {} ended",
+                index,
+                index - 1
+            );
+            assert!(
+                nonce(journal) > nonce(prev),
+                "Batch {} does not advance the nonce",
+                index
+            );
+        }
+
+        actions_hasher.update(&journal[96..128]);
+        prev = journal;
+    }
+
+    // ========================================================================
+    // COMMIT TO PUBLIC VALUES (same layout as the state-transition guest)
+    // ========================================================================
+
+    let mut aggregate = [0u8; 168];
+    aggregate[0..96].copy_from_slice(&first[0..96]); // oracle_root, seed_commitment, prev_state_root
+    aggregate[96..128].copy_from_slice(&actions_hasher.finalize()); // actions_root
+    aggregate[128..168].copy_from_slice(&prev[128..168]); // new_state_root, new_nonce
+
+    sp1_zkvm::io::commit_slice(&aggregate);
+}
@@ -0,0 +1,52 @@
+//! Merkle state root gadget.
+//!
+//! Recomputes [`GameState::compute_merkle_root`](game_core::GameState::compute_merkle_root)
+//! in R1CS with the SHA-256 gadget, so the circuit root is byte-for-byte the
+//! host root. SHA-256 is expensive in constraints (~37k per 64-byte block), but
+//! it keeps the circuit on the same commitment as the zkVM backends.
+
+use ark_bn254::Fr;
+use ark_crypto_primitives::crh::sha256::constraints::Sha256Gadget;
+use ark_r1cs_std::uint8::UInt8;
+use ark_relations::r1cs::SynthesisError;
+use game_core::{MERKLE_LEAF_DOMAIN, MERKLE_NODE_DOMAIN, MERKLE_ROOT_DOMAIN};
+
+/// Bytes of a hash or encoding in the circuit.
+pub(crate) type Bytes = Vec<UInt8<Fr>>;
+
+/// Leaf hash of an encoded leaf.
+pub(crate) fn hash_leaf(encoded: &[UInt8<Fr>]) -> Result<Bytes, SynthesisError> {
+    sha256(&[&UInt8::constant_vec(MERKLE_LEAF_DOMAIN), encoded])
+}
+
+/// Merkle state root over leaf hashes in tree order.
+pub(crate) fn root(leaves: Vec<Bytes>) -> Result<Bytes, SynthesisError> {
+    let leaf_count = leaves.len() as u32;
+    let mut level = leaves;
+    while level.len() > 1 {
+        let mut next = Vec::with_capacity(level.len().div_ceil(2));
+        let mut nodes = level.into_iter();
+        while let Some(left) = nodes.next() {
+            match nodes.next() {
+                Some(right) => next.push(sha256(&[
+                    &UInt8::constant_vec(MERKLE_NODE_DOMAIN),
+                    &left,
+                    &right,
+                ])?),
+                None => next.push(left),
+            }
+        }
+        level = next;
+    }
+
+    let top = level.pop().ok_or(SynthesisError::Unsatisfiable)?;
+    sha256(&[
+        &UInt8::constant_vec(MERKLE_ROOT_DOMAIN),
+        &UInt8::constant_vec(&leaf_count.to_le_bytes()),
+        &top,
+    ])
+}
+
+fn sha256(parts: &[&[UInt8<Fr>]]) -> Result<Bytes, SynthesisError> {
+    Ok(Sha256Gadget::digest(&parts.concat())?.0)
+}
@@ -1,136 +1,507 @@
-//! Arkworks circuit proving backend (Phase 2+).
+//! Arkworks circuit proving backend.
 //!
-//! This module implements hand-crafted circuits using Arkworks with explicit Merkle witness generation.
-//! Uses Poseidon hash for Merkle trees and Groth16 for proof generation.
-//! Provides better performance than zkVMs but requires more implementation effort.
+//! Hand-written R1CS circuits over the Merkle state root
+//! ([`GameState::compute_merkle_root`]), proven with Groth16 on BN254. One
+//! proof covers one action; a batch proof is the list of per-action proofs.
 //!
 //! # Architecture (from docs/state-delta-architecture.md)
 //!
 //! ```text
 //! StateDelta (bitmask)
 //!   ↓
-//! StateTransition (Merkle witnesses)
+//! StateTransition (opened leaves + shared leaf hashes)
 //!   ↓
-//! Circuit (constraint system)
+//! TransitionCircuit (constraint system)
 //!   ↓
-//! Proof (compact, fast verification)
+//! Groth16 proof
 //! ```
 //!
-//! # Modules (to be implemented)
+//! # Modules
 //!
-//! - `merkle/`: Sparse Merkle tree implementation
-//! - `witness/`: Witness generation from StateDelta
-//! - `transition/`: StateTransition structure
-//! - `circuits/`: Circuit definitions for each action type
+//! - [`merkle`]: SHA-256 Merkle root gadget
+//! - [`witness`]: Leaf layout, statements and witness generation from a `StateDelta`
+//! - [`transition`]: Constraints for each supported action
 //!
-//! # Implementation Status
+//! # What the circuits prove
 //!
-//! **Not yet implemented** - This is Phase 2+ work that will be done when:
-//! 1. zkVM proof generation time becomes a bottleneck
-//! 2. On-chain verification costs are too high
-//! 3. Team has bandwidth for multi-month circuit development
+//! The circuits check how the state root changes, not that the change
+//! follows the game rules: a valid proof shows the action was applied with
+//! the claimed cost and damage, not that the action was legal. Use a zkVM
+//! backend where the transitions themselves must be proven.
 //!
-//! For now, use the zkVM backend (default feature).
+//! Supported actions are `Move` and `MeleeAttack`; any other action makes
+//! [`ArkworksProver::prove`] fail with [`ProofError::CircuitProofError`].
+//!
+//! For both, the circuit proves that `new_root` is `prev_root` with the turn
+//! nonce advanced by one, the acting actor (the current actor) delayed by
+//! `cost` ticks, and:
+//!
+//! - `Move`: the actor stepped one tile in the given direction and moved from
+//!   the occupants of its old tile to the end of the occupants of its new tile;
+//! - `MeleeAttack`: an actor within one tile lost `damage` HP.
+//!
+//! Every other leaf is unchanged.
+//!
+//! # What they do not prove
+//!
+//! These are the rules that make a transition legal. The circuits leave them
+//! to the prover, which only proves actions the engine executed:
+//!
+//! - that `cost` and `damage` follow the action profile and damage formula
+//! - that the destination tile is passable
+//! - tile order of a newly occupied tile's leaf, and uniqueness of occupants
+//! - that the acting actor is scheduled (`ready_at` is `Some`)
+//! - the oracle root and seed commitment of the journal
+//!
+//! The action itself is a public input, so this backend cannot hide NPC
+//! actions the way the zkVM backends do (see [`game_core::PublishedAction`]).
+//!
+//! # Keys
+//!
+//! Keys are generated per [`TransitionShape`] from a seed derived from the
+//! shape, so every prover and verifier derives the same keys without a setup
+//! ceremony. Anyone can derive the trapdoor the same way: **the keys are for
+//! development and benchmarking only**.
 
-#![allow(dead_code)] // Allow dead code since this is future work
+pub mod merkle;
+pub mod transition;
+pub mod witness;
 
-use crate::{ProofData, ProofError};
-use game_core::{GameState, StateDelta};
+pub use witness::{
+    LeafRole, Slot, StateTransition, TransitionRule, TransitionShape, TransitionStatement,
+};
 
-/// State transition with Merkle witnesses (Phase 2).
-///
-/// This structure represents a proven state transition with all necessary
-/// Merkle proofs for changed entities.
-///
-/// See: docs/state-delta-architecture.md Section 5.2
-pub struct StateTransition {
-    // TODO: Implement in Phase 2
-    _placeholder: (),
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use ark_bn254::Bn254;
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use ark_std::rand::{SeedableRng, rngs::StdRng};
+use game_core::{
+    Action, ActionInput, CharacterAction, GameEngine, GameState, OracleSnapshot,
+    SnapshotOracleBundle, StateDelta, compute_published_actions_root, publish_actions,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::prover::{
+    JournalFields, ProofBackend, ProofData, ProofError, Prover, compute_journal_digest,
+    compute_seed_commitment, parse_journal,
+};
+use transition::TransitionCircuit;
+
+/// Domain tag of the per-shape key generation seed.
+pub const CIRCUIT_SETUP_DOMAIN: &[u8] = b"dungeon.arkworks.setup.v1";
+
+/// Proof of a single action.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionProof {
+    pub statement: TransitionStatement,
+    pub shape: TransitionShape,
+    /// Compressed Groth16 proof.
+    pub proof: Vec<u8>,
+}
+
+impl TransitionRule {
+    /// The action this rule proves.
+    pub fn to_action(&self) -> Action {
+        let (actor, kind, input) = match *self {
+            TransitionRule::Move { actor, direction } => {
+                (actor, self.kind(), ActionInput::Direction(direction))
+            }
+            TransitionRule::MeleeAttack { actor, target } => {
+                (actor, self.kind(), ActionInput::Target(target))
+            }
+        };
+        Action::character(CharacterAction::new(actor, kind, input))
+    }
 }
 
 impl StateTransition {
-    /// Convert a StateDelta into a StateTransition with Merkle witnesses.
-    ///
-    /// # Algorithm (from architecture doc)
-    ///
-    /// 1. Build full Merkle trees from before_state
-    /// 2. Build full Merkle trees from after_state
-    /// 3. Generate witnesses using delta as guide (only changed entities)
-    /// 4. Construct StateTransition with before/after roots and witnesses
-    ///
-    /// # Complexity
-    ///
-    /// - Time: O(n log n) where n = entity count
-    /// - Space: O(k log n) where k = changed entities
-    ///
-    /// See: docs/state-delta-architecture.md Section 5.4
-    pub fn from_delta(
-        _delta: StateDelta,
-        _before_state: &GameState,
-        _after_state: &GameState,
-    ) -> Result<Self, ProofError> {
-        // Phase 2: Implement Poseidon-based Merkle tree building and witness generation
-        Err(ProofError::CircuitProofError(
-            "Arkworks circuit not yet implemented - use zkVM backend".to_string(),
-        ))
+    /// Generate a Groth16 proof of this transition.
+    pub fn prove(&self, proving_key: &ProvingKey<Bn254>) -> Result<TransitionProof, ProofError> {
+        let proof = Groth16::<Bn254>::prove(
+            proving_key,
+            TransitionCircuit::new(self),
+            &mut rand::thread_rng(),
+        )
+        .map_err(|e| ProofError::CircuitProofError(e.to_string()))?;
+
+        let mut bytes = Vec::new();
+        proof
+            .serialize_compressed(&mut bytes)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+
+        Ok(TransitionProof {
+            statement: self.statement.clone(),
+            shape: self.shape.clone(),
+            proof: bytes,
+        })
     }
+}
 
-    /// Generate a Groth16 proof from this transition.
-    pub fn prove(&self) -> Result<ProofData, ProofError> {
-        // Phase 2: Implement Groth16 proving with Arkworks
-        Err(ProofError::CircuitProofError(
-            "Arkworks circuit not yet implemented - use zkVM backend".to_string(),
-        ))
+impl TransitionProof {
+    /// Check the Groth16 proof against the statement, with the keys of
+    /// [`TransitionProof::shape`].
+    pub fn verify(&self, keys: &CircuitKeys) -> Result<bool, ProofError> {
+        if self.shape.kind != self.statement.rule.kind() {
+            return Ok(false);
+        }
+        let proof = Proof::<Bn254>::deserialize_compressed(&self.proof[..])
+            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+        Groth16::<Bn254>::verify_with_processed_vk(
+            &keys.verifying_key,
+            &self.statement.public_inputs(),
+            &proof,
+        )
+        .map_err(|e| ProofError::CircuitProofError(e.to_string()))
+    }
+}
+
+/// Groth16 keys of one transition shape.
+pub struct CircuitKeys {
+    pub proving_key: ProvingKey<Bn254>,
+    pub verifying_key: PreparedVerifyingKey<Bn254>,
+}
+
+impl CircuitKeys {
+    /// Deterministic development keys for a shape (see [module docs](self)).
+    pub fn setup(shape: &TransitionShape) -> Result<Self, ProofError> {
+        let encoded =
+            bincode::serialize(shape).map_err(|e| ProofError::SerializationError(e.to_string()))?;
+        let mut hasher = Sha256::new();
+        hasher.update(CIRCUIT_SETUP_DOMAIN);
+        hasher.update(&encoded);
+        let mut rng = StdRng::from_seed(hasher.finalize().into());
+
+        let (proving_key, verifying_key) =
+            Groth16::<Bn254>::circuit_specific_setup(TransitionCircuit::blank(shape), &mut rng)
+                .map_err(|e| ProofError::CircuitProofError(e.to_string()))?;
+        let verifying_key = Groth16::<Bn254>::process_vk(&verifying_key)
+            .map_err(|e| ProofError::CircuitProofError(e.to_string()))?;
+
+        Ok(Self {
+            proving_key,
+            verifying_key,
+        })
     }
 }
 
-/// Arkworks circuit prover using Groth16 (Phase 2+).
+/// Arkworks circuit prover using Groth16.
+///
+/// Proves the state updates of batches of `Move` and `MeleeAttack` actions,
+/// one circuit per action; see the [module docs](self) for what this leaves
+/// unproven.
+/// Keys are generated on first use of each [`TransitionShape`] and cached.
 pub struct ArkworksProver {
-    _placeholder: (),
+    oracle_snapshot: OracleSnapshot,
+    seed_salt: [u8; 32],
+    keys: Mutex<HashMap<TransitionShape, Arc<CircuitKeys>>>,
 }
 
 impl ArkworksProver {
-    pub fn new() -> Self {
-        Self { _placeholder: () }
+    pub fn new(oracle_snapshot: OracleSnapshot, seed_salt: [u8; 32]) -> Self {
+        Self {
+            oracle_snapshot,
+            seed_salt,
+            keys: Mutex::new(HashMap::new()),
+        }
     }
 
-    pub fn prove(
+    pub fn oracle_snapshot(&self) -> &OracleSnapshot {
+        &self.oracle_snapshot
+    }
+
+    /// Prove a single action from its delta and the states around it.
+    pub fn prove_transition(
         &self,
-        _delta: &StateDelta,
-        _before_state: &GameState,
-        _after_state: &GameState,
+        delta: &StateDelta,
+        before_state: &GameState,
+        after_state: &GameState,
+    ) -> Result<TransitionProof, ProofError> {
+        let transition = StateTransition::from_delta(delta.clone(), before_state, after_state)?;
+        transition.prove(&self.keys(&transition.shape)?.proving_key)
+    }
+
+    /// Verify a single action proof against its statement.
+    pub fn verify_transition(&self, proof: &TransitionProof) -> Result<bool, ProofError> {
+        proof.verify(&*self.keys(&proof.shape)?)
+    }
+
+    fn keys(&self, shape: &TransitionShape) -> Result<Arc<CircuitKeys>, ProofError> {
+        let mut keys = self.keys.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(cached) = keys.get(shape) {
+            return Ok(Arc::clone(cached));
+        }
+        tracing::info!("Generating arkworks keys for a {:?} circuit", shape.kind);
+        let generated = Arc::new(CircuitKeys::setup(shape)?);
+        keys.insert(shape.clone(), Arc::clone(&generated));
+        Ok(generated)
+    }
+
+    fn proofs(proof: &ProofData) -> Result<Vec<TransitionProof>, ProofError> {
+        bincode::deserialize(&proof.bytes)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))
+    }
+}
+
+impl Prover for ArkworksProver {
+    fn prove(
+        &self,
+        start_state: &GameState,
+        actions: &[Action],
+        end_state: &GameState,
     ) -> Result<ProofData, ProofError> {
-        Err(ProofError::CircuitProofError(
-            "Arkworks circuit not yet implemented - use zkVM backend".to_string(),
+        let oracle_bundle = SnapshotOracleBundle::new(&self.oracle_snapshot);
+        let env = oracle_bundle.as_env();
+        let mut state = start_state.clone();
+        let mut proofs = Vec::with_capacity(actions.len());
+
+        for (index, action) in actions.iter().enumerate() {
+            TransitionRule::from_action(action)?;
+            let before = state.clone();
+            let outcome = GameEngine::new(&mut state)
+                .execute(env.as_game_env(), action)
+                .map_err(|e| {
+                    ProofError::CircuitProofError(format!(
+                        "Action execution failed (action {}/{}): {:?}",
+                        index + 1,
+                        actions.len(),
+                        e
+                    ))
+                })?;
+            proofs.push(self.prove_transition(&outcome.delta, &before, &state)?);
+        }
+
+        let expected_state_root = end_state.compute_journal_state_root(&self.seed_salt);
+        let new_state_root = state.compute_journal_state_root(&self.seed_salt);
+        if new_state_root != expected_state_root || state.nonce() != end_state.nonce() {
+            return Err(ProofError::StateInconsistency(format!(
+                "new_state_root mismatch: executed {:?} (nonce {}), expected {:?} (nonce {})",
+                new_state_root,
+                state.nonce(),
+                expected_state_root,
+                end_state.nonce()
+            )));
+        }
+
+        let fields = JournalFields {
+            oracle_root: self.oracle_snapshot.compute_oracle_root(),
+            seed_commitment: compute_seed_commitment(start_state, &self.seed_salt),
+            prev_state_root: start_state.compute_journal_state_root(&self.seed_salt),
+            actions_root: compute_published_actions_root(&publish_actions(
+                start_state.nonce(),
+                actions,
+                &self.seed_salt,
+            )),
+            new_state_root,
+            new_nonce: state.nonce(),
+        };
+
+        let journal = fields.to_bytes().to_vec();
+        let journal_digest = compute_journal_digest(&journal);
+        let bytes = bincode::serialize(&proofs)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+
+        Ok(ProofData {
+            bytes,
+            backend: ProofBackend::Arkworks,
+            journal,
+            journal_digest,
+        })
+    }
+
+    fn aggregate(&self, _proofs: &[ProofData]) -> Result<ProofData, ProofError> {
+        Err(ProofError::AggregationError(
+            "Arkworks proofs cannot be aggregated yet".to_string(),
         ))
     }
-}
 
-impl Default for ArkworksProver {
-    fn default() -> Self {
-        Self::new()
+    fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
+        if proof.backend != ProofBackend::Arkworks {
+            return Err(ProofError::CircuitProofError(format!(
+                "ArkworksProver can only verify arkworks proofs, got {:?}",
+                proof.backend
+            )));
+        }
+
+        let actual = compute_journal_digest(&proof.journal);
+        if actual != proof.journal_digest {
+            return Err(ProofError::JournalDigestMismatch {
+                expected: proof.journal_digest,
+                actual,
+            });
+        }
+
+        let fields = parse_journal(&proof.journal)?;
+        let proofs = Self::proofs(proof)?;
+        let (Some(first), Some(last)) = (proofs.first(), proofs.last()) else {
+            return Ok(false);
+        };
+        let start_nonce = first.statement.nonce;
+
+        // The per-action statements must chain into the journal.
+        let chained = proofs.iter().enumerate().all(|(index, proof)| {
+            proof.statement.nonce == start_nonce + index as u64
+                && (index == 0 || proofs[index - 1].statement.new_root == proof.statement.prev_root)
+        });
+        let actions: Vec<_> = proofs
+            .iter()
+            .map(|proof| proof.statement.rule.to_action())
+            .collect();
+        let actions_root = compute_published_actions_root(&publish_actions(
+            start_nonce,
+            &actions,
+            &self.seed_salt,
+        ));
+
+        if !chained
+            || fields.oracle_root != self.oracle_snapshot.compute_oracle_root()
+            || fields.prev_state_root != first.statement.prev_root
+            || fields.new_state_root != last.statement.new_root
+            || fields.new_nonce != start_nonce + proofs.len() as u64
+            || fields.actions_root != actions_root
+        {
+            return Ok(false);
+        }
+
+        for proof in &proofs {
+            if !self.verify_transition(proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
     }
 }
 
-// Submodules (to be implemented in Phase 2)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+    use game_core::{ActionKind, CardinalDirection, EntityId, Position};
 
-#[cfg(feature = "arkworks")]
-/// Poseidon-based Merkle tree implementations (Phase 2).
-///
-/// Sparse Merkle tree for state commitments using Poseidon hash.
-/// See: docs/state-delta-architecture.md Section 5.3
-pub mod merkle {}
+    /// Player alone at (1, 1), about to move north.
+    fn step() -> (StateDelta, GameState, GameState) {
+        let mut before = GameState::with_player();
+        let player = EntityId::PLAYER;
+        let from = Position::new(1, 1);
+        let actor = before.entities.actor_mut(player).unwrap();
+        actor.position = Some(from);
+        actor.ready_at = Some(0);
+        before.world.tile_map.add_occupant(from, player);
+        before.turn.current_actor = player;
+        before.turn.active_actors.insert(player);
 
-#[cfg(feature = "arkworks")]
-/// Witness generation (Phase 2).
-///
-/// Generate Merkle witnesses from StateDelta.
-/// See: docs/state-delta-architecture.md Section 5.4
-pub mod witness {}
+        let action = Action::character(CharacterAction::new(
+            player,
+            ActionKind::Move,
+            ActionInput::Direction(CardinalDirection::North),
+        ));
+        let mut after = before.clone();
+        let to = Position::new(1, 2);
+        let actor = after.entities.actor_mut(player).unwrap();
+        actor.position = Some(to);
+        actor.ready_at = Some(100);
+        after.world.tile_map.remove_occupant(&from, player);
+        after.world.tile_map.add_occupant(to, player);
+        after.turn.nonce += 1;
 
-#[cfg(feature = "arkworks")]
-/// State commitment structures (Phase 2).
-///
-/// State root computation and commitment schemes using Poseidon hash.
-/// See: docs/state-delta-architecture.md Section 5.2
-pub mod commitment {}
+        let delta = StateDelta::from_states(action, &before, &after);
+        (delta, before, after)
+    }
+
+    fn is_satisfied(circuit: TransitionCircuit) -> bool {
+        let cs = ConstraintSystem::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+        cs.is_satisfied().unwrap()
+    }
+
+    #[test]
+    fn move_witness_satisfies_the_circuit() {
+        let (delta, before, after) = step();
+        let transition = StateTransition::from_delta(delta, &before, &after).unwrap();
+        assert_eq!(transition.statement.cost, 100);
+        assert!(is_satisfied(TransitionCircuit::new(&transition)));
+
+        let mut wrong_direction = TransitionCircuit::new(&transition);
+        wrong_direction.statement.rule = TransitionRule::Move {
+            actor: EntityId::PLAYER,
+            direction: CardinalDirection::NorthEast,
+        };
+        assert!(!is_satisfied(wrong_direction));
+
+        let mut tampered = TransitionCircuit::new(&transition);
+        tampered.statement.cost = 99;
+        assert!(!is_satisfied(tampered));
+    }
+
+    /// Player at (1, 1) hits a goblin standing at `target` for 7 HP.
+    fn melee(target: Position) -> StateTransition {
+        let (_, mut before, _) = step();
+        let player = EntityId::PLAYER;
+        let mut goblin = before.entities.actor(player).unwrap().clone();
+        goblin.id = EntityId(1);
+        goblin.position = Some(target);
+        goblin.resources.hp = 10;
+        before.entities.actors.push(goblin).unwrap();
+        before.world.tile_map.add_occupant(target, EntityId(1));
+
+        let action = Action::character(CharacterAction::new(
+            player,
+            ActionKind::MeleeAttack,
+            ActionInput::Target(EntityId(1)),
+        ));
+        let mut after = before.clone();
+        after.entities.actor_mut(player).unwrap().ready_at = Some(100);
+        after.entities.actor_mut(EntityId(1)).unwrap().resources.hp = 3;
+        after.turn.nonce += 1;
+
+        let delta = StateDelta::from_states(action, &before, &after);
+        StateTransition::from_delta(delta, &before, &after).unwrap()
+    }
+
+    #[test]
+    fn melee_witness_satisfies_the_circuit() {
+        let transition = melee(Position::new(2, 0));
+        assert_eq!(transition.statement.damage, 7);
+        assert!(is_satisfied(TransitionCircuit::new(&transition)));
+
+        let mut tampered = TransitionCircuit::new(&transition);
+        tampered.statement.damage = 8;
+        assert!(!is_satisfied(tampered));
+    }
+
+    #[test]
+    fn melee_target_two_tiles_away_is_rejected() {
+        // (b - a + 1) = 3 on the x axis
+        let transition = melee(Position::new(3, 1));
+        assert!(!is_satisfied(TransitionCircuit::new(&transition)));
+    }
+
+    #[test]
+    fn uncovered_changes_are_rejected() {
+        let (delta, before, mut after) = step();
+        after.world.flags.insert("moved".to_string());
+        let delta = StateDelta::from_states(delta.action, &before, &after);
+        assert!(matches!(
+            StateTransition::from_delta(delta, &before, &after),
+            Err(ProofError::WitnessError(_))
+        ));
+    }
+
+    #[test]
+    #[ignore = "Groth16 setup over ~1M constraints; run with --release"]
+    fn move_proof_round_trip() {
+        let (delta, before, after) = step();
+        let transition = StateTransition::from_delta(delta, &before, &after).unwrap();
+        let keys = CircuitKeys::setup(&transition.shape).unwrap();
+        let proof = transition.prove(&keys.proving_key).unwrap();
+        assert!(proof.verify(&keys).unwrap());
+
+        let mut forged = proof;
+        forged.statement.cost += 1;
+        assert!(!forged.verify(&keys).unwrap());
+    }
+}
@@ -0,0 +1,401 @@
+//! R1CS constraints of a transition.
+//!
+//! Both Merkle roots are recomputed from the [`TransitionShape`]: shared leaf
+//! hashes are allocated once and used in both trees, opened leaves are hashed
+//! from their bytes. The rule constraints then relate the opened before and
+//! after leaves byte by byte, using the offsets from [`super::witness`].
+
+use ark_bn254::Fr;
+use ark_ff::ToConstraintField;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::*};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use game_core::{ActionKind, CardinalDirection, EntityId};
+
+use super::merkle::{self, Bytes};
+use super::witness::*;
+
+/// Circuit for one [`StateTransition`].
+///
+/// Public inputs, in order: `prev_root` and `new_root` (two field elements
+/// each, see [`TransitionStatement::public_inputs`]), then nonce, actor, two
+/// rule arguments, cost and damage.
+#[derive(Clone)]
+pub(crate) struct TransitionCircuit {
+    pub statement: TransitionStatement,
+    pub shape: TransitionShape,
+    pub witness: TransitionWitness,
+}
+
+impl TransitionCircuit {
+    pub(crate) fn new(transition: &StateTransition) -> Self {
+        Self {
+            statement: transition.statement.clone(),
+            shape: transition.shape.clone(),
+            witness: transition.witness.clone(),
+        }
+    }
+
+    /// Circuit with placeholder values, for key generation.
+    pub(crate) fn blank(shape: &TransitionShape) -> Self {
+        let rule = match shape.kind {
+            ActionKind::MeleeAttack => TransitionRule::MeleeAttack {
+                actor: EntityId(0),
+                target: EntityId(0),
+            },
+            _ => TransitionRule::Move {
+                actor: EntityId(0),
+                direction: CardinalDirection::North,
+            },
+        };
+        Self {
+            statement: TransitionStatement {
+                rule,
+                prev_root: [0; 32],
+                new_root: [0; 32],
+                nonce: 0,
+                cost: 0,
+                damage: 0,
+            },
+            shape: shape.clone(),
+            witness: TransitionWitness::blank(shape),
+        }
+    }
+}
+
+impl TransitionStatement {
+    /// Field elements the verifier checks the proof against.
+    pub fn public_inputs(&self) -> Vec<Fr> {
+        let mut inputs = Vec::new();
+        for root in [&self.prev_root, &self.new_root] {
+            inputs.extend(
+                ToConstraintField::<Fr>::to_field_elements(&root[..])
+                    .expect("bytes always pack into field elements"),
+            );
+        }
+        inputs.extend(self.scalars());
+        inputs
+    }
+
+    /// Nonce, actor, rule arguments, cost and damage.
+    ///
+    /// `Move` passes `dx + 1` and `dy + 1`; `MeleeAttack` passes the target.
+    fn scalars(&self) -> [Fr; 6] {
+        let (arg0, arg1) = match self.rule {
+            TransitionRule::Move { direction, .. } => {
+                let (dx, dy) = direction.offset();
+                ((dx + 1) as u64, (dy + 1) as u64)
+            }
+            TransitionRule::MeleeAttack { target, .. } => (target.0 as u64, 0),
+        };
+        [
+            self.nonce,
+            self.rule.actor().0 as u64,
+            arg0,
+            arg1,
+            self.cost,
+            self.damage as u64,
+        ]
+        .map(Fr::from)
+    }
+}
+
+/// Public scalar inputs as circuit variables.
+struct Inputs {
+    nonce: FpVar<Fr>,
+    actor: FpVar<Fr>,
+    arg0: FpVar<Fr>,
+    arg1: FpVar<Fr>,
+    cost: FpVar<Fr>,
+    damage: FpVar<Fr>,
+}
+
+/// Leaves of one tree: every leaf hash in tree order, plus opened bytes.
+struct Tree {
+    hashes: Vec<Bytes>,
+    open: Vec<(LeafRole, Bytes)>,
+}
+
+impl Tree {
+    fn alloc(
+        cs: &ConstraintSystemRef<Fr>,
+        slots: &[Slot],
+        encodings: &[Vec<u8>],
+        shared: &[Bytes],
+    ) -> Result<Self, SynthesisError> {
+        let mut encodings = encodings.iter();
+        let mut shared = shared.iter();
+        let mut tree = Tree {
+            hashes: Vec::with_capacity(slots.len()),
+            open: Vec::new(),
+        };
+        for slot in slots {
+            match slot {
+                Slot::Shared => {
+                    let hash = shared.next().ok_or(SynthesisError::Unsatisfiable)?;
+                    tree.hashes.push(hash.clone());
+                }
+                Slot::Open { role, len } => {
+                    let bytes = encodings
+                        .next()
+                        .filter(|bytes| bytes.len() == *len as usize)
+                        .ok_or(SynthesisError::Unsatisfiable)?;
+                    let bytes = UInt8::new_witness_vec(cs.clone(), bytes)?;
+                    tree.hashes.push(merkle::hash_leaf(&bytes)?);
+                    tree.open.push((*role, bytes));
+                }
+            }
+        }
+        if shared.next().is_some() || encodings.next().is_some() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        Ok(tree)
+    }
+
+    fn get(&self, role: LeafRole) -> Option<&[UInt8<Fr>]> {
+        self.open
+            .iter()
+            .find(|(open, _)| *open == role)
+            .map(|(_, bytes)| &bytes[..])
+    }
+
+    fn leaf(&self, role: LeafRole) -> Result<&[UInt8<Fr>], SynthesisError> {
+        self.get(role).ok_or(SynthesisError::Unsatisfiable)
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for TransitionCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let prev_root = UInt8::new_input_vec(cs.clone(), &self.statement.prev_root)?;
+        let new_root = UInt8::new_input_vec(cs.clone(), &self.statement.new_root)?;
+        let [nonce, actor, arg0, arg1, cost, damage] = self.statement.scalars();
+        let input = |value: Fr| FpVar::new_input(cs.clone(), || Ok(value));
+        let inputs = Inputs {
+            nonce: input(nonce)?,
+            actor: input(actor)?,
+            arg0: input(arg0)?,
+            arg1: input(arg1)?,
+            cost: input(cost)?,
+            damage: input(damage)?,
+        };
+
+        let shared = self
+            .witness
+            .shared
+            .iter()
+            .map(|hash| UInt8::new_witness_vec(cs.clone(), hash))
+            .collect::<Result<Vec<_>, _>>()?;
+        let before = Tree::alloc(&cs, &self.shape.before, &self.witness.before, &shared)?;
+        let after = Tree::alloc(&cs, &self.shape.after, &self.witness.after, &shared)?;
+        merkle::root(before.hashes.clone())?.enforce_equal(&prev_root)?;
+        merkle::root(after.hashes.clone())?.enforce_equal(&new_root)?;
+
+        enforce_turn(
+            before.leaf(LeafRole::Turn)?,
+            after.leaf(LeafRole::Turn)?,
+            &inputs,
+        )?;
+        match self.shape.kind {
+            ActionKind::Move => enforce_move(&before, &after, &inputs, self.shape.from_index),
+            ActionKind::MeleeAttack => enforce_melee(&before, &after, &inputs),
+            _ => Err(SynthesisError::Unsatisfiable),
+        }
+    }
+}
+
+/// The nonce advances by one; the rest of the turn leaf is unchanged and
+/// names the actor as the current actor.
+fn enforce_turn(
+    before: &[UInt8<Fr>],
+    after: &[UInt8<Fr>],
+    inputs: &Inputs,
+) -> Result<(), SynthesisError> {
+    same_len(before, after, TURN_NONCE.end + TURN_CURRENT_ACTOR_LEN)?;
+    enforce_bytes(&before[..4], &TURN_TAG.to_le_bytes())?;
+    before[..TURN_NONCE.start].enforce_equal(&after[..TURN_NONCE.start])?;
+    before[TURN_NONCE.end..].enforce_equal(&after[TURN_NONCE.end..])?;
+    fp(&before[TURN_NONCE])?.enforce_equal(&inputs.nonce)?;
+    fp(&after[TURN_NONCE])?.enforce_equal(&(&inputs.nonce + FpVar::one()))?;
+    fp(&before[before.len() - TURN_CURRENT_ACTOR_LEN..])?.enforce_equal(&inputs.actor)
+}
+
+/// The actor steps by (`arg0 - 1`, `arg1 - 1`) and leaves one tile for the
+/// other; only position and `ready_at` of the actor change.
+fn enforce_move(
+    before: &Tree,
+    after: &Tree,
+    inputs: &Inputs,
+    from_index: u32,
+) -> Result<(), SynthesisError> {
+    inputs.damage.enforce_equal(&FpVar::zero())?;
+
+    let actor_before = before.leaf(LeafRole::Actor)?;
+    let actor_after = after.leaf(LeafRole::Actor)?;
+    same_len(actor_before, actor_after, ACTOR_LEN)?;
+    enforce_actor(actor_before, &inputs.actor)?;
+    actor_before[..ACTOR_X.start].enforce_equal(&actor_after[..ACTOR_X.start])?;
+    enforce_step(&actor_before[ACTOR_X], &actor_after[ACTOR_X], &inputs.arg0)?;
+    enforce_step(&actor_before[ACTOR_Y], &actor_after[ACTOR_Y], &inputs.arg1)?;
+    enforce_ready_at(actor_before, actor_after, ACTOR_Y.end, &inputs.cost)?;
+    let id = &actor_before[ACTOR_ID];
+
+    // Origin tile: the actor is removed from its occupants.
+    let from_before = before.leaf(LeafRole::FromTile)?;
+    enforce_tile(from_before, actor_before)?;
+    let count = occupant_count(from_before)?;
+    let from_index = from_index as usize;
+    if from_index >= count {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+    occupant(from_before, from_index).enforce_equal(id)?;
+    match after.get(LeafRole::FromTile) {
+        Some(from_after) => {
+            if occupant_count(from_after)? + 1 != count {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+            from_before[..TILE_COUNT.start].enforce_equal(&from_after[..TILE_COUNT.start])?;
+            enforce_bytes(&from_after[TILE_COUNT], &(count as u64 - 1).to_le_bytes())?;
+            for index in 0..count - 1 {
+                let source = if index < from_index { index } else { index + 1 };
+                occupant(from_after, index).enforce_equal(occupant(from_before, source))?;
+            }
+        }
+        None if count == 1 => {}
+        None => return Err(SynthesisError::Unsatisfiable),
+    }
+
+    // Destination tile: the actor is appended to its occupants.
+    let to_after = after.leaf(LeafRole::ToTile)?;
+    enforce_tile(to_after, actor_after)?;
+    let count = occupant_count(to_after)?;
+    occupant(to_after, count - 1).enforce_equal(id)?;
+    match before.get(LeafRole::ToTile) {
+        Some(to_before) => {
+            if occupant_count(to_before)? + 1 != count {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+            to_before[..TILE_COUNT.start].enforce_equal(&to_after[..TILE_COUNT.start])?;
+            enforce_bytes(&to_before[TILE_COUNT], &(count as u64 - 1).to_le_bytes())?;
+            to_before[TILE_OCCUPANTS..]
+                .enforce_equal(&to_after[TILE_OCCUPANTS..to_after.len() - 4])?;
+        }
+        None if count == 1 => {}
+        None => return Err(SynthesisError::Unsatisfiable),
+    }
+    Ok(())
+}
+
+/// The target stands next to the attacker and loses `damage` HP; only the
+/// attacker's `ready_at` and the target's HP change.
+fn enforce_melee(before: &Tree, after: &Tree, inputs: &Inputs) -> Result<(), SynthesisError> {
+    inputs.arg1.enforce_equal(&FpVar::zero())?;
+
+    let actor_before = before.leaf(LeafRole::Actor)?;
+    let actor_after = after.leaf(LeafRole::Actor)?;
+    same_len(actor_before, actor_after, ACTOR_LEN)?;
+    enforce_actor(actor_before, &inputs.actor)?;
+    enforce_ready_at(actor_before, actor_after, 0, &inputs.cost)?;
+
+    let target_before = before.leaf(LeafRole::Target)?;
+    let target_after = after.leaf(LeafRole::Target)?;
+    same_len(target_before, target_after, ACTOR_LEN)?;
+    enforce_actor(target_before, &inputs.arg0)?;
+    target_before[..ACTOR_HP.start].enforce_equal(&target_after[..ACTOR_HP.start])?;
+    target_before[ACTOR_HP.end..].enforce_equal(&target_after[ACTOR_HP.end..])?;
+    fp(&target_before[ACTOR_HP])?
+        .enforce_equal(&(fp(&target_after[ACTOR_HP])? + &inputs.damage))?;
+
+    enforce_adjacent(&actor_before[ACTOR_X], &target_before[ACTOR_X])?;
+    enforce_adjacent(&actor_before[ACTOR_Y], &target_before[ACTOR_Y])
+}
+
+/// Shortest possible actor leaf: up to HP, then `ready_at`.
+const ACTOR_LEN: usize = ACTOR_HP.end + READY_AT_LEN;
+
+/// Actor leaf of `id` with a position.
+fn enforce_actor(leaf: &[UInt8<Fr>], id: &FpVar<Fr>) -> Result<(), SynthesisError> {
+    enforce_bytes(&leaf[..4], &ACTOR_TAG.to_le_bytes())?;
+    fp(&leaf[ACTOR_ID])?.enforce_equal(id)?;
+    enforce_bytes(&leaf[ACTOR_POSITION_TAG..ACTOR_X.start], &[1])
+}
+
+/// Bytes from `from` up to `ready_at` are unchanged and `ready_at` grows by
+/// `cost`.
+fn enforce_ready_at(
+    before: &[UInt8<Fr>],
+    after: &[UInt8<Fr>],
+    from: usize,
+    cost: &FpVar<Fr>,
+) -> Result<(), SynthesisError> {
+    let value = before.len() - (READY_AT_LEN - 1);
+    enforce_bytes(&before[value - 1..value], &[1])?;
+    before[from..value].enforce_equal(&after[from..value])?;
+    fp(&after[value..])?.enforce_equal(&(fp(&before[value..])? + cost))
+}
+
+/// Occupancy leaf of the tile the actor leaf stands on.
+fn enforce_tile(tile: &[UInt8<Fr>], actor: &[UInt8<Fr>]) -> Result<(), SynthesisError> {
+    enforce_bytes(&tile[..4], &OCCUPANCY_TAG.to_le_bytes())?;
+    tile[TILE_X].enforce_equal(&actor[ACTOR_X])?;
+    tile[TILE_Y].enforce_equal(&actor[ACTOR_Y])?;
+    let count = occupant_count(tile)?;
+    enforce_bytes(&tile[TILE_COUNT], &(count as u64).to_le_bytes())
+}
+
+/// `after ≡ before + step - 1 (mod 2^32)` for a 4-byte coordinate.
+fn enforce_step(
+    before: &[UInt8<Fr>],
+    after: &[UInt8<Fr>],
+    step: &FpVar<Fr>,
+) -> Result<(), SynthesisError> {
+    let sum = fp(before)? + step + FpVar::constant(Fr::from(u32::MAX as u64));
+    let (bits, _) = sum.to_bits_le_with_top_bits_zero(34)?;
+    bits_le(after)?[..].enforce_equal(&bits[..32])
+}
+
+/// Two 4-byte coordinates differ by at most one (mod 2^32).
+fn enforce_adjacent(a: &[UInt8<Fr>], b: &[UInt8<Fr>]) -> Result<(), SynthesisError> {
+    // (b - a + 1) mod 2^32 must be 0, 1 or 2: no bits above the low two,
+    // which must not both be set.
+    let shifted = fp(b)? - fp(a)? + FpVar::constant(Fr::from((1u64 << 32) + 1));
+    let (bits, _) = shifted.to_bits_le_with_top_bits_zero(34)?;
+    bits[2..32].enforce_equal(&vec![Boolean::FALSE; 30])?;
+    (&bits[0] & &bits[1]).enforce_equal(&Boolean::FALSE)
+}
+
+fn occupant_count(tile: &[UInt8<Fr>]) -> Result<usize, SynthesisError> {
+    match tile.len().checked_sub(TILE_OCCUPANTS) {
+        Some(len) if len > 0 && len % 4 == 0 => Ok(len / 4),
+        _ => Err(SynthesisError::Unsatisfiable),
+    }
+}
+
+fn occupant(tile: &[UInt8<Fr>], index: usize) -> &[UInt8<Fr>] {
+    let start = TILE_OCCUPANTS + 4 * index;
+    &tile[start..start + 4]
+}
+
+/// Equal lengths of at least `min` bytes.
+fn same_len(before: &[UInt8<Fr>], after: &[UInt8<Fr>], min: usize) -> Result<(), SynthesisError> {
+    if before.len() == after.len() && before.len() >= min {
+        Ok(())
+    } else {
+        Err(SynthesisError::Unsatisfiable)
+    }
+}
+
+fn enforce_bytes(bytes: &[UInt8<Fr>], value: &[u8]) -> Result<(), SynthesisError> {
+    bytes.enforce_equal(&UInt8::constant_vec(value))
+}
+
+fn bits_le(bytes: &[UInt8<Fr>]) -> Result<Vec<Boolean<Fr>>, SynthesisError> {
+    Ok(bytes
+        .iter()
+        .map(|byte| byte.to_bits_le())
+        .collect::<Result<Vec<_>, _>>()?
+        .concat())
+}
+
+/// Little-endian bytes as a field element.
+fn fp(bytes: &[UInt8<Fr>]) -> Result<FpVar<Fr>, SynthesisError> {
+    Boolean::le_bits_to_fp(&bits_le(bytes)?)
+}
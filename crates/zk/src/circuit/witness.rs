@@ -0,0 +1,515 @@
+//! Witness generation: from a [`StateDelta`] to a [`StateTransition`].
+//!
+//! The delta says which leaves of the Merkle state tree an action touched.
+//! Those leaves are opened (passed to the circuit as bytes); every other leaf
+//! is passed as its hash and shared between the before and after trees, which
+//! is what proves it unchanged.
+
+use std::ops::Range;
+
+use game_core::{
+    Action, ActionInput, ActionKind, ActorFields, CardinalDirection, EntityId, GameState, Position,
+    StateDelta, StateLeaf, TurnFields,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::ProofError;
+
+// ============================================================================
+// Leaf Layout
+// ============================================================================
+//
+// The circuit reads fields straight out of the bincode encoding of a leaf:
+// little-endian fixed-width integers, u32 enum tags, u8 option tags and u64
+// sequence lengths. `check_layout` verifies these offsets against the decoded
+// values for every opened leaf.
+
+/// Enum tag of [`StateLeaf::Turn`].
+pub(crate) const TURN_TAG: u32 = 0;
+/// Enum tag of [`StateLeaf::Actor`].
+pub(crate) const ACTOR_TAG: u32 = 1;
+/// Enum tag of [`StateLeaf::Occupancy`].
+pub(crate) const OCCUPANCY_TAG: u32 = 4;
+
+/// Turn leaf: tag, `game_seed`, `next_entity_id`, then the nonce.
+pub(crate) const TURN_NONCE: Range<usize> = 16..24;
+/// Turn leaf: `current_actor` is the last field.
+pub(crate) const TURN_CURRENT_ACTOR_LEN: usize = 4;
+
+/// Actor leaf: tag, then the actor ID.
+pub(crate) const ACTOR_ID: Range<usize> = 4..8;
+/// Actor leaf: `Some` tag of the position.
+pub(crate) const ACTOR_POSITION_TAG: usize = 8;
+/// Actor leaf: position x.
+pub(crate) const ACTOR_X: Range<usize> = 9..13;
+/// Actor leaf: position y.
+pub(crate) const ACTOR_Y: Range<usize> = 13..17;
+/// Actor leaf: current HP, after seven core stats.
+pub(crate) const ACTOR_HP: Range<usize> = 45..49;
+/// Actor leaf: `ready_at` is the last field, a `Some` tag and a u64.
+pub(crate) const READY_AT_LEN: usize = 9;
+
+/// Occupancy leaf: tile x.
+pub(crate) const TILE_X: Range<usize> = 4..8;
+/// Occupancy leaf: tile y.
+pub(crate) const TILE_Y: Range<usize> = 8..12;
+/// Occupancy leaf: number of occupants.
+pub(crate) const TILE_COUNT: Range<usize> = 12..20;
+/// Occupancy leaf: start of the occupant IDs (4 bytes each).
+pub(crate) const TILE_OCCUPANTS: usize = 20;
+
+// ============================================================================
+// Statement
+// ============================================================================
+
+/// Action rule a transition circuit enforces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransitionRule {
+    /// Step one tile in `direction`.
+    Move {
+        actor: EntityId,
+        direction: CardinalDirection,
+    },
+    /// Damage an adjacent actor.
+    MeleeAttack { actor: EntityId, target: EntityId },
+}
+
+impl TransitionRule {
+    /// The rule for an action, or an error if no circuit covers it.
+    pub fn from_action(action: &Action) -> Result<Self, ProofError> {
+        let Action::Character(character) = action else {
+            return Err(ProofError::CircuitProofError(format!(
+                "No circuit for system action {:?}",
+                action
+            )));
+        };
+        match (character.kind, &character.input) {
+            (ActionKind::Move, ActionInput::Direction(direction)) => Ok(TransitionRule::Move {
+                actor: character.actor,
+                direction: *direction,
+            }),
+            (ActionKind::MeleeAttack, ActionInput::Target(target)) => {
+                Ok(TransitionRule::MeleeAttack {
+                    actor: character.actor,
+                    target: *target,
+                })
+            }
+            (kind, input) => Err(ProofError::CircuitProofError(format!(
+                "No circuit for {:?} with input {:?}",
+                kind, input
+            ))),
+        }
+    }
+
+    pub fn kind(&self) -> ActionKind {
+        match self {
+            TransitionRule::Move { .. } => ActionKind::Move,
+            TransitionRule::MeleeAttack { .. } => ActionKind::MeleeAttack,
+        }
+    }
+
+    pub fn actor(&self) -> EntityId {
+        match self {
+            TransitionRule::Move { actor, .. } | TransitionRule::MeleeAttack { actor, .. } => {
+                *actor
+            }
+        }
+    }
+}
+
+/// Public statement of a transition proof.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionStatement {
+    pub rule: TransitionRule,
+    /// Merkle state root before the action.
+    pub prev_root: [u8; 32],
+    /// Merkle state root after the action.
+    pub new_root: [u8; 32],
+    /// Nonce before the action.
+    pub nonce: u64,
+    /// Ticks added to the actor's `ready_at`.
+    pub cost: u64,
+    /// HP taken from the target (always 0 for `Move`).
+    pub damage: u32,
+}
+
+// ============================================================================
+// Shape
+// ============================================================================
+
+/// Role of an opened leaf.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LeafRole {
+    Turn,
+    /// The acting actor.
+    Actor,
+    /// The attacked actor (`MeleeAttack`).
+    Target,
+    /// Occupancy of the tile the actor leaves (`Move`).
+    FromTile,
+    /// Occupancy of the tile the actor enters (`Move`).
+    ToTile,
+}
+
+/// One leaf position of the before or after tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Slot {
+    /// Unchanged leaf, passed as its hash and shared by both trees.
+    Shared,
+    /// Leaf opened in the circuit; its encoding is `len` bytes long.
+    Open { role: LeafRole, len: u32 },
+}
+
+/// Everything that determines the constraint system of a transition.
+///
+/// Transitions with the same shape share proving and verifying keys.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TransitionShape {
+    pub kind: ActionKind,
+    /// Leaves of the before tree, in tree order.
+    pub before: Vec<Slot>,
+    /// Leaves of the after tree, in tree order.
+    pub after: Vec<Slot>,
+    /// Index of the actor in the occupants of the tile it leaves (`Move`).
+    pub from_index: u32,
+}
+
+impl TransitionShape {
+    /// Number of [`Slot::Shared`] leaves (the same in both trees).
+    pub fn shared_count(&self) -> usize {
+        self.before
+            .iter()
+            .filter(|slot| matches!(slot, Slot::Shared))
+            .count()
+    }
+}
+
+/// Private inputs of a transition circuit.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TransitionWitness {
+    /// Hashes of the shared leaves, in tree order.
+    pub shared: Vec<[u8; 32]>,
+    /// Encodings of the opened before leaves, in tree order.
+    pub before: Vec<Vec<u8>>,
+    /// Encodings of the opened after leaves, in tree order.
+    pub after: Vec<Vec<u8>>,
+}
+
+impl TransitionWitness {
+    /// All-zero witness of the right size, for key generation.
+    pub(crate) fn blank(shape: &TransitionShape) -> Self {
+        let open = |slots: &[Slot]| {
+            slots
+                .iter()
+                .filter_map(|slot| match slot {
+                    Slot::Shared => None,
+                    Slot::Open { len, .. } => Some(vec![0; *len as usize]),
+                })
+                .collect()
+        };
+        Self {
+            shared: vec![[0; 32]; shape.shared_count()],
+            before: open(&shape.before),
+            after: open(&shape.after),
+        }
+    }
+}
+
+// ============================================================================
+// StateTransition
+// ============================================================================
+
+/// A single action as a circuit instance: statement, shape and witness.
+#[derive(Clone, Debug)]
+pub struct StateTransition {
+    pub statement: TransitionStatement,
+    pub shape: TransitionShape,
+    pub(crate) witness: TransitionWitness,
+}
+
+impl StateTransition {
+    /// Build the circuit instance for the action recorded in `delta`.
+    ///
+    /// `before_state` and `after_state` are the states around the action; the
+    /// delta must be the one [`game_core::GameEngine`] produced for them.
+    /// Fails with [`ProofError::CircuitProofError`] for actions without a
+    /// circuit and [`ProofError::WitnessError`] when the delta touches state
+    /// the circuit does not cover (e.g. a move that triggered a trap).
+    pub fn from_delta(
+        delta: StateDelta,
+        before_state: &GameState,
+        after_state: &GameState,
+    ) -> Result<Self, ProofError> {
+        let rule = TransitionRule::from_action(&delta.action)?;
+        check_delta(&rule, &delta)?;
+
+        let actor = rule.actor();
+        let (actor_before, actor_after) = actor_pair(before_state, after_state, actor)?;
+        let from = actor_before
+            .position
+            .ok_or_else(|| witness_error(format!("Actor {} is not on the map", actor)))?;
+        let to = actor_after
+            .position
+            .ok_or_else(|| witness_error(format!("Actor {} left the map", actor)))?;
+
+        let mut statement = TransitionStatement {
+            rule,
+            prev_root: before_state.compute_merkle_root(),
+            new_root: after_state.compute_merkle_root(),
+            nonce: before_state.turn.nonce,
+            cost: ready_delta(actor_before.ready_at, actor_after.ready_at)?,
+            damage: 0,
+        };
+        let mut from_index = 0;
+
+        match rule {
+            TransitionRule::Move { direction, .. } => {
+                let (dx, dy) = direction.offset();
+                if Position::new(from.x.wrapping_add(dx), from.y.wrapping_add(dy)) != to {
+                    return Err(witness_error(format!(
+                        "Actor {} moved from {:?} to {:?}, not {:?}",
+                        actor, from, to, direction
+                    )));
+                }
+                from_index = before_state
+                    .world
+                    .tile_map
+                    .occupants(&from)
+                    .and_then(|ids| ids.iter().position(|id| *id == actor))
+                    .ok_or_else(|| {
+                        witness_error(format!("Actor {} not among occupants of {:?}", actor, from))
+                    })? as u32;
+            }
+            TransitionRule::MeleeAttack { target, .. } => {
+                let (target_before, target_after) = actor_pair(before_state, after_state, target)?;
+                statement.damage = target_before
+                    .resources
+                    .hp
+                    .checked_sub(target_after.resources.hp)
+                    .ok_or_else(|| witness_error(format!("Target {} gained HP", target)))?;
+            }
+        }
+
+        let role = |leaf: &StateLeaf| -> Option<LeafRole> {
+            match (leaf, rule) {
+                (StateLeaf::Turn { .. }, _) => Some(LeafRole::Turn),
+                (StateLeaf::Actor(state), _) if state.id == actor => Some(LeafRole::Actor),
+                (StateLeaf::Actor(state), TransitionRule::MeleeAttack { target, .. })
+                    if state.id == target =>
+                {
+                    Some(LeafRole::Target)
+                }
+                (StateLeaf::Occupancy { position, .. }, TransitionRule::Move { .. })
+                    if *position == from =>
+                {
+                    Some(LeafRole::FromTile)
+                }
+                (StateLeaf::Occupancy { position, .. }, TransitionRule::Move { .. })
+                    if *position == to =>
+                {
+                    Some(LeafRole::ToTile)
+                }
+                _ => None,
+            }
+        };
+
+        let mut witness = TransitionWitness::default();
+        let mut shared_after = Vec::new();
+        let open = |leaves: Vec<StateLeaf>,
+                    encoded: &mut Vec<Vec<u8>>,
+                    shared: &mut Vec<[u8; 32]>|
+         -> Result<Vec<Slot>, ProofError> {
+            leaves
+                .iter()
+                .map(|leaf| match role(leaf) {
+                    Some(role) => {
+                        let bytes = leaf.encode();
+                        check_layout(leaf, &bytes)?;
+                        let len = bytes.len() as u32;
+                        encoded.push(bytes);
+                        Ok(Slot::Open { role, len })
+                    }
+                    None => {
+                        shared.push(leaf.hash());
+                        Ok(Slot::Shared)
+                    }
+                })
+                .collect()
+        };
+        let before = open(
+            before_state.merkle_leaves(),
+            &mut witness.before,
+            &mut witness.shared,
+        )?;
+        let after = open(
+            after_state.merkle_leaves(),
+            &mut witness.after,
+            &mut shared_after,
+        )?;
+
+        if witness.shared != shared_after {
+            return Err(witness_error(
+                "Action changed leaves outside the circuit's scope".to_string(),
+            ));
+        }
+
+        let shape = TransitionShape {
+            kind: rule.kind(),
+            before,
+            after,
+            from_index,
+        };
+        let roles = |slots: &[Slot]| -> Vec<LeafRole> {
+            slots
+                .iter()
+                .filter_map(|slot| match slot {
+                    Slot::Open { role, .. } => Some(*role),
+                    Slot::Shared => None,
+                })
+                .collect()
+        };
+        let (before_roles, after_roles) = (roles(&shape.before), roles(&shape.after));
+        let required: &[LeafRole] = match rule {
+            TransitionRule::Move { .. } => &[LeafRole::Turn, LeafRole::Actor],
+            TransitionRule::MeleeAttack { .. } => {
+                &[LeafRole::Turn, LeafRole::Actor, LeafRole::Target]
+            }
+        };
+        for role in required {
+            if !before_roles.contains(role) || !after_roles.contains(role) {
+                return Err(witness_error(format!("Missing {:?} leaf", role)));
+            }
+        }
+        if matches!(rule, TransitionRule::Move { .. })
+            && !(before_roles.contains(&LeafRole::FromTile)
+                && after_roles.contains(&LeafRole::ToTile))
+        {
+            return Err(witness_error("Missing occupancy leaves".to_string()));
+        }
+
+        Ok(Self {
+            statement,
+            shape,
+            witness,
+        })
+    }
+}
+
+/// Check the delta only touches what the rule's circuit covers.
+fn check_delta(rule: &TransitionRule, delta: &StateDelta) -> Result<(), ProofError> {
+    let entities = &delta.entities;
+    if delta.turn.fields != TurnFields::NONCE
+        || !delta.turn.activated.is_empty()
+        || !delta.turn.deactivated.is_empty()
+        || !entities.actors.added.is_empty()
+        || !entities.actors.removed.is_empty()
+        || !entities.props.is_empty()
+        || !entities.items.is_empty()
+        || delta.world.flags
+        || delta.world.conversation
+    {
+        return Err(witness_error(format!(
+            "Delta of {:?} changes more than the actor's turn",
+            rule.kind()
+        )));
+    }
+
+    let mut expected = vec![(rule.actor(), ActorFields::READY_AT)];
+    let occupancy_changes = match rule {
+        TransitionRule::Move { actor, .. } => {
+            expected[0] = (*actor, ActorFields::POSITION | ActorFields::READY_AT);
+            2
+        }
+        TransitionRule::MeleeAttack { target, .. } => {
+            expected.push((*target, ActorFields::RESOURCES));
+            0
+        }
+    };
+    let allowed = |id: EntityId, fields: ActorFields| {
+        expected
+            .iter()
+            .any(|(expected_id, allowed)| *expected_id == id && allowed.contains(fields))
+    };
+    if delta.world.occupancy.len() != occupancy_changes
+        || !entities
+            .actors
+            .updated
+            .iter()
+            .all(|changes| allowed(changes.id, changes.fields))
+    {
+        return Err(witness_error(format!(
+            "Delta of {:?} changes actors or tiles the circuit does not cover",
+            rule.kind()
+        )));
+    }
+    Ok(())
+}
+
+/// Check the circuit's byte offsets against a decoded leaf.
+fn check_layout(leaf: &StateLeaf, bytes: &[u8]) -> Result<(), ProofError> {
+    let u32_at = |range: Range<usize>| u32::from_le_bytes(bytes[range].try_into().unwrap());
+    let u64_at = |range: Range<usize>| u64::from_le_bytes(bytes[range].try_into().unwrap());
+
+    let matches = match leaf {
+        StateLeaf::Turn { turn, .. } => {
+            let actor = bytes.len() - TURN_CURRENT_ACTOR_LEN..bytes.len();
+            u32_at(0..4) == TURN_TAG
+                && u64_at(TURN_NONCE) == turn.nonce
+                && u32_at(actor) == turn.current_actor.0
+        }
+        StateLeaf::Actor(actor) => {
+            let (x, y) = actor.position.map_or((0, 0), |p| (p.x, p.y));
+            bytes.len() >= ACTOR_HP.end + READY_AT_LEN
+                && u32_at(0..4) == ACTOR_TAG
+                && u32_at(ACTOR_ID) == actor.id.0
+                && bytes[ACTOR_POSITION_TAG] == actor.position.is_some() as u8
+                && u32_at(ACTOR_X) == x as u32
+                && u32_at(ACTOR_Y) == y as u32
+                && u32_at(ACTOR_HP) == actor.resources.hp
+                && bytes[bytes.len() - READY_AT_LEN] == 1
+                && Some(u64_at(bytes.len() - 8..bytes.len())) == actor.ready_at
+        }
+        StateLeaf::Occupancy {
+            position,
+            occupants,
+        } => {
+            u32_at(0..4) == OCCUPANCY_TAG
+                && u32_at(TILE_X) == position.x as u32
+                && u32_at(TILE_Y) == position.y as u32
+                && u64_at(TILE_COUNT) == occupants.len() as u64
+                && bytes.len() == TILE_OCCUPANTS + 4 * occupants.len()
+        }
+        _ => false,
+    };
+
+    if matches {
+        Ok(())
+    } else {
+        Err(witness_error(format!(
+            "Leaf {:?} does not match the circuit's layout",
+            leaf.key()
+        )))
+    }
+}
+
+fn actor_pair<'a>(
+    before: &'a GameState,
+    after: &'a GameState,
+    id: EntityId,
+) -> Result<(&'a game_core::ActorState, &'a game_core::ActorState), ProofError> {
+    match (before.entities.actor(id), after.entities.actor(id)) {
+        (Some(before), Some(after)) => Ok((before, after)),
+        _ => Err(witness_error(format!("Actor {} not found", id))),
+    }
+}
+
+fn ready_delta(before: Option<u64>, after: Option<u64>) -> Result<u64, ProofError> {
+    before
+        .zip(after)
+        .and_then(|(before, after)| after.checked_sub(before))
+        .ok_or_else(|| witness_error("Actor is not scheduled".to_string()))
+}
+
+fn witness_error(message: String) -> ProofError {
+    ProofError::WitnessError(message)
+}
@@ -4,7 +4,9 @@
 //! - **RISC0** (default): Production zkVM backend with Groth16 support
 //! - **SP1**: Alternative production zkVM with platform-independent Groth16/PLONK
 //! - **Stub**: Dummy prover for testing
-//! - **Arkworks** (future): Custom circuit proving
+//! - **Native**: Execute-only prover producing real journals without proofs
+//! - **Arkworks**: Groth16 circuits for the state updates of `Move` and
+//!   `MeleeAttack`; they do not prove the game rules
 //!
 //! This crate is for host-side proof generation only. Guest programs should
 //! depend on `game-core` directly.
@@ -15,28 +17,37 @@
 //! - `risc0` (default): RISC0 zkVM backend
 //! - `sp1`: SP1 zkVM backend
 //! - `stub`: Stub prover for testing
-//! - `arkworks`: Arkworks circuit proving (future)
+//! - `native`: Execute-only prover for end-to-end tests without a zkVM
+//! - `arkworks`: Arkworks Groth16 circuits (rules unproven, development keys only)
+//!
+//! Independent of the backend, `remote` adds [`RemoteProver`], which offloads
+//! proving to a `prover-server` daemon.
 
 // Feature conflict checks
 #[cfg(any(
     all(feature = "risc0", feature = "sp1"),
     all(feature = "risc0", feature = "stub"),
+    all(feature = "risc0", feature = "native"),
     all(feature = "risc0", feature = "arkworks"),
     all(feature = "sp1", feature = "stub"),
+    all(feature = "sp1", feature = "native"),
     all(feature = "sp1", feature = "arkworks"),
-    all(feature = "stub", feature = "arkworks")
+    all(feature = "stub", feature = "native"),
+    all(feature = "stub", feature = "arkworks"),
+    all(feature = "native", feature = "arkworks")
 ))]
-compile_error!("Enable exactly one backend: risc0, sp1, stub, or arkworks");
+compile_error!("Enable exactly one backend: risc0, sp1, stub, native, or arkworks");
 
 #[cfg(not(any(
     feature = "risc0",
     feature = "sp1",
     feature = "stub",
+    feature = "native",
     feature = "arkworks"
 )))]
-compile_error!("Enable exactly one backend: risc0, sp1, stub, or arkworks");
+compile_error!("Enable exactly one backend: risc0, sp1, stub, native, or arkworks");
 
-// Include generated methods from build.rs (RISC0 ELF and ImageID)
+// Include generated methods from build.rs (RISC0 ELFs and ImageIDs)
 // The build script generates this file with the guest program binary
 #[cfg(feature = "risc0")]
 mod generated {
@@ -45,16 +56,16 @@ mod generated {
 }
 
 #[cfg(feature = "risc0")]
-pub use generated::{STATE_TRANSITION_ELF, STATE_TRANSITION_ID};
+pub use generated::{AGGREGATION_ELF, AGGREGATION_ID, STATE_TRANSITION_ELF, STATE_TRANSITION_ID};
 
-// Include generated methods from build.rs (SP1 ELF and verification key)
+// Include generated methods from build.rs (SP1 ELFs)
 #[cfg(feature = "sp1")]
 mod sp1_generated {
     include!(concat!(env!("OUT_DIR"), "/methods.rs"));
 }
 
 #[cfg(feature = "sp1")]
-pub use sp1_generated::STATE_TRANSITION_ELF;
+pub use sp1_generated::{AGGREGATION_ELF, STATE_TRANSITION_ELF};
 
 // Oracle snapshot for serializable game content
 pub mod oracle;
@@ -65,13 +76,29 @@ pub use oracle::{
 // Prover module - universal interface and types for all proving backends
 pub mod prover;
 pub use prover::{
-    JournalFields, ProofBackend, ProofData, ProofError, Prover, compute_journal_digest,
-    parse_journal, verify_journal_structure,
+    AGGREGATE_ACTIONS_DOMAIN, JournalFields, ProofBackend, ProofData, ProofError, Prover,
+    aggregate_journals, compute_aggregate_actions_root, compute_journal_digest,
+    compute_seed_commitment, parse_journal, verify_journal_structure,
 };
 
+// Standalone verification of published proofs (verifying key only)
+pub mod verifier;
+pub use verifier::{JournalExpectations, ProofVerifier, VerifyError, VerifyingKey, check_linkage};
+
+// Native execute-only backend (no zkVM needed, so always available for tests)
+pub mod native;
+pub use native::NativeProver;
+
 #[cfg(feature = "stub")]
 pub use prover::StubProver;
 
+// Remote proving over HTTP (client and daemon)
+#[cfg(feature = "remote")]
+pub mod remote;
+
+#[cfg(feature = "remote")]
+pub use remote::{ProverServer, RemoteConfig, RemoteProver};
+
 // RISC0 zkVM backend module
 #[cfg(feature = "risc0")]
 pub mod risc0;
@@ -86,7 +113,7 @@ pub mod sp1;
 #[cfg(feature = "sp1")]
 pub use sp1::*;
 
-// Arkworks circuit module (optional, Phase 2+)
+// Arkworks circuit backend
 #[cfg(feature = "arkworks")]
 pub mod circuit;
 
@@ -102,6 +129,8 @@ pub use game_core::{Action, GameState, StateDelta, compute_actions_root};
 /// - `risc0` (default) → Risc0Prover
 /// - `sp1` → Sp1Prover
 /// - `stub` → StubProver (testing only)
+/// - `native` → NativeProver (execute-only, testing only)
+/// - `arkworks` → ArkworksProver (Move and MeleeAttack only)
 #[cfg(feature = "risc0")]
 pub type ZkProver = Risc0Prover;
 
@@ -110,3 +139,9 @@ pub type ZkProver = Sp1Prover;
 
 #[cfg(feature = "stub")]
 pub type ZkProver = crate::prover::StubProver;
+
+#[cfg(feature = "native")]
+pub type ZkProver = crate::native::NativeProver;
+
+#[cfg(feature = "arkworks")]
+pub type ZkProver = crate::circuit::ArkworksProver;
@@ -0,0 +1,374 @@
+//! Native execute-only backend.
+//!
+//! Runs the state-transition guest logic on the host instead of inside a
+//! zkVM. The journal it produces is byte-identical to the SP1/RISC0 guest's,
+//! so everything downstream of proving (journal parsing, `inspect-proof`,
+//! on-chain submission) can be exercised without a zkVM toolchain.
+//!
+//! There is no proof: `bytes` carries the execution witness (start state and
+//! actions), and [`NativeProver::verify`] re-executes it to check the journal.
+//! Aggregates carry their batch proofs and are checked by verifying each batch
+//! and folding the journals again.
+//!
+//! **Warning**: Provides no cryptographic guarantees - do not use in production.
+
+use game_core::{
+    Action, GameEngine, GameState, OracleSnapshot, SnapshotOracleBundle,
+    compute_published_actions_root, publish_actions,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::prover::{
+    JournalFields, ProofBackend, ProofData, ProofError, Prover, aggregate_journals,
+    compute_journal_digest, compute_seed_commitment, parse_journal,
+};
+
+/// Batch program identifier folded into native aggregates.
+///
+/// There is no guest binary to identify, so this is all zeros.
+pub const NATIVE_BATCH_PROGRAM: [u8; 32] = [0; 32];
+
+/// What a native proof carries in [`ProofData::bytes`].
+#[derive(Serialize, Deserialize)]
+enum Witness {
+    /// Inputs needed to re-execute a batch.
+    Execution {
+        start_state: Box<GameState>,
+        actions: Vec<Action>,
+    },
+    /// Batch proofs folded into an aggregate.
+    Aggregation { proofs: Vec<ProofData> },
+}
+
+/// Execute-only prover producing real journals without proving.
+#[derive(Debug, Clone)]
+pub struct NativeProver {
+    oracle_snapshot: OracleSnapshot,
+    seed_salt: [u8; 32],
+}
+
+impl NativeProver {
+    pub fn new(oracle_snapshot: OracleSnapshot, seed_salt: [u8; 32]) -> Self {
+        Self {
+            oracle_snapshot,
+            seed_salt,
+        }
+    }
+
+    pub fn oracle_snapshot(&self) -> &OracleSnapshot {
+        &self.oracle_snapshot
+    }
+
+    /// Execute a batch exactly as the guest program does and return the
+    /// journal fields it would commit, together with the final state.
+    pub fn execute(
+        &self,
+        start_state: &GameState,
+        actions: &[Action],
+    ) -> Result<(JournalFields, GameState), ProofError> {
+        let seed_commitment = compute_seed_commitment(start_state, &self.seed_salt);
+        let mut state = start_state.clone();
+
+        let oracle_root = self.oracle_snapshot.compute_oracle_root();
+        let prev_state_root = state.compute_journal_state_root(&self.seed_salt);
+        let actions_root = compute_published_actions_root(&publish_actions(
+            state.nonce(),
+            actions,
+            &self.seed_salt,
+        ));
+
+        let oracle_bundle = SnapshotOracleBundle::new(&self.oracle_snapshot);
+        let env = oracle_bundle.as_env();
+        let mut engine = GameEngine::new(&mut state);
+        for (index, action) in actions.iter().enumerate() {
+            engine.execute(env.as_game_env(), action).map_err(|e| {
+                ProofError::ZkvmError(format!(
+                    "Action execution failed (action {}/{}): {:?}",
+                    index + 1,
+                    actions.len(),
+                    e
+                ))
+            })?;
+        }
+
+        let fields = JournalFields {
+            oracle_root,
+            seed_commitment,
+            prev_state_root,
+            actions_root,
+            new_state_root: state.compute_journal_state_root(&self.seed_salt),
+            new_nonce: state.nonce(),
+        };
+        Ok((fields, state))
+    }
+}
+
+impl Prover for NativeProver {
+    fn prove(
+        &self,
+        start_state: &GameState,
+        actions: &[Action],
+        end_state: &GameState,
+    ) -> Result<ProofData, ProofError> {
+        let (fields, _) = self.execute(start_state, actions)?;
+
+        let expected_state_root = end_state.compute_journal_state_root(&self.seed_salt);
+        if fields.new_state_root != expected_state_root || fields.new_nonce != end_state.nonce() {
+            return Err(ProofError::StateInconsistency(format!(
+                "new_state_root mismatch: executed {:?} (nonce {}), expected {:?} (nonce {})",
+                fields.new_state_root,
+                fields.new_nonce,
+                expected_state_root,
+                end_state.nonce()
+            )));
+        }
+
+        Self::proof_data(
+            &fields,
+            &Witness::Execution {
+                start_state: Box::new(start_state.clone()),
+                actions: actions.to_vec(),
+            },
+        )
+    }
+
+    fn aggregate(&self, proofs: &[ProofData]) -> Result<ProofData, ProofError> {
+        let journals = proofs
+            .iter()
+            .enumerate()
+            .map(|(index, proof)| self.verify_batch(proof, index))
+            .collect::<Result<Vec<_>, _>>()?;
+        let fields = aggregate_journals(&NATIVE_BATCH_PROGRAM, &journals)?;
+
+        Self::proof_data(
+            &fields,
+            &Witness::Aggregation {
+                proofs: proofs.to_vec(),
+            },
+        )
+    }
+
+    fn batch_program(&self) -> Option<[u8; 32]> {
+        Some(NATIVE_BATCH_PROGRAM)
+    }
+
+    fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
+        if proof.backend != ProofBackend::Native {
+            return Err(ProofError::ZkvmError(format!(
+                "NativeProver can only verify native proofs, got {:?}",
+                proof.backend
+            )));
+        }
+
+        let actual = compute_journal_digest(&proof.journal);
+        if actual != proof.journal_digest {
+            return Err(ProofError::JournalDigestMismatch {
+                expected: proof.journal_digest,
+                actual,
+            });
+        }
+
+        let fields = match Self::witness(proof)? {
+            Witness::Execution {
+                start_state,
+                actions,
+            } => self.execute(&start_state, &actions)?.0,
+            Witness::Aggregation { proofs } => {
+                let journals = proofs
+                    .iter()
+                    .enumerate()
+                    .map(|(index, proof)| self.verify_batch(proof, index))
+                    .collect::<Result<Vec<_>, _>>()?;
+                aggregate_journals(&NATIVE_BATCH_PROGRAM, &journals)?
+            }
+        };
+        Ok(fields.to_bytes()[..] == proof.journal[..])
+    }
+}
+
+impl NativeProver {
+    fn proof_data(fields: &JournalFields, witness: &Witness) -> Result<ProofData, ProofError> {
+        let journal = fields.to_bytes().to_vec();
+        let journal_digest = compute_journal_digest(&journal);
+        let bytes = bincode::serialize(witness)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+
+        Ok(ProofData {
+            bytes,
+            backend: ProofBackend::Native,
+            journal,
+            journal_digest,
+        })
+    }
+
+    fn witness(proof: &ProofData) -> Result<Witness, ProofError> {
+        bincode::deserialize(&proof.bytes)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))
+    }
+
+    /// Check one input of an aggregate: a valid batch (not aggregate) proof.
+    fn verify_batch(&self, proof: &ProofData, index: usize) -> Result<JournalFields, ProofError> {
+        if proof.backend == ProofBackend::Native
+            && !matches!(Self::witness(proof)?, Witness::Execution { .. })
+        {
+            return Err(ProofError::AggregationError(format!(
+                "Proof {} is an aggregate; only batch proofs can be folded",
+                index
+            )));
+        }
+        if !self.verify(proof)? {
+            return Err(ProofError::AggregationError(format!(
+                "Proof {} does not verify",
+                index
+            )));
+        }
+        parse_journal(&proof.journal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::{
+        ActionAbility, ActionInput, ActionKind, ActionProfile, ActionSnapshot, ActorsSnapshot,
+        CharacterAction, ConfigSnapshot, DialoguesSnapshot, EntityId, GameConfig, ItemsSnapshot,
+        MapDimensions, MapSnapshot, PrepareTurnAction, PublishedAction, StaticTile,
+        SystemActionKind, TargetingMode, TerrainKind,
+    };
+
+    use super::*;
+    use crate::{compute_aggregate_actions_root, verify_journal_structure};
+
+    const SALT: [u8; 32] = [7; 32];
+
+    fn snapshot() -> OracleSnapshot {
+        let wait = ActionProfile {
+            kind: ActionKind::Wait,
+            tags: vec![],
+            targeting: TargetingMode::None,
+            base_cost: 100,
+            resource_costs: vec![],
+            effects: vec![],
+            requirements: vec![],
+            cooldown: None,
+        };
+        OracleSnapshot::new(
+            MapSnapshot::new(
+                MapDimensions::new(10, 10),
+                vec![Some(StaticTile::new(TerrainKind::Floor)); 100],
+            ),
+            ItemsSnapshot::empty(),
+            ActorsSnapshot::empty(),
+            ActionSnapshot::new([(ActionKind::Wait, wait)].into()),
+            ConfigSnapshot::new(GameConfig::default()),
+            DialoguesSnapshot::empty(),
+        )
+    }
+
+    /// Genesis state with a player that can wait.
+    fn genesis() -> GameState {
+        let mut state = GameState::with_player();
+        let player = &mut state.entities.actors[0];
+        player.actions.push(ActionAbility::new(ActionKind::Wait));
+        let position = player.position.unwrap();
+        state
+            .world
+            .tile_map
+            .add_occupant(position, EntityId::PLAYER);
+        state
+    }
+
+    /// One turn: prepare it, then the player waits.
+    fn turn() -> [Action; 2] {
+        [
+            Action::system(SystemActionKind::PrepareTurn(PrepareTurnAction)),
+            Action::character(CharacterAction::new(
+                EntityId::PLAYER,
+                ActionKind::Wait,
+                ActionInput::None,
+            )),
+        ]
+    }
+
+    #[test]
+    fn journals_match_reexecution() {
+        let snapshot = snapshot();
+        let prover = NativeProver::new(snapshot.clone(), SALT);
+        let start = genesis();
+        let actions = turn();
+        let (_, end) = prover.execute(&start, &actions).unwrap();
+
+        let proof = prover.prove(&start, &actions, &end).unwrap();
+        let fields = verify_journal_structure(&proof.journal, Some(&proof.journal_digest)).unwrap();
+        assert_eq!(fields.oracle_root, snapshot.compute_oracle_root());
+        assert_eq!(
+            fields.seed_commitment,
+            compute_seed_commitment(&start, &SALT)
+        );
+        assert_eq!(
+            fields.prev_state_root,
+            start.compute_journal_state_root(&SALT)
+        );
+        let published = publish_actions(start.nonce(), &actions, &SALT);
+        assert!(matches!(published[1], PublishedAction::Revealed(_)));
+        assert_eq!(
+            fields.actions_root,
+            compute_published_actions_root(&published)
+        );
+        assert_eq!(fields.new_state_root, end.compute_journal_state_root(&SALT));
+        assert_eq!(fields.new_nonce, 2);
+        assert!(prover.verify(&proof).unwrap());
+
+        // A tampered journal no longer matches the re-execution
+        let mut forged = proof.clone();
+        forged.journal[160] += 1;
+        forged.journal_digest = compute_journal_digest(&forged.journal);
+        assert!(!prover.verify(&forged).unwrap());
+
+        // The seed commitment is bound to the salt, not just the seed
+        let other_salt = NativeProver::new(snapshot, [8; 32]);
+        assert!(!other_salt.verify(&proof).unwrap());
+
+        // The claimed end state must be the one the actions lead to
+        assert!(prover.prove(&start, &actions, &start).is_err());
+    }
+
+    #[test]
+    fn aggregates_linked_batches() {
+        let prover = NativeProver::new(snapshot(), SALT);
+        let start = genesis();
+        let batch = turn();
+        let (_, middle) = prover.execute(&start, &batch).unwrap();
+        let (_, end) = prover.execute(&middle, &batch).unwrap();
+        let first = prover.prove(&start, &batch, &middle).unwrap();
+        let second = prover.prove(&middle, &batch, &end).unwrap();
+
+        let aggregate = prover.aggregate(&[first.clone(), second.clone()]).unwrap();
+        let fields = parse_journal(&aggregate.journal).unwrap();
+        assert_eq!(
+            fields.prev_state_root,
+            start.compute_journal_state_root(&SALT)
+        );
+        assert_eq!(fields.new_state_root, end.compute_journal_state_root(&SALT));
+        assert_eq!(fields.new_nonce, 4);
+        let roots = [
+            compute_published_actions_root(&publish_actions(start.nonce(), &batch, &SALT)),
+            compute_published_actions_root(&publish_actions(middle.nonce(), &batch, &SALT)),
+        ];
+        assert_eq!(
+            fields.actions_root,
+            compute_aggregate_actions_root(&NATIVE_BATCH_PROGRAM, &roots)
+        );
+        assert!(prover.verify(&aggregate).unwrap());
+
+        // Batches must link up, in order, and aggregates don't nest
+        assert!(matches!(
+            prover.aggregate(&[second, first.clone()]),
+            Err(ProofError::AggregationError(_))
+        ));
+        assert!(matches!(
+            prover.aggregate(&[first, aggregate]),
+            Err(ProofError::AggregationError(_))
+        ));
+        assert!(prover.aggregate(&[]).is_err());
+    }
+}
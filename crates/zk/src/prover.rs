@@ -55,6 +55,8 @@ pub enum ProofBackend {
     Sp1,
     Risc0,
     Arkworks,
+    /// Execute-only backend: real journal, no proof
+    Native,
 }
 
 /// Errors that can occur during proof generation or verification.
@@ -89,6 +91,13 @@ pub enum ProofError {
         expected: [u8; 32],
         actual: [u8; 32],
     },
+
+    #[error("Proof aggregation failed: {0}")]
+    AggregationError(String),
+
+    #[cfg(feature = "remote")]
+    #[error("Remote prover error: {0}")]
+    Remote(String),
 }
 
 // ============================================================================
@@ -108,6 +117,28 @@ pub struct JournalFields {
     pub new_nonce: u64,
 }
 
+impl JournalFields {
+    /// Encode the fields in the 168-byte layout committed by the guest.
+    pub fn to_bytes(&self) -> [u8; 168] {
+        let mut journal = [0u8; 168];
+        journal[0..32].copy_from_slice(&self.oracle_root);
+        journal[32..64].copy_from_slice(&self.seed_commitment);
+        journal[64..96].copy_from_slice(&self.prev_state_root);
+        journal[96..128].copy_from_slice(&self.actions_root);
+        journal[128..160].copy_from_slice(&self.new_state_root);
+        journal[160..168].copy_from_slice(&self.new_nonce.to_le_bytes());
+        journal
+    }
+}
+
+/// Compute the seed commitment the guest commits for a batch.
+///
+/// Salted commitment to `state.game_seed`; see
+/// [`game_core::compute_seed_commitment`].
+pub fn compute_seed_commitment(state: &GameState, seed_salt: &[u8; 32]) -> [u8; 32] {
+    game_core::compute_seed_commitment(state.game_seed, seed_salt)
+}
+
 /// Compute SHA-256 digest of journal bytes.
 ///
 /// This is the public input to the Groth16 proof.
@@ -152,6 +183,95 @@ pub fn parse_journal(journal: &[u8]) -> Result<JournalFields, ProofError> {
     })
 }
 
+/// Domain tag prefixed to the aggregated actions root preimage.
+pub const AGGREGATE_ACTIONS_DOMAIN: &[u8] = b"dungeon.aggregate_actions.v1";
+
+/// Compute the actions root of an aggregated proof.
+///
+/// `SHA-256(AGGREGATE_ACTIONS_DOMAIN || batch_program || actions_root_1 || ...)`.
+/// `batch_program` identifies the guest whose proofs were folded (RISC0 image
+/// ID or SP1 verifying key hash, as little-endian words), so a verifier that
+/// recomputes this root from the published action logs also pins the program
+/// that executed them.
+pub fn compute_aggregate_actions_root(
+    batch_program: &[u8; 32],
+    actions_roots: &[[u8; 32]],
+) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(AGGREGATE_ACTIONS_DOMAIN);
+    hasher.update(batch_program);
+    for root in actions_roots {
+        hasher.update(root);
+    }
+    hasher.finalize().into()
+}
+
+/// Fold a chain of batch journals into the journal of their aggregate.
+///
+/// Mirrors the aggregation guests. Every batch must share the oracle root and
+/// seed commitment, and each batch's `new_state_root` must be the next batch's
+/// `prev_state_root` with a strictly increasing nonce. The aggregate spans from
+/// the first batch's `prev_state_root` to the last batch's `new_state_root`
+/// and `new_nonce`, with the actions root from
+/// [`compute_aggregate_actions_root`].
+///
+/// # Errors
+///
+/// Returns `ProofError::AggregationError` if the chain is empty or broken.
+pub fn aggregate_journals(
+    batch_program: &[u8; 32],
+    journals: &[JournalFields],
+) -> Result<JournalFields, ProofError> {
+    let (first, last) = match (journals.first(), journals.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => {
+            return Err(ProofError::AggregationError(
+                "No batch proofs to aggregate".to_string(),
+            ));
+        }
+    };
+
+    for (index, pair) in journals.windows(2).enumerate() {
+        let (prev, next) = (&pair[0], &pair[1]);
+        if next.oracle_root != first.oracle_root || next.seed_commitment != first.seed_commitment {
+            return Err(ProofError::AggregationError(format!(
+                "Batch {} was proven against a different oracle root or seed commitment",
+                index + 1
+            )));
+        }
+        if next.prev_state_root != prev.new_state_root {
+            return Err(ProofError::AggregationError(format!(
+                "Batch {} starts from {:?} but batch {} ended at {:?}",
+                index + 1,
+                next.prev_state_root,
+                index,
+                prev.new_state_root
+            )));
+        }
+        if next.new_nonce <= prev.new_nonce {
+            return Err(ProofError::AggregationError(format!(
+                "Batch {} ends at nonce {}, not after batch {} (nonce {})",
+                index + 1,
+                next.new_nonce,
+                index,
+                prev.new_nonce
+            )));
+        }
+    }
+
+    let actions_roots: Vec<[u8; 32]> = journals.iter().map(|j| j.actions_root).collect();
+    Ok(JournalFields {
+        oracle_root: first.oracle_root,
+        seed_commitment: first.seed_commitment,
+        prev_state_root: first.prev_state_root,
+        actions_root: compute_aggregate_actions_root(batch_program, &actions_roots),
+        new_state_root: last.new_state_root,
+        new_nonce: last.new_nonce,
+    })
+}
+
 /// Verify journal structure and compute digest.
 ///
 /// Checks that:
@@ -206,6 +326,22 @@ pub trait Prover: Send + Sync {
         end_state: &GameState,
     ) -> Result<ProofData, ProofError>;
 
+    /// Fold a chain of batch proofs into a single proof.
+    ///
+    /// `proofs` must be consecutive batches produced by [`Prover::prove`], in
+    /// nonce order. The result carries one journal covering the whole range
+    /// (see [`aggregate_journals`]), so it can be verified with a single
+    /// on-chain transaction instead of one per batch.
+    fn aggregate(&self, proofs: &[ProofData]) -> Result<ProofData, ProofError>;
+
+    /// Identifier of the batch program this backend folds into aggregate
+    /// actions roots (see [`compute_aggregate_actions_root`]).
+    ///
+    /// `None` for backends whose aggregate journals carry no such commitment.
+    fn batch_program(&self) -> Option<[u8; 32]> {
+        None
+    }
+
     /// Verify a proof locally (for testing and debugging).
     ///
     /// Note: This is host-side verification. For on-chain verification,
@@ -263,6 +399,24 @@ impl Prover for StubProver {
         })
     }
 
+    fn aggregate(&self, proofs: &[ProofData]) -> Result<ProofData, ProofError> {
+        // Stub prover: nothing to fold, return a dummy proof over the batch count
+        let batch_count = proofs.len() as u32;
+        let mut proof_bytes = vec![0x5A, 0x4B]; // "ZK" prefix
+        proof_bytes.extend_from_slice(&batch_count.to_le_bytes());
+        proof_bytes.extend_from_slice(&[0xA6, 0x67]);
+
+        let journal = vec![0u8; 168];
+        let journal_digest = compute_journal_digest(&journal);
+
+        Ok(ProofData {
+            bytes: proof_bytes,
+            backend: ProofBackend::Stub,
+            journal,
+            journal_digest,
+        })
+    }
+
     fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
         if proof.backend != ProofBackend::Stub {
             return Err(ProofError::ZkvmError(format!(
@@ -0,0 +1,235 @@
+//! Client side: a [`Prover`] that delegates to a remote daemon.
+
+use std::fmt;
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+use game_core::{Action, GameState, OracleSnapshot};
+
+use super::{API_PREFIX, JobId, JobStatus, ProofJob, decode, encode};
+use crate::native::NativeProver;
+use crate::prover::{
+    ProofBackend, ProofData, ProofError, Prover, aggregate_journals, parse_journal,
+};
+
+/// Connection settings for a [`RemoteProver`].
+#[derive(Clone)]
+pub struct RemoteConfig {
+    /// Base URL of the daemon, e.g. `http://10.0.0.5:7879`
+    pub endpoint: String,
+    /// Shared secret sent as a bearer token
+    pub secret: String,
+    /// Delay between status polls (default: 2s)
+    pub poll_interval: Duration,
+    /// Give up on a job after this long (default: 1h)
+    pub timeout: Duration,
+}
+
+impl RemoteConfig {
+    pub fn new(endpoint: impl Into<String>, secret: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into().trim_end_matches('/').to_string(),
+            secret: secret.into(),
+            poll_interval: Duration::from_secs(2),
+            timeout: Duration::from_secs(60 * 60),
+        }
+    }
+
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl fmt::Debug for RemoteConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteConfig")
+            .field("endpoint", &self.endpoint)
+            .field("secret", &"<redacted>")
+            .field("poll_interval", &self.poll_interval)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// Prover that ships jobs to a `prover-server` and checks what comes back.
+///
+/// Returned proofs are only accepted if the local `verifier` (the same backend
+/// the daemon runs) verifies them. Batch proofs must additionally commit to the
+/// journal obtained by executing the batch locally, which is cheap compared to
+/// proving, and aggregate proofs to the journal folded from the input batches.
+/// Stub proofs carry no journal, so those checks are skipped for them.
+pub struct RemoteProver {
+    config: RemoteConfig,
+    agent: ureq::Agent,
+    oracle_snapshot: OracleSnapshot,
+    seed_salt: [u8; 32],
+    verifier: Box<dyn Prover>,
+    executor: NativeProver,
+}
+
+impl RemoteProver {
+    pub fn new(
+        config: RemoteConfig,
+        oracle_snapshot: OracleSnapshot,
+        seed_salt: [u8; 32],
+        verifier: impl Prover + 'static,
+    ) -> Self {
+        Self {
+            config,
+            agent: ureq::AgentBuilder::new()
+                .timeout_connect(Duration::from_secs(10))
+                .build(),
+            executor: NativeProver::new(oracle_snapshot.clone(), seed_salt),
+            oracle_snapshot,
+            seed_salt,
+            verifier: Box::new(verifier),
+        }
+    }
+
+    fn submit(&self, job: &ProofJob) -> Result<JobId, ProofError> {
+        let response = self
+            .agent
+            .post(&format!("{}{API_PREFIX}/jobs", self.config.endpoint))
+            .set("Authorization", &format!("Bearer {}", self.config.secret))
+            .set("Content-Type", "application/octet-stream")
+            .send_bytes(&encode(job)?)
+            .map_err(request_error)?;
+        decode(&read_body(response)?)
+    }
+
+    fn status(&self, id: JobId) -> Result<JobStatus, ProofError> {
+        let response = self
+            .agent
+            .get(&format!("{}{API_PREFIX}/jobs/{id}", self.config.endpoint))
+            .set("Authorization", &format!("Bearer {}", self.config.secret))
+            .call()
+            .map_err(request_error)?;
+        decode(&read_body(response)?)
+    }
+
+    /// Submits `job` and polls until the daemon reports a result.
+    fn run(&self, job: &ProofJob) -> Result<ProofData, ProofError> {
+        let id = self.submit(job)?;
+        tracing::debug!("Submitted remote proof job {}", id);
+
+        let deadline = Instant::now() + self.config.timeout;
+        loop {
+            match self.status(id)? {
+                JobStatus::Done(proof) => return Ok(proof),
+                JobStatus::Failed(reason) => {
+                    return Err(ProofError::Remote(format!("job {id} failed: {reason}")));
+                }
+                JobStatus::Queued | JobStatus::Running => {}
+            }
+            if Instant::now() >= deadline {
+                return Err(ProofError::Remote(format!(
+                    "job {id} timed out after {:?}",
+                    self.config.timeout
+                )));
+            }
+            std::thread::sleep(self.config.poll_interval);
+        }
+    }
+
+    fn check(&self, proof: &ProofData) -> Result<(), ProofError> {
+        if self.verifier.verify(proof)? {
+            Ok(())
+        } else {
+            Err(ProofError::Remote(
+                "returned proof failed local verification".to_string(),
+            ))
+        }
+    }
+}
+
+impl Prover for RemoteProver {
+    fn prove(
+        &self,
+        start_state: &GameState,
+        actions: &[Action],
+        end_state: &GameState,
+    ) -> Result<ProofData, ProofError> {
+        let proof = self.run(&ProofJob::Prove {
+            oracle_snapshot: Box::new(self.oracle_snapshot.clone()),
+            seed_salt: self.seed_salt,
+            start_state: Box::new(start_state.clone()),
+            actions: actions.to_vec(),
+            end_state: Box::new(end_state.clone()),
+        })?;
+        self.check(&proof)?;
+
+        if proof.backend != ProofBackend::Stub {
+            let (fields, _) = self.executor.execute(start_state, actions)?;
+            if proof.journal[..] != fields.to_bytes()[..] {
+                return Err(ProofError::Remote(
+                    "returned journal does not match local execution".to_string(),
+                ));
+            }
+        }
+        Ok(proof)
+    }
+
+    fn aggregate(&self, proofs: &[ProofData]) -> Result<ProofData, ProofError> {
+        let proof = self.run(&ProofJob::Aggregate {
+            oracle_snapshot: Box::new(self.oracle_snapshot.clone()),
+            seed_salt: self.seed_salt,
+            proofs: proofs.to_vec(),
+        })?;
+        self.check(&proof)?;
+
+        if proof.backend != ProofBackend::Stub {
+            let batch_program = self.verifier.batch_program().ok_or_else(|| {
+                ProofError::Remote(format!(
+                    "cannot check aggregate journals of {:?} proofs",
+                    proof.backend
+                ))
+            })?;
+            let journals = proofs
+                .iter()
+                .map(|batch| parse_journal(&batch.journal))
+                .collect::<Result<Vec<_>, _>>()?;
+            let expected = aggregate_journals(&batch_program, &journals)?;
+            if proof.journal[..] != expected.to_bytes()[..] {
+                return Err(ProofError::Remote(
+                    "returned journal does not match the aggregated batches".to_string(),
+                ));
+            }
+        }
+        Ok(proof)
+    }
+
+    fn batch_program(&self) -> Option<[u8; 32]> {
+        self.verifier.batch_program()
+    }
+
+    fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
+        self.verifier.verify(proof)
+    }
+}
+
+fn request_error(error: ureq::Error) -> ProofError {
+    match error {
+        ureq::Error::Status(code, response) => {
+            let body = response.into_string().unwrap_or_default();
+            ProofError::Remote(format!("daemon answered {code}: {body}"))
+        }
+        ureq::Error::Transport(transport) => {
+            ProofError::Remote(format!("daemon unreachable: {transport}"))
+        }
+    }
+}
+
+fn read_body(response: ureq::Response) -> Result<Vec<u8>, ProofError> {
+    let mut body = Vec::new();
+    response
+        .into_reader()
+        .read_to_end(&mut body)
+        .map_err(|e| ProofError::Remote(format!("failed to read response: {e}")))?;
+    Ok(body)
+}
@@ -0,0 +1,270 @@
+//! Remote proving: offload proof generation to a `prover-server` daemon.
+//!
+//! [`RemoteProver`] implements [`Prover`](crate::Prover) by shipping each job
+//! to a [`ProverServer`] and polling until the proof is ready, so the player's
+//! machine only pays for local verification of the result.
+//!
+//! # Protocol
+//!
+//! Plain HTTP/1.1 with bincode bodies. Every request carries
+//! `Authorization: Bearer <secret>`; a missing or wrong secret gets `401`.
+//!
+//! ```text
+//! POST /v1/jobs        body: ProofJob   -> 202, body: JobId
+//! GET  /v1/jobs/{id}                    -> 200, body: JobStatus
+//! ```
+//!
+//! Finished and failed statuses are handed out once and then forgotten.
+//! Unknown job ids get `404`, undecodable bodies `400`, and jobs larger than
+//! [`MAX_JOB_SIZE`] `413`.
+//!
+//! The daemon sees the seed salt, so it must be run by someone the player
+//! already trusts with hidden state. The protocol has no transport security:
+//! jobs and the secret travel in clear text, so serve it on loopback behind an
+//! encrypted tunnel, or only on a network the player trusts.
+
+mod client;
+mod server;
+
+pub use client::{RemoteConfig, RemoteProver};
+pub use server::{ProverFactory, ProverServer};
+
+use game_core::{Action, GameState, OracleSnapshot};
+use serde::{Deserialize, Serialize};
+
+use crate::prover::{ProofData, ProofError};
+
+/// Path prefix of the current protocol version.
+pub const API_PREFIX: &str = "/v1";
+
+/// Largest job body the daemon accepts, in bytes.
+pub const MAX_JOB_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Identifier the server assigns to a submitted job.
+pub type JobId = u64;
+
+/// Work submitted to the prover daemon.
+///
+/// Carries everything a backend needs to be constructed on the other side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ProofJob {
+    Prove {
+        oracle_snapshot: Box<OracleSnapshot>,
+        seed_salt: [u8; 32],
+        start_state: Box<GameState>,
+        actions: Vec<Action>,
+        end_state: Box<GameState>,
+    },
+    Aggregate {
+        oracle_snapshot: Box<OracleSnapshot>,
+        seed_salt: [u8; 32],
+        proofs: Vec<ProofData>,
+    },
+}
+
+/// Progress of a submitted job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Done(ProofData),
+    Failed(String),
+}
+
+impl JobStatus {
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Done(_) | Self::Failed(_))
+    }
+}
+
+fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, ProofError> {
+    bincode::serialize(value).map_err(|e| ProofError::SerializationError(e.to_string()))
+}
+
+fn decode<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Result<T, ProofError> {
+    bincode::deserialize(bytes).map_err(|e| ProofError::SerializationError(e.to_string()))
+}
+
+/// Compares an `Authorization` header against the shared secret in constant
+/// time, so response timing does not leak how much of a guess was right.
+fn authorized(header: Option<&str>, secret: &str) -> bool {
+    let Some(token) = header.and_then(|h| h.strip_prefix("Bearer ")) else {
+        return false;
+    };
+    let (token, secret) = (token.as_bytes(), secret.as_bytes());
+    if token.len() != secret.len() {
+        return false;
+    }
+    token
+        .iter()
+        .zip(secret)
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    use game_core::{
+        ActionSnapshot, ActorsSnapshot, ConfigSnapshot, DialoguesSnapshot, GameConfig,
+        ItemsSnapshot, MapDimensions, MapSnapshot,
+    };
+
+    use super::*;
+    use crate::{NativeProver, Prover};
+
+    const SECRET: &str = "correct horse battery staple";
+
+    fn snapshot() -> OracleSnapshot {
+        OracleSnapshot::new(
+            MapSnapshot::new(MapDimensions::new(4, 4), vec![None; 16]),
+            ItemsSnapshot::empty(),
+            ActorsSnapshot::empty(),
+            ActionSnapshot::new(Default::default()),
+            ConfigSnapshot::new(GameConfig::default()),
+            DialoguesSnapshot::empty(),
+        )
+    }
+
+    /// Starts a native-backed daemon on an ephemeral localhost port.
+    fn serve(salt_override: Option<[u8; 32]>) -> String {
+        let server = ProverServer::bind("127.0.0.1:0", SECRET, move |snapshot, salt| {
+            Box::new(NativeProver::new(snapshot, salt_override.unwrap_or(salt)))
+        })
+        .unwrap();
+        let endpoint = format!("http://{}", server.local_addr());
+        std::thread::spawn(move || server.run());
+        endpoint
+    }
+
+    fn remote(endpoint: String, secret: &str) -> RemoteProver {
+        let config =
+            RemoteConfig::new(endpoint, secret).with_poll_interval(Duration::from_millis(10));
+        RemoteProver::new(
+            config,
+            snapshot(),
+            [7; 32],
+            NativeProver::new(snapshot(), [7; 32]),
+        )
+    }
+
+    #[test]
+    fn remote_proofs_match_local_proofs() {
+        let prover = remote(serve(None), SECRET);
+        let state = GameState::with_player();
+
+        let proof = prover.prove(&state, &[], &state).unwrap();
+        let local = NativeProver::new(snapshot(), [7; 32])
+            .prove(&state, &[], &state)
+            .unwrap();
+        assert_eq!(proof.journal, local.journal);
+
+        let aggregate = prover.aggregate(&[proof]).unwrap();
+        assert!(prover.verify(&aggregate).unwrap());
+    }
+
+    #[test]
+    fn wrong_secret_is_rejected() {
+        let prover = remote(serve(None), "hunter2");
+        let state = GameState::with_player();
+
+        let err = prover.prove(&state, &[], &state).unwrap_err();
+        assert!(err.to_string().contains("401"), "{err}");
+        assert!(!authorized(Some("Bearer hunter2"), SECRET));
+        assert!(!authorized(Some(SECRET), SECRET));
+        assert!(authorized(Some(&format!("Bearer {SECRET}")), SECRET));
+    }
+
+    #[test]
+    fn proofs_failing_local_verification_are_rejected() {
+        // A daemon proving under another salt commits to different roots
+        let prover = remote(serve(Some([8; 32])), SECRET);
+        let state = GameState::with_player();
+
+        let err = prover.prove(&state, &[], &state).unwrap_err();
+        assert!(matches!(err, ProofError::Remote(_)), "{err}");
+    }
+
+    /// Answers aggregation jobs with a valid proof of an unrelated batch.
+    struct UnrelatedAggregate(NativeProver);
+
+    impl Prover for UnrelatedAggregate {
+        fn prove(
+            &self,
+            start_state: &GameState,
+            actions: &[Action],
+            end_state: &GameState,
+        ) -> Result<ProofData, ProofError> {
+            self.0.prove(start_state, actions, end_state)
+        }
+
+        fn aggregate(&self, _proofs: &[ProofData]) -> Result<ProofData, ProofError> {
+            let mut other = GameState::with_player();
+            other.turn.nonce = 5;
+            self.0.prove(&other, &[], &other)
+        }
+
+        fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
+            self.0.verify(proof)
+        }
+    }
+
+    #[test]
+    fn aggregates_of_other_batches_are_rejected() {
+        let server = ProverServer::bind("127.0.0.1:0", SECRET, |snapshot, salt| {
+            Box::new(UnrelatedAggregate(NativeProver::new(snapshot, salt)))
+        })
+        .unwrap();
+        let endpoint = format!("http://{}", server.local_addr());
+        std::thread::spawn(move || server.run());
+        let prover = remote(endpoint, SECRET);
+        let state = GameState::with_player();
+
+        let proof = prover.prove(&state, &[], &state).unwrap();
+        let err = prover.aggregate(&[proof]).unwrap_err();
+        assert!(matches!(err, ProofError::Remote(_)), "{err}");
+    }
+
+    #[test]
+    fn panicking_backend_fails_only_its_job() {
+        let panicked = AtomicBool::new(false);
+        let server = ProverServer::bind("127.0.0.1:0", SECRET, move |snapshot, salt| {
+            if !panicked.swap(true, Ordering::SeqCst) {
+                panic!("backend crashed");
+            }
+            Box::new(NativeProver::new(snapshot, salt))
+        })
+        .unwrap();
+        let endpoint = format!("http://{}", server.local_addr());
+        std::thread::spawn(move || server.run());
+        let prover = remote(endpoint, SECRET);
+        let state = GameState::with_player();
+
+        let err = prover.prove(&state, &[], &state).unwrap_err();
+        assert!(err.to_string().contains("backend crashed"), "{err}");
+
+        // The single worker survived and proves the next job
+        prover.prove(&state, &[], &state).unwrap();
+    }
+
+    #[test]
+    fn oversized_jobs_are_refused_unread() {
+        let endpoint = serve(None);
+        let mut stream =
+            std::net::TcpStream::connect(endpoint.trim_start_matches("http://")).unwrap();
+        write!(
+            stream,
+            "POST {API_PREFIX}/jobs HTTP/1.1\r\nAuthorization: Bearer {SECRET}\r\n\
+             Content-Length: {}\r\n\r\n",
+            MAX_JOB_SIZE + 1
+        )
+        .unwrap();
+
+        let mut status = [0; 12];
+        stream.read_exact(&mut status).unwrap();
+        assert_eq!(&status, b"HTTP/1.1 413");
+    }
+}
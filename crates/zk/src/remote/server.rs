@@ -0,0 +1,233 @@
+//! Daemon side: accepts jobs over HTTP and proves them on worker threads.
+
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::io::Read;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex};
+
+use game_core::OracleSnapshot;
+use tiny_http::{Method, Request, Response, Server};
+
+use super::{API_PREFIX, JobId, JobStatus, MAX_JOB_SIZE, ProofJob, authorized, decode, encode};
+use crate::prover::{ProofData, ProofError, Prover};
+
+/// Builds the backend for one job from the snapshot and salt it carries.
+pub type ProverFactory = dyn Fn(OracleSnapshot, [u8; 32]) -> Box<dyn Prover> + Send + Sync;
+
+#[derive(Default)]
+struct Jobs {
+    next_id: JobId,
+    queue: VecDeque<(JobId, ProofJob)>,
+    statuses: HashMap<JobId, JobStatus>,
+}
+
+#[derive(Default)]
+struct Shared {
+    jobs: Mutex<Jobs>,
+    available: Condvar,
+}
+
+/// HTTP front end of the `prover-server` daemon.
+pub struct ProverServer {
+    http: Server,
+    secret: String,
+    factory: Arc<ProverFactory>,
+    workers: usize,
+    shared: Arc<Shared>,
+}
+
+impl ProverServer {
+    /// Listens on `addr`; jobs are proved by backends built with `factory`.
+    pub fn bind(
+        addr: impl ToSocketAddrs,
+        secret: impl Into<String>,
+        factory: impl Fn(OracleSnapshot, [u8; 32]) -> Box<dyn Prover> + Send + Sync + 'static,
+    ) -> Result<Self, ProofError> {
+        let secret = secret.into();
+        if secret.is_empty() {
+            return Err(ProofError::Remote("shared secret must not be empty".into()));
+        }
+        let http = Server::http(addr).map_err(|e| ProofError::Remote(e.to_string()))?;
+
+        Ok(Self {
+            http,
+            secret,
+            factory: Arc::new(factory),
+            workers: 1,
+            shared: Arc::default(),
+        })
+    }
+
+    /// Number of jobs proved concurrently (default: 1).
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.http
+            .server_addr()
+            .to_ip()
+            .expect("bound to a TCP address")
+    }
+
+    /// Serves requests until the process exits.
+    pub fn run(self) {
+        for _ in 0..self.workers {
+            let shared = Arc::clone(&self.shared);
+            let factory = Arc::clone(&self.factory);
+            std::thread::spawn(move || work(&shared, factory.as_ref()));
+        }
+
+        for request in self.http.incoming_requests() {
+            self.handle(request);
+        }
+    }
+
+    fn handle(&self, mut request: Request) {
+        let header = request
+            .headers()
+            .iter()
+            .find(|h| h.field.equiv("Authorization"))
+            .map(|h| h.value.as_str());
+        let response = if !authorized(header, &self.secret) {
+            tracing::warn!(
+                "Rejected unauthenticated request from {:?}",
+                request.remote_addr()
+            );
+            Err((401, "invalid or missing secret".to_string()))
+        } else {
+            let path = request.url().strip_prefix(API_PREFIX).unwrap_or("");
+            match (request.method(), path.strip_prefix("/jobs")) {
+                (Method::Post, Some("")) => self.submit(&mut request),
+                (Method::Get, Some(id)) => match id.strip_prefix('/').map(str::parse) {
+                    Some(Ok(id)) => self.status(id),
+                    _ => Err((404, "unknown job".to_string())),
+                },
+                _ => Err((404, "not found".to_string())),
+            }
+        };
+
+        let response = match response {
+            Ok((code, body)) => Response::from_data(body).with_status_code(code),
+            Err((code, message)) => Response::from_string(message).with_status_code(code),
+        };
+        if let Err(e) = request.respond(response) {
+            tracing::warn!("Failed to send response: {}", e);
+        }
+    }
+
+    fn submit(&self, request: &mut Request) -> Result<(u16, Vec<u8>), (u16, String)> {
+        let too_large = || (413, format!("job exceeds {MAX_JOB_SIZE} bytes"));
+        if request
+            .body_length()
+            .is_some_and(|len| len as u64 > MAX_JOB_SIZE)
+        {
+            return Err(too_large());
+        }
+        // Chunked bodies announce no length, so stop reading past the limit
+        let mut body = Vec::new();
+        request
+            .as_reader()
+            .take(MAX_JOB_SIZE + 1)
+            .read_to_end(&mut body)
+            .map_err(|e| (400, e.to_string()))?;
+        if body.len() as u64 > MAX_JOB_SIZE {
+            return Err(too_large());
+        }
+        let job: ProofJob = decode(&body).map_err(|e| (400, e.to_string()))?;
+
+        let mut jobs = self.shared.jobs.lock().expect("job table poisoned");
+        let id = jobs.next_id;
+        jobs.next_id += 1;
+        jobs.queue.push_back((id, job));
+        jobs.statuses.insert(id, JobStatus::Queued);
+        drop(jobs);
+        self.shared.available.notify_one();
+
+        tracing::info!("Queued proof job {}", id);
+        Ok((202, encode(&id).map_err(|e| (500, e.to_string()))?))
+    }
+
+    fn status(&self, id: JobId) -> Result<(u16, Vec<u8>), (u16, String)> {
+        let mut jobs = self.shared.jobs.lock().expect("job table poisoned");
+        let status = match jobs.statuses.get(&id) {
+            Some(status) if status.is_terminal() => jobs.statuses.remove(&id),
+            status => status.cloned(),
+        }
+        .ok_or((404, "unknown job".to_string()))?;
+        drop(jobs);
+
+        Ok((200, encode(&status).map_err(|e| (500, e.to_string()))?))
+    }
+}
+
+/// Worker loop: takes queued jobs and records their outcome.
+///
+/// A panicking backend fails its job instead of taking the worker down.
+fn work(shared: &Shared, factory: &ProverFactory) {
+    loop {
+        let (id, job) = {
+            let mut jobs = shared.jobs.lock().expect("job table poisoned");
+            loop {
+                if let Some(next) = jobs.queue.pop_front() {
+                    break next;
+                }
+                jobs = shared.available.wait(jobs).expect("job table poisoned");
+            }
+        };
+        set_status(shared, id, JobStatus::Running);
+
+        let started = std::time::Instant::now();
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| prove(factory, job)))
+            .unwrap_or_else(|payload| Err(ProofError::Remote(panic_message(payload.as_ref()))));
+        let status = match outcome {
+            Ok(proof) => {
+                tracing::info!("Proof job {} done in {:?}", id, started.elapsed());
+                JobStatus::Done(proof)
+            }
+            Err(e) => {
+                tracing::warn!("Proof job {} failed: {}", id, e);
+                JobStatus::Failed(e.to_string())
+            }
+        };
+        set_status(shared, id, status);
+    }
+}
+
+fn set_status(shared: &Shared, id: JobId, status: JobStatus) {
+    shared
+        .jobs
+        .lock()
+        .expect("job table poisoned")
+        .statuses
+        .insert(id, status);
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause");
+    format!("prover panicked: {message}")
+}
+
+fn prove(factory: &ProverFactory, job: ProofJob) -> Result<ProofData, ProofError> {
+    match job {
+        ProofJob::Prove {
+            oracle_snapshot,
+            seed_salt,
+            start_state,
+            actions,
+            end_state,
+        } => factory(*oracle_snapshot, seed_salt).prove(&start_state, &actions, &end_state),
+        ProofJob::Aggregate {
+            oracle_snapshot,
+            seed_salt,
+            proofs,
+        } => factory(*oracle_snapshot, seed_salt).aggregate(&proofs),
+    }
+}
@@ -38,21 +38,29 @@
 //!
 //! After proof generation, the host verifies journal consistency:
 //! - oracle_root matches OracleSnapshot hash
+//! - seed_commitment matches the salted commitment to start_state's seed
 //! - prev_state_root matches before_state hash
 //! - new_state_root matches expected_after_state hash
 //! - new_nonce matches expected_after_state.nonce()
 //!
 //! This catches non-determinism bugs and oracle mismatches before on-chain submission.
+//!
+//! # Aggregation
+//!
+//! The `aggregation` guest verifies a chain of state transition receipts
+//! (added as assumptions) and commits one journal in the same layout covering
+//! the whole range. See [`crate::aggregate_journals`].
 
 use risc0_zkvm::{ExecutorEnv, Receipt, default_prover};
-use sha2::{Digest, Sha256};
 
-use crate::prover::{ProofBackend, ProofData, ProofError};
-use crate::{OracleSnapshot, Prover, compute_journal_digest, parse_journal};
+use crate::prover::{ProofBackend, ProofData, ProofError, aggregate_journals};
+use crate::{
+    OracleSnapshot, Prover, compute_journal_digest, compute_seed_commitment, parse_journal,
+};
 use game_core::{Action, GameState};
 
 // Generated by build.rs - contains ELFs and ImageIDs
-use crate::{STATE_TRANSITION_ELF, STATE_TRANSITION_ID};
+use crate::{AGGREGATION_ELF, AGGREGATION_ID, STATE_TRANSITION_ELF, STATE_TRANSITION_ID};
 
 /// RISC0 zkVM prover.
 ///
@@ -60,22 +68,17 @@ use crate::{STATE_TRANSITION_ELF, STATE_TRANSITION_ID};
 #[derive(Clone)]
 pub struct Risc0Prover {
     oracle_snapshot: OracleSnapshot,
+    seed_salt: [u8; 32],
 }
 
 impl Risc0Prover {
-    /// Creates a new RISC0 prover with the given oracle snapshot.
-    pub fn new(oracle_snapshot: OracleSnapshot) -> Self {
-        Self { oracle_snapshot }
-    }
-
-    /// Computes seed commitment from GameState's game_seed.
-    ///
-    /// The seed commitment is SHA-256(game_seed), providing a cryptographic
-    /// commitment to the RNG seed without revealing it.
-    fn compute_seed_commitment(state: &GameState) -> [u8; 32] {
-        let mut hasher = Sha256::new();
-        hasher.update(state.game_seed.to_le_bytes());
-        hasher.finalize().into()
+    /// Creates a new RISC0 prover with the given oracle snapshot and the
+    /// session's private seed salt.
+    pub fn new(oracle_snapshot: OracleSnapshot, seed_salt: [u8; 32]) -> Self {
+        Self {
+            oracle_snapshot,
+            seed_salt,
+        }
     }
 
     /// Verifies journal fields match expected values.
@@ -83,9 +86,9 @@ impl Risc0Prover {
     /// Checks that zkVM-committed values match host-computed values:
     /// - oracle_root: Must match oracle snapshot hash
     /// - seed_commitment: Must match game seed commitment
-    /// - prev_state_root: Must match start_state hash
-    /// - actions_root: Must match hash of action sequence
-    /// - new_state_root: Must match expected_end_state hash
+    /// - prev_state_root: Must match start_state commitment root
+    /// - actions_root: Must match root of the published action sequence
+    /// - new_state_root: Must match expected_end_state commitment root
     /// - new_nonce: Must match expected_end_state.nonce()
     ///
     /// # Errors
@@ -103,10 +106,13 @@ impl Risc0Prover {
 
         // Compute expected values
         let expected_oracle_root = self.oracle_snapshot.compute_oracle_root();
-        let expected_seed_commitment = Self::compute_seed_commitment(start_state);
-        let expected_prev_state_root = start_state.compute_state_root();
-        let expected_actions_root = game_core::compute_actions_root(actions);
-        let expected_new_state_root = expected_end_state.compute_state_root();
+        let expected_seed_commitment = compute_seed_commitment(start_state, &self.seed_salt);
+        let expected_prev_state_root = start_state.compute_journal_state_root(&self.seed_salt);
+        let expected_actions_root = game_core::compute_published_actions_root(
+            &game_core::publish_actions(start_state.nonce(), actions, &self.seed_salt),
+        );
+        let expected_new_state_root =
+            expected_end_state.compute_journal_state_root(&self.seed_salt);
         let expected_new_nonce = expected_end_state.nonce();
 
         // Verify oracle_root
@@ -172,18 +178,15 @@ impl Prover for Risc0Prover {
         actions: &[Action],
         expected_end_state: &GameState,
     ) -> Result<ProofData, ProofError> {
-        // Compute seed commitment from game state
-        let seed_commitment = Self::compute_seed_commitment(start_state);
-
         // Build executor environment
         // Order must match state-transition guest's env::read() calls
         let env = ExecutorEnv::builder()
             // 1. Oracle snapshot (static game content)
             .write(&self.oracle_snapshot)
             .map_err(|e| ProofError::ZkvmError(format!("Failed to write oracle_snapshot: {}", e)))?
-            // 2. Seed commitment (RNG seed commitment)
-            .write(&seed_commitment)
-            .map_err(|e| ProofError::ZkvmError(format!("Failed to write seed_commitment: {}", e)))?
+            // 2. Seed salt (private; the guest derives the commitment from it)
+            .write(&self.seed_salt)
+            .map_err(|e| ProofError::ZkvmError(format!("Failed to write seed_salt: {}", e)))?
             // 3. Game state at start
             .write(start_state)
             .map_err(|e| ProofError::ZkvmError(format!("Failed to write start_state: {}", e)))?
@@ -222,6 +225,77 @@ impl Prover for Risc0Prover {
         })
     }
 
+    fn aggregate(&self, proofs: &[ProofData]) -> Result<ProofData, ProofError> {
+        let mut journals = Vec::with_capacity(proofs.len());
+        let mut receipts = Vec::with_capacity(proofs.len());
+        for (index, proof) in proofs.iter().enumerate() {
+            if proof.backend != ProofBackend::Risc0 {
+                return Err(ProofError::AggregationError(format!(
+                    "Proof {} was generated by {:?}, expected Risc0",
+                    index, proof.backend
+                )));
+            }
+            let receipt: Receipt = bincode::deserialize(&proof.bytes)
+                .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+            journals.push(parse_journal(&receipt.journal.bytes)?);
+            receipts.push(receipt);
+        }
+
+        // Check the chain on the host first, so a broken chain fails before proving
+        let batch_program = program_id(&STATE_TRANSITION_ID);
+        let expected = aggregate_journals(&batch_program, &journals)?;
+
+        // Build executor environment
+        // Order must match aggregation guest's env::read() calls
+        let journal_bytes: Vec<Vec<u8>> =
+            receipts.iter().map(|r| r.journal.bytes.clone()).collect();
+        let mut builder = ExecutorEnv::builder();
+        // 1. Image ID of the state transition guest
+        builder
+            .write(&STATE_TRANSITION_ID)
+            .map_err(|e| ProofError::ZkvmError(format!("Failed to write image ID: {}", e)))?;
+        // 2. Batch journals, in chain order
+        builder
+            .write(&journal_bytes)
+            .map_err(|e| ProofError::ZkvmError(format!("Failed to write journals: {}", e)))?;
+        // Batch receipts resolve the guest's env::verify() calls
+        for receipt in receipts {
+            builder.add_assumption(receipt);
+        }
+        let env = builder
+            .build()
+            .map_err(|e| ProofError::ZkvmError(format!("Failed to build ExecutorEnv: {}", e)))?;
+
+        let prove_info = default_prover()
+            .prove(env, AGGREGATION_ELF)
+            .map_err(|e| ProofError::ZkvmError(format!("Aggregation proof failed: {}", e)))?;
+        let receipt = prove_info.receipt;
+
+        let journal = receipt.journal.bytes.clone();
+        if journal[..] != expected.to_bytes()[..] {
+            return Err(ProofError::StateInconsistency(format!(
+                "aggregate journal mismatch: zkVM committed {:?}, expected {:?}",
+                parse_journal(&journal)?,
+                expected
+            )));
+        }
+
+        let journal_digest = compute_journal_digest(&journal);
+        let bytes = bincode::serialize(&receipt)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+
+        Ok(ProofData {
+            bytes,
+            backend: ProofBackend::Risc0,
+            journal,
+            journal_digest,
+        })
+    }
+
+    fn batch_program(&self) -> Option<[u8; 32]> {
+        Some(program_id(&STATE_TRANSITION_ID))
+    }
+
     fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
         // Verify backend matches
         if proof.backend != ProofBackend::Risc0 {
@@ -235,11 +309,23 @@ impl Prover for Risc0Prover {
         let receipt: Receipt = bincode::deserialize(&proof.bytes)
             .map_err(|e| ProofError::SerializationError(e.to_string()))?;
 
-        // Verify against state transition guest program ImageID
+        // Verify against state transition guest program ImageID, or the
+        // aggregation guest for aggregates
         receipt
             .verify(STATE_TRANSITION_ID)
+            .or_else(|_| receipt.verify(AGGREGATION_ID))
             .map_err(|e| ProofError::ZkvmError(format!("Proof verification failed: {:?}", e)))?;
 
         Ok(true)
     }
 }
+
+/// Image ID as the bytes the aggregation guest folds into the actions root
+/// (little-endian words).
+fn program_id(image_id: &[u32; 8]) -> [u8; 32] {
+    let mut id = [0u8; 32];
+    for (chunk, word) in id.chunks_exact_mut(4).zip(image_id) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    id
+}
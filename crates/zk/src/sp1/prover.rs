@@ -2,15 +2,16 @@
 //!
 //! Generates zero-knowledge proofs of game action execution using SP1's zkVM.
 
-use sha2::{Digest, Sha256};
-use sp1_sdk::{EnvProver, SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin, SP1VerifyingKey};
+use sp1_sdk::{
+    EnvProver, SP1Proof, SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin, SP1VerifyingKey,
+};
 
-use crate::prover::{ProofBackend, ProofData, ProofError, Prover};
-use crate::{OracleSnapshot, compute_journal_digest, parse_journal};
+use crate::prover::{ProofBackend, ProofData, ProofError, Prover, aggregate_journals};
+use crate::{OracleSnapshot, compute_journal_digest, compute_seed_commitment, parse_journal};
 use game_core::{Action, GameState};
 
-// Generated by build.rs - contains ELF
-use crate::STATE_TRANSITION_ELF;
+// Generated by build.rs - contains ELFs
+use crate::{AGGREGATION_ELF, STATE_TRANSITION_ELF};
 
 /// SP1 zkVM prover.
 ///
@@ -28,30 +29,63 @@ use crate::STATE_TRANSITION_ELF;
 /// - `compressed` (default): Compressed STARK (~4-5MB, off-chain verification)
 /// - `groth16`: Groth16 SNARK (~260 bytes, on-chain verification, Sui compatible)
 /// - `plonk`: PLONK SNARK (~868 bytes, on-chain, no trusted setup)
+///
+/// ## Aggregation Proof Mode (`SP1_AGGREGATE_PROOF_MODE`)
+/// Same values as `SP1_PROOF_MODE`, which it defaults to. The aggregation guest
+/// can only verify compressed batch proofs, so a typical on-chain setup proves
+/// batches `compressed` and aggregates with `groth16`.
 pub struct Sp1Prover {
     oracle_snapshot: OracleSnapshot,
+    seed_salt: [u8; 32],
     client: EnvProver,
     pk: SP1ProvingKey,
     vk: SP1VerifyingKey,
+    aggregation_pk: SP1ProvingKey,
+    aggregation_vk: SP1VerifyingKey,
+}
+
+/// Proof mode of batch proofs (`SP1_PROOF_MODE`, default `compressed`).
+fn batch_proof_mode() -> String {
+    std::env::var("SP1_PROOF_MODE").unwrap_or_else(|_| "compressed".to_string())
+}
+
+/// Proof mode of aggregate proofs (`SP1_AGGREGATE_PROOF_MODE`, default batch mode).
+fn aggregate_proof_mode() -> String {
+    std::env::var("SP1_AGGREGATE_PROOF_MODE").unwrap_or_else(|_| batch_proof_mode())
+}
+
+/// Verifying key hash as the bytes the aggregation guest folds into the
+/// actions root (little-endian words).
+fn program_id(vk: &SP1VerifyingKey) -> [u8; 32] {
+    let mut id = [0u8; 32];
+    for (chunk, word) in id.chunks_exact_mut(4).zip(vk.hash_u32()) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    id
 }
 
 impl Sp1Prover {
-    /// Creates a new SP1 prover with the given oracle snapshot.
+    /// Creates a new SP1 prover with the given oracle snapshot and the
+    /// session's private seed salt.
     ///
     /// Initializes EnvProver from environment variables:
     /// - `SP1_PROVER`: Selects prover mode (cpu, network, cuda, mock)
     /// - `SP1_PROOF_MODE`: Selects proof type (compressed, groth16, plonk)
     /// - `NETWORK_PRIVATE_KEY`: Required for network mode
     /// - `NETWORK_RPC_URL`: Optional custom RPC endpoint for network mode
-    pub fn new(oracle_snapshot: OracleSnapshot) -> Self {
+    pub fn new(oracle_snapshot: OracleSnapshot, seed_salt: [u8; 32]) -> Self {
         let client = sp1_sdk::ProverClient::from_env();
         let (pk, vk) = client.setup(STATE_TRANSITION_ELF);
+        let (aggregation_pk, aggregation_vk) = client.setup(AGGREGATION_ELF);
 
         Self {
             oracle_snapshot,
+            seed_salt,
             client,
             pk,
             vk,
+            aggregation_pk,
+            aggregation_vk,
         }
     }
 
@@ -77,14 +111,69 @@ impl Sp1Prover {
         &self.oracle_snapshot
     }
 
-    /// Computes seed commitment from GameState's game_seed.
-    ///
-    /// The seed commitment is SHA-256(game_seed), providing a cryptographic
-    /// commitment to the RNG seed without revealing it.
-    pub fn compute_seed_commitment(state: &GameState) -> [u8; 32] {
-        let mut hasher = Sha256::new();
-        hasher.update(state.game_seed.to_le_bytes());
-        hasher.finalize().into()
+    /// Prove `stdin` against `pk` in the given proof mode.
+    fn run(
+        &self,
+        pk: &SP1ProvingKey,
+        stdin: &SP1Stdin,
+        proof_mode: &str,
+    ) -> Result<SP1ProofWithPublicValues, ProofError> {
+        let proof = match proof_mode.to_lowercase().as_str() {
+            "groth16" => {
+                tracing::info!("Generating Groth16 proof (SP1_PROOF_MODE=groth16)");
+                self.client.prove(pk, stdin).groth16().run().map_err(|e| {
+                    ProofError::ZkvmError(format!(
+                        "SP1 Groth16 proof generation failed: {}. \
+                             Platform-independent, works on all systems.",
+                        e
+                    ))
+                })?
+            }
+            "plonk" => {
+                tracing::info!("Generating PLONK proof (SP1_PROOF_MODE=plonk)");
+                self.client.prove(pk, stdin).plonk().run().map_err(|e| {
+                    ProofError::ZkvmError(format!("SP1 PLONK proof generation failed: {}", e))
+                })?
+            }
+            "compressed" => {
+                tracing::info!("Generating Compressed proof (SP1_PROOF_MODE=compressed)");
+                self.client
+                    .prove(pk, stdin)
+                    .compressed()
+                    .run()
+                    .map_err(|e| {
+                        ProofError::ZkvmError(format!(
+                            "SP1 Compressed proof generation failed: {}",
+                            e
+                        ))
+                    })?
+            }
+            _ => {
+                tracing::warn!(
+                    "Invalid SP1_PROOF_MODE '{}', defaulting to 'compressed'. \
+                     Valid options: compressed, groth16, plonk",
+                    proof_mode
+                );
+                tracing::info!("Generating Compressed proof (SP1_PROOF_MODE=compressed)");
+                self.client
+                    .prove(pk, stdin)
+                    .compressed()
+                    .run()
+                    .map_err(|e| {
+                        ProofError::ZkvmError(format!(
+                            "SP1 Compressed proof generation failed: {}",
+                            e
+                        ))
+                    })?
+            }
+        };
+
+        Ok(proof)
+    }
+
+    /// Salted seed commitment the guest derives from `state.game_seed`.
+    pub fn compute_seed_commitment(&self, state: &GameState) -> [u8; 32] {
+        compute_seed_commitment(state, &self.seed_salt)
     }
 
     /// Verifies public values fields match expected values (exposed for groth16).
@@ -104,10 +193,13 @@ impl Sp1Prover {
 
         // Compute expected values
         let expected_oracle_root = self.oracle_snapshot.compute_oracle_root();
-        let expected_seed_commitment = Self::compute_seed_commitment(start_state);
-        let expected_prev_state_root = start_state.compute_state_root();
-        let expected_actions_root = game_core::compute_actions_root(actions);
-        let expected_new_state_root = expected_end_state.compute_state_root();
+        let expected_seed_commitment = self.compute_seed_commitment(start_state);
+        let expected_prev_state_root = start_state.compute_journal_state_root(&self.seed_salt);
+        let expected_actions_root = game_core::compute_published_actions_root(
+            &game_core::publish_actions(start_state.nonce(), actions, &self.seed_salt),
+        );
+        let expected_new_state_root =
+            expected_end_state.compute_journal_state_root(&self.seed_salt);
         let expected_new_nonce = expected_end_state.nonce();
 
         // Verify oracle_root
@@ -173,9 +265,6 @@ impl Prover for Sp1Prover {
         actions: &[Action],
         expected_end_state: &GameState,
     ) -> Result<ProofData, ProofError> {
-        // Compute seed commitment from game state
-        let seed_commitment = Self::compute_seed_commitment(start_state);
-
         // Build stdin (SP1's equivalent of ExecutorEnv)
         // Order must match state-transition guest's sp1_zkvm::io::read() calls
         let mut stdin = SP1Stdin::new();
@@ -183,8 +272,8 @@ impl Prover for Sp1Prover {
         // 1. Oracle snapshot (static game content)
         stdin.write(&self.oracle_snapshot);
 
-        // 2. Seed commitment (RNG seed commitment)
-        stdin.write(&seed_commitment);
+        // 2. Seed salt (private; the guest derives the commitment from it)
+        stdin.write(&self.seed_salt);
 
         // 3. Game state at start
         stdin.write(start_state);
@@ -193,66 +282,7 @@ impl Prover for Sp1Prover {
         stdin.write(&actions.to_vec());
 
         // Generate proof using state transition guest
-        let proof_mode =
-            std::env::var("SP1_PROOF_MODE").unwrap_or_else(|_| "compressed".to_string());
-
-        let proof = match proof_mode.to_lowercase().as_str() {
-            "groth16" => {
-                tracing::info!("Generating Groth16 proof (SP1_PROOF_MODE=groth16)");
-                self.client
-                    .prove(&self.pk, &stdin)
-                    .groth16()
-                    .run()
-                    .map_err(|e| {
-                        ProofError::ZkvmError(format!(
-                            "SP1 Groth16 proof generation failed: {}. \
-                             Platform-independent, works on all systems.",
-                            e
-                        ))
-                    })?
-            }
-            "plonk" => {
-                tracing::info!("Generating PLONK proof (SP1_PROOF_MODE=plonk)");
-                self.client
-                    .prove(&self.pk, &stdin)
-                    .plonk()
-                    .run()
-                    .map_err(|e| {
-                        ProofError::ZkvmError(format!("SP1 PLONK proof generation failed: {}", e))
-                    })?
-            }
-            "compressed" => {
-                tracing::info!("Generating Compressed proof (SP1_PROOF_MODE=compressed)");
-                self.client
-                    .prove(&self.pk, &stdin)
-                    .compressed()
-                    .run()
-                    .map_err(|e| {
-                        ProofError::ZkvmError(format!(
-                            "SP1 Compressed proof generation failed: {}",
-                            e
-                        ))
-                    })?
-            }
-            _ => {
-                tracing::warn!(
-                    "Invalid SP1_PROOF_MODE '{}', defaulting to 'compressed'. \
-                     Valid options: compressed, groth16, plonk",
-                    proof_mode
-                );
-                tracing::info!("Generating Compressed proof (SP1_PROOF_MODE=compressed)");
-                self.client
-                    .prove(&self.pk, &stdin)
-                    .compressed()
-                    .run()
-                    .map_err(|e| {
-                        ProofError::ZkvmError(format!(
-                            "SP1 Compressed proof generation failed: {}",
-                            e
-                        ))
-                    })?
-            }
-        };
+        let proof = self.run(&self.pk, &stdin, &batch_proof_mode())?;
 
         // Extract 168-byte public values from proof
         let journal = proof.public_values.to_vec();
@@ -275,6 +305,68 @@ impl Prover for Sp1Prover {
         })
     }
 
+    fn aggregate(&self, proofs: &[ProofData]) -> Result<ProofData, ProofError> {
+        // Build stdin
+        // Order must match aggregation guest's sp1_zkvm::io::read() calls
+        let mut stdin = SP1Stdin::new();
+
+        // 1. Verifying key hash of the state transition guest
+        stdin.write(&self.vk.hash_u32());
+
+        // 2. Batch journals, in chain order
+        let journal_bytes: Vec<Vec<u8>> = proofs.iter().map(|p| p.journal.clone()).collect();
+        stdin.write(&journal_bytes);
+
+        // 3. Batch proofs, verified by the guest through the recursion API
+        let mut journals = Vec::with_capacity(proofs.len());
+        for (index, proof) in proofs.iter().enumerate() {
+            if proof.backend != ProofBackend::Sp1 {
+                return Err(ProofError::AggregationError(format!(
+                    "Proof {} was generated by {:?}, expected SP1",
+                    index, proof.backend
+                )));
+            }
+            let sp1_proof: SP1ProofWithPublicValues = bincode::deserialize(&proof.bytes)
+                .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+            let SP1Proof::Compressed(reduced) = sp1_proof.proof else {
+                return Err(ProofError::AggregationError(format!(
+                    "Proof {} is not a compressed proof (prove batches with SP1_PROOF_MODE=compressed)",
+                    index
+                )));
+            };
+            stdin.write_proof(*reduced, self.vk.vk.clone());
+            journals.push(parse_journal(&proof.journal)?);
+        }
+
+        // Check the chain on the host first, so a broken chain fails before proving
+        let expected = aggregate_journals(&program_id(&self.vk), &journals)?;
+
+        let proof = self.run(&self.aggregation_pk, &stdin, &aggregate_proof_mode())?;
+        let journal = proof.public_values.to_vec();
+        if journal[..] != expected.to_bytes()[..] {
+            return Err(ProofError::StateInconsistency(format!(
+                "aggregate public values mismatch: zkVM committed {:?}, expected {:?}",
+                parse_journal(&journal)?,
+                expected
+            )));
+        }
+
+        let journal_digest = compute_journal_digest(&journal);
+        let bytes = bincode::serialize(&proof)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+
+        Ok(ProofData {
+            bytes,
+            backend: ProofBackend::Sp1,
+            journal,
+            journal_digest,
+        })
+    }
+
+    fn batch_program(&self) -> Option<[u8; 32]> {
+        Some(program_id(&self.vk))
+    }
+
     fn verify(&self, proof: &ProofData) -> Result<bool, ProofError> {
         // Verify backend matches
         if proof.backend != ProofBackend::Sp1 {
@@ -288,11 +380,15 @@ impl Prover for Sp1Prover {
         let sp1_proof: SP1ProofWithPublicValues = bincode::deserialize(&proof.bytes)
             .map_err(|e| ProofError::SerializationError(e.to_string()))?;
 
-        // Verify proof against state transition guest program
+        // Verify proof against the state transition guest, or the aggregation
+        // guest for aggregates
         // Reuse the cached ProverClient from initialization for performance
-        self.client.verify(&sp1_proof, &self.vk).map_err(|e| {
-            ProofError::ZkvmError(format!("SP1 proof verification failed: {:?}", e))
-        })?;
+        self.client
+            .verify(&sp1_proof, &self.vk)
+            .or_else(|_| self.client.verify(&sp1_proof, &self.aggregation_vk))
+            .map_err(|e| {
+                ProofError::ZkvmError(format!("SP1 proof verification failed: {:?}", e))
+            })?;
 
         Ok(true)
     }
@@ -0,0 +1,383 @@
+//! Standalone proof verification for third parties.
+//!
+//! [`Prover::verify`](crate::Prover::verify) needs a fully configured backend,
+//! including the session's oracle snapshot and seed salt. [`ProofVerifier`]
+//! only needs what a published run exposes: the proof files and the
+//! verifying key of the guest program that produced them.
+//!
+//! Checks, in order:
+//! 1. the cryptographic proof against the [`VerifyingKey`]
+//! 2. the journal digest and layout
+//! 3. optional [`JournalExpectations`] (oracle root, previous state root, nonce)
+//! 4. for a sequence of batch proofs, that each batch starts where the previous
+//!    one ended, under the same oracle root and seed commitment
+//!
+//! RISC0 proofs need the `verify-risc0` feature and SP1 proofs `verify-sp1`;
+//! both work next to any proving backend. Stub and native proofs contain no proof at
+//! all; they are only accepted with [`ProofVerifier::allow_insecure`].
+//! Arkworks batch proofs bind the actions root to the seed salt, so they can
+//! only be verified with [`Prover::verify`](crate::Prover::verify).
+
+use crate::prover::{
+    JournalFields, ProofBackend, ProofData, ProofError, compute_journal_digest, parse_journal,
+};
+
+/// Errors returned by [`ProofVerifier`].
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyError {
+    #[error("{0:?} proofs carry no cryptographic proof")]
+    Insecure(ProofBackend),
+
+    #[error("cannot verify {backend:?} proofs: {reason}")]
+    Unsupported {
+        backend: ProofBackend,
+        reason: &'static str,
+    },
+
+    #[error("invalid verifying key: {0}")]
+    InvalidKey(String),
+
+    #[error("proof rejected: {0}")]
+    Rejected(String),
+
+    #[error(transparent)]
+    Journal(#[from] ProofError),
+
+    #[error("{field} mismatch: expected {expected}, journal has {actual}")]
+    Mismatch {
+        field: &'static str,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("proof {index} does not continue proof {}: {reason}", index - 1)]
+    Unlinked { index: usize, reason: &'static str },
+
+    #[error("no proofs to verify")]
+    Empty,
+}
+
+/// Backend-specific verifying key.
+///
+/// - RISC0: the 32-byte image ID of the guest that produced the proof
+/// - SP1: a bincode-encoded `SP1VerifyingKey`
+/// - Stub and native: unused
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyingKey(pub Vec<u8>);
+
+impl VerifyingKey {
+    /// Key of this build's state-transition guest.
+    #[cfg(feature = "risc0")]
+    pub fn state_transition() -> Self {
+        let digest = risc0_zkvm::sha::Digest::from(crate::STATE_TRANSITION_ID);
+        Self(digest.as_bytes().to_vec())
+    }
+
+    /// Key of this build's state-transition guest.
+    #[cfg(feature = "sp1")]
+    pub fn state_transition() -> Result<Self, VerifyError> {
+        let (_, vk) = sp1_sdk::ProverClient::from_env().setup(crate::STATE_TRANSITION_ELF);
+        bincode::serialize(&vk)
+            .map(Self)
+            .map_err(|e| VerifyError::InvalidKey(e.to_string()))
+    }
+}
+
+/// Public values a verifier may pin in addition to the proof itself.
+///
+/// For a sequence of proofs, `prev_state_root` applies to the first proof and
+/// `new_nonce` to the last.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalExpectations {
+    pub oracle_root: Option<[u8; 32]>,
+    pub prev_state_root: Option<[u8; 32]>,
+    pub new_nonce: Option<u64>,
+}
+
+impl JournalExpectations {
+    fn check(&self, first: &JournalFields, last: &JournalFields) -> Result<(), VerifyError> {
+        if let Some(expected) = self.oracle_root {
+            mismatch("oracle_root", &expected, &first.oracle_root)?;
+        }
+        if let Some(expected) = self.prev_state_root {
+            mismatch("prev_state_root", &expected, &first.prev_state_root)?;
+        }
+        match self.new_nonce {
+            Some(expected) if expected != last.new_nonce => Err(VerifyError::Mismatch {
+                field: "new_nonce",
+                expected: expected.to_string(),
+                actual: last.new_nonce.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Verifies proofs against a [`VerifyingKey`] without a configured prover.
+#[derive(Debug, Clone, Default)]
+pub struct ProofVerifier {
+    key: VerifyingKey,
+    allow_insecure: bool,
+}
+
+impl ProofVerifier {
+    pub fn new(key: VerifyingKey) -> Self {
+        Self {
+            key,
+            allow_insecure: false,
+        }
+    }
+
+    pub fn key(&self) -> &VerifyingKey {
+        &self.key
+    }
+
+    /// Accept stub and native proofs on their journal alone (for testing).
+    pub fn allow_insecure(mut self, allow: bool) -> Self {
+        self.allow_insecure = allow;
+        self
+    }
+
+    /// Verifies one proof and returns its journal.
+    pub fn verify(
+        &self,
+        proof: &ProofData,
+        expected: &JournalExpectations,
+    ) -> Result<JournalFields, VerifyError> {
+        let fields = self.verify_one(proof)?;
+        expected.check(&fields, &fields)?;
+        Ok(fields)
+    }
+
+    /// Verifies sequential batch proofs, oldest first, and their linkage.
+    pub fn verify_chain(
+        &self,
+        proofs: &[ProofData],
+        expected: &JournalExpectations,
+    ) -> Result<Vec<JournalFields>, VerifyError> {
+        let journals = proofs
+            .iter()
+            .map(|proof| self.verify_one(proof))
+            .collect::<Result<Vec<_>, _>>()?;
+        check_linkage(&journals)?;
+        if let (Some(first), Some(last)) = (journals.first(), journals.last()) {
+            expected.check(first, last)?;
+        }
+        Ok(journals)
+    }
+
+    fn verify_one(&self, proof: &ProofData) -> Result<JournalFields, VerifyError> {
+        self.verify_seal(proof)?;
+
+        let actual = compute_journal_digest(&proof.journal);
+        if actual != proof.journal_digest {
+            return Err(ProofError::JournalDigestMismatch {
+                expected: proof.journal_digest,
+                actual,
+            }
+            .into());
+        }
+        Ok(parse_journal(&proof.journal)?)
+    }
+
+    /// Checks the cryptographic proof, which must commit to `proof.journal`.
+    fn verify_seal(&self, proof: &ProofData) -> Result<(), VerifyError> {
+        match proof.backend {
+            ProofBackend::Stub | ProofBackend::Native if self.allow_insecure => Ok(()),
+            ProofBackend::Stub | ProofBackend::Native => Err(VerifyError::Insecure(proof.backend)),
+            ProofBackend::Risc0 => self.verify_risc0(proof),
+            ProofBackend::Sp1 => self.verify_sp1(proof),
+            ProofBackend::Arkworks => Err(VerifyError::Unsupported {
+                backend: proof.backend,
+                reason: "arkworks batch proofs can only be checked with the seed salt",
+            }),
+        }
+    }
+
+    #[cfg(feature = "verify-risc0")]
+    fn verify_risc0(&self, proof: &ProofData) -> Result<(), VerifyError> {
+        use risc0_zkvm::{Receipt, sha::Digest};
+
+        let image_id = Digest::try_from(self.key.0.as_slice())
+            .map_err(|_| VerifyError::InvalidKey("expected a 32-byte image ID".into()))?;
+        let receipt: Receipt = bincode::deserialize(&proof.bytes)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+        receipt
+            .verify(image_id)
+            .map_err(|e| VerifyError::Rejected(e.to_string()))?;
+        if receipt.journal.bytes != proof.journal {
+            return Err(VerifyError::Rejected(
+                "journal differs from the one the receipt commits to".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "verify-risc0"))]
+    fn verify_risc0(&self, proof: &ProofData) -> Result<(), VerifyError> {
+        Err(VerifyError::Unsupported {
+            backend: proof.backend,
+            reason: "built without the verify-risc0 feature",
+        })
+    }
+
+    #[cfg(feature = "verify-sp1")]
+    fn verify_sp1(&self, proof: &ProofData) -> Result<(), VerifyError> {
+        use sp1_sdk::{SP1ProofWithPublicValues, SP1VerifyingKey};
+
+        let vk: SP1VerifyingKey = bincode::deserialize(&self.key.0)
+            .map_err(|e| VerifyError::InvalidKey(e.to_string()))?;
+        let sp1_proof: SP1ProofWithPublicValues = bincode::deserialize(&proof.bytes)
+            .map_err(|e| ProofError::SerializationError(e.to_string()))?;
+        sp1_sdk::ProverClient::from_env()
+            .verify(&sp1_proof, &vk)
+            .map_err(|e| VerifyError::Rejected(e.to_string()))?;
+        if sp1_proof.public_values.as_slice() != proof.journal.as_slice() {
+            return Err(VerifyError::Rejected(
+                "journal differs from the proof's public values".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "verify-sp1"))]
+    fn verify_sp1(&self, proof: &ProofData) -> Result<(), VerifyError> {
+        Err(VerifyError::Unsupported {
+            backend: proof.backend,
+            reason: "built without the verify-sp1 feature",
+        })
+    }
+}
+
+/// Checks that each journal continues the previous one.
+pub fn check_linkage(journals: &[JournalFields]) -> Result<(), VerifyError> {
+    if journals.is_empty() {
+        return Err(VerifyError::Empty);
+    }
+    for (index, pair) in journals.windows(2).enumerate() {
+        let (prev, next) = (&pair[0], &pair[1]);
+        let reason = if next.prev_state_root != prev.new_state_root {
+            "starts from a different state root"
+        } else if next.oracle_root != prev.oracle_root {
+            "oracle root changed"
+        } else if next.seed_commitment != prev.seed_commitment {
+            "seed commitment changed"
+        } else if next.new_nonce <= prev.new_nonce {
+            "nonce did not advance"
+        } else {
+            continue;
+        };
+        return Err(VerifyError::Unlinked {
+            index: index + 1,
+            reason,
+        });
+    }
+    Ok(())
+}
+
+fn mismatch(
+    field: &'static str,
+    expected: &[u8; 32],
+    actual: &[u8; 32],
+) -> Result<(), VerifyError> {
+    if expected == actual {
+        return Ok(());
+    }
+    let hex = |bytes: &[u8; 32]| bytes.iter().map(|b| format!("{b:02x}")).collect();
+    Err(VerifyError::Mismatch {
+        field,
+        expected: hex(expected),
+        actual: hex(actual),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn journal(prev: u8, new: u8, new_nonce: u64) -> JournalFields {
+        JournalFields {
+            oracle_root: [1; 32],
+            seed_commitment: [2; 32],
+            prev_state_root: [prev; 32],
+            actions_root: [3; 32],
+            new_state_root: [new; 32],
+            new_nonce,
+        }
+    }
+
+    fn proof(fields: &JournalFields) -> ProofData {
+        let journal = fields.to_bytes().to_vec();
+        ProofData {
+            bytes: Vec::new(),
+            backend: ProofBackend::Native,
+            journal_digest: compute_journal_digest(&journal),
+            journal,
+        }
+    }
+
+    #[test]
+    fn chains_link_and_match_expectations() {
+        let chain = [proof(&journal(10, 11, 5)), proof(&journal(11, 12, 9))];
+        let verifier = ProofVerifier::default().allow_insecure(true);
+        let expected = JournalExpectations {
+            oracle_root: Some([1; 32]),
+            prev_state_root: Some([10; 32]),
+            new_nonce: Some(9),
+        };
+        assert_eq!(verifier.verify_chain(&chain, &expected).unwrap().len(), 2);
+
+        let err = verifier
+            .verify(
+                &chain[1],
+                &JournalExpectations {
+                    prev_state_root: Some([10; 32]),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                VerifyError::Mismatch {
+                    field: "prev_state_root",
+                    ..
+                }
+            ),
+            "{err}"
+        );
+
+        let reordered = [chain[1].clone(), chain[0].clone()];
+        let err = verifier
+            .verify_chain(&reordered, &JournalExpectations::default())
+            .unwrap_err();
+        assert!(
+            matches!(err, VerifyError::Unlinked { index: 1, .. }),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn insecure_and_tampered_proofs_are_rejected() {
+        let honest = proof(&journal(10, 11, 5));
+        let expected = JournalExpectations::default();
+        let err = ProofVerifier::default()
+            .verify(&honest, &expected)
+            .unwrap_err();
+        assert!(matches!(err, VerifyError::Insecure(ProofBackend::Native)));
+
+        let mut tampered = honest.clone();
+        tampered.journal[160] += 1;
+        let err = ProofVerifier::default()
+            .allow_insecure(true)
+            .verify(&tampered, &expected)
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                VerifyError::Journal(ProofError::JournalDigestMismatch { .. })
+            ),
+            "{err}"
+        );
+    }
+}